@@ -68,6 +68,9 @@ fn raphtory(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     add_functions!(
         algorithm_module,
         dijkstra_single_source_shortest_paths,
+        temporal_paths,
+        random_walks,
+        epidemic,
         global_reciprocity,
         all_local_reciprocity,
         triplet_count,
@@ -81,17 +84,47 @@ fn raphtory(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         max_in_degree,
         min_out_degree,
         min_in_degree,
+        degree_distribution,
+        eccentricity,
+        diameter,
+        approximate_diameter,
+        radius,
+        degree_assortativity,
+        property_assortativity,
         pagerank,
+        temporal_pagerank,
+        temporal_pagerank_checkpoints,
+        eigenvector_centrality,
+        katz_centrality,
         single_source_shortest_path,
         global_clustering_coefficient,
         temporally_reachable_nodes,
         local_clustering_coefficient,
         weakly_connected_components,
+        strongly_connected_components,
         global_temporal_three_node_motif,
         global_temporal_three_node_motif_multi,
         local_temporal_three_node_motifs,
+        temporal_cycles,
+        count_temporal_cycles,
+        random_vertex_sample,
+        random_edge_sample,
+        snowball_sample,
+        forest_fire_sample,
+        time_stratified_sample,
+        truss_numbers,
+        k_truss,
+        maximal_cliques,
         hits,
+        betweenness_centrality,
+        edge_betweenness_centrality,
+        closeness_centrality,
+        harmonic_centrality,
         balance,
+        louvain,
+        leiden,
+        link_prediction,
+        top_k_links,
     );
     m.add_submodule(algorithm_module)?;
 
@@ -116,6 +149,12 @@ fn raphtory(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         graph_gen_module,
         random_attachment,
         ba_preferential_attachment,
+        erdos_renyi,
+        watts_strogatz,
+        stochastic_block_model,
+        configuration_model,
+        activity_driven,
+        hawkes_edges,
     );
     m.add_submodule(graph_gen_module)?;
 
@@ -1658,6 +1658,19 @@ def test_deletions():
     assert list(g.edge(edges[0][1], edges[0][2]).explode().latest_time) == [10]
 
 
+def test_vertex_deletions():
+    g = GraphWithDeletions()
+    g.add_edge(0, 1, 2)
+    g.add_edge(0, 2, 3)
+    g.delete_vertex(5, 2)
+
+    assert g.window(1, 2).has_edge(1, 2)
+    assert not g.window(6, 10).has_vertex(2)
+    assert not g.window(6, 10).has_edge(1, 2)
+    assert not g.window(6, 10).has_edge(2, 3)
+    assert g.edge(1, 2).latest_time == 5
+
+
 def test_edge_layer():
     g = Graph()
     g.add_edge(1, 1, 2, layer="layer 1").add_constant_properties(
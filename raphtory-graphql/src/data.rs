@@ -1,6 +1,7 @@
 use parking_lot::RwLock;
 use raphtory::{
-    core::Prop,
+    core::{utils::errors::GraphError, Prop},
+    db::graph::logged_graph::{is_logged_graph, LoggedGraph},
     prelude::{Graph, GraphViewOps, PropertyAdditionOps},
     search::IndexedGraph,
     vectors::vectorized_graph::VectorizedGraph,
@@ -11,40 +12,76 @@ use std::{
 };
 use walkdir::WalkDir;
 
+/// A graph read from disk with its event log if it is stored as a logged graph directory
+pub(crate) type LoadedGraph = (IndexedGraph<Graph>, Option<LoggedGraph<Graph>>);
+
 #[derive(Default)]
 pub(crate) struct Data {
     pub(crate) graphs: RwLock<HashMap<String, IndexedGraph<Graph>>>,
+    /// Event logs of the graphs loaded from logged graph directories, updates to these graphs
+    /// are persisted by appending to the log
+    pub(crate) logs: RwLock<HashMap<String, LoggedGraph<Graph>>>,
     pub(crate) vector_stores: RwLock<HashMap<String, VectorizedGraph<Graph>>>,
 }
 
 impl Data {
     pub fn from_map(graphs: HashMap<String, Graph>) -> Self {
         let graphs = RwLock::new(Self::convert_graphs(graphs));
+        let logs = RwLock::new(HashMap::new());
         let vector_stores = RwLock::new(HashMap::new());
         Self {
             graphs,
+            logs,
             vector_stores,
         }
     }
 
     pub fn from_directory(directory_path: &str) -> Self {
-        let graphs = RwLock::new(Self::load_from_file(directory_path));
-        let vector_stores = RwLock::new(HashMap::new());
-        Self {
-            graphs,
-            vector_stores,
-        }
+        let data = Self::default();
+        data.insert_loaded(Self::load_from_file(directory_path));
+        data
     }
 
     pub fn from_map_and_directory(graphs: HashMap<String, Graph>, directory_path: &str) -> Self {
-        let mut graphs = Self::convert_graphs(graphs);
-        graphs.extend(Self::load_from_file(directory_path));
-        let graphs = RwLock::new(graphs);
-        let vector_stores = RwLock::new(HashMap::new());
-        Self {
-            graphs,
-            vector_stores,
+        let data = Self::from_map(graphs);
+        data.insert_loaded(Self::load_from_file(directory_path));
+        data
+    }
+
+    /// Register graphs returned by `load_from_file`, replacing graphs with the same name
+    pub(crate) fn insert_loaded(&self, loaded: HashMap<String, LoadedGraph>) {
+        let mut graphs = self.graphs.write();
+        let mut logs = self.logs.write();
+        for (name, (graph, log)) in loaded {
+            match log {
+                Some(log) => logs.insert(name.clone(), log),
+                None => logs.remove(&name),
+            };
+            graphs.insert(name, graph);
+        }
+    }
+
+    /// Write `graph` to `path` and register it as `name`.
+    ///
+    /// If `path` is a logged graph directory it gets a fresh snapshot and an empty event log,
+    /// otherwise the graph is written as a single bincode file.
+    pub(crate) fn store(
+        &self,
+        graphs: &mut HashMap<String, IndexedGraph<Graph>>,
+        name: String,
+        graph: Graph,
+        path: &str,
+    ) -> Result<(), GraphError> {
+        let mut logs = self.logs.write();
+        if is_logged_graph(path) {
+            let log = LoggedGraph::create(graph.clone(), path)?;
+            logs.insert(name.clone(), log);
+        } else {
+            graph.save_to_file(path)?;
+            logs.remove(&name);
         }
+        graphs.insert(name, graph.into());
+        Ok(())
     }
 
     fn convert_graphs(graphs: HashMap<String, Graph>) -> HashMap<String, IndexedGraph<Graph>> {
@@ -59,12 +96,23 @@ impl Data {
             .collect()
     }
 
-    pub fn load_from_file(path: &str) -> HashMap<String, IndexedGraph<Graph>> {
+    /// Load all graph files and logged graph directories below `path`
+    pub fn load_from_file(path: &str) -> HashMap<String, LoadedGraph> {
         let mut valid_paths = HashSet::<String>::new();
+        let mut logged_paths = HashSet::<String>::new();
 
-        for entry in WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
+        let mut entries = WalkDir::new(path).into_iter();
+        while let Some(entry) = entries.next() {
+            let Ok(entry) = entry else {
+                continue;
+            };
             let path = entry.path();
             let path_string = path.display().to_string();
+            if path.is_dir() && is_logged_graph(path) {
+                logged_paths.insert(path_string);
+                entries.skip_current_dir();
+                continue;
+            }
             let filename = path.file_name().and_then(|name| name.to_str());
             if let Some(filename) = filename {
                 if path.is_file() && !filename.starts_with('.') {
@@ -82,32 +130,45 @@ impl Data {
             }
         };
 
-        let graphs: HashMap<String, IndexedGraph<Graph>> = valid_paths
-            .into_iter()
-            .map(|path| {
-                println!("loading graph from {path}");
-                let graph = Graph::load_from_file(&path).expect("Unable to load from graph");
+        let files = valid_paths.into_iter().map(|path| {
+            println!("loading graph from {path}");
+            let graph = Graph::load_from_file(&path).expect("Unable to load from graph");
+            (path, graph, None)
+        });
+        let logged = logged_paths.into_iter().map(|path| {
+            println!("loading logged graph from {path}");
+            let log = LoggedGraph::<Graph>::open(&path).expect("Unable to load logged graph");
+            (path, log.graph().clone(), Some(log))
+        });
+
+        let graphs: HashMap<String, LoadedGraph> = files
+            .chain(logged)
+            .map(|(path, graph, log)| {
+                // the path is only known at runtime so it is not written to the event log
                 graph
                     .add_constant_properties([("path".to_string(), Prop::str(path.clone()))])
                     .expect("Failed to add static property");
                 let maybe_graph_name = graph.properties().get("name");
 
-                return match maybe_graph_name {
-                    None => {
-                        let graph_name = Path::new(&path).file_name().unwrap().to_str().unwrap();
-                        is_graph_already_loaded(graph_name.to_string());
-                        (graph_name.to_string(), graph)
-                    }
-                    Some(graph_name) => {
-                        is_graph_already_loaded(graph_name.to_string());
-                        (graph_name.to_string(), graph)
-                    }
+                let graph_name = match maybe_graph_name {
+                    None => Path::new(&path)
+                        .file_name()
+                        .unwrap()
+                        .to_str()
+                        .unwrap()
+                        .to_string(),
+                    Some(graph_name) => graph_name.to_string(),
                 };
+                is_graph_already_loaded(graph_name.clone());
+                (graph_name, (graph, log))
             })
-            .map(|(name, g)| {
+            .map(|(name, (g, log))| {
                 (
                     name,
-                    IndexedGraph::from_graph(&g).expect("Unable to index graph"),
+                    (
+                        IndexedGraph::from_graph(&g).expect("Unable to index graph"),
+                        log,
+                    ),
                 )
             })
             .collect();
@@ -10,10 +10,10 @@ pub mod server;
 mod data;
 #[derive(thiserror::Error, Debug)]
 pub enum UrlDecodeError {
-    #[error("Bincode operation failed")]
-    BincodeError {
+    #[error("Failed to decode graph")]
+    GraphError {
         #[from]
-        source: Box<bincode::ErrorKind>,
+        source: GraphError,
     },
     #[error("Base64 decoding failed")]
     DecodeError {
@@ -24,11 +24,11 @@ pub enum UrlDecodeError {
 
 pub fn url_encode_graph<G: Into<MaterializedGraph>>(graph: G) -> Result<String, GraphError> {
     let g: MaterializedGraph = graph.into();
-    Ok(BASE64_URL_SAFE_NO_PAD.encode(bincode::serialize(&g)?))
+    Ok(BASE64_URL_SAFE_NO_PAD.encode(g.bincode()?))
 }
 
 pub fn url_decode_graph<T: AsRef<[u8]>>(graph: T) -> Result<MaterializedGraph, UrlDecodeError> {
-    Ok(bincode::deserialize(
+    Ok(MaterializedGraph::from_bincode(
         &BASE64_URL_SAFE_NO_PAD.decode(graph)?,
     )?)
 }
@@ -39,7 +39,10 @@ mod graphql_test {
     use crate::{data::Data, model::App};
     use async_graphql::UploadValue;
     use dynamic_graphql::{Request, Variables};
-    use raphtory::{db::api::view::internal::IntoDynamic, prelude::*};
+    use raphtory::{
+        db::{api::view::internal::IntoDynamic, graph::logged_graph::LoggedGraph},
+        prelude::*,
+    };
     use serde_json::json;
     use std::collections::HashMap;
     use tempfile::tempdir;
@@ -196,6 +199,108 @@ mod graphql_test {
         );
     }
 
+    #[tokio::test]
+    async fn query_node_types() {
+        let graph = Graph::new();
+        graph
+            .add_vertex_with_type(0, "gandalf", NO_PROPS, Some("wizard"))
+            .expect("Could not add vertex!");
+        graph
+            .add_vertex(0, "frodo", [("type".to_string(), Prop::str("hobbit"))])
+            .expect("Could not add vertex!");
+        graph
+            .add_vertex(0, "treebeard", NO_PROPS)
+            .expect("Could not add vertex!");
+
+        let graphs = HashMap::from([("lotr".to_string(), graph)]);
+        let data = Data::from_map(graphs);
+        let schema = App::create_schema().data(data).finish().unwrap();
+
+        let query = r#"
+        {
+          graph(name: "lotr") {
+            nodes {
+              name
+              nodeType
+            }
+            hobbits: nodes(filter: { nodeType: { eq: "hobbit" } }) {
+              name
+            }
+            schema {
+              nodes {
+                typeName
+              }
+            }
+          }
+        }
+        "#;
+        let req = Request::new(query);
+        let res = schema.execute(req).await;
+        let data = res.data.into_json().unwrap();
+
+        assert_eq!(
+            data,
+            json!({
+                "graph": {
+                    "nodes": [
+                        { "name": "gandalf", "nodeType": "wizard" },
+                        { "name": "frodo", "nodeType": "hobbit" },
+                        { "name": "treebeard", "nodeType": "NONE" }
+                    ],
+                    "hobbits": [
+                        { "name": "frodo" }
+                    ],
+                    "schema": {
+                        "nodes": [
+                            { "typeName": "wizard" },
+                            { "typeName": "hobbit" }
+                        ]
+                    }
+                }
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn query_communities() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (6, 4), (3, 4)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        let graphs = HashMap::from([("triangles".to_string(), graph)]);
+        let data = Data::from_map(graphs);
+        let schema = App::create_schema().data(data).finish().unwrap();
+
+        for algo in ["louvain", "leiden"] {
+            let query = format!(
+                r#"
+                {{
+                  graph(name: "triangles") {{
+                    algorithms {{
+                      {algo}(resolution: 1.0) {{
+                        modularity
+                        assignments {{
+                          name
+                          community
+                        }}
+                      }}
+                    }}
+                  }}
+                }}
+                "#
+            );
+            let req = Request::new(query);
+            let res = schema.execute(req).await;
+            assert!(res.errors.is_empty(), "{:?}", res.errors);
+            let data = res.data.into_json().unwrap();
+            let communities = &data["graph"]["algorithms"][algo];
+            let modularity = communities["modularity"].as_f64().unwrap();
+            assert!((modularity - 5.0 / 14.0).abs() < 1e-9);
+            let assignments = communities["assignments"].as_array().unwrap();
+            assert_eq!(assignments.len(), 6);
+        }
+    }
+
     #[tokio::test]
     async fn query_properties() {
         let graph = Graph::new();
@@ -478,4 +583,49 @@ mod graphql_test {
         let graph_roundtrip = url_decode_graph(graph_encoded).unwrap().into_dynamic();
         assert_eq!(g, graph_roundtrip);
     }
+
+    #[tokio::test]
+    async fn test_logged_graph() {
+        let test_dir = tempdir().unwrap();
+        let graph_dir = test_dir.path().join("logged");
+        let g = LoggedGraph::create(Graph::new(), &graph_dir).unwrap();
+        g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        g.flush().unwrap();
+        drop(g);
+        let snapshot = std::fs::read(graph_dir.join("graph")).unwrap();
+
+        let data = Data::from_directory(test_dir.path().to_str().unwrap());
+        let schema = App::create_schema().data(data).finish().unwrap();
+
+        // the edge is only in the event log
+        let list_nodes = r#"
+        query {
+            graph(name: "logged") {
+                nodes {
+                    id
+                }
+            }
+        }
+        "#;
+        let res = schema.execute(Request::new(list_nodes)).await;
+        assert_eq!(res.errors.len(), 0);
+        assert_eq!(
+            res.data.into_json().unwrap(),
+            json!({"graph": {"nodes": [{"id": 1}, {"id": 2}]}})
+        );
+
+        let archive = r#"
+        mutation {
+            archiveGraph(graphName: "logged", parentGraphName: "logged", isArchive: 1)
+        }
+        "#;
+        let res = schema.execute(Request::new(archive)).await;
+        assert_eq!(res.errors.len(), 0);
+
+        // the update is appended to the log instead of rewriting the snapshot
+        assert_eq!(std::fs::read(graph_dir.join("graph")).unwrap(), snapshot);
+        let g = LoggedGraph::<Graph>::open(&graph_dir).unwrap();
+        assert_eq!(g.properties().get("isArchive"), Some(Prop::U8(1)));
+        assert_eq!(g.count_edges(), 1);
+    }
 }
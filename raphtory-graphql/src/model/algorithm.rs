@@ -9,7 +9,11 @@ use dynamic_graphql::{
 use once_cell::sync::Lazy;
 use ordered_float::OrderedFloat;
 use raphtory::{
-    algorithms::centrality::pagerank::unweighted_page_rank,
+    algorithms::{
+        algorithm_result::AlgorithmResult,
+        centrality::pagerank::unweighted_page_rank,
+        community_detection::{leiden::leiden, louvain::louvain},
+    },
     db::api::view::{internal::DynamicGraph, GraphViewOps},
 };
 use std::{borrow::Cow, collections::HashMap, sync::Mutex};
@@ -34,7 +38,11 @@ impl Register for Algorithms {
         let mut registry = registry;
         let mut object = Object::new("Algorithms");
 
-        let algos = HashMap::from([("pagerank", Pagerank::register_algo)]);
+        let algos = HashMap::from([
+            ("pagerank", Pagerank::register_algo as RegisterFunction),
+            ("louvain", Louvain::register_algo),
+            ("leiden", Leiden::register_algo),
+        ]);
         for (name, register_algo) in algos {
             (registry, object) = register_algo(name, registry, object);
         }
@@ -139,3 +147,95 @@ impl Algorithm for Pagerank {
         Ok(Some(FieldValue::list(result)))
     }
 }
+
+#[derive(SimpleObject)]
+struct CommunityAssignment {
+    name: String,
+    community: u64,
+}
+
+#[derive(SimpleObject)]
+struct Communities {
+    modularity: f64,
+    assignments: Vec<CommunityAssignment>,
+}
+
+impl From<(AlgorithmResult<String, u64>, f64)> for Communities {
+    fn from((result, modularity): (AlgorithmResult<String, u64>, f64)) -> Self {
+        let assignments = result
+            .into_iter()
+            .map(|(name, community)| CommunityAssignment { name, community })
+            .collect();
+        Self {
+            modularity,
+            assignments,
+        }
+    }
+}
+
+fn community_args<'a>() -> Vec<(&'a str, TypeRef)> {
+    vec![
+        ("weight", TypeRef::named(TypeRef::STRING)),
+        ("resolution", TypeRef::named(TypeRef::FLOAT)),
+    ]
+}
+
+fn community_params(ctx: &ResolverContext) -> FieldResult<(Option<String>, f64)> {
+    let weight = ctx
+        .args
+        .get("weight")
+        .map(|v| v.string().map(|s| s.to_string()))
+        .transpose()?;
+    let resolution = ctx.args.get("resolution").map(|v| v.f64()).transpose()?;
+    Ok((weight, resolution.unwrap_or(1.0)))
+}
+
+struct Louvain;
+
+impl Register for Louvain {
+    fn register(registry: Registry) -> Registry {
+        registry.register::<Communities>()
+    }
+}
+
+impl Algorithm for Louvain {
+    fn output_type() -> TypeRef {
+        TypeRef::named_nn(Communities::get_type_name())
+    }
+    fn args<'a>() -> Vec<(&'a str, TypeRef)> {
+        community_args()
+    }
+    fn apply_algo<'a, G: GraphViewOps>(
+        graph: &G,
+        ctx: ResolverContext,
+    ) -> FieldResult<Option<FieldValue<'a>>> {
+        let (weight, resolution) = community_params(&ctx)?;
+        let communities = Communities::from(louvain(graph, weight.as_deref(), resolution));
+        Ok(Some(FieldValue::owned_any(communities)))
+    }
+}
+
+struct Leiden;
+
+impl Register for Leiden {
+    fn register(registry: Registry) -> Registry {
+        registry.register::<Communities>()
+    }
+}
+
+impl Algorithm for Leiden {
+    fn output_type() -> TypeRef {
+        TypeRef::named_nn(Communities::get_type_name())
+    }
+    fn args<'a>() -> Vec<(&'a str, TypeRef)> {
+        community_args()
+    }
+    fn apply_algo<'a, G: GraphViewOps>(
+        graph: &G,
+        ctx: ResolverContext,
+    ) -> FieldResult<Option<FieldValue<'a>>> {
+        let (weight, resolution) = community_params(&ctx)?;
+        let communities = Communities::from(leiden(graph, weight.as_deref(), resolution));
+        Ok(Some(FieldValue::owned_any(communities)))
+    }
+}
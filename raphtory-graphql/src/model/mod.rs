@@ -88,7 +88,7 @@ impl QueryRoot {
             .cloned()
             .ok_or(MissingGraph)?
             .materialize()?;
-        let bincode = bincode::serialize(&g)?;
+        let bincode = g.bincode()?;
         Ok(URL_SAFE_NO_PAD.encode(bincode))
     }
 
@@ -145,8 +145,7 @@ impl Mut {
     async fn load_graphs_from_path<'a>(ctx: &Context<'a>, path: String) -> Vec<String> {
         let new_graphs = Data::load_from_file(&path);
         let keys: Vec<_> = new_graphs.keys().cloned().collect();
-        let mut data = ctx.data_unchecked::<Data>().graphs.write();
-        data.extend(new_graphs);
+        ctx.data_unchecked::<Data>().insert_loaded(new_graphs);
         keys
     }
 
@@ -157,7 +156,8 @@ impl Mut {
         new_graph_name: String,
     ) -> Result<bool> {
         if new_graph_name.ne(&graph_name) && parent_graph_name.ne(&graph_name) {
-            let mut data = ctx.data_unchecked::<Data>().graphs.write();
+            let store = ctx.data_unchecked::<Data>();
+            let mut data = store.graphs.write();
 
             let subgraph = data.get(&graph_name).ok_or("Graph not found")?;
             let path = subgraph
@@ -188,15 +188,12 @@ impl Mut {
             new_subgraph
                 .update_constant_properties([("lastUpdated", Prop::I64(timestamp * 1000))])?;
 
-            new_subgraph.save_to_file(path)?;
-
-            let gi: IndexedGraph<Graph> = new_subgraph
+            let new_subgraph = new_subgraph
                 .into_events()
-                .ok_or("Graph with deletions not supported")?
-                .into();
-
-            data.insert(new_graph_name, gi);
+                .ok_or("Graph with deletions not supported")?;
+            store.store(&mut data, new_graph_name, new_subgraph, &path)?;
             data.remove(&graph_name);
+            store.logs.write().remove(&graph_name);
         }
 
         Ok(true)
@@ -210,7 +207,8 @@ impl Mut {
         props: String,
         graph_nodes: Vec<String>,
     ) -> Result<bool> {
-        let mut data = ctx.data_unchecked::<Data>().graphs.write();
+        let store = ctx.data_unchecked::<Data>();
+        let mut data = store.graphs.write();
 
         let subgraph = data.get(&graph_name).ok_or("Graph not found")?;
         let mut path = subgraph
@@ -278,14 +276,10 @@ impl Mut {
         new_subgraph.update_constant_properties([("lastUpdated", Prop::I64(timestamp * 1000))])?;
         new_subgraph.update_constant_properties([("uiProps", Prop::Str(props.into()))])?;
 
-        new_subgraph.save_to_file(path)?;
-
-        let gi: IndexedGraph<Graph> = new_subgraph
+        let new_subgraph = new_subgraph
             .into_events()
-            .ok_or("Graph with deletions not supported")?
-            .into();
-
-        data.insert(new_graph_name, gi);
+            .ok_or("Graph with deletions not supported")?;
+        store.store(&mut data, new_graph_name, new_subgraph, &path)?;
 
         Ok(true)
     }
@@ -295,13 +289,13 @@ impl Mut {
     /// Returns::
     ///   list of names for newly added graphs
     async fn load_new_graphs_from_path<'a>(ctx: &Context<'a>, path: String) -> Vec<String> {
-        let mut data = ctx.data_unchecked::<Data>().graphs.write();
+        let data = ctx.data_unchecked::<Data>();
         let new_graphs: HashMap<_, _> = Data::load_from_file(&path)
             .into_iter()
-            .filter(|(key, _)| !data.contains_key(key))
+            .filter(|(key, _)| !data.graphs.read().contains_key(key))
             .collect();
         let keys: Vec<_> = new_graphs.keys().cloned().collect();
-        data.extend(new_graphs);
+        data.insert_loaded(new_graphs);
         keys
     }
 
@@ -310,8 +304,8 @@ impl Mut {
     /// Returns::
     ///    name of the new graph
     async fn upload_graph<'a>(ctx: &Context<'a>, name: String, graph: Upload) -> Result<String> {
-        let g: MaterializedGraph =
-            bincode::deserialize_from(BufReader::new(graph.value(ctx)?.content))?;
+        let g =
+            MaterializedGraph::load_from_reader(&mut BufReader::new(graph.value(ctx)?.content))?;
         let gi: IndexedGraph<Graph> = g
             .into_events()
             .ok_or("Graph with deletions not supported")?
@@ -326,7 +320,7 @@ impl Mut {
     /// Returns::
     ///    name of the new graph
     async fn send_graph<'a>(ctx: &Context<'a>, name: String, graph: String) -> Result<String> {
-        let g: MaterializedGraph = bincode::deserialize(&URL_SAFE_NO_PAD.decode(graph)?)?;
+        let g = MaterializedGraph::from_bincode(&URL_SAFE_NO_PAD.decode(graph)?)?;
         let mut data = ctx.data_unchecked::<Data>().graphs.write();
         data.insert(
             name.clone(),
@@ -343,7 +337,14 @@ impl Mut {
         parent_graph_name: String,
         is_archive: u8,
     ) -> Result<bool> {
-        let mut data = ctx.data_unchecked::<Data>().graphs.write();
+        let store = ctx.data_unchecked::<Data>();
+        // logged graphs are archived in place by appending the update to their event log
+        if let Some(log) = store.logs.read().get(&graph_name) {
+            log.update_constant_properties([("isArchive", Prop::U8(is_archive))])?;
+            log.flush()?;
+            return Ok(true);
+        }
+        let mut data = store.graphs.write();
 
         let subgraph = data.get(&graph_name).ok_or("Graph not found")?;
 
@@ -366,14 +367,11 @@ impl Mut {
             .collect_vec();
         new_subgraph.update_constant_properties(static_props_without_isactive)?;
         new_subgraph.update_constant_properties([("isArchive", Prop::U8(is_archive))])?;
-        new_subgraph.save_to_file(path)?;
 
-        let gi: IndexedGraph<Graph> = new_subgraph
+        let new_subgraph = new_subgraph
             .into_events()
-            .ok_or("Graph with deletions not supported")?
-            .into();
-
-        data.insert(graph_name, gi);
+            .ok_or("Graph with deletions not supported")?;
+        store.store(&mut data, graph_name, new_subgraph, &path)?;
 
         Ok(true)
     }
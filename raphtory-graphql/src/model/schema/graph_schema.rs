@@ -1,9 +1,9 @@
-use crate::model::schema::{layer_schema::LayerSchema, node_schema::NodeSchema};
+use crate::model::schema::{layer_schema::LayerSchema, node_schema::NodeSchema, vertex_type_name};
 use dynamic_graphql::SimpleObject;
 use itertools::Itertools;
 use raphtory::{
     db::api::view::internal::DynamicGraph,
-    prelude::{GraphViewOps, LayerOps, VertexViewOps},
+    prelude::{GraphViewOps, LayerOps},
 };
 
 #[derive(SimpleObject)]
@@ -17,7 +17,7 @@ impl GraphSchema {
         let nodes = graph
             .vertices()
             .iter()
-            .filter_map(|vertex| vertex.properties().get("type").map(|p| p.to_string()))
+            .filter_map(|vertex| vertex_type_name(&vertex))
             .unique()
             .map(|node_type| NodeSchema::new(node_type, graph.clone()))
             .collect_vec();
@@ -12,10 +12,16 @@ pub(crate) mod property_schema;
 
 const ENUM_BOUNDARY: usize = 20;
 
-fn get_vertex_type<G: GraphViewOps>(vertex: VertexView<G>) -> String {
-    let prop = vertex.properties().get("type");
-    prop.map(|prop| prop.to_string())
-        .unwrap_or_else(|| "NONE".to_string())
+/// The type of a vertex, falling back to its `"type"` property for graphs that predate vertex types
+pub(crate) fn vertex_type_name<G: GraphViewOps>(vertex: &VertexView<G>) -> Option<String> {
+    vertex
+        .vertex_type()
+        .map(|vertex_type| vertex_type.to_string())
+        .or_else(|| vertex.properties().get("type").map(|prop| prop.to_string()))
+}
+
+pub(crate) fn get_vertex_type<G: GraphViewOps>(vertex: VertexView<G>) -> String {
+    vertex_type_name(&vertex).unwrap_or_else(|| "NONE".to_string())
 }
 
 type SchemaAggregate = HashMap<String, HashSet<String>>;
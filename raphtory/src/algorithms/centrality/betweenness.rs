@@ -0,0 +1,406 @@
+//! Brandes' algorithm for vertex and edge betweenness centrality, together with the shortest
+//! path search shared with [`super::closeness`].
+//!
+//! Paths follow the direction of the edges. With a weight, the numeric edge property with that
+//! name is used as the length of an edge (edges without the property have length 1), weights
+//! need to be non-negative. Without a weight all edges have length 1.
+
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    core::entities::VID,
+    db::task::{custom_pool, POOL},
+    prelude::{EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use ordered_float::OrderedFloat;
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+};
+
+/// The edges of a view in compressed sparse row format over the vertices `0..len()`
+pub(crate) struct PathGraph {
+    pub(crate) names: Vec<String>,
+    offsets: Vec<usize>,
+    src: Vec<usize>,
+    dst: Vec<usize>,
+    lengths: Option<Vec<f64>>,
+}
+
+/// Result of a single source shortest path search
+pub(crate) struct ShortestPaths {
+    /// vertices in the order they were settled (non-decreasing distance)
+    pub(crate) order: Vec<usize>,
+    /// distance from the source, `f64::INFINITY` if not reachable
+    pub(crate) dist: Vec<f64>,
+    /// number of shortest paths from the source
+    sigma: Vec<f64>,
+    /// last edge of the shortest paths to each vertex
+    preds: Vec<Vec<usize>>,
+}
+
+impl PathGraph {
+    pub(crate) fn from_view<G: GraphViewOps>(graph: &G, weight: Option<&str>) -> Self {
+        let vertices: Vec<_> = graph.vertices().iter().collect();
+        let index: FxHashMap<VID, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.vertex, i))
+            .collect();
+        let mut offsets = Vec::with_capacity(vertices.len() + 1);
+        let mut src = vec![];
+        let mut dst = vec![];
+        let mut lengths = weight.map(|_| vec![]);
+        offsets.push(0);
+        for (i, v) in vertices.iter().enumerate() {
+            for e in v.out_edges() {
+                if let Some(n) = index.get(&e.dst().vertex) {
+                    src.push(i);
+                    dst.push(*n);
+                    if let (Some(lengths), Some(name)) = (lengths.as_mut(), weight) {
+                        let length = e.properties().get(name).and_then(|p| p.as_f64());
+                        lengths.push(length.unwrap_or(1.0));
+                    }
+                }
+            }
+            offsets.push(dst.len());
+        }
+        let names = vertices.iter().map(|v| v.name()).collect();
+        Self {
+            names,
+            offsets,
+            src,
+            dst,
+            lengths,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub(crate) fn num_edges(&self) -> usize {
+        self.dst.len()
+    }
+
+    fn out_edges(&self, v: usize) -> std::ops::Range<usize> {
+        self.offsets[v]..self.offsets[v + 1]
+    }
+
+    /// Breadth-first search if the graph is unweighted, Dijkstra's algorithm otherwise
+    pub(crate) fn shortest_paths(&self, source: usize) -> ShortestPaths {
+        let n = self.len();
+        let mut paths = ShortestPaths {
+            order: Vec::with_capacity(n),
+            dist: vec![f64::INFINITY; n],
+            sigma: vec![0.0; n],
+            preds: vec![vec![]; n],
+        };
+        paths.dist[source] = 0.0;
+        paths.sigma[source] = 1.0;
+        match &self.lengths {
+            None => {
+                let mut queue = VecDeque::from([source]);
+                while let Some(v) = queue.pop_front() {
+                    paths.order.push(v);
+                    let d = paths.dist[v] + 1.0;
+                    for e in self.out_edges(v) {
+                        let w = self.dst[e];
+                        if paths.dist[w] == f64::INFINITY {
+                            paths.dist[w] = d;
+                            queue.push_back(w);
+                        }
+                        if paths.dist[w] == d {
+                            paths.sigma[w] += paths.sigma[v];
+                            paths.preds[w].push(e);
+                        }
+                    }
+                }
+            }
+            Some(lengths) => {
+                let mut settled = vec![false; n];
+                let mut heap = BinaryHeap::from([(Reverse(OrderedFloat(0.0)), source)]);
+                while let Some((Reverse(OrderedFloat(d)), v)) = heap.pop() {
+                    if settled[v] || d > paths.dist[v] {
+                        continue;
+                    }
+                    settled[v] = true;
+                    paths.order.push(v);
+                    for e in self.out_edges(v) {
+                        let w = self.dst[e];
+                        let dw = d + lengths[e];
+                        if dw < paths.dist[w] {
+                            paths.dist[w] = dw;
+                            paths.sigma[w] = paths.sigma[v];
+                            paths.preds[w].clear();
+                            paths.preds[w].push(e);
+                            heap.push((Reverse(OrderedFloat(dw)), w));
+                        } else if dw == paths.dist[w] && !settled[w] {
+                            paths.sigma[w] += paths.sigma[v];
+                            paths.preds[w].push(e);
+                        }
+                    }
+                }
+            }
+        }
+        paths
+    }
+
+    /// Add the dependencies of the vertices and edges on the paths from a single source
+    fn accumulate(&self, source: usize, vertex_bc: &mut [f64], edge_bc: &mut [f64]) {
+        let paths = self.shortest_paths(source);
+        let mut delta = vec![0.0; self.len()];
+        for &w in paths.order.iter().rev() {
+            for &e in &paths.preds[w] {
+                let v = self.src[e];
+                let c = paths.sigma[v] / paths.sigma[w] * (1.0 + delta[w]);
+                edge_bc[e] += c;
+                delta[v] += c;
+            }
+            if w != source {
+                vertex_bc[w] += delta[w];
+            }
+        }
+    }
+
+    /// Betweenness of all vertices and edges, from all or `k` randomly chosen sources
+    fn betweenness(
+        &self,
+        k: Option<usize>,
+        seed: Option<u64>,
+        threads: Option<usize>,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let n = self.len();
+        let sources: Vec<usize> = match k {
+            Some(k) if k < n => {
+                let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+                sample(&mut rng, n, k).into_vec()
+            }
+            _ => (0..n).collect(),
+        };
+        let pool = threads.map(custom_pool).unwrap_or_else(|| POOL.clone());
+        let (mut vertex_bc, mut edge_bc) = pool.install(|| {
+            sources
+                .par_iter()
+                .fold(
+                    || (vec![0.0; n], vec![0.0; self.num_edges()]),
+                    |(mut vertex_bc, mut edge_bc), s| {
+                        self.accumulate(*s, &mut vertex_bc, &mut edge_bc);
+                        (vertex_bc, edge_bc)
+                    },
+                )
+                .reduce(
+                    || (vec![0.0; n], vec![0.0; self.num_edges()]),
+                    |(mut va, mut ea), (vb, eb)| {
+                        va.iter_mut().zip(vb).for_each(|(a, b)| *a += b);
+                        ea.iter_mut().zip(eb).for_each(|(a, b)| *a += b);
+                        (va, ea)
+                    },
+                )
+        });
+        if !sources.is_empty() && sources.len() < n {
+            let scale = n as f64 / sources.len() as f64;
+            vertex_bc.iter_mut().for_each(|v| *v *= scale);
+            edge_bc.iter_mut().for_each(|v| *v *= scale);
+        }
+        (vertex_bc, edge_bc)
+    }
+}
+
+/// Computes the betweenness centrality of the vertices of a graph
+///
+/// The betweenness of a vertex is the sum over all pairs of other vertices of the fraction of
+/// shortest paths between them that pass through the vertex.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `k` - Estimate the betweenness from `k` randomly sampled source vertices instead of all
+///   vertices (the result is scaled by `n / k`)
+/// * `normalized` - Divide the result by `(n - 1)(n - 2)`, the number of pairs of other vertices
+/// * `weight` - The name of a numeric edge property to use as edge length, all edges have
+///   length 1 if `None`
+/// * `seed` - Seed for the sampling of source vertices
+/// * `threads` - Number of threads to use
+///
+/// Returns:
+///
+/// An AlgorithmResult mapping each vertex to its betweenness
+///
+pub fn betweenness_centrality<G: GraphViewOps>(
+    graph: &G,
+    k: Option<usize>,
+    normalized: bool,
+    weight: Option<&str>,
+    seed: Option<u64>,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
+    let g = PathGraph::from_view(graph, weight);
+    let (mut bc, _) = g.betweenness(k, seed, threads);
+    let n = g.len() as f64;
+    if normalized && n > 2.0 {
+        let scale = 1.0 / ((n - 1.0) * (n - 2.0));
+        bc.iter_mut().for_each(|v| *v *= scale);
+    }
+    let results_type = std::any::type_name::<HashMap<String, f64>>();
+    let res = g.names.into_iter().zip(bc).collect();
+    AlgorithmResult::new("Betweenness Centrality", results_type, res)
+}
+
+/// Computes the betweenness centrality of the edges of a graph
+///
+/// The betweenness of an edge is the sum over all pairs of vertices of the fraction of shortest
+/// paths between them that use the edge.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `k` - Estimate the betweenness from `k` randomly sampled source vertices instead of all
+///   vertices (the result is scaled by `n / k`)
+/// * `normalized` - Divide the result by `n(n - 1)`, the number of pairs of vertices
+/// * `weight` - The name of a numeric edge property to use as edge length, all edges have
+///   length 1 if `None`
+/// * `seed` - Seed for the sampling of source vertices
+/// * `threads` - Number of threads to use
+///
+/// Returns:
+///
+/// An AlgorithmResult mapping the names of the source and destination of each edge to its
+/// betweenness
+///
+pub fn edge_betweenness_centrality<G: GraphViewOps>(
+    graph: &G,
+    k: Option<usize>,
+    normalized: bool,
+    weight: Option<&str>,
+    seed: Option<u64>,
+    threads: Option<usize>,
+) -> AlgorithmResult<(String, String), f64, OrderedFloat<f64>> {
+    let g = PathGraph::from_view(graph, weight);
+    let (_, mut bc) = g.betweenness(k, seed, threads);
+    let n = g.len() as f64;
+    if normalized && n > 1.0 {
+        let scale = 1.0 / (n * (n - 1.0));
+        bc.iter_mut().for_each(|v| *v *= scale);
+    }
+    let results_type = std::any::type_name::<HashMap<(String, String), f64>>();
+    let res = bc
+        .into_iter()
+        .enumerate()
+        .map(|(e, v)| ((g.names[g.src[e]].clone(), g.names[g.dst[e]].clone()), v))
+        .collect();
+    AlgorithmResult::new("Edge Betweenness Centrality", results_type, res)
+}
+
+#[cfg(test)]
+mod betweenness_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn assert_close(result: &HashMap<String, f64>, expected: &[(u64, f64)]) {
+        assert_eq!(result.len(), expected.len());
+        for (v, value) in expected {
+            let actual = result[&v.to_string()];
+            assert!((actual - value).abs() < 1e-9, "{v}: {actual} != {value}");
+        }
+    }
+
+    fn path_graph() -> Graph {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (3, 4), (4, 5)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn betweenness_on_path() {
+        let graph = path_graph();
+        let result = betweenness_centrality(&graph, None, false, None, None, None);
+        assert_close(
+            result.get_all(),
+            &[(1, 0.0), (2, 3.0), (3, 4.0), (4, 3.0), (5, 0.0)],
+        );
+        let result = betweenness_centrality(&graph, None, true, None, None, Some(2));
+        assert_close(
+            result.get_all(),
+            &[(1, 0.0), (2, 0.25), (3, 1.0 / 3.0), (4, 0.25), (5, 0.0)],
+        );
+    }
+
+    #[test]
+    fn betweenness_counts_path_fractions() {
+        // two shortest paths from 1 to 4, through 2 and 3
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (1, 3), (2, 4), (3, 4)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        let result = betweenness_centrality(&graph, None, false, None, None, None);
+        assert_close(result.get_all(), &[(1, 0.0), (2, 0.5), (3, 0.5), (4, 0.0)]);
+
+        let result = edge_betweenness_centrality(&graph, None, false, None, None, None);
+        let expected = [((1, 2), 1.5), ((1, 3), 1.5), ((2, 4), 1.5), ((3, 4), 1.5)];
+        assert_eq!(result.get_all().len(), expected.len());
+        for ((src, dst), value) in expected {
+            let key = (src.to_string(), dst.to_string());
+            assert!((result.get_all()[&key] - value).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn weighted_betweenness() {
+        let graph = Graph::new();
+        for (src, dst, w) in [(1, 2, 1.0), (2, 4, 1.0), (1, 3, 0.5), (3, 4, 0.5)] {
+            graph.add_edge(0, src, dst, [("w", w)], None).unwrap();
+        }
+        let result = betweenness_centrality(&graph, None, false, Some("w"), None, None);
+        assert_close(result.get_all(), &[(1, 0.0), (2, 0.0), (3, 1.0), (4, 0.0)]);
+    }
+
+    #[test]
+    fn betweenness_on_views() {
+        let graph = path_graph();
+        graph.add_edge(5, 1, 5, NO_PROPS, Some("shortcut")).unwrap();
+        let result = betweenness_centrality(&graph.window(0, 5), None, false, None, None, None);
+        assert_close(
+            result.get_all(),
+            &[(1, 0.0), (2, 3.0), (3, 4.0), (4, 3.0), (5, 0.0)],
+        );
+        let result = betweenness_centrality(
+            &graph.layer("_default").unwrap(),
+            None,
+            false,
+            None,
+            None,
+            None,
+        );
+        assert_close(
+            result.get_all(),
+            &[(1, 0.0), (2, 3.0), (3, 4.0), (4, 3.0), (5, 0.0)],
+        );
+        let result =
+            betweenness_centrality(&graph.subgraph([1, 2, 3]), None, false, None, None, None);
+        assert_close(result.get_all(), &[(1, 0.0), (2, 1.0), (3, 0.0)]);
+    }
+
+    #[test]
+    fn sampled_betweenness() {
+        let graph = path_graph();
+        let exact = betweenness_centrality(&graph, None, false, None, None, None);
+        let all = betweenness_centrality(&graph, Some(5), false, None, Some(42), None);
+        assert_close(
+            all.get_all(),
+            &exact
+                .get_all()
+                .iter()
+                .map(|(k, v)| (k.parse().unwrap(), *v))
+                .collect::<Vec<_>>(),
+        );
+        let sampled = betweenness_centrality(&graph, Some(2), false, None, Some(42), None);
+        let again = betweenness_centrality(&graph, Some(2), false, None, Some(42), None);
+        assert_eq!(sampled.get_all(), again.get_all());
+        assert!(sampled.get_all().values().all(|v| *v >= 0.0));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    algorithms::{algorithm_result::AlgorithmResult, centrality::betweenness::PathGraph},
+    db::task::{custom_pool, POOL},
+    prelude::GraphViewOps,
+};
+use ordered_float::OrderedFloat;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Apply `f` to the distances from every vertex in parallel
+fn from_distances<G: GraphViewOps>(
+    graph: &G,
+    weight: Option<&str>,
+    threads: Option<usize>,
+    algo_name: &str,
+    f: impl Fn(&[f64], usize) -> f64 + Send + Sync,
+) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
+    let g = PathGraph::from_view(graph, weight);
+    let pool = threads.map(custom_pool).unwrap_or_else(|| POOL.clone());
+    let values: Vec<f64> = pool.install(|| {
+        (0..g.len())
+            .into_par_iter()
+            .map(|v| {
+                let paths = g.shortest_paths(v);
+                let dist: Vec<f64> = paths.order.iter().map(|w| paths.dist[*w]).collect();
+                f(&dist, g.len())
+            })
+            .collect()
+    });
+    let results_type = std::any::type_name::<HashMap<String, f64>>();
+    let res = g.names.into_iter().zip(values).collect();
+    AlgorithmResult::new(algo_name, results_type, res)
+}
+
+/// Computes the closeness centrality of the vertices of a graph
+///
+/// The closeness of a vertex is the inverse of the average distance to the vertices it can
+/// reach by following the direction of the edges. To make the values of vertices that can reach
+/// different numbers of vertices comparable, the result is multiplied by the fraction of the
+/// other vertices that are reachable (Wasserman and Faust).
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `weight` - The name of a numeric edge property to use as edge length, all edges have
+///   length 1 if `None`
+/// * `threads` - Number of threads to use
+///
+/// Returns:
+///
+/// An AlgorithmResult mapping each vertex to its closeness
+///
+pub fn closeness_centrality<G: GraphViewOps>(
+    graph: &G,
+    weight: Option<&str>,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
+    from_distances(graph, weight, threads, "Closeness Centrality", |dist, n| {
+        let total: f64 = dist.iter().sum();
+        let reachable = (dist.len() - 1) as f64;
+        if total > 0.0 && n > 1 {
+            (reachable / total) * (reachable / (n - 1) as f64)
+        } else {
+            0.0
+        }
+    })
+}
+
+/// Computes the harmonic centrality of the vertices of a graph
+///
+/// The harmonic centrality of a vertex is the sum of the inverse distances to all other
+/// vertices, following the direction of the edges. Vertices that are not reachable contribute 0.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `weight` - The name of a numeric edge property to use as edge length, all edges have
+///   length 1 if `None`
+/// * `threads` - Number of threads to use
+///
+/// Returns:
+///
+/// An AlgorithmResult mapping each vertex to its harmonic centrality
+///
+pub fn harmonic_centrality<G: GraphViewOps>(
+    graph: &G,
+    weight: Option<&str>,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
+    from_distances(graph, weight, threads, "Harmonic Centrality", |dist, _| {
+        dist.iter().filter(|d| **d > 0.0).map(|d| 1.0 / d).sum()
+    })
+}
+
+#[cfg(test)]
+mod closeness_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn assert_close(result: &HashMap<String, f64>, expected: &[(u64, f64)]) {
+        assert_eq!(result.len(), expected.len());
+        for (v, value) in expected {
+            let actual = result[&v.to_string()];
+            assert!((actual - value).abs() < 1e-9, "{v}: {actual} != {value}");
+        }
+    }
+
+    #[test]
+    fn closeness_on_path() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (3, 4)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        let result = closeness_centrality(&graph, None, None);
+        assert_close(
+            result.get_all(),
+            &[(1, 0.5), (2, 4.0 / 9.0), (3, 1.0 / 3.0), (4, 0.0)],
+        );
+        let result = harmonic_centrality(&graph, None, None);
+        assert_close(
+            result.get_all(),
+            &[(1, 1.0 + 0.5 + 1.0 / 3.0), (2, 1.5), (3, 1.0), (4, 0.0)],
+        );
+    }
+
+    #[test]
+    fn weighted_closeness_on_window() {
+        let graph = Graph::new();
+        for (src, dst, w) in [(1, 2, 2.0), (1, 3, 4.0), (2, 3, 1.0)] {
+            graph.add_edge(0, src, dst, [("w", w)], None).unwrap();
+        }
+        graph.add_edge(5, 3, 1, [("w", 1.0)], None).unwrap();
+        let window = graph.window(0, 5);
+        let result = closeness_centrality(&window, Some("w"), None);
+        assert_close(result.get_all(), &[(1, 2.0 / 5.0), (2, 0.5), (3, 0.0)]);
+        let result = harmonic_centrality(&window, Some("w"), None);
+        assert_close(
+            result.get_all(),
+            &[(1, 0.5 + 1.0 / 3.0), (2, 1.0), (3, 0.0)],
+        );
+    }
+}
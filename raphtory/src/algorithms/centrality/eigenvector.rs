@@ -0,0 +1,240 @@
+use crate::{
+    algorithms::{
+        algorithm_result::AlgorithmResult,
+        centrality::pagerank::{edge_weight, WeightAggregation},
+    },
+    core::{
+        entities::{vertices::vertex_ref::VertexRef, VID},
+        state::{accumulator_id::accumulators, compute_state::ComputeStateVec},
+    },
+    db::{
+        api::view::{EdgeViewOps, GraphViewOps, VertexViewOps},
+        task::{
+            context::Context,
+            task::{ATask, Job, Step},
+            task_runner::TaskRunner,
+        },
+    },
+};
+use ordered_float::OrderedFloat;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default)]
+struct EigenvectorState {
+    score: f64,
+}
+
+/// Eigenvector Centrality:
+/// The centrality of a vertex is proportional to the sum of the centralities of the vertices
+/// pointing to it, i.e. the scores form the principal eigenvector of the (transposed) adjacency matrix.
+///
+/// The scores are computed by power iteration on `A + I`, which has the same principal eigenvector
+/// but also converges on bipartite graphs, and are normalised to unit L2 norm after every iteration.
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `iter_count`: Maximum number of iterations to run the algorithm for
+/// * `threads`: Number of threads to use for parallel execution
+/// * `tol`: The tolerance value for convergence, the algorithm stops once the sum of the absolute
+///   changes of the scores is below `tol * n`
+/// * `weight`: The name of a numeric edge property and how its values in the view are combined
+///   into the weight of an edge, all edges have weight 1 if `None`
+///
+/// Result:
+///
+/// * An AlgorithmResult object containing the mapping from vertex ID to the eigenvector centrality of the vertex
+///
+pub fn eigenvector_centrality<G: GraphViewOps>(
+    g: &G,
+    iter_count: usize,
+    threads: Option<usize>,
+    tol: Option<f64>,
+    weight: Option<(&str, WeightAggregation)>,
+) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
+    let n = g.count_vertices();
+
+    let mut ctx: Context<G, ComputeStateVec> = g.into();
+
+    let init = vec![
+        EigenvectorState {
+            score: 1f64 / n as f64
+        };
+        g.unfiltered_num_vertices()
+    ];
+
+    let tol: f64 = tol.unwrap_or(0.000001f64);
+
+    let sum_of_squares = accumulators::sum::<f64>(0);
+    let total_diff = accumulators::sum::<f64>(1);
+
+    ctx.global_agg_reset(sum_of_squares);
+    ctx.global_agg_reset(total_diff);
+
+    let weight = weight.map(|(name, aggregation)| (name.to_owned(), aggregation));
+
+    let step1: ATask<G, ComputeStateVec, EigenvectorState, _> = ATask::new(move |s| {
+        let prev: &EigenvectorState = s.prev();
+        let mut score = prev.score;
+        match &weight {
+            None => {
+                for t in s.in_neighbours() {
+                    score += t.prev().score;
+                }
+            }
+            Some((name, aggregation)) => {
+                for e in s.in_edges() {
+                    let w = edge_weight(&e, name, *aggregation);
+                    score += e.src().prev().score * w;
+                }
+            }
+        }
+        s.get_mut().score = score;
+        s.global_update(&sum_of_squares, score * score);
+        Step::Continue
+    });
+
+    let step2 = ATask::new(move |s| {
+        let norm = s
+            .read_global_state(&sum_of_squares)
+            .unwrap_or_default()
+            .sqrt();
+        let prev: &EigenvectorState = s.prev();
+        let prev = prev.score;
+        let state: &mut EigenvectorState = s.get_mut();
+        if norm > 0f64 {
+            state.score /= norm;
+        }
+        let diff = (state.score - prev).abs();
+        s.global_update(&total_diff, diff);
+        Step::Continue
+    });
+
+    let step3 = Job::Check(Box::new(move |state| {
+        if state.read(&total_diff) > tol * n as f64 {
+            Step::Continue
+        } else {
+            Step::Done
+        }
+    }));
+
+    let mut runner: TaskRunner<G, _> = TaskRunner::new(ctx);
+
+    let results_type = std::any::type_name::<HashMap<VID, f64>>();
+    let out: HashMap<VID, f64> = runner.run(
+        vec![],
+        vec![Job::new(step1), Job::new(step2), step3],
+        Some(init),
+        |_, _, _, local| {
+            let layers = g.layer_ids();
+            let edge_filter = g.edge_filter();
+            local
+                .iter()
+                .enumerate()
+                .filter_map(|(v_ref, state)| {
+                    g.has_vertex_ref(VertexRef::Internal(v_ref.into()), &layers, edge_filter)
+                        .then_some((v_ref.into(), state.score))
+                })
+                .collect::<HashMap<VID, f64>>()
+        },
+        threads,
+        iter_count,
+        None,
+        None,
+    );
+
+    let res: HashMap<String, f64> = out
+        .into_iter()
+        .map(|(k, v)| (g.vertex_name(k), v))
+        .collect();
+
+    AlgorithmResult::new("Eigenvector Centrality", results_type, res)
+}
+
+#[cfg(test)]
+mod eigenvector_tests {
+    use super::*;
+    use crate::{
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::{LayerOps, TimeOps, NO_PROPS},
+    };
+
+    fn assert_close(results: &AlgorithmResult<String, f64, OrderedFloat<f64>>, v: &str, x: f64) {
+        let actual = *results.get(v).unwrap();
+        assert!((actual - x).abs() < 1e-4, "{v}: {actual} != {x}");
+    }
+
+    #[test]
+    fn directed_cycle() {
+        let graph = Graph::new();
+        for (t, (src, dst)) in [(1, 2), (2, 3), (3, 1)].into_iter().enumerate() {
+            graph.add_edge(t as i64, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        let results = eigenvector_centrality(&graph, 100, Some(2), None, None);
+
+        for v in ["1", "2", "3"] {
+            assert_close(&results, v, 1f64 / 3f64.sqrt());
+        }
+    }
+
+    #[test]
+    fn bipartite_star_converges() {
+        let graph = Graph::new();
+        for leaf in 2..5 {
+            graph.add_edge(0, 1, leaf, NO_PROPS, None).unwrap();
+            graph.add_edge(0, leaf, 1, NO_PROPS, None).unwrap();
+        }
+
+        let results = eigenvector_centrality(&graph, 1000, None, None, None);
+
+        assert_close(&results, "1", 1f64 / 2f64.sqrt());
+        for v in ["2", "3", "4"] {
+            assert_close(&results, v, 1f64 / 6f64.sqrt());
+        }
+    }
+
+    #[test]
+    fn weighted() {
+        let graph = Graph::new();
+        for (src, dst, w) in [(1, 2, 4.0), (2, 1, 1.0)] {
+            graph.add_edge(0, src, dst, [("w", w)], None).unwrap();
+        }
+
+        let results = eigenvector_centrality(
+            &graph,
+            1000,
+            None,
+            None,
+            Some(("w", WeightAggregation::Sum)),
+        );
+
+        // x1 = x2 * 1, x2 = x1 * 4 up to scaling, so x2 = 2 * x1
+        assert_close(&results, "1", 1f64 / 5f64.sqrt());
+        assert_close(&results, "2", 2f64 / 5f64.sqrt());
+    }
+
+    #[test]
+    fn windows_and_layers() {
+        let graph = Graph::new();
+        for leaf in 2..5 {
+            graph.add_edge(0, 1, leaf, NO_PROPS, Some("star")).unwrap();
+            graph.add_edge(0, leaf, 1, NO_PROPS, Some("star")).unwrap();
+        }
+        for (src, dst) in [(2, 3), (3, 4), (4, 2)] {
+            graph
+                .add_edge(10, src, dst, NO_PROPS, Some("cycle"))
+                .unwrap();
+        }
+
+        let windowed = eigenvector_centrality(&graph.window(5, 15), 1000, None, None, None);
+        assert_eq!(windowed.get("1"), None);
+        for v in ["2", "3", "4"] {
+            assert_close(&windowed, v, 1f64 / 3f64.sqrt());
+        }
+
+        let layered = eigenvector_centrality(&graph.layer("star").unwrap(), 1000, None, None, None);
+        assert_close(&layered, "1", 1f64 / 2f64.sqrt());
+        assert_close(&layered, "2", 1f64 / 6f64.sqrt());
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{
+    algorithms::{
+        algorithm_result::AlgorithmResult,
+        centrality::pagerank::{edge_weight, WeightAggregation},
+    },
+    core::{
+        entities::{vertices::vertex_ref::VertexRef, VID},
+        state::{accumulator_id::accumulators, compute_state::ComputeStateVec},
+        utils::errors::GraphError,
+    },
+    db::{
+        api::view::{EdgeViewOps, GraphViewOps, VertexViewOps},
+        task::{
+            context::Context,
+            task::{ATask, Job, Step},
+            task_runner::TaskRunner,
+        },
+    },
+};
+use ordered_float::OrderedFloat;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+#[derive(Clone, Debug, Default)]
+struct KatzState {
+    score: f64,
+}
+
+/// Katz Centrality:
+/// The centrality of a vertex is `alpha` times the sum of the centralities of the vertices
+/// pointing to it plus a constant `beta`, i.e. the (attenuated) number of walks ending at the vertex.
+///
+/// The scores are computed by iterating `x = alpha * A^T x + beta` starting from zero, which only
+/// converges if `alpha` is smaller than the reciprocal of the largest eigenvalue of the adjacency matrix.
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `alpha`: The attenuation factor
+/// * `beta`: The centrality every vertex receives regardless of its neighbours
+/// * `iter_count`: Maximum number of iterations to run the algorithm for
+/// * `threads`: Number of threads to use for parallel execution
+/// * `tol`: The tolerance value for convergence, the algorithm stops once the sum of the absolute
+///   changes of the scores is below `tol * n`
+/// * `normalized`: Whether to normalise the scores to unit L2 norm
+/// * `weight`: The name of a numeric edge property and how its values in the view are combined
+///   into the weight of an edge, all edges have weight 1 if `None`
+///
+/// Result:
+///
+/// * An AlgorithmResult object containing the mapping from vertex ID to the Katz centrality of the vertex
+///
+/// Errors:
+///
+/// * `GraphError::NotConverged` if the scores did not converge within `iter_count` iterations,
+///   which happens if `alpha` is too large and the scores grow without bound
+///
+#[allow(clippy::too_many_arguments)]
+pub fn katz_centrality<G: GraphViewOps>(
+    g: &G,
+    alpha: f64,
+    beta: f64,
+    iter_count: usize,
+    threads: Option<usize>,
+    tol: Option<f64>,
+    normalized: bool,
+    weight: Option<(&str, WeightAggregation)>,
+) -> Result<AlgorithmResult<String, f64, OrderedFloat<f64>>, GraphError> {
+    let n = g.count_vertices();
+
+    let mut ctx: Context<G, ComputeStateVec> = g.into();
+
+    let tol: f64 = tol.unwrap_or(0.000001f64);
+
+    let total_diff = accumulators::sum::<f64>(0);
+
+    ctx.global_agg_reset(total_diff);
+
+    let weight = weight.map(|(name, aggregation)| (name.to_owned(), aggregation));
+
+    let step1: ATask<G, ComputeStateVec, KatzState, _> = ATask::new(move |s| {
+        let prev: &KatzState = s.prev();
+        let prev = prev.score;
+        let mut score = 0f64;
+        match &weight {
+            None => {
+                for t in s.in_neighbours() {
+                    score += t.prev().score;
+                }
+            }
+            Some((name, aggregation)) => {
+                for e in s.in_edges() {
+                    let w = edge_weight(&e, name, *aggregation);
+                    score += e.src().prev().score * w;
+                }
+            }
+        }
+        let score = alpha * score + beta;
+        let diff = (score - prev).abs();
+        s.get_mut().score = score;
+        s.global_update(&total_diff, diff);
+        Step::Continue
+    });
+
+    let converged = Arc::new(AtomicBool::new(false));
+    let check_converged = converged.clone();
+    let step2 = Job::Check(Box::new(move |state| {
+        let diff = state.read(&total_diff);
+        if diff <= tol * n as f64 {
+            check_converged.store(true, Ordering::Relaxed);
+            Step::Done
+        } else if diff.is_finite() {
+            Step::Continue
+        } else {
+            // the scores overflowed, further iterations can not recover
+            Step::Done
+        }
+    }));
+
+    let mut runner: TaskRunner<G, _> = TaskRunner::new(ctx);
+
+    let results_type = std::any::type_name::<HashMap<VID, f64>>();
+    let out: HashMap<VID, f64> = runner.run(
+        vec![],
+        vec![Job::new(step1), step2],
+        None,
+        |_, _, _, local| {
+            let layers = g.layer_ids();
+            let edge_filter = g.edge_filter();
+            local
+                .iter()
+                .enumerate()
+                .filter_map(|(v_ref, state)| {
+                    g.has_vertex_ref(VertexRef::Internal(v_ref.into()), &layers, edge_filter)
+                        .then_some((v_ref.into(), state.score))
+                })
+                .collect::<HashMap<VID, f64>>()
+        },
+        threads,
+        iter_count,
+        None,
+        None,
+    );
+
+    if !converged.load(Ordering::Relaxed) || out.values().any(|x| !x.is_finite()) {
+        return Err(GraphError::NotConverged {
+            algorithm: "Katz Centrality",
+            iterations: iter_count,
+        });
+    }
+
+    let norm = if normalized {
+        out.values().map(|x| x * x).sum::<f64>().sqrt()
+    } else {
+        1f64
+    };
+    let norm = if norm > 0f64 { norm } else { 1f64 };
+
+    let res: HashMap<String, f64> = out
+        .into_iter()
+        .map(|(k, v)| (g.vertex_name(k), v / norm))
+        .collect();
+
+    Ok(AlgorithmResult::new("Katz Centrality", results_type, res))
+}
+
+#[cfg(test)]
+mod katz_tests {
+    use super::*;
+    use crate::{
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::{LayerOps, TimeOps, NO_PROPS},
+    };
+
+    fn assert_close(results: &AlgorithmResult<String, f64, OrderedFloat<f64>>, v: &str, x: f64) {
+        let actual = *results.get(v).unwrap();
+        assert!((actual - x).abs() < 1e-4, "{v}: {actual} != {x}");
+    }
+
+    fn star() -> Graph {
+        let graph = Graph::new();
+        for leaf in 2..5 {
+            graph.add_edge(0, 1, leaf, NO_PROPS, Some("star")).unwrap();
+            graph.add_edge(0, leaf, 1, NO_PROPS, Some("star")).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn star_unnormalized() {
+        let results = katz_centrality(&star(), 0.1, 1.0, 1000, Some(2), None, false, None).unwrap();
+
+        // x1 = 0.3 * x2 + 1, x2 = 0.1 * x1 + 1
+        let x1 = 1.3 / 0.97;
+        assert_close(&results, "1", x1);
+        for v in ["2", "3", "4"] {
+            assert_close(&results, v, 0.1 * x1 + 1.0);
+        }
+    }
+
+    #[test]
+    fn star_normalized() {
+        let results = katz_centrality(&star(), 0.1, 1.0, 1000, None, None, true, None).unwrap();
+
+        let x1: f64 = 1.3 / 0.97;
+        let x2 = 0.1 * x1 + 1.0;
+        let norm = (x1 * x1 + 3.0 * x2 * x2).sqrt();
+        assert_close(&results, "1", x1 / norm);
+        assert_close(&results, "2", x2 / norm);
+    }
+
+    #[test]
+    fn diverging_scores_are_reported() {
+        // the largest eigenvalue of the star is sqrt(3), so alpha = 1 diverges
+        let result = katz_centrality(&star(), 1.0, 1.0, 100, None, None, true, None);
+        assert!(matches!(
+            result,
+            Err(GraphError::NotConverged {
+                iterations: 100,
+                ..
+            })
+        ));
+
+        // scores that overflow stop the iteration early
+        let result = katz_centrality(&star(), 1e300, 1.0, 1000, None, None, false, None);
+        assert!(matches!(result, Err(GraphError::NotConverged { .. })));
+    }
+
+    #[test]
+    fn weighted_chain() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, [("w", 2.0)], None).unwrap();
+        graph.add_edge(1, 2, 3, [("w", 3.0)], None).unwrap();
+
+        let results = katz_centrality(
+            &graph,
+            0.5,
+            1.0,
+            100,
+            None,
+            None,
+            false,
+            Some(("w", WeightAggregation::Sum)),
+        )
+        .unwrap();
+
+        assert_close(&results, "1", 1.0);
+        assert_close(&results, "2", 0.5 * 2.0 + 1.0);
+        assert_close(&results, "3", 0.5 * 3.0 * 2.0 + 1.0);
+    }
+
+    #[test]
+    fn windows_and_layers() {
+        let graph = star();
+        graph.add_edge(10, 2, 3, NO_PROPS, Some("chain")).unwrap();
+
+        let windowed =
+            katz_centrality(&graph.window(5, 15), 0.1, 1.0, 100, None, None, false, None).unwrap();
+        assert_eq!(windowed.get("1"), None);
+        assert_close(&windowed, "2", 1.0);
+        assert_close(&windowed, "3", 1.1);
+
+        let layered = katz_centrality(
+            &graph.layer("chain").unwrap(),
+            0.1,
+            1.0,
+            100,
+            None,
+            None,
+            false,
+            None,
+        )
+        .unwrap();
+        assert_close(&layered, "2", 1.0);
+        assert_close(&layered, "3", 1.1);
+    }
+}
@@ -1,3 +1,8 @@
+pub mod betweenness;
+pub mod closeness;
 pub mod degree_centrality;
+pub mod eigenvector;
 pub mod hits;
+pub mod katz;
 pub mod pagerank;
+pub mod temporal_pagerank;
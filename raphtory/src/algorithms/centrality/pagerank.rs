@@ -5,7 +5,7 @@ use crate::{
         state::{accumulator_id::accumulators, compute_state::ComputeStateVec},
     },
     db::{
-        api::view::{GraphViewOps, VertexViewOps},
+        api::view::{EdgeViewOps, GraphViewOps, VertexViewOps},
         task::{
             context::Context,
             task::{ATask, Job, Step},
@@ -20,14 +20,19 @@ use std::collections::HashMap;
 #[derive(Clone, Debug, Default)]
 struct PageRankState {
     score: f64,
-    out_degree: usize,
+    out_weight: f64,
+    teleport: f64,
+    // weights of the in-edges in the order of `in_edges`, only used for weighted PageRank
+    in_weights: Vec<f64>,
 }
 
 impl PageRankState {
     fn new(num_vertices: usize) -> Self {
         Self {
             score: 1f64 / num_vertices as f64,
-            out_degree: 0,
+            out_weight: 0f64,
+            teleport: 0f64,
+            in_weights: vec![],
         }
     }
 
@@ -36,6 +41,49 @@ impl PageRankState {
     }
 }
 
+/// How the updates of an edge in the view are combined into a single edge weight
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WeightAggregation {
+    /// The sum of all values of the property
+    #[default]
+    Sum,
+    /// The latest value of the property
+    Latest,
+    /// The number of updates of the edge, ignoring the property
+    Count,
+}
+
+/// The weight of an edge, edges without a numeric value for the property in the view have
+/// weight 1
+pub(crate) fn edge_weight<E: EdgeViewOps>(
+    e: &E,
+    name: &str,
+    aggregation: WeightAggregation,
+) -> f64 {
+    match aggregation {
+        WeightAggregation::Count => e.explode().into_iter().count() as f64,
+        WeightAggregation::Latest => e
+            .properties()
+            .get(name)
+            .and_then(|p| p.as_f64())
+            .unwrap_or(1f64),
+        WeightAggregation::Sum => match e.properties().temporal().get(name) {
+            Some(values) => {
+                let mut numeric = values.values().into_iter().filter_map(|p| p.as_f64());
+                numeric
+                    .next()
+                    .map_or(1f64, |first| first + numeric.sum::<f64>())
+            }
+            None => e
+                .properties()
+                .constant()
+                .get(name)
+                .and_then(|p| p.as_f64())
+                .unwrap_or(1f64),
+        },
+    }
+}
+
 /// PageRank Algorithm:
 /// PageRank shows how important a vertex is in a graph.
 ///
@@ -51,23 +99,80 @@ impl PageRankState {
 ///
 /// * An AlgorithmResult object containing the mapping from vertex ID to the PageRank score of the vertex
 ///
-#[allow(unused_variables)]
 pub fn unweighted_page_rank<G: GraphViewOps>(
     g: &G,
     iter_count: usize,
     threads: Option<usize>,
     tol: Option<f64>,
     use_l2_norm: bool,
+) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
+    page_rank(g, iter_count, threads, tol, use_l2_norm, None, None)
+}
+
+/// Weighted and personalized PageRank Algorithm:
+/// PageRank where the random surfer follows edges proportionally to their weight and teleports
+/// to the vertices of a personalization vector. Scores of dangling vertices are redistributed
+/// according to the personalization vector as well.
+///
+/// Arguments:
+///
+/// * `g`: A GraphView object
+/// * `iter_count`: Number of iterations to run the algorithm for
+/// * `threads`: Number of threads to use for parallel execution
+/// * `tol`: The tolerance value for convergence
+/// * `use_l2_norm`: Whether to use L2 norm for convergence
+/// * `weight`: The name of a numeric edge property and how its values in the view are combined
+///   into the weight of an edge, all edges have weight 1 if `None`. Negative weights are treated
+///   as 0
+/// * `personalization`: The teleport probability of vertices by name (normalised to sum to 1),
+///   teleports are uniform if `None` or if none of the vertices exist in the view
+///
+/// Result:
+///
+/// * An AlgorithmResult object containing the mapping from vertex ID to the PageRank score of the vertex
+///
+#[allow(unused_variables)]
+pub fn page_rank<G: GraphViewOps>(
+    g: &G,
+    iter_count: usize,
+    threads: Option<usize>,
+    tol: Option<f64>,
+    use_l2_norm: bool,
+    weight: Option<(&str, WeightAggregation)>,
+    personalization: Option<&HashMap<String, f64>>,
 ) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
     let n = g.count_vertices();
     let total_edges = g.count_edges();
 
     let mut ctx: Context<G, ComputeStateVec> = g.into();
 
+    let num_vertices = g.count_vertices();
+    let teleport: Option<Vec<f64>> = personalization.and_then(|personalization| {
+        let mut teleport = vec![0f64; g.unfiltered_num_vertices()];
+        for (name, p) in personalization {
+            if let Some(v) = g.vertex(name.as_str()) {
+                teleport[usize::from(v.vertex)] += p.max(0f64);
+            }
+        }
+        let total: f64 = teleport.iter().sum();
+        (total > 0f64).then(|| teleport.into_iter().map(|p| p / total).collect())
+    });
+    let personalized = teleport.is_some();
+    let init = match teleport {
+        None => vec![PageRankState::new(num_vertices); num_vertices],
+        Some(teleport) => teleport
+            .into_iter()
+            .map(|p| PageRankState {
+                teleport: p,
+                ..PageRankState::new(num_vertices)
+            })
+            .collect(),
+    };
+
     let tol: f64 = tol.unwrap_or_else(|| 0.000001f64);
     let damp = 0.85;
     let teleport_prob = (1f64 - damp) / n as f64;
-    let factor = damp / n as f64;
+    let factor = if personalized { damp } else { damp / n as f64 };
 
     let max_diff = accumulators::sum::<f64>(2);
 
@@ -77,10 +182,22 @@ pub fn unweighted_page_rank<G: GraphViewOps>(
 
     ctx.global_agg_reset(total_sink_contribution);
 
+    let weight = weight.map(|(name, aggregation)| (name.to_owned(), aggregation));
+    let out_weight = weight.clone();
     let step1 = ATask::new(move |s| {
-        let out_degree = s.out_degree();
+        let (out_weight, in_weights) = match &out_weight {
+            None => (s.out_degree() as f64, vec![]),
+            Some((name, aggregation)) => {
+                let weight = |e| edge_weight(&e, name, *aggregation).max(0f64);
+                (
+                    s.out_edges().map(weight).sum(),
+                    s.in_edges().map(weight).collect(),
+                )
+            }
+        };
         let state: &mut PageRankState = s.get_mut();
-        state.out_degree = out_degree;
+        state.out_weight = out_weight;
+        state.in_weights = in_weights;
         Step::Continue
     });
 
@@ -91,22 +208,42 @@ pub fn unweighted_page_rank<G: GraphViewOps>(
             state.reset();
         }
 
-        for t in s.in_neighbours() {
-            let prev = t.prev();
+        match &weight {
+            None => {
+                for t in s.in_neighbours() {
+                    let prev = t.prev();
 
-            s.get_mut().score += prev.score / prev.out_degree as f64;
+                    s.get_mut().score += prev.score / prev.out_weight;
+                }
+            }
+            Some(_) => {
+                let mut score = 0f64;
+                for (e, w) in s.in_edges().zip(&s.get().in_weights) {
+                    let src = e.src();
+                    let prev = src.prev();
+                    if prev.out_weight > 0f64 {
+                        score += prev.score * w / prev.out_weight;
+                    }
+                }
+                s.get_mut().score = score;
+            }
         }
 
         s.get_mut().score *= damp;
 
-        s.get_mut().score += teleport_prob;
+        if personalized {
+            let state = s.get_mut();
+            state.score += (1f64 - damp) * state.teleport;
+        } else {
+            s.get_mut().score += teleport_prob;
+        }
         Step::Continue
     });
 
     let step3 = ATask::new(move |s| {
         let state: &mut PageRankState = s.get_mut();
 
-        if state.out_degree == 0 {
+        if state.out_weight == 0f64 {
             let curr = s.prev().score;
 
             let ts_contrib = factor * curr;
@@ -122,7 +259,11 @@ pub fn unweighted_page_rank<G: GraphViewOps>(
             .unwrap_or_default();
         // update local score with total sink contribution
         let state: &mut PageRankState = s.get_mut();
-        state.score += total_sink_contribution;
+        if personalized {
+            state.score += total_sink_contribution * state.teleport;
+        } else {
+            state.score += total_sink_contribution;
+        }
 
         // update global max diff
 
@@ -156,12 +297,11 @@ pub fn unweighted_page_rank<G: GraphViewOps>(
 
     let mut runner: TaskRunner<G, _> = TaskRunner::new(ctx);
 
-    let num_vertices = g.count_vertices();
     let results_type = std::any::type_name::<HashMap<VID, f64>>();
     let out: HashMap<VID, f64> = runner.run(
         vec![Job::new(step1)],
         vec![Job::new(step2), Job::new(step3), Job::new(step4), step5],
-        Some(vec![PageRankState::new(num_vertices); num_vertices]),
+        Some(init),
         |_, _, _, local| {
             let layers = g.layer_ids();
             let edge_filter = g.edge_filter();
@@ -197,7 +337,7 @@ mod page_rank_tests {
 
     use crate::{
         db::{api::mutation::AdditionOps, graph::graph::Graph},
-        prelude::NO_PROPS,
+        prelude::{TimeOps, NO_PROPS},
     };
 
     use super::*;
@@ -351,6 +491,109 @@ mod page_rank_tests {
         assert_eq_f64(results.get("11"), Some(&0.122), 3);
     }
 
+    #[test]
+    fn weighted_page_rank() {
+        let graph = Graph::new();
+        for (src, dst, w) in [(1, 2, 3), (1, 3, 1), (2, 1, 1), (3, 1, 1)] {
+            graph.add_edge(0, src, dst, [("w", w)], None).unwrap();
+        }
+
+        let results = page_rank(
+            &graph,
+            1000,
+            Some(1),
+            None,
+            true,
+            Some(("w", WeightAggregation::Sum)),
+            None,
+        );
+
+        // s1 = 0.05 + 0.85 * (s2 + s3), s2 = 0.05 + 0.85 * 3/4 * s1, s3 = 0.05 + 0.85 * 1/4 * s1
+        let s1 = 0.9 / 1.85;
+        assert_eq_f64(results.get("1"), Some(&s1), 4);
+        assert_eq_f64(results.get("2"), Some(&(0.05 + 0.6375 * s1)), 4);
+        assert_eq_f64(results.get("3"), Some(&(0.05 + 0.2125 * s1)), 4);
+    }
+
+    #[test]
+    fn weight_aggregation_in_windows() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, [("w", 1.0)], None).unwrap();
+        graph.add_edge(10, 1, 2, [("w", 5.0)], None).unwrap();
+        graph.add_edge(0, 1, 3, [("w", 2.0)], None).unwrap();
+        graph.add_edge(0, 2, 1, [("w", 1.0)], None).unwrap();
+        graph.add_edge(0, 3, 1, [("w", 1.0)], None).unwrap();
+
+        let window = graph.window(0, 5);
+        let rank = |aggregation, windowed: bool| {
+            let weight = Some(("w", aggregation));
+            let results = if windowed {
+                page_rank(&window, 100, None, None, true, weight, None)
+            } else {
+                page_rank(&graph, 100, None, None, true, weight, None)
+            };
+            (results.get("2").copied(), results.get("3").copied())
+        };
+
+        for aggregation in [
+            WeightAggregation::Sum,
+            WeightAggregation::Latest,
+            WeightAggregation::Count,
+        ] {
+            let (two, three) = rank(aggregation, false);
+            assert!(two > three, "{aggregation:?}");
+        }
+        let (two, three) = rank(WeightAggregation::Sum, true);
+        assert!(two < three);
+        let (two, three) = rank(WeightAggregation::Count, true);
+        assert_eq!(two, three);
+    }
+
+    #[test]
+    fn non_numeric_and_negative_weights() {
+        // edges without numeric values count as weight 1
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (1, 3), (2, 1), (3, 1)] {
+            graph.add_edge(0, src, dst, [("w", "heavy")], None).unwrap();
+        }
+        let weight = Some(("w", WeightAggregation::Sum));
+        let weighted = page_rank(&graph, 1000, None, None, true, weight, None);
+        let unweighted = unweighted_page_rank(&graph, 1000, None, None, true);
+        assert_eq!(weighted.get_all(), unweighted.get_all());
+
+        // negative weights are treated as 0, so vertex 3 only receives teleports
+        let graph = Graph::new();
+        for (src, dst, w) in [(1, 2, 1.0), (1, 3, -2.0), (2, 1, 1.0), (3, 1, 1.0)] {
+            graph.add_edge(0, src, dst, [("w", w)], None).unwrap();
+        }
+        let results = page_rank(&graph, 1000, None, None, true, weight, None);
+        assert_eq_f64(results.get("3"), Some(&0.05), 5);
+    }
+
+    #[test]
+    fn personalized_page_rank() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (3, 1), (4, 1), (3, 5)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        let personalization = HashMap::from([("1".to_string(), 1.0), ("missing".to_string(), 1.0)]);
+
+        let results = page_rank(&graph, 1000, None, None, true, None, Some(&personalization));
+
+        assert_eq!(results.get("4"), Some(&0.0));
+        let total: f64 = results.get_all().values().sum();
+        assert_eq_f64(Some(total), Some(1.0), 5);
+        assert!(results.get("1") > results.get("2"));
+        assert!(results.get("2") > results.get("3"));
+        assert!(results.get("3") > results.get("5"));
+
+        // without any seeds in the view the teleports are uniform
+        let personalization = HashMap::from([("missing".to_string(), 1.0)]);
+        let personalized = page_rank(&graph, 1000, None, None, true, None, Some(&personalization));
+        let uniform = unweighted_page_rank(&graph, 1000, None, None, true);
+        assert_eq!(personalized.get_all(), uniform.get_all());
+    }
+
     fn assert_eq_f64<T: Borrow<f64> + PartialEq + std::fmt::Debug>(
         a: Option<T>,
         b: Option<T>,
@@ -0,0 +1,220 @@
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    core::entities::VID,
+    prelude::{EdgeListOps, EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use ordered_float::OrderedFloat;
+use rustc_hash::FxHashMap;
+use std::collections::HashMap;
+
+/// The time and the temporal PageRank scores at each checkpoint
+pub type Checkpoints = Vec<(i64, AlgorithmResult<String, f64, OrderedFloat<f64>>)>;
+
+/// Temporal PageRank scores, normalised to sum to 1
+fn normalised_result(
+    names: &[String],
+    rank: &[f64],
+) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
+    let total: f64 = rank.iter().sum();
+    let res = names
+        .iter()
+        .cloned()
+        .zip(
+            rank.iter()
+                .map(|r| if total > 0.0 { r / total } else { 0.0 }),
+        )
+        .collect();
+    let results_type = std::any::type_name::<HashMap<String, f64>>();
+    AlgorithmResult::new("Temporal Pagerank", results_type, res)
+}
+
+/// Temporal PageRank (Rozenshtein and Gionis) with the scores at a number of checkpoints
+///
+/// The exploded edges of the view are processed in time order, such that a walk can only
+/// continue along an edge that happened after the edge it arrived by. Only time-respecting paths
+/// contribute to the scores.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `alpha` - The probability of following an edge instead of teleporting (damping factor)
+/// * `beta` - The probability of a walk waiting at a vertex instead of continuing along an
+///   edge, which biases walks towards edges that follow soon after the edge they arrived by
+/// * `checkpoints` - Times at which to report the scores, each based on the edges up to and
+///   including that time
+///
+/// Returns:
+///
+/// The time and the normalised scores of all vertices for each checkpoint in ascending order
+///
+pub fn temporal_page_rank_checkpoints<G: GraphViewOps>(
+    graph: &G,
+    alpha: f64,
+    beta: f64,
+    checkpoints: &[i64],
+) -> Checkpoints {
+    let vertices: Vec<_> = graph.vertices().iter().collect();
+    let index: FxHashMap<VID, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.vertex, i))
+        .collect();
+    let names: Vec<String> = vertices.iter().map(|v| v.name()).collect();
+
+    let mut events: Vec<(i64, usize, usize)> = graph
+        .edges()
+        .explode()
+        .filter_map(|e| {
+            let t = e.time()?;
+            Some((t, index[&e.src().vertex], index[&e.dst().vertex]))
+        })
+        .collect();
+    events.sort_by_key(|(t, _, _)| *t);
+
+    let mut checkpoints = checkpoints.to_vec();
+    checkpoints.sort();
+
+    // `rank` are the unnormalised scores and `active` the mass of walks waiting at each vertex
+    let mut rank = vec![0.0; names.len()];
+    let mut active = vec![0.0; names.len()];
+    let mut events = events.into_iter().peekable();
+    let mut results = Vec::with_capacity(checkpoints.len());
+    for checkpoint in checkpoints {
+        while let Some((_, src, dst)) = events.next_if(|(t, _, _)| *t <= checkpoint) {
+            rank[src] += 1.0 - alpha;
+            active[src] += 1.0 - alpha;
+            rank[dst] += active[src] * alpha;
+            if beta < 1.0 {
+                active[dst] += active[src] * (1.0 - beta) * alpha;
+                active[src] *= beta;
+            } else {
+                active[dst] += active[src] * alpha;
+                active[src] = 0.0;
+            }
+        }
+        results.push((checkpoint, normalised_result(&names, &rank)));
+    }
+    results
+}
+
+/// Temporal PageRank (Rozenshtein and Gionis)
+///
+/// The exploded edges of the view are processed in time order, such that a walk can only
+/// continue along an edge that happened after the edge it arrived by. Only time-respecting paths
+/// contribute to the scores.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `alpha` - The probability of following an edge instead of teleporting (damping factor)
+/// * `beta` - The probability of a walk waiting at a vertex instead of continuing along an
+///   edge, which biases walks towards edges that follow soon after the edge they arrived by
+///
+/// Returns:
+///
+/// An AlgorithmResult mapping each vertex to its normalised score
+///
+pub fn temporal_page_rank<G: GraphViewOps>(
+    graph: &G,
+    alpha: f64,
+    beta: f64,
+) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
+    temporal_page_rank_checkpoints(graph, alpha, beta, &[i64::MAX])
+        .pop()
+        .map(|(_, result)| result)
+        .expect("one checkpoint")
+}
+
+#[cfg(test)]
+mod temporal_page_rank_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn assert_scores(
+        result: &AlgorithmResult<String, f64, OrderedFloat<f64>>,
+        expected: &[(&str, f64)],
+    ) {
+        assert_eq!(result.get_all().len(), expected.len());
+        for (v, score) in expected {
+            let actual = result.get(*v).unwrap();
+            assert!((actual - score).abs() < 1e-9, "{v}: {actual} != {score}");
+        }
+    }
+
+    #[test]
+    fn only_time_respecting_paths_contribute() {
+        let graph = Graph::new();
+        graph.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        graph.add_edge(2, "b", "c", NO_PROPS, None).unwrap();
+        let result = temporal_page_rank(&graph, 0.5, 0.0);
+        assert_scores(
+            &result,
+            &[
+                ("a", 0.5 / 1.625),
+                ("b", 0.75 / 1.625),
+                ("c", 0.375 / 1.625),
+            ],
+        );
+
+        // the walk from a cannot continue to c if b -> c happened first
+        let graph = Graph::new();
+        graph.add_edge(1, "b", "c", NO_PROPS, None).unwrap();
+        graph.add_edge(2, "a", "b", NO_PROPS, None).unwrap();
+        let result = temporal_page_rank(&graph, 0.5, 0.0);
+        assert_scores(
+            &result,
+            &[("a", 0.5 / 1.5), ("b", 0.75 / 1.5), ("c", 0.25 / 1.5)],
+        );
+    }
+
+    #[test]
+    fn checkpoints_and_windows() {
+        let graph = Graph::new();
+        graph.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        graph.add_edge(2, "b", "c", NO_PROPS, None).unwrap();
+        graph.add_edge(3, "c", "a", NO_PROPS, None).unwrap();
+
+        let results = temporal_page_rank_checkpoints(&graph, 0.5, 0.0, &[2, 0, 1]);
+        assert_eq!(
+            results.iter().map(|(t, _)| *t).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert_scores(&results[0].1, &[("a", 0.0), ("b", 0.0), ("c", 0.0)]);
+        assert_scores(
+            &results[1].1,
+            &[("a", 0.5 / 0.75), ("b", 0.25 / 0.75), ("c", 0.0)],
+        );
+        assert_scores(
+            &results[2].1,
+            &[
+                ("a", 0.5 / 1.625),
+                ("b", 0.75 / 1.625),
+                ("c", 0.375 / 1.625),
+            ],
+        );
+
+        let window = graph.window(0, 3);
+        assert_eq!(
+            temporal_page_rank(&window, 0.5, 0.0).get_all(),
+            results[2].1.get_all()
+        );
+    }
+
+    #[test]
+    fn waiting_walks_continue_later() {
+        let graph = Graph::new();
+        graph.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        graph.add_edge(2, "b", "c", NO_PROPS, None).unwrap();
+        graph.add_edge(3, "b", "d", NO_PROPS, None).unwrap();
+        // without waiting only the walks arriving at b just before b -> d continue along it,
+        // with waiting the walks arriving at b before b -> c also continue along b -> d
+        let result = temporal_page_rank(&graph, 0.5, 0.0);
+        let ratio = result.get("d").unwrap() / result.get("c").unwrap();
+        assert!((ratio - 0.25 / 0.375).abs() < 1e-9);
+        let result = temporal_page_rank(&graph, 0.5, 0.5);
+        let ratio = result.get("d").unwrap() / result.get("c").unwrap();
+        assert!((ratio - 0.40625 / 0.3125).abs() < 1e-9);
+        let total: f64 = result.get_all().values().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+}
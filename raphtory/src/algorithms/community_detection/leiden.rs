@@ -0,0 +1,214 @@
+use crate::{
+    algorithms::{
+        algorithm_result::AlgorithmResult,
+        community_detection::modularity::{
+            community_result, renumber, ModularityGraph, NeighbourWeights, MIN_GAIN,
+        },
+    },
+    db::api::view::GraphViewOps,
+};
+use std::collections::VecDeque;
+
+/// Move single vertices between communities, only revisiting vertices whose neighbourhood changed
+fn fast_local_moving(graph: &ModularityGraph, partition: &mut [usize], resolution: f64) {
+    let m2 = graph.total_weight();
+    let mut totals = graph.community_degrees(partition);
+    let mut weights = NeighbourWeights::new(graph.len());
+    let mut queue: VecDeque<usize> = (0..graph.len()).collect();
+    let mut queued = vec![true; graph.len()];
+    while let Some(v) = queue.pop_front() {
+        queued[v] = false;
+        let current = partition[v];
+        let k = graph.degree(v);
+        totals[current] -= k;
+        weights.collect(graph, v, |n| partition[n], |_| true);
+        let mut best = current;
+        let mut best_gain = weights.weight(current) - resolution * totals[current] * k / m2;
+        for &c in weights.communities() {
+            let gain = weights.weight(c) - resolution * totals[c] * k / m2;
+            if gain > best_gain + MIN_GAIN {
+                best = c;
+                best_gain = gain;
+            }
+        }
+        totals[best] += k;
+        if best != current {
+            partition[v] = best;
+            for (n, _) in graph.neighbours(v) {
+                if !queued[*n] && partition[*n] != best {
+                    queued[*n] = true;
+                    queue.push_back(*n);
+                }
+            }
+        }
+    }
+}
+
+/// Split the communities of `partition` into well-connected subcommunities by merging singletons,
+/// returns the refined partition and whether any vertices were merged
+fn refine(graph: &ModularityGraph, partition: &[usize], resolution: f64) -> (Vec<usize>, bool) {
+    let m2 = graph.total_weight();
+    let totals = graph.community_degrees(partition);
+    let mut refined: Vec<usize> = (0..graph.len()).collect();
+    let mut sub_totals: Vec<f64> = (0..graph.len()).map(|v| graph.degree(v)).collect();
+    // weight between a subcommunity and the rest of its community
+    let mut external: Vec<f64> = (0..graph.len())
+        .map(|v| {
+            graph
+                .neighbours(v)
+                .iter()
+                .filter(|(n, _)| partition[*n] == partition[v])
+                .map(|(_, w)| w)
+                .sum()
+        })
+        .collect();
+    let mut singleton = vec![true; graph.len()];
+    let mut weights = NeighbourWeights::new(graph.len());
+    let mut merged = false;
+    for v in 0..graph.len() {
+        let c = partition[v];
+        let k = graph.degree(v);
+        if !singleton[v] || external[v] < resolution * k * (totals[c] - k) / m2 {
+            continue;
+        }
+        weights.collect(graph, v, |n| refined[n], |n| partition[n] == c);
+        let mut best: Option<(usize, f64)> = None;
+        for &s in weights.communities() {
+            let well_connected =
+                external[s] >= resolution * sub_totals[s] * (totals[c] - sub_totals[s]) / m2;
+            if !well_connected {
+                continue;
+            }
+            let gain = weights.weight(s) - resolution * k * sub_totals[s] / m2;
+            if gain >= -MIN_GAIN && best.map_or(true, |(_, best_gain)| gain > best_gain + MIN_GAIN)
+            {
+                best = Some((s, gain));
+            }
+        }
+        if let Some((s, _)) = best {
+            refined[v] = s;
+            sub_totals[s] += k;
+            external[s] += external[v] - 2.0 * weights.weight(s);
+            singleton[v] = false;
+            singleton[s] = false;
+            merged = true;
+        }
+    }
+    (refined, merged)
+}
+
+/// Computes communities using the Leiden algorithm for modularity optimisation
+///
+/// Compared to [`louvain`](super::louvain::louvain) the communities found by the Leiden
+/// algorithm are guaranteed to be connected. Edges are treated as undirected and only the
+/// edges (and layers) of the view are considered.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `weight` - The name of a numeric edge property to use as edge weight, all edges have
+///   weight 1 if `None`
+/// * `resolution` - The resolution parameter, higher values favour smaller communities
+///
+/// Returns:
+///
+/// An AlgorithmResult mapping each vertex to its community id and the modularity of the
+/// communities
+pub fn leiden<G: GraphViewOps>(
+    graph: &G,
+    weight: Option<&str>,
+    resolution: f64,
+) -> (AlgorithmResult<String, u64>, f64) {
+    let (mut g, names) = ModularityGraph::from_view(graph, weight);
+    let mut membership: Vec<usize> = (0..g.len()).collect();
+    let mut partition: Vec<usize> = (0..g.len()).collect();
+    if g.total_weight() > 0.0 {
+        loop {
+            fast_local_moving(&g, &mut partition, resolution);
+            let num_communities = renumber(&mut partition);
+            if num_communities == g.len() {
+                break;
+            }
+            let (mut refined, merged) = refine(&g, &partition, resolution);
+            if !merged {
+                refined = partition.clone();
+            }
+            let num_refined = renumber(&mut refined);
+            // the aggregated vertices start out in the community of their vertices
+            let mut aggregated_partition = vec![0; num_refined];
+            for (v, s) in refined.iter().enumerate() {
+                aggregated_partition[*s] = partition[v];
+            }
+            for c in membership.iter_mut() {
+                *c = refined[*c];
+            }
+            g = g.aggregate(&refined, num_refined);
+            partition = aggregated_partition;
+        }
+        for c in membership.iter_mut() {
+            *c = partition[*c];
+        }
+    }
+    let modularity = g.modularity(&partition, resolution);
+    (community_result("Leiden", names, &membership), modularity)
+}
+
+#[cfg(test)]
+mod leiden_test {
+    use super::*;
+    use crate::{
+        algorithms::community_detection::{
+            louvain::louvain_test::{assert_communities, two_cliques},
+            modularity::modularity,
+        },
+        prelude::*,
+    };
+
+    #[test]
+    fn leiden_two_cliques() {
+        let g = two_cliques();
+        let (result, q) = leiden(&g, None, 1.0);
+        assert_communities(result.get_all(), &[&[1, 2, 3, 4], &[5, 6, 7, 8]]);
+        assert!((q - 11.0 / 26.0).abs() < 1e-9);
+        assert!((modularity(&g, result.get_all(), None, 1.0) - q).abs() < 1e-9);
+    }
+
+    #[test]
+    fn leiden_weighted() {
+        let g = Graph::new();
+        for (src, dst, w) in [
+            (1, 2, 10),
+            (2, 3, 1),
+            (3, 4, 10),
+            (4, 5, 1),
+            (5, 6, 10),
+            (6, 1, 1),
+        ] {
+            g.add_edge(0, src, dst, [("w", w)], None).unwrap();
+        }
+        let (result, q) = leiden(&g, Some("w"), 1.0);
+        assert_communities(result.get_all(), &[&[1, 2], &[3, 4], &[5, 6]]);
+        assert!((modularity(&g, result.get_all(), Some("w"), 1.0) - q).abs() < 1e-9);
+    }
+
+    #[test]
+    fn leiden_resolution() {
+        let g = two_cliques();
+        let (result, q) = leiden(&g, None, 0.0);
+        assert_communities(result.get_all(), &[&[1, 2, 3, 4, 5, 6, 7, 8]]);
+        assert!((q - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn leiden_respects_windows_and_layers() {
+        let g = two_cliques();
+        for (src, dst) in [(1, 5), (2, 6), (3, 7), (4, 8), (1, 6), (2, 7)] {
+            g.add_edge(5, src, dst, NO_PROPS, Some("noise")).unwrap();
+        }
+        let (result, _) = leiden(&g.window(0, 5), None, 1.0);
+        assert_communities(result.get_all(), &[&[1, 2, 3, 4], &[5, 6, 7, 8]]);
+
+        let (result, _) = leiden(&g.layer("_default").unwrap(), None, 1.0);
+        assert_communities(result.get_all(), &[&[1, 2, 3, 4], &[5, 6, 7, 8]]);
+    }
+}
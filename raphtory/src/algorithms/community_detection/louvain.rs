@@ -0,0 +1,177 @@
+use crate::{
+    algorithms::{
+        algorithm_result::AlgorithmResult,
+        community_detection::modularity::{
+            community_result, renumber, ModularityGraph, NeighbourWeights, MIN_GAIN,
+        },
+    },
+    db::api::view::GraphViewOps,
+};
+
+/// Move single vertices between communities until no move improves the modularity, returns
+/// `true` if any vertex was moved
+fn local_moving(graph: &ModularityGraph, partition: &mut [usize], resolution: f64) -> bool {
+    let m2 = graph.total_weight();
+    let mut totals = graph.community_degrees(partition);
+    let mut weights = NeighbourWeights::new(graph.len());
+    let mut moved_any = false;
+    loop {
+        let mut moved = false;
+        for v in 0..graph.len() {
+            let current = partition[v];
+            let k = graph.degree(v);
+            totals[current] -= k;
+            weights.collect(graph, v, |n| partition[n], |_| true);
+            let mut best = current;
+            let mut best_gain = weights.weight(current) - resolution * totals[current] * k / m2;
+            for &c in weights.communities() {
+                let gain = weights.weight(c) - resolution * totals[c] * k / m2;
+                if gain > best_gain + MIN_GAIN {
+                    best = c;
+                    best_gain = gain;
+                }
+            }
+            totals[best] += k;
+            if best != current {
+                partition[v] = best;
+                moved = true;
+            }
+        }
+        if !moved {
+            break;
+        }
+        moved_any = true;
+    }
+    moved_any
+}
+
+/// Computes communities using the Louvain method for modularity optimisation
+///
+/// Edges are treated as undirected and only the edges (and layers) of the view are considered.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `weight` - The name of a numeric edge property to use as edge weight, all edges have
+///   weight 1 if `None`
+/// * `resolution` - The resolution parameter, higher values favour smaller communities
+///
+/// Returns:
+///
+/// An AlgorithmResult mapping each vertex to its community id and the modularity of the
+/// communities
+pub fn louvain<G: GraphViewOps>(
+    graph: &G,
+    weight: Option<&str>,
+    resolution: f64,
+) -> (AlgorithmResult<String, u64>, f64) {
+    let (mut g, names) = ModularityGraph::from_view(graph, weight);
+    let mut membership: Vec<usize> = (0..g.len()).collect();
+    if g.total_weight() > 0.0 {
+        loop {
+            let mut partition: Vec<usize> = (0..g.len()).collect();
+            if !local_moving(&g, &mut partition, resolution) {
+                break;
+            }
+            let num_communities = renumber(&mut partition);
+            for c in membership.iter_mut() {
+                *c = partition[*c];
+            }
+            g = g.aggregate(&partition, num_communities);
+        }
+    }
+    let identity: Vec<usize> = (0..g.len()).collect();
+    let modularity = g.modularity(&identity, resolution);
+    (community_result("Louvain", names, &membership), modularity)
+}
+
+#[cfg(test)]
+pub(crate) mod louvain_test {
+    use super::*;
+    use crate::{algorithms::community_detection::modularity::modularity, prelude::*};
+    use std::collections::HashMap;
+
+    /// Two 4-cliques joined by a single edge
+    pub(crate) fn two_cliques() -> Graph {
+        let g = Graph::new();
+        for clique in [[1, 2, 3, 4], [5, 6, 7, 8]] {
+            for (i, src) in clique.iter().enumerate() {
+                for dst in &clique[i + 1..] {
+                    g.add_edge(0, *src, *dst, NO_PROPS, None).unwrap();
+                }
+            }
+        }
+        g.add_edge(1, 4, 5, NO_PROPS, None).unwrap();
+        g
+    }
+
+    pub(crate) fn assert_communities(result: &HashMap<String, u64>, expected: &[&[u64]]) {
+        let mut communities: Vec<u64> = vec![];
+        for group in expected {
+            let c = result[&group[0].to_string()];
+            assert!(
+                !communities.contains(&c),
+                "communities not distinct: {result:?}"
+            );
+            communities.push(c);
+            for v in group.iter() {
+                assert_eq!(result[&v.to_string()], c, "{result:?}");
+            }
+        }
+        assert_eq!(
+            result.len(),
+            expected.iter().map(|group| group.len()).sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn louvain_two_cliques() {
+        let g = two_cliques();
+        let (result, q) = louvain(&g, None, 1.0);
+        assert_communities(result.get_all(), &[&[1, 2, 3, 4], &[5, 6, 7, 8]]);
+        // 2 * (12/26 - (13/26)^2)
+        assert!((q - 11.0 / 26.0).abs() < 1e-9);
+        assert!((modularity(&g, result.get_all(), None, 1.0) - q).abs() < 1e-9);
+    }
+
+    #[test]
+    fn louvain_weighted() {
+        let g = Graph::new();
+        for (src, dst, w) in [
+            (1, 2, 10),
+            (2, 3, 1),
+            (3, 4, 10),
+            (4, 5, 1),
+            (5, 6, 10),
+            (6, 1, 1),
+        ] {
+            g.add_edge(0, src, dst, [("w", w)], None).unwrap();
+        }
+        let (result, q) = louvain(&g, Some("w"), 1.0);
+        assert_communities(result.get_all(), &[&[1, 2], &[3, 4], &[5, 6]]);
+        assert!((modularity(&g, result.get_all(), Some("w"), 1.0) - q).abs() < 1e-9);
+    }
+
+    #[test]
+    fn louvain_respects_windows_and_layers() {
+        let g = two_cliques();
+        for (src, dst) in [(1, 5), (2, 6), (3, 7), (4, 8), (1, 6), (2, 7)] {
+            g.add_edge(5, src, dst, NO_PROPS, Some("noise")).unwrap();
+        }
+        let (result, _) = louvain(&g.window(0, 5), None, 1.0);
+        assert_communities(result.get_all(), &[&[1, 2, 3, 4], &[5, 6, 7, 8]]);
+
+        let (result, _) = louvain(&g.layer("_default").unwrap(), None, 1.0);
+        assert_communities(result.get_all(), &[&[1, 2, 3, 4], &[5, 6, 7, 8]]);
+    }
+
+    #[test]
+    fn louvain_without_edges() {
+        let g = Graph::new();
+        g.add_vertex(0, 1, NO_PROPS).unwrap();
+        g.add_vertex(0, 2, NO_PROPS).unwrap();
+        let (result, q) = louvain(&g, None, 1.0);
+        assert_communities(result.get_all(), &[&[1], &[2]]);
+        assert_eq!(q, 0.0);
+    }
+}
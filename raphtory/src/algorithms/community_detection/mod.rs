@@ -1 +1,5 @@
 pub mod connected_components;
+pub mod leiden;
+pub mod louvain;
+pub mod modularity;
+pub mod strongly_connected_components;
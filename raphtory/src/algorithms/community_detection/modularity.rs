@@ -0,0 +1,310 @@
+//! Modularity of vertex partitions and the weighted graph representation shared by the
+//! modularity based community detection algorithms ([`super::louvain`] and [`super::leiden`]).
+//!
+//! Modularity is defined for undirected graphs, edges are treated as undirected and the weights
+//! of edges in both directions between two vertices are added up.
+
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    core::entities::VID,
+    db::api::view::{GraphViewOps, VertexViewOps},
+    prelude::EdgeViewOps,
+};
+use rustc_hash::FxHashMap;
+use std::collections::HashMap;
+
+/// Improvements in quality smaller than this are treated as rounding errors
+pub(crate) const MIN_GAIN: f64 = 1e-12;
+
+/// An undirected weighted graph on the vertices `0..len()`
+#[derive(Clone, Debug)]
+pub(crate) struct ModularityGraph {
+    /// neighbours and the total weight of the edges to them, excluding self-loops
+    adj: Vec<Vec<(usize, f64)>>,
+    self_loops: Vec<f64>,
+    /// weighted degree of each vertex, counting self-loops twice
+    degree: Vec<f64>,
+    /// sum of all degrees (twice the total edge weight)
+    total_weight: f64,
+}
+
+impl ModularityGraph {
+    fn from_edges(len: usize, edges: impl IntoIterator<Item = (usize, usize, f64)>) -> Self {
+        let mut weights: FxHashMap<(usize, usize), f64> = FxHashMap::default();
+        for (src, dst, w) in edges {
+            *weights.entry((src.min(dst), src.max(dst))).or_default() += w;
+        }
+        let mut adj = vec![vec![]; len];
+        let mut self_loops = vec![0.0; len];
+        let mut degree = vec![0.0; len];
+        for ((src, dst), w) in weights {
+            if src == dst {
+                self_loops[src] += w;
+                degree[src] += 2.0 * w;
+            } else {
+                adj[src].push((dst, w));
+                adj[dst].push((src, w));
+                degree[src] += w;
+                degree[dst] += w;
+            }
+        }
+        for neighbours in adj.iter_mut() {
+            neighbours.sort_by_key(|(n, _)| *n);
+        }
+        let total_weight = degree.iter().sum();
+        Self {
+            adj,
+            self_loops,
+            degree,
+            total_weight,
+        }
+    }
+
+    /// Build the graph for a view, returning the names of the vertices in index order.
+    ///
+    /// With a `weight` the numeric edge property with that name is used as the edge weight
+    /// (edges without the property have weight 1), otherwise all edges have weight 1.
+    pub(crate) fn from_view<G: GraphViewOps>(
+        graph: &G,
+        weight: Option<&str>,
+    ) -> (Self, Vec<String>) {
+        let vertices: Vec<_> = graph.vertices().iter().collect();
+        let index: FxHashMap<VID, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.vertex, i))
+            .collect();
+        let edges = graph.edges().filter_map(|e| {
+            let w = match weight {
+                Some(name) => e
+                    .properties()
+                    .get(name)
+                    .and_then(|p| p.as_f64())
+                    .unwrap_or(1.0),
+                None => 1.0,
+            };
+            Some((
+                *index.get(&e.src().vertex)?,
+                *index.get(&e.dst().vertex)?,
+                w,
+            ))
+        });
+        let names = vertices.iter().map(|v| v.name()).collect();
+        (Self::from_edges(index.len(), edges), names)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.adj.len()
+    }
+
+    pub(crate) fn neighbours(&self, v: usize) -> &[(usize, f64)] {
+        &self.adj[v]
+    }
+
+    pub(crate) fn degree(&self, v: usize) -> f64 {
+        self.degree[v]
+    }
+
+    pub(crate) fn total_weight(&self) -> f64 {
+        self.total_weight
+    }
+
+    /// Total degree of each community of `partition`
+    pub(crate) fn community_degrees(&self, partition: &[usize]) -> Vec<f64> {
+        let mut totals = vec![0.0; self.len()];
+        for (v, c) in partition.iter().enumerate() {
+            totals[*c] += self.degree[v];
+        }
+        totals
+    }
+
+    /// Modularity of `partition` (community ids need to be smaller than `len()`)
+    pub(crate) fn modularity(&self, partition: &[usize], resolution: f64) -> f64 {
+        if self.total_weight == 0.0 {
+            return 0.0;
+        }
+        let mut internal = vec![0.0; self.len()];
+        for (v, c) in partition.iter().enumerate() {
+            internal[*c] += 2.0 * self.self_loops[v];
+            internal[*c] += self.adj[v]
+                .iter()
+                .filter(|(n, _)| partition[*n] == *c)
+                .map(|(_, w)| w)
+                .sum::<f64>();
+        }
+        let m2 = self.total_weight;
+        internal
+            .iter()
+            .zip(self.community_degrees(partition))
+            .map(|(internal, total)| internal / m2 - resolution * (total / m2).powi(2))
+            .sum()
+    }
+
+    /// Collapse every community of `partition` (with ids `0..num_communities`) into a vertex
+    pub(crate) fn aggregate(&self, partition: &[usize], num_communities: usize) -> Self {
+        let loops = self
+            .self_loops
+            .iter()
+            .enumerate()
+            .map(|(v, w)| (partition[v], partition[v], *w));
+        let edges = self.adj.iter().enumerate().flat_map(|(v, neighbours)| {
+            neighbours
+                .iter()
+                .filter(move |(n, _)| *n > v)
+                .map(move |(n, w)| (partition[v], partition[*n], *w))
+        });
+        Self::from_edges(num_communities, loops.chain(edges))
+    }
+}
+
+/// Accumulates the weight from a vertex to neighbouring communities
+pub(crate) struct NeighbourWeights {
+    weights: Vec<f64>,
+    seen: Vec<bool>,
+    touched: Vec<usize>,
+}
+
+impl NeighbourWeights {
+    pub(crate) fn new(len: usize) -> Self {
+        Self {
+            weights: vec![0.0; len],
+            seen: vec![false; len],
+            touched: vec![],
+        }
+    }
+
+    /// Collect the weights from `v` to the communities of its neighbours for which `include`
+    /// holds
+    pub(crate) fn collect(
+        &mut self,
+        graph: &ModularityGraph,
+        v: usize,
+        community: impl Fn(usize) -> usize,
+        include: impl Fn(usize) -> bool,
+    ) {
+        for c in self.touched.drain(..) {
+            self.weights[c] = 0.0;
+            self.seen[c] = false;
+        }
+        for (n, w) in graph.neighbours(v) {
+            if !include(*n) {
+                continue;
+            }
+            let c = community(*n);
+            if !self.seen[c] {
+                self.seen[c] = true;
+                self.touched.push(c);
+            }
+            self.weights[c] += w;
+        }
+    }
+
+    /// The communities found by the last call to `collect` in the order they were first seen
+    pub(crate) fn communities(&self) -> &[usize] {
+        &self.touched
+    }
+
+    pub(crate) fn weight(&self, community: usize) -> f64 {
+        self.weights[community]
+    }
+}
+
+/// Relabel the communities of `partition` as `0..n` in order of first appearance, returns `n`
+pub(crate) fn renumber(partition: &mut [usize]) -> usize {
+    let mut ids: FxHashMap<usize, usize> = FxHashMap::default();
+    for c in partition.iter_mut() {
+        let next = ids.len();
+        *c = *ids.entry(*c).or_insert(next);
+    }
+    ids.len()
+}
+
+/// Package the final partition of the vertices as an `AlgorithmResult`
+pub(crate) fn community_result(
+    algo_name: &str,
+    names: Vec<String>,
+    partition: &[usize],
+) -> AlgorithmResult<String, u64> {
+    let result_type = std::any::type_name::<HashMap<String, u64>>();
+    let result = names
+        .into_iter()
+        .zip(partition.iter().map(|c| *c as u64))
+        .collect();
+    AlgorithmResult::new(algo_name, result_type, result)
+}
+
+/// Computes the modularity of a partition of the vertices of a graph
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `communities` - The community of each vertex by name, vertices that are missing are put in
+///   a community of their own
+/// * `weight` - The name of a numeric edge property to use as edge weight, all edges have
+///   weight 1 if `None`
+/// * `resolution` - The resolution parameter, higher values favour smaller communities
+///
+/// Returns:
+///
+/// The modularity of the partition, edges are treated as undirected
+pub fn modularity<G: GraphViewOps>(
+    graph: &G,
+    communities: &HashMap<String, u64>,
+    weight: Option<&str>,
+    resolution: f64,
+) -> f64 {
+    let (g, names) = ModularityGraph::from_view(graph, weight);
+    let mut partition: Vec<usize> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            communities
+                .get(name)
+                .map(|c| *c as usize)
+                .unwrap_or(usize::MAX - i)
+        })
+        .collect();
+    renumber(&mut partition);
+    g.modularity(&partition, resolution)
+}
+
+#[cfg(test)]
+mod modularity_test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn modularity_of_two_triangles() {
+        let g = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (6, 4), (3, 4)] {
+            g.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        let split: HashMap<String, u64> = (1..=6).map(|v| (v.to_string(), v / 4)).collect();
+        // 2 * (6/14 - (7/14)^2)
+        assert!((modularity(&g, &split, None, 1.0) - 5.0 / 14.0).abs() < 1e-12);
+
+        let together: HashMap<String, u64> = (1..=6).map(|v| (v.to_string(), 0)).collect();
+        assert!(modularity(&g, &together, None, 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn aggregation_preserves_modularity() {
+        let g = Graph::new();
+        for (src, dst, w) in [
+            (1, 2, 2.0),
+            (2, 3, 1.0),
+            (3, 3, 0.5),
+            (3, 4, 4.0),
+            (4, 1, 1.0),
+        ] {
+            g.add_edge(0, src, dst, [("w", w)], None).unwrap();
+        }
+        let (mg, _) = ModularityGraph::from_view(&g, Some("w"));
+        let partition = vec![0, 0, 1, 1];
+        let aggregated = mg.aggregate(&partition, 2);
+        assert_eq!(aggregated.total_weight(), mg.total_weight());
+        assert!(
+            (aggregated.modularity(&[0, 1], 0.5) - mg.modularity(&partition, 0.5)).abs() < 1e-12
+        );
+    }
+}
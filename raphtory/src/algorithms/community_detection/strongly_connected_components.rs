@@ -0,0 +1,377 @@
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    core::{
+        entities::{vertices::vertex_ref::VertexRef, VID},
+        state::compute_state::ComputeStateVec,
+        utils::errors::GraphError,
+        Prop,
+    },
+    db::{
+        api::{
+            mutation::AdditionOps,
+            view::{EdgeViewOps, GraphViewOps, VertexViewOps},
+        },
+        graph::graph::Graph,
+        task::{
+            context::Context,
+            task::{ATask, Job, Step},
+            task_runner::TaskRunner,
+            vertex::eval_vertex::EvalVertexView,
+        },
+    },
+};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default)]
+struct SccState {
+    assigned: bool,
+    component: u64,
+    colour: u64,
+    reached: bool,
+}
+
+type SccJob<G> = Job<G, ComputeStateVec, SccState>;
+
+fn run_phase<G: GraphViewOps>(
+    graph: &G,
+    state: Vec<SccState>,
+    init_tasks: Vec<SccJob<G>>,
+    tasks: Vec<SccJob<G>>,
+    threads: Option<usize>,
+) -> Vec<SccState> {
+    let ctx: Context<G, ComputeStateVec> = graph.into();
+    let mut runner: TaskRunner<G, _> = TaskRunner::new(ctx);
+    runner.run(
+        init_tasks,
+        tasks,
+        Some(state),
+        |_, _, _, local| local,
+        threads,
+        usize::MAX,
+        None,
+        None,
+    )
+}
+
+/// Vertices without unassigned in- or out-neighbours are components on their own
+fn trim_tasks<G: GraphViewOps>() -> Vec<SccJob<G>> {
+    let step = ATask::new(
+        move |vv: &mut EvalVertexView<'_, G, ComputeStateVec, SccState>| {
+            if vv.prev().assigned {
+                return Step::Done;
+            }
+            let has_in = vv.in_neighbours().into_iter().any(|n| !n.prev().assigned);
+            let has_out = vv.out_neighbours().into_iter().any(|n| !n.prev().assigned);
+            if has_in && has_out {
+                Step::Done
+            } else {
+                let id = vv.id();
+                let state: &mut SccState = vv.get_mut();
+                state.assigned = true;
+                state.component = id;
+                Step::Continue
+            }
+        },
+    );
+    vec![Job::read_only(step)]
+}
+
+/// Propagate the smallest id that reaches each vertex
+fn colour_tasks<G: GraphViewOps>() -> (Vec<SccJob<G>>, Vec<SccJob<G>>) {
+    let init = ATask::new(move |vv| {
+        let id = vv.id();
+        let state: &mut SccState = vv.get_mut();
+        state.colour = id;
+        Step::Continue
+    });
+    let step = ATask::new(
+        move |vv: &mut EvalVertexView<'_, G, ComputeStateVec, SccState>| {
+            let prev = vv.prev();
+            if prev.assigned {
+                return Step::Done;
+            }
+            let prev = prev.colour;
+            let current = vv
+                .in_neighbours()
+                .into_iter()
+                .filter(|n| !n.prev().assigned)
+                .map(|n| n.prev().colour)
+                .min()
+                .unwrap_or(prev);
+            if current < prev {
+                let state: &mut SccState = vv.get_mut();
+                state.colour = current;
+                Step::Continue
+            } else {
+                Step::Done
+            }
+        },
+    );
+    (vec![Job::new(init)], vec![Job::read_only(step)])
+}
+
+/// Mark the vertices with the same colour that can reach the vertex the colour came from
+fn reach_tasks<G: GraphViewOps>() -> (Vec<SccJob<G>>, Vec<SccJob<G>>) {
+    let init = ATask::new(move |vv| {
+        let id = vv.id();
+        let state: &mut SccState = vv.get_mut();
+        state.reached = !state.assigned && state.colour == id;
+        Step::Continue
+    });
+    let step = ATask::new(
+        move |vv: &mut EvalVertexView<'_, G, ComputeStateVec, SccState>| {
+            let prev = vv.prev();
+            if prev.assigned || prev.reached {
+                return Step::Done;
+            }
+            let colour = prev.colour;
+            let reached = vv.out_neighbours().into_iter().any(|n| {
+                let n = n.prev();
+                !n.assigned && n.reached && n.colour == colour
+            });
+            if reached {
+                let state: &mut SccState = vv.get_mut();
+                state.reached = true;
+                Step::Continue
+            } else {
+                Step::Done
+            }
+        },
+    );
+    (vec![Job::new(init)], vec![Job::read_only(step)])
+}
+
+/// Computes the strongly connected components of a graph using the colouring algorithm
+///
+/// Vertices without incoming or outgoing edges are trimmed first, then every remaining vertex
+/// is coloured with the smallest id that can reach it. The vertices that can reach the vertex
+/// with that id form its component. This is repeated until every vertex has a component.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `threads` - Number of threads to use
+///
+/// Returns:
+///
+/// An AlgorithmResult containing the mapping from each vertex to its component id, which is
+/// the smallest vertex id in the component
+///
+pub fn strongly_connected_components<G>(
+    graph: &G,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, u64>
+where
+    G: GraphViewOps,
+{
+    let num_vertices = graph.count_vertices();
+    let mut state: Vec<SccState> = vec![];
+    let mut assigned = 0;
+    while assigned < num_vertices {
+        state = run_phase(graph, state, vec![], trim_tasks(), threads);
+        let (init, step) = colour_tasks();
+        state = run_phase(graph, state, init, step, threads);
+        let (init, step) = reach_tasks();
+        state = run_phase(graph, state, init, step, threads);
+        for s in state.iter_mut() {
+            if s.reached {
+                s.assigned = true;
+                s.component = s.colour;
+                s.reached = false;
+            }
+        }
+        assigned = state.iter().filter(|s| s.assigned).count();
+    }
+
+    let layers = graph.layer_ids();
+    let edge_filter = graph.edge_filter();
+    let res = state
+        .iter()
+        .enumerate()
+        .filter_map(|(v_ref, state)| {
+            let v_ref = VID(v_ref);
+            graph
+                .has_vertex_ref(VertexRef::Internal(v_ref), &layers, edge_filter)
+                .then_some((graph.vertex_name(v_ref), state.component))
+        })
+        .collect::<HashMap<_, _>>();
+    let results_type = std::any::type_name::<HashMap<String, u64>>();
+    AlgorithmResult::new("Strongly Connected Components", results_type, res)
+}
+
+/// Builds the condensation of a graph, the directed acyclic graph of its strongly connected
+/// components
+///
+/// Every component becomes a vertex named by its component id with a `size` property holding
+/// the number of vertices in the component. The vertex is added at the earliest time of any
+/// of its vertices. Components are connected if there are edges between their vertices, the
+/// edge is added at the earliest time of these edges and its `count` property holds the
+/// number of edges between the components.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `components` - The strongly connected components of the graph as computed by
+///   [`strongly_connected_components`]
+///
+/// Returns:
+///
+/// A new graph with the components as vertices
+///
+pub fn condensation<G: GraphViewOps>(
+    graph: &G,
+    components: &AlgorithmResult<String, u64>,
+) -> Result<Graph, GraphError> {
+    let mut vertices: HashMap<u64, (i64, u64)> = HashMap::new();
+    for v in graph.vertices() {
+        if let Some(c) = components.get(&v.name()) {
+            let t = v.earliest_time().unwrap_or(i64::MAX);
+            let entry = vertices.entry(*c).or_insert((t, 0));
+            entry.0 = entry.0.min(t);
+            entry.1 += 1;
+        }
+    }
+    let mut edges: HashMap<(u64, u64), (i64, u64)> = HashMap::new();
+    for e in graph.edges() {
+        let src = components.get(&e.src().name());
+        let dst = components.get(&e.dst().name());
+        if let (Some(src), Some(dst)) = (src, dst) {
+            if src != dst {
+                let t = e.earliest_time().unwrap_or(i64::MAX);
+                let entry = edges.entry((*src, *dst)).or_insert((t, 0));
+                entry.0 = entry.0.min(t);
+                entry.1 += 1;
+            }
+        }
+    }
+
+    let condensed = Graph::new();
+    for (c, (t, size)) in vertices {
+        condensed.add_vertex(t, c, [("size", Prop::U64(size))])?;
+    }
+    for ((src, dst), (t, count)) in edges {
+        condensed.add_edge(t, src, dst, [("count", Prop::U64(count))], None)?;
+    }
+    Ok(condensed)
+}
+
+#[cfg(test)]
+mod scc_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn components(graph: &Graph) -> HashMap<String, u64> {
+        strongly_connected_components(graph, None).get_all().clone()
+    }
+
+    fn expected(groups: &[(u64, &[u64])]) -> HashMap<String, u64> {
+        groups
+            .iter()
+            .flat_map(|(c, vs)| vs.iter().map(move |v| (v.to_string(), *c)))
+            .collect()
+    }
+
+    #[test]
+    fn scc_of_cycles_and_chains() {
+        let graph = Graph::new();
+        let edges = vec![
+            (1, 2, 1),
+            (2, 3, 2),
+            (3, 1, 3),
+            (3, 4, 4),
+            (4, 5, 5),
+            (5, 6, 6),
+            (6, 4, 7),
+            (6, 7, 8),
+            (8, 7, 9),
+            (9, 9, 10),
+        ];
+        for (src, dst, ts) in edges {
+            graph.add_edge(ts, src, dst, NO_PROPS, None).unwrap();
+        }
+        assert_eq!(
+            components(&graph),
+            expected(&[
+                (1, &[1, 2, 3]),
+                (4, &[4, 5, 6]),
+                (7, &[7]),
+                (8, &[8]),
+                (9, &[9])
+            ])
+        );
+    }
+
+    #[test]
+    fn scc_of_chain_of_cycles() {
+        let graph = Graph::new();
+        // 10 <-> 5 -> 3 <-> 8 -> 1 <-> 7 with the smaller ids downstream
+        let edges = vec![
+            (10, 5),
+            (5, 10),
+            (5, 3),
+            (3, 8),
+            (8, 3),
+            (8, 1),
+            (1, 7),
+            (7, 1),
+        ];
+        for (src, dst) in edges {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        assert_eq!(
+            components(&graph),
+            expected(&[(5, &[5, 10]), (3, &[3, 8]), (1, &[1, 7])])
+        );
+    }
+
+    #[test]
+    fn windowed_scc() {
+        let graph = Graph::new();
+        for (src, dst, ts) in [(1, 2, 1), (2, 3, 2), (3, 1, 3), (3, 4, 4), (4, 3, 10)] {
+            graph.add_edge(ts, src, dst, NO_PROPS, None).unwrap();
+        }
+        let window = graph.window(0, 5);
+        assert_eq!(
+            strongly_connected_components(&window, None).get_all(),
+            &expected(&[(1, &[1, 2, 3]), (4, &[4])])
+        );
+        assert_eq!(components(&graph), expected(&[(1, &[1, 2, 3, 4])]));
+    }
+
+    #[test]
+    fn condensation_is_a_dag() {
+        let graph = Graph::new();
+        let edges = vec![
+            (1, 2, 1),
+            (2, 1, 2),
+            (2, 3, 3),
+            (1, 3, 4),
+            (3, 4, 5),
+            (4, 3, 6),
+            (4, 5, 7),
+        ];
+        for (src, dst, ts) in edges {
+            graph.add_edge(ts, src, dst, NO_PROPS, None).unwrap();
+        }
+        let scc = strongly_connected_components(&graph, None);
+        let condensed = condensation(&graph, &scc).unwrap();
+
+        assert_eq!(condensed.count_vertices(), 3);
+        assert_eq!(condensed.count_edges(), 2);
+        let sizes: HashMap<_, _> = condensed
+            .vertices()
+            .iter()
+            .map(|v| (v.id(), v.properties().get("size").unwrap()))
+            .collect();
+        assert_eq!(
+            sizes,
+            HashMap::from([(1, Prop::U64(2)), (3, Prop::U64(2)), (5, Prop::U64(1))])
+        );
+        let e = condensed.edge(1, 3).unwrap();
+        assert_eq!(e.earliest_time(), Some(3));
+        assert_eq!(e.properties().get("count"), Some(Prop::U64(2)));
+        let e = condensed.edge(3, 5).unwrap();
+        assert_eq!(e.earliest_time(), Some(7));
+        assert_eq!(e.properties().get("count"), Some(Prop::U64(1)));
+    }
+}
@@ -0,0 +1,235 @@
+//! Maximal clique enumeration on the undirected projection of a graph
+//!
+//! Cliques are enumerated with the Bron–Kerbosch algorithm with pivoting, see Tomita, Etsuji,
+//! Akira Tanaka, and Haruhisa Takahashi. "The worst-case time complexity for generating all
+//! maximal cliques and computational experiments." Theoretical Computer Science 363.1 (2006).
+//! The outer level follows a degeneracy ordering of the vertices, which keeps the candidate
+//! sets small on sparse graphs, see Eppstein, David, Maarten Löffler, and Darren Strash.
+//! "Listing all maximal cliques in sparse graphs in near-optimal time." ISAAC (2010).
+use crate::{algorithms::cores::k_truss::undirected_projection, prelude::GraphViewOps};
+use rustc_hash::FxHashSet;
+use std::collections::BTreeSet;
+
+/// A state of the search as `(clique, candidates, excluded)`
+type Frame = (Vec<usize>, Vec<usize>, Vec<usize>);
+
+/// An iterator over the maximal cliques of a graph, the cliques are found lazily
+pub struct MaximalCliques {
+    names: Vec<String>,
+    adj: Vec<FxHashSet<usize>>,
+    /// the vertices in degeneracy order and their position in the order
+    order: Vec<usize>,
+    position: Vec<usize>,
+    next_vertex: usize,
+    min_size: usize,
+    stack: Vec<Frame>,
+}
+
+impl MaximalCliques {
+    fn new(names: Vec<String>, adj: Vec<FxHashSet<usize>>, min_size: usize) -> Self {
+        let n = adj.len();
+        let mut degree: Vec<usize> = adj.iter().map(|a| a.len()).collect();
+        let mut queue: BTreeSet<(usize, usize)> = (0..n).map(|v| (degree[v], v)).collect();
+        let mut position = vec![usize::MAX; n];
+        let mut order = Vec::with_capacity(n);
+        while let Some((_, v)) = queue.pop_first() {
+            position[v] = order.len();
+            order.push(v);
+            for &w in adj[v].iter() {
+                if position[w] == usize::MAX {
+                    queue.remove(&(degree[w], w));
+                    degree[w] -= 1;
+                    queue.insert((degree[w], w));
+                }
+            }
+        }
+        Self {
+            names,
+            adj,
+            order,
+            position,
+            next_vertex: 0,
+            min_size,
+            stack: vec![],
+        }
+    }
+
+    /// Expand a state into the states of its child calls
+    fn expand(&mut self, (clique, mut candidates, mut excluded): Frame) {
+        let adj = &self.adj;
+        let pivot = candidates
+            .iter()
+            .chain(excluded.iter())
+            .max_by_key(|u| candidates.iter().filter(|v| adj[**u].contains(v)).count())
+            .copied();
+        let branches: Vec<usize> = match pivot {
+            Some(pivot) => candidates
+                .iter()
+                .filter(|v| !adj[pivot].contains(v))
+                .copied()
+                .collect(),
+            None => vec![],
+        };
+        let mut children = Vec::with_capacity(branches.len());
+        for v in branches {
+            let mut child = clique.clone();
+            child.push(v);
+            children.push((
+                child,
+                candidates
+                    .iter()
+                    .filter(|w| adj[v].contains(w))
+                    .copied()
+                    .collect(),
+                excluded
+                    .iter()
+                    .filter(|w| adj[v].contains(w))
+                    .copied()
+                    .collect(),
+            ));
+            candidates.retain(|w| *w != v);
+            excluded.push(v);
+        }
+        self.stack.extend(children.into_iter().rev());
+    }
+}
+
+impl Iterator for MaximalCliques {
+    type Item = Vec<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame = match self.stack.pop() {
+                Some(frame) => frame,
+                None => {
+                    let v = *self.order.get(self.next_vertex)?;
+                    self.next_vertex += 1;
+                    let (later, earlier): (Vec<usize>, Vec<usize>) = self.adj[v]
+                        .iter()
+                        .partition(|w| self.position[**w] > self.position[v]);
+                    (vec![v], later, earlier)
+                }
+            };
+            let (clique, candidates, excluded) = &frame;
+            if clique.len() + candidates.len() < self.min_size {
+                continue;
+            }
+            if candidates.is_empty() {
+                if excluded.is_empty() {
+                    let mut names: Vec<String> =
+                        clique.iter().map(|v| self.names[*v].clone()).collect();
+                    names.sort();
+                    return Some(names);
+                }
+                continue;
+            }
+            self.expand(frame);
+        }
+    }
+}
+
+/// Enumerates the maximal cliques of the undirected projection of a graph
+///
+/// Self-loops are ignored, so isolated vertices form maximal cliques of size 1.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `min_size` - The minimum number of vertices of the returned cliques
+///
+/// Returns:
+///
+/// An iterator over the maximal cliques with at least `min_size` vertices, each as a sorted
+/// vector of vertex names
+///
+pub fn maximal_cliques<G: GraphViewOps>(graph: &G, min_size: usize) -> MaximalCliques {
+    let (names, _, adj) = undirected_projection(graph);
+    MaximalCliques::new(names, adj, min_size)
+}
+
+#[cfg(test)]
+mod cliques_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn clique(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn finds_all_maximal_cliques() {
+        let graph = Graph::new();
+        for (src, dst) in [
+            ("a", "b"),
+            ("a", "c"),
+            ("a", "d"),
+            ("b", "c"),
+            ("b", "d"),
+            ("d", "c"),
+            ("c", "e"),
+            ("e", "f"),
+            ("f", "c"),
+            ("f", "g"),
+            ("g", "g"),
+        ] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph.add_vertex(0, "h", NO_PROPS).unwrap();
+
+        let mut cliques: Vec<_> = maximal_cliques(&graph, 1).collect();
+        cliques.sort();
+        assert_eq!(
+            cliques,
+            vec![
+                clique(&["a", "b", "c", "d"]),
+                clique(&["c", "e", "f"]),
+                clique(&["f", "g"]),
+                clique(&["h"]),
+            ]
+        );
+        let mut cliques: Vec<_> = maximal_cliques(&graph, 3).collect();
+        cliques.sort();
+        assert_eq!(
+            cliques,
+            vec![clique(&["a", "b", "c", "d"]), clique(&["c", "e", "f"])]
+        );
+        assert_eq!(maximal_cliques(&graph, 5).count(), 0);
+    }
+
+    #[test]
+    fn cliques_are_maximal_and_unique() {
+        let graph = Graph::new();
+        for i in 0..30u64 {
+            for j in [1, 2, 5] {
+                graph
+                    .add_edge(0, i, (i * j + 3 * j) % 30, NO_PROPS, None)
+                    .unwrap();
+            }
+        }
+        let cliques: Vec<_> = maximal_cliques(&graph, 1).collect();
+        let unique: FxHashSet<_> = cliques.iter().cloned().collect();
+        assert_eq!(unique.len(), cliques.len());
+        let connected = |a: &String, b: &String| {
+            let (a, b): (u64, u64) = (a.parse().unwrap(), b.parse().unwrap());
+            graph.has_edge(a, b, Layer::All) || graph.has_edge(b, a, Layer::All)
+        };
+        let names: Vec<String> = graph.vertices().name().collect();
+        for c in cliques.iter() {
+            for (i, a) in c.iter().enumerate() {
+                assert!(c[i + 1..].iter().all(|b| connected(a, b)));
+            }
+            // no other vertex can be added
+            assert!(names
+                .iter()
+                .filter(|v| !c.contains(v))
+                .all(|v| !c.iter().all(|a| a != v && connected(a, v))));
+        }
+        // every edge is in some clique
+        for e in graph.edges() {
+            let (src, dst) = (e.src().name(), e.dst().name());
+            if src != dst {
+                assert!(cliques.iter().any(|c| c.contains(&src) && c.contains(&dst)));
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! k-truss decomposition of the undirected projection of a graph
+//!
+//! The k-truss is the largest subgraph in which every edge is part of at least `k - 2`
+//! triangles within the subgraph. The truss number of an edge is the largest `k` such that
+//! the edge is in the k-truss. Every edge is in the 2-truss, and the (k+1)-truss is contained
+//! in the k-truss, so trusses find increasingly cohesive groups with a stricter condition
+//! than cores. The truss numbers are computed by peeling edges with the fewest triangles,
+//! see Wang, Jia, and James Cheng. "Truss decomposition in massive networks." Proceedings of
+//! the VLDB Endowment 5.9 (2012).
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    core::entities::VID,
+    db::graph::views::edge_subgraph::EdgeSubgraph,
+    prelude::{EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::{BTreeSet, HashMap};
+
+/// The undirected projection of a view without self-loops, as the vertex names and the
+/// neighbours of every vertex
+pub(crate) fn undirected_projection<G: GraphViewOps>(
+    graph: &G,
+) -> (Vec<String>, FxHashMap<VID, usize>, Vec<FxHashSet<usize>>) {
+    let vertices: Vec<_> = graph.vertices().iter().collect();
+    let index: FxHashMap<VID, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.vertex, i))
+        .collect();
+    let names = vertices.iter().map(|v| v.name()).collect();
+    let mut adj = vec![FxHashSet::default(); vertices.len()];
+    for e in graph.edges() {
+        let (src, dst) = (index[&e.src().vertex], index[&e.dst().vertex]);
+        if src != dst {
+            adj[src].insert(dst);
+            adj[dst].insert(src);
+        }
+    }
+    (names, index, adj)
+}
+
+fn common_neighbours(adj: &[FxHashSet<usize>], u: usize, v: usize) -> Vec<usize> {
+    let (small, large) = if adj[u].len() <= adj[v].len() {
+        (&adj[u], &adj[v])
+    } else {
+        (&adj[v], &adj[u])
+    };
+    small
+        .iter()
+        .filter(|w| large.contains(w))
+        .copied()
+        .collect()
+}
+
+fn pair(u: usize, v: usize) -> (usize, usize) {
+    (u.min(v), u.max(v))
+}
+
+/// The truss number of every undirected pair `(u, v)` with `u < v`
+fn truss_decomposition(mut adj: Vec<FxHashSet<usize>>) -> FxHashMap<(usize, usize), u64> {
+    let mut support: FxHashMap<(usize, usize), usize> = FxHashMap::default();
+    for u in 0..adj.len() {
+        for &v in adj[u].iter().filter(|v| **v > u) {
+            support.insert((u, v), common_neighbours(&adj, u, v).len());
+        }
+    }
+    let mut queue: BTreeSet<(usize, (usize, usize))> =
+        support.iter().map(|(e, s)| (*s, *e)).collect();
+
+    let mut truss: FxHashMap<(usize, usize), u64> = FxHashMap::default();
+    let mut k = 2;
+    while let Some((s, (u, v))) = queue.pop_first() {
+        k = k.max(s as u64 + 2);
+        truss.insert((u, v), k);
+        for w in common_neighbours(&adj, u, v) {
+            for other in [pair(u, w), pair(v, w)] {
+                let s = support[&other];
+                queue.remove(&(s, other));
+                queue.insert((s - 1, other));
+                support.insert(other, s - 1);
+            }
+        }
+        adj[u].remove(&v);
+        adj[v].remove(&u);
+    }
+    truss
+}
+
+/// Computes the truss number of every edge of the undirected projection of a graph
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+///
+/// Returns:
+///
+/// An AlgorithmResult mapping every edge `(src, dst)` of the view to its truss number, edges
+/// in both directions between two vertices have the same truss number. Self-loops are ignored.
+///
+pub fn truss_numbers<G: GraphViewOps>(graph: &G) -> AlgorithmResult<(String, String), u64> {
+    let (names, index, adj) = undirected_projection(graph);
+    let truss = truss_decomposition(adj);
+    let results: HashMap<(String, String), u64> = graph
+        .edges()
+        .filter_map(|e| {
+            let (src, dst) = (index[&e.src().vertex], index[&e.dst().vertex]);
+            let k = truss.get(&pair(src, dst))?;
+            Some(((names[src].clone(), names[dst].clone()), *k))
+        })
+        .collect();
+    let results_type = std::any::type_name::<HashMap<(String, String), u64>>();
+    AlgorithmResult::new("Truss Numbers", results_type, results)
+}
+
+/// Finds the k-truss of a graph
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `k` - Value of k such that every edge of the result is part of at least `k - 2` triangles
+///   within the result
+///
+/// Returns:
+///
+/// The subgraph of the edges with truss number at least `k`, edges in both directions between
+/// two vertices are both kept
+///
+pub fn k_truss<G: GraphViewOps>(graph: &G, k: u64) -> EdgeSubgraph<G> {
+    let (_, index, adj) = undirected_projection(graph);
+    let truss = truss_decomposition(adj);
+    let edges: Vec<_> = graph
+        .edges()
+        .filter(|e| {
+            let (src, dst) = (index[&e.src().vertex], index[&e.dst().vertex]);
+            truss.get(&pair(src, dst)).map_or(false, |t| *t >= k)
+        })
+        .map(|e| e.edge)
+        .collect();
+    EdgeSubgraph::new(graph.clone(), edges)
+}
+
+#[cfg(test)]
+mod k_truss_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn graph() -> Graph {
+        let graph = Graph::new();
+        // a 4-clique 1-2-3-4, a triangle 4-5-6 and a pendant edge 6-7
+        for (t, src, dst) in [
+            (1, 1, 2),
+            (2, 1, 3),
+            (3, 1, 4),
+            (4, 2, 3),
+            (5, 2, 4),
+            (6, 4, 3),
+            (7, 3, 4),
+            (8, 4, 5),
+            (9, 5, 6),
+            (10, 6, 4),
+            (11, 6, 7),
+            (12, 7, 7),
+        ] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn truss_numbers_of_cliques() {
+        let graph = graph();
+        let result = truss_numbers(&graph);
+        let truss = |src: &str, dst: &str| result.get(&(src.to_string(), dst.to_string())).copied();
+        assert_eq!(truss("1", "2"), Some(4));
+        assert_eq!(truss("4", "3"), Some(4));
+        assert_eq!(truss("3", "4"), Some(4));
+        assert_eq!(truss("4", "5"), Some(3));
+        assert_eq!(truss("6", "4"), Some(3));
+        assert_eq!(truss("6", "7"), Some(2));
+        assert_eq!(truss("7", "7"), None);
+        assert_eq!(result.get_all().len(), 11);
+    }
+
+    #[test]
+    fn k_truss_subgraphs() {
+        let graph = graph();
+        let truss = k_truss(&graph, 4);
+        let mut ids: Vec<u64> = truss.vertices().id().collect();
+        ids.sort();
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+        assert_eq!(truss.count_edges(), 7);
+        assert_eq!(k_truss(&graph, 3).count_vertices(), 6);
+        assert_eq!(k_truss(&graph, 2).count_edges(), 11);
+        assert_eq!(k_truss(&graph, 5).count_vertices(), 0);
+
+        // in the window the edge 1-4 is missing, so 1-2-3 is only a triangle
+        let truss = truss_numbers(&graph.window(4, 20));
+        assert_eq!(truss.get(&("2".to_string(), "3".to_string())), Some(&3));
+    }
+}
@@ -1 +1,3 @@
+pub mod cliques;
 pub mod k_core;
+pub mod k_truss;
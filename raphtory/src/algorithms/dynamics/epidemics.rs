@@ -0,0 +1,415 @@
+//! Stochastic epidemic simulations on temporal contact networks
+//!
+//! The exploded edges of a view are replayed in time order as contacts. A contact between an
+//! infectious and a susceptible vertex infects the susceptible vertex with the given probability.
+//! Depending on which durations are given this simulates the SI, SIR or SEIR model:
+//!
+//! * without an incubation time vertices are infectious as soon as they are infected
+//! * without a recovery time vertices stay infectious forever
+use crate::{
+    core::{
+        entities::{vertices::input_vertex::InputVertex, VID},
+        utils::errors::GraphError,
+    },
+    prelude::{EdgeListOps, EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::Distribution;
+use rustc_hash::FxHashMap;
+use std::collections::HashMap;
+
+/// The course of the infection of a single vertex
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Infection {
+    /// The time the vertex was infected
+    pub infected: i64,
+    /// The time the vertex became infectious
+    pub infectious: i64,
+    /// The time the vertex recovered, `None` if it never recovers
+    pub recovered: Option<i64>,
+}
+
+/// The number of vertices in each state at a point in time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EpidemicPoint {
+    pub time: i64,
+    pub susceptible: usize,
+    pub exposed: usize,
+    pub infectious: usize,
+    pub recovered: usize,
+}
+
+/// The outcome of an epidemic simulation
+#[derive(Clone, Debug, PartialEq)]
+pub struct Epidemic {
+    /// The infection of every vertex that was infected
+    pub infections: HashMap<String, Infection>,
+    /// The number of vertices in each state after every time a state changed
+    pub curve: Vec<EpidemicPoint>,
+}
+
+fn duration<D: Distribution<f64>, R: Rng>(distribution: &Option<D>, rng: &mut R) -> Option<i64> {
+    distribution
+        .as_ref()
+        .map(|d| d.sample(rng).max(0.0).ceil() as i64)
+}
+
+fn curve(num_vertices: usize, infections: &[Option<Infection>]) -> Vec<EpidemicPoint> {
+    // (time, change in exposed, change in infectious, change in recovered)
+    let mut changes: Vec<(i64, i64, i64, i64)> = vec![];
+    for infection in infections.iter().flatten() {
+        changes.push((infection.infected, 1, 0, 0));
+        changes.push((infection.infectious, -1, 1, 0));
+        if let Some(recovered) = infection.recovered {
+            changes.push((recovered, 0, -1, 1));
+        }
+    }
+    changes.sort_by_key(|(t, _, _, _)| *t);
+
+    let mut curve: Vec<EpidemicPoint> = vec![];
+    let (mut exposed, mut infectious, mut recovered) = (0i64, 0i64, 0i64);
+    for (time, de, di, dr) in changes {
+        exposed += de;
+        infectious += di;
+        recovered += dr;
+        let point = EpidemicPoint {
+            time,
+            susceptible: num_vertices - (exposed + infectious + recovered) as usize,
+            exposed: exposed as usize,
+            infectious: infectious as usize,
+            recovered: recovered as usize,
+        };
+        match curve.last_mut() {
+            Some(last) if last.time == time => *last = point,
+            _ => curve.push(point),
+        }
+    }
+    curve
+}
+
+/// Simulates the spread of an epidemic over the contacts of a graph
+///
+/// The seeds are infected and infectious at the start time. Afterwards, a vertex can infect
+/// others through contacts after it was infected, while it is infectious and before it
+/// recovered. Sampled durations are rounded up to whole time units.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `seeds` - The initially infected vertices
+/// * `infection_prob` - The probability that a contact infects a susceptible vertex
+/// * `start_time` - The time the seeds are infected, contacts before are ignored. Defaults to
+///   the earliest time of the view
+/// * `recovery_time` - The distribution of the time between becoming infectious and recovering,
+///   vertices never recover if `None`
+/// * `incubation_time` - The distribution of the time between being infected and becoming
+///   infectious, vertices are infectious immediately if `None`
+/// * `directed` - Whether infections only spread from the source to the destination of an edge
+/// * `seed` - The seed of the random number generator
+///
+/// Returns:
+///
+/// The infection of every infected vertex and the number of vertices in each state over time,
+/// or an error if a seed is not in the graph
+///
+#[allow(clippy::too_many_arguments)]
+pub fn epidemic<G, T, R, E>(
+    graph: &G,
+    seeds: Vec<T>,
+    infection_prob: f64,
+    start_time: Option<i64>,
+    recovery_time: Option<R>,
+    incubation_time: Option<E>,
+    directed: bool,
+    seed: Option<u64>,
+) -> Result<Epidemic, GraphError>
+where
+    G: GraphViewOps,
+    T: InputVertex,
+    R: Distribution<f64>,
+    E: Distribution<f64>,
+{
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let vertices: Vec<_> = graph.vertices().iter().collect();
+    let index: FxHashMap<VID, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.vertex, i))
+        .collect();
+    let start_time = start_time.or_else(|| graph.earliest_time()).unwrap_or(0);
+
+    let mut infections: Vec<Option<Infection>> = vec![None; vertices.len()];
+    let mut is_seed = vec![false; vertices.len()];
+    for s in seeds {
+        let v = match graph.vertex(s.clone()) {
+            Some(v) => index[&v.vertex],
+            None => {
+                return Err(match s.id_str() {
+                    Some(name) => GraphError::VertexNameError(name.to_string()),
+                    None => GraphError::VertexIdError(s.id()),
+                })
+            }
+        };
+        is_seed[v] = true;
+        infections[v] = Some(Infection {
+            infected: start_time,
+            infectious: start_time,
+            recovered: duration(&recovery_time, &mut rng).map(|d| start_time.saturating_add(d)),
+        });
+    }
+
+    let mut contacts: Vec<(i64, usize, usize)> = graph
+        .edges()
+        .explode()
+        .filter_map(|e| {
+            let t = e.time()?;
+            Some((t, index[&e.src().vertex], index[&e.dst().vertex]))
+        })
+        .filter(|(t, _, _)| *t >= start_time)
+        .collect();
+    contacts.sort_by_key(|(t, _, _)| *t);
+
+    let can_infect = |infections: &[Option<Infection>], v: usize, t: i64| match infections[v] {
+        Some(infection) => {
+            (is_seed[v] || infection.infected < t)
+                && infection.infectious <= t
+                && infection.recovered.map_or(true, |r| t < r)
+        }
+        None => false,
+    };
+    for (t, src, dst) in contacts {
+        let directions: &[(usize, usize)] = if directed {
+            &[(src, dst)]
+        } else {
+            &[(src, dst), (dst, src)]
+        };
+        for &(from, to) in directions {
+            if infections[to].is_none()
+                && can_infect(&infections, from, t)
+                && rng.gen::<f64>() < infection_prob
+            {
+                let infectious =
+                    t.saturating_add(duration(&incubation_time, &mut rng).unwrap_or(0));
+                let recovered =
+                    duration(&recovery_time, &mut rng).map(|d| infectious.saturating_add(d));
+                infections[to] = Some(Infection {
+                    infected: t,
+                    infectious,
+                    recovered,
+                });
+            }
+        }
+    }
+
+    let curve = curve(vertices.len(), &infections);
+    let infections = vertices
+        .iter()
+        .zip(infections)
+        .filter_map(|(v, infection)| Some((v.name(), infection?)))
+        .collect();
+    Ok(Epidemic { infections, curve })
+}
+
+#[cfg(test)]
+mod epidemics_test {
+    use super::*;
+    use crate::prelude::*;
+    use rand_distr::Exp;
+
+    /// constant durations
+    struct Fixed(f64);
+
+    impl Distribution<f64> for Fixed {
+        fn sample<R: Rng + ?Sized>(&self, _rng: &mut R) -> f64 {
+            self.0
+        }
+    }
+
+    fn chain() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst) in [(1, "a", "b"), (2, "b", "c"), (3, "d", "c"), (6, "c", "e")] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn si_follows_contacts() {
+        let graph = chain();
+        let result = epidemic(
+            &graph,
+            vec!["a"],
+            1.0,
+            None,
+            None::<Fixed>,
+            None::<Fixed>,
+            true,
+            Some(1),
+        )
+        .unwrap();
+        let infected: HashMap<&str, i64> = result
+            .infections
+            .iter()
+            .map(|(v, i)| (v.as_str(), i.infected))
+            .collect();
+        assert_eq!(
+            infected,
+            HashMap::from([("a", 1), ("b", 1), ("c", 2), ("e", 6)])
+        );
+        assert_eq!(result.curve.last().unwrap().infectious, 4);
+        assert_eq!(result.curve.last().unwrap().susceptible, 1);
+
+        // d is only infected if contacts spread both ways
+        let result = epidemic(
+            &graph,
+            vec!["a"],
+            1.0,
+            None,
+            None::<Fixed>,
+            None::<Fixed>,
+            false,
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(result.infections["d"].infected, 3);
+
+        let result = epidemic(
+            &graph,
+            vec!["a"],
+            0.0,
+            None,
+            None::<Fixed>,
+            None::<Fixed>,
+            true,
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(result.infections.len(), 1);
+    }
+
+    #[test]
+    fn sir_and_seir() {
+        let graph = chain();
+        // c recovers at 2 + 3 = 5, before the contact with e
+        let result = epidemic(
+            &graph,
+            vec!["a"],
+            1.0,
+            None,
+            Some(Fixed(3.0)),
+            None::<Fixed>,
+            true,
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(
+            result.infections["c"],
+            Infection {
+                infected: 2,
+                infectious: 2,
+                recovered: Some(5)
+            }
+        );
+        assert!(!result.infections.contains_key("e"));
+
+        // with an incubation time of 1.5 b is not infectious at the contact with c
+        let result = epidemic(
+            &graph,
+            vec!["a"],
+            1.0,
+            None,
+            Some(Fixed(3.0)),
+            Some(Fixed(1.5)),
+            true,
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(
+            result.infections["b"],
+            Infection {
+                infected: 1,
+                infectious: 3,
+                recovered: Some(6)
+            }
+        );
+        assert_eq!(result.infections.len(), 2);
+        assert_eq!(
+            result.curve,
+            vec![
+                EpidemicPoint {
+                    time: 1,
+                    susceptible: 3,
+                    exposed: 1,
+                    infectious: 1,
+                    recovered: 0
+                },
+                EpidemicPoint {
+                    time: 3,
+                    susceptible: 3,
+                    exposed: 0,
+                    infectious: 2,
+                    recovered: 0
+                },
+                EpidemicPoint {
+                    time: 4,
+                    susceptible: 3,
+                    exposed: 0,
+                    infectious: 1,
+                    recovered: 1
+                },
+                EpidemicPoint {
+                    time: 6,
+                    susceptible: 3,
+                    exposed: 0,
+                    infectious: 0,
+                    recovered: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn seeded_simulations_are_reproducible() {
+        let graph = Graph::new();
+        for t in 0..200u64 {
+            graph
+                .add_edge(t as i64, t % 17, (t * 7 + 3) % 17, NO_PROPS, None)
+                .unwrap();
+        }
+        let run = |seed| {
+            epidemic(
+                &graph.window(0, 150),
+                vec![0u64],
+                0.5,
+                Some(0),
+                Some(Exp::new(0.1).unwrap()),
+                Some(Exp::new(0.5).unwrap()),
+                false,
+                Some(seed),
+            )
+            .unwrap()
+        };
+        assert_eq!(run(3), run(3));
+        let result = run(3);
+        assert!(result.infections.values().all(|i| i.infected < 150));
+        let last = result.curve.last().unwrap();
+        assert_eq!(
+            last.susceptible + last.exposed + last.infectious + last.recovered,
+            17
+        );
+
+        assert!(matches!(
+            epidemic(
+                &graph,
+                vec![99u64],
+                0.5,
+                None,
+                None::<Fixed>,
+                None::<Fixed>,
+                false,
+                None
+            ),
+            Err(GraphError::VertexIdError(99))
+        ));
+    }
+}
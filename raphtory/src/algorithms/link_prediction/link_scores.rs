@@ -0,0 +1,364 @@
+//! Neighbourhood based scores for predicting links between vertices
+//!
+//! Edges are treated as undirected. All scores can weight the neighbours of a vertex by how
+//! recently the vertex interacted with them, such that a neighbour with a latest interaction
+//! `half_life` before the latest time of the view counts half as much as a neighbour
+//! interacted with at the latest time. Without a half-life all neighbours have weight 1 and
+//! the scores reduce to their usual definitions.
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    core::entities::{vertices::input_vertex::InputVertex, VID},
+    db::task::{custom_pool, POOL},
+    prelude::{EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use ordered_float::OrderedFloat;
+use rayon::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::HashMap;
+
+/// The score used to rank a possible link between two vertices
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinkScore {
+    /// The (weighted) number of common neighbours
+    CommonNeighbours,
+    /// The common neighbours relative to all neighbours of the two vertices
+    Jaccard,
+    /// The common neighbours, each weighted by the inverse logarithm of its degree
+    AdamicAdar,
+    /// The common neighbours, each weighted by the inverse of its degree
+    ResourceAllocation,
+    /// The product of the (weighted) degrees of the two vertices
+    PreferentialAttachment,
+}
+
+impl LinkScore {
+    fn name(&self) -> &'static str {
+        match self {
+            LinkScore::CommonNeighbours => "Common Neighbours",
+            LinkScore::Jaccard => "Jaccard",
+            LinkScore::AdamicAdar => "Adamic Adar",
+            LinkScore::ResourceAllocation => "Resource Allocation",
+            LinkScore::PreferentialAttachment => "Preferential Attachment",
+        }
+    }
+
+    fn score(&self, nbrs: &Neighbourhoods, u: usize, v: usize) -> f64 {
+        let (nu, nv) = (&nbrs.weights[u], &nbrs.weights[v]);
+        match self {
+            LinkScore::PreferentialAttachment => {
+                nu.values().sum::<f64>() * nv.values().sum::<f64>()
+            }
+            LinkScore::Jaccard => {
+                let intersection: f64 = nu
+                    .iter()
+                    .filter_map(|(z, wu)| nv.get(z).map(|wv| wu.min(*wv)))
+                    .sum();
+                let union: f64 = nu
+                    .iter()
+                    .map(|(z, wu)| nv.get(z).map_or(*wu, |wv| wu.max(*wv)))
+                    .chain(
+                        nv.iter()
+                            .filter(|(z, _)| !nu.contains_key(z))
+                            .map(|(_, wv)| *wv),
+                    )
+                    .sum();
+                if union > 0.0 {
+                    intersection / union
+                } else {
+                    0.0
+                }
+            }
+            _ => {
+                let (small, large) = if nu.len() <= nv.len() {
+                    (nu, nv)
+                } else {
+                    (nv, nu)
+                };
+                small
+                    .iter()
+                    .filter_map(|(z, ws)| {
+                        let wl = large.get(z)?;
+                        let degree = nbrs.weights[*z].len() as f64;
+                        let factor = match self {
+                            LinkScore::AdamicAdar if degree > 1.0 => 1.0 / degree.ln(),
+                            LinkScore::AdamicAdar => return None,
+                            LinkScore::ResourceAllocation => 1.0 / degree,
+                            _ => 1.0,
+                        };
+                        Some(ws * wl * factor)
+                    })
+                    .sum()
+            }
+        }
+    }
+}
+
+/// The neighbours of every vertex with their recency weights
+struct Neighbourhoods {
+    names: Vec<String>,
+    index: FxHashMap<VID, usize>,
+    weights: Vec<FxHashMap<usize, f64>>,
+}
+
+impl Neighbourhoods {
+    fn from_view<G: GraphViewOps>(graph: &G, half_life: Option<i64>) -> Self {
+        let vertices: Vec<_> = graph.vertices().iter().collect();
+        let index: FxHashMap<VID, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.vertex, i))
+            .collect();
+        let names = vertices.iter().map(|v| v.name()).collect();
+        let now = graph.latest_time().unwrap_or(0);
+        let weights = vertices
+            .iter()
+            .map(|v| {
+                let mut weights: FxHashMap<usize, f64> = FxHashMap::default();
+                for e in v.edges() {
+                    let nbr = if e.src().vertex == v.vertex {
+                        e.dst().vertex
+                    } else {
+                        e.src().vertex
+                    };
+                    if nbr == v.vertex {
+                        continue;
+                    }
+                    let weight = match half_life {
+                        None => 1.0,
+                        Some(half_life) => {
+                            let age = (now - e.latest_time().unwrap_or(now)).max(0);
+                            0.5f64.powf(age as f64 / half_life.max(1) as f64)
+                        }
+                    };
+                    let entry = weights.entry(index[&nbr]).or_insert(0.0);
+                    *entry = entry.max(weight);
+                }
+                weights
+            })
+            .collect();
+        Self {
+            names,
+            index,
+            weights,
+        }
+    }
+}
+
+/// Computes a link prediction score for pairs of vertices
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `pairs` - The pairs of vertices to score, pairs with vertices not in the graph are skipped
+/// * `score` - The score to compute
+/// * `half_life` - Weight neighbours by the recency of their latest interaction, all
+///   neighbours have weight 1 if `None`
+///
+/// Returns:
+///
+/// An AlgorithmResult mapping each pair of vertex names to its score
+///
+pub fn link_scores<G: GraphViewOps, V: InputVertex>(
+    graph: &G,
+    pairs: Vec<(V, V)>,
+    score: LinkScore,
+    half_life: Option<i64>,
+) -> AlgorithmResult<(String, String), f64, OrderedFloat<f64>> {
+    let nbrs = Neighbourhoods::from_view(graph, half_life);
+    let res = pairs
+        .into_iter()
+        .filter_map(|(u, v)| {
+            let u = nbrs.index[&graph.vertex(u)?.vertex];
+            let v = nbrs.index[&graph.vertex(v)?.vertex];
+            let key = (nbrs.names[u].clone(), nbrs.names[v].clone());
+            Some((key, score.score(&nbrs, u, v)))
+        })
+        .collect();
+    let results_type = std::any::type_name::<HashMap<(String, String), f64>>();
+    AlgorithmResult::new(score.name(), results_type, res)
+}
+
+/// Finds the `k` most likely new links of every vertex
+///
+/// Vertices that are already neighbours are not considered. For all scores except preferential
+/// attachment only vertices that share a neighbour can have a non-zero score, so only these are
+/// considered as candidates.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `score` - The score to rank the candidates by
+/// * `k` - The number of candidates to return for each vertex
+/// * `half_life` - Weight neighbours by the recency of their latest interaction, all
+///   neighbours have weight 1 if `None`
+/// * `threads` - Number of threads to use
+///
+/// Returns:
+///
+/// An AlgorithmResult mapping each pair of a vertex and one of its top candidates to their
+/// score
+///
+pub fn top_k_links<G: GraphViewOps>(
+    graph: &G,
+    score: LinkScore,
+    k: usize,
+    half_life: Option<i64>,
+    threads: Option<usize>,
+) -> AlgorithmResult<(String, String), f64, OrderedFloat<f64>> {
+    let nbrs = Neighbourhoods::from_view(graph, half_life);
+    // vertices by decreasing weighted degree for preferential attachment
+    let mut by_degree: Vec<(usize, f64)> = nbrs
+        .weights
+        .iter()
+        .map(|w| w.values().sum())
+        .enumerate()
+        .collect();
+    by_degree.sort_by(|(a, da), (b, db)| {
+        db.total_cmp(da)
+            .then_with(|| nbrs.names[*a].cmp(&nbrs.names[*b]))
+    });
+
+    let pool = threads.map(custom_pool).unwrap_or_else(|| POOL.clone());
+    let res = pool.install(|| {
+        (0..nbrs.names.len())
+            .into_par_iter()
+            .flat_map_iter(|u| {
+                let excluded = |v: &usize| *v == u || nbrs.weights[u].contains_key(v);
+                let mut scored: Vec<(usize, f64)> = match score {
+                    LinkScore::PreferentialAttachment => by_degree
+                        .iter()
+                        .filter(|(v, _)| !excluded(v))
+                        .take(k)
+                        .map(|(v, _)| (*v, score.score(&nbrs, u, *v)))
+                        .collect(),
+                    _ => {
+                        let candidates: FxHashSet<usize> = nbrs.weights[u]
+                            .keys()
+                            .flat_map(|z| nbrs.weights[*z].keys().copied())
+                            .filter(|v| !excluded(v))
+                            .collect();
+                        candidates
+                            .into_iter()
+                            .map(|v| (v, score.score(&nbrs, u, v)))
+                            .collect()
+                    }
+                };
+                scored.sort_by(|(a, sa), (b, sb)| {
+                    sb.total_cmp(sa)
+                        .then_with(|| nbrs.names[*a].cmp(&nbrs.names[*b]))
+                });
+                scored.truncate(k);
+                let nbrs = &nbrs;
+                scored
+                    .into_iter()
+                    .map(move |(v, s)| ((nbrs.names[u].clone(), nbrs.names[v].clone()), s))
+            })
+            .collect()
+    });
+    let results_type = std::any::type_name::<HashMap<(String, String), f64>>();
+    AlgorithmResult::new(score.name(), results_type, res)
+}
+
+#[cfg(test)]
+mod link_scores_test {
+    use super::*;
+    use crate::prelude::*;
+
+    /// 1 and 2 share the neighbours 3 and 4, 4 is also a neighbour of 5
+    fn graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst) in [(1, 1, 3), (2, 3, 2), (3, 1, 4), (4, 2, 4), (5, 4, 5)] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    fn pair_score(graph: &Graph, score: LinkScore, half_life: Option<i64>) -> f64 {
+        let result = link_scores(graph, vec![(1, 2)], score, half_life);
+        *result.get(&("1".to_string(), "2".to_string())).unwrap()
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn static_scores() {
+        let graph = graph();
+        assert_close(pair_score(&graph, LinkScore::CommonNeighbours, None), 2.0);
+        assert_close(pair_score(&graph, LinkScore::Jaccard, None), 1.0);
+        assert_close(
+            pair_score(&graph, LinkScore::AdamicAdar, None),
+            1.0 / 2f64.ln() + 1.0 / 3f64.ln(),
+        );
+        assert_close(
+            pair_score(&graph, LinkScore::ResourceAllocation, None),
+            0.5 + 1.0 / 3.0,
+        );
+        assert_close(
+            pair_score(&graph, LinkScore::PreferentialAttachment, None),
+            4.0,
+        );
+
+        let result = link_scores(&graph, vec![(1, 5), (1, 99)], LinkScore::Jaccard, None);
+        assert_eq!(result.get_all().len(), 1);
+        assert_close(
+            *result.get(&("1".to_string(), "5".to_string())).unwrap(),
+            0.5,
+        );
+    }
+
+    #[test]
+    fn recency_weighted_scores() {
+        let graph = graph();
+        // latest time is 5, so the weights of the interactions are 2^-(5 - t)
+        let w = |t: i64| 0.5f64.powi((5 - t) as i32);
+        assert_close(
+            pair_score(&graph, LinkScore::CommonNeighbours, Some(1)),
+            w(1) * w(2) + w(3) * w(4),
+        );
+        assert_close(
+            pair_score(&graph, LinkScore::Jaccard, Some(1)),
+            (w(1) + w(3)) / (w(2) + w(4)),
+        );
+        assert_close(
+            pair_score(&graph, LinkScore::PreferentialAttachment, Some(1)),
+            (w(1) + w(3)) * (w(2) + w(4)),
+        );
+        // a longer half-life forgets more slowly
+        assert!(
+            pair_score(&graph, LinkScore::CommonNeighbours, Some(10))
+                > pair_score(&graph, LinkScore::CommonNeighbours, Some(1))
+        );
+    }
+
+    #[test]
+    fn top_k_candidates() {
+        let graph = graph();
+        let result = top_k_links(&graph, LinkScore::CommonNeighbours, 1, None, None);
+        let top: HashMap<String, (String, f64)> = result
+            .get_all()
+            .iter()
+            .map(|((u, v), s)| (u.clone(), (v.clone(), *s)))
+            .collect();
+        assert_eq!(top["1"], ("2".to_string(), 2.0));
+        assert_eq!(top["2"], ("1".to_string(), 2.0));
+        assert_eq!(top["5"], ("1".to_string(), 1.0));
+        // 3 and 4 are not connected but share the neighbours 1 and 2
+        assert_eq!(top["3"], ("4".to_string(), 2.0));
+
+        let result = top_k_links(&graph, LinkScore::PreferentialAttachment, 2, None, None);
+        let mut top5: Vec<_> = result
+            .get_all()
+            .iter()
+            .filter(|((u, _), _)| u == "5")
+            .map(|((_, v), s)| (v.clone(), *s))
+            .collect();
+        top5.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(top5, vec![("1".to_string(), 2.0), ("2".to_string(), 2.0)]);
+
+        let window = graph.window(0, 4);
+        let result = top_k_links(&window, LinkScore::CommonNeighbours, 5, None, None);
+        assert!(result.get_all().keys().all(|(u, v)| u != "5" && v != "5"));
+    }
+}
@@ -0,0 +1,242 @@
+//! Assortativity - whether edges tend to connect similar vertices
+//!
+//! Assortativity is positive if edges tend to connect vertices with similar degree or
+//! property values, e.g. hubs connected to hubs, and negative if they tend to connect
+//! dissimilar vertices, e.g. hubs connected to many low degree vertices. It ranges from -1 to 1.
+//!
+//! - `degree_assortativity` - The Pearson correlation of the degrees at either end of the
+//!   edges, see Newman, Mark EJ. "Assortative mixing in networks." Physical Review Letters 89.20
+//!   (2002): 208701.
+//! - `property_assortativity` - The assortativity of a categorical vertex property, see Newman,
+//!   Mark EJ. "Mixing patterns in networks." Physical Review E 67.2 (2003): 026126.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use raphtory::algorithms::metrics::assortativity::*;
+//! use raphtory::core::Direction;
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! for (t, src, dst) in [(1, 1, 2), (2, 1, 3), (3, 1, 4), (4, 4, 5)] {
+//!     g.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+//! }
+//!
+//! println!(
+//!     "degree assortativity: {:?}",
+//!     degree_assortativity(&g, Direction::BOTH, Direction::BOTH)
+//! );
+//! ```
+use crate::{
+    core::{entities::VID, Direction},
+    prelude::{EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use rustc_hash::FxHashMap;
+use std::collections::HashMap;
+
+fn pearson(pairs: &[(f64, f64)]) -> Option<f64> {
+    let n = pairs.len() as f64;
+    if pairs.is_empty() {
+        return None;
+    }
+    let (mean_x, mean_y) = pairs
+        .iter()
+        .fold((0.0, 0.0), |(x, y), (a, b)| (x + a / n, y + b / n));
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in pairs {
+        cov += (x - mean_x) * (y - mean_y);
+        var_x += (x - mean_x).powi(2);
+        var_y += (y - mean_y).powi(2);
+    }
+    let r = cov / (var_x * var_y).sqrt();
+    r.is_finite().then_some(r)
+}
+
+/// Computes the degree assortativity of a graph
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `source_degree` - Which degree of the source of every edge is used
+/// * `target_degree` - Which degree of the destination of every edge is used
+///
+/// If both degrees are `Direction::BOTH`, the graph is treated as undirected and every edge is
+/// counted in both directions.
+///
+/// Returns:
+///
+/// The degree assortativity, `None` if it is undefined because all degrees at one end of the
+/// edges are the same
+///
+pub fn degree_assortativity<G: GraphViewOps>(
+    graph: &G,
+    source_degree: Direction,
+    target_degree: Direction,
+) -> Option<f64> {
+    let degrees: FxHashMap<VID, (usize, usize, usize)> = graph
+        .vertices()
+        .iter()
+        .map(|v| (v.vertex, (v.out_degree(), v.in_degree(), v.degree())))
+        .collect();
+    let degree = |v: VID, direction: Direction| {
+        let (out_degree, in_degree, degree) = degrees[&v];
+        match direction {
+            Direction::OUT => out_degree as f64,
+            Direction::IN => in_degree as f64,
+            Direction::BOTH => degree as f64,
+        }
+    };
+    let undirected = source_degree == Direction::BOTH && target_degree == Direction::BOTH;
+    let mut pairs = vec![];
+    for e in graph.edges() {
+        let (src, dst) = (e.src().vertex, e.dst().vertex);
+        pairs.push((degree(src, source_degree), degree(dst, target_degree)));
+        if undirected {
+            pairs.push((degree(dst, source_degree), degree(src, target_degree)));
+        }
+    }
+    pearson(&pairs)
+}
+
+/// Computes the assortativity of a categorical vertex property
+///
+/// Property values are compared by their string representation. Edges with an endpoint
+/// without the property are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `property` - The name of the vertex property
+/// * `directed` - Whether edges are directed, otherwise every edge is counted in both
+///   directions
+///
+/// Returns:
+///
+/// The property assortativity, `None` if it is undefined because there are no edges between
+/// vertices with the property or all of them have the same value
+///
+pub fn property_assortativity<G: GraphViewOps>(
+    graph: &G,
+    property: &str,
+    directed: bool,
+) -> Option<f64> {
+    let mut categories: HashMap<String, usize> = HashMap::new();
+    let values: FxHashMap<VID, usize> = graph
+        .vertices()
+        .iter()
+        .filter_map(|v| {
+            let value = v.properties().get(property)?.to_string();
+            let next = categories.len();
+            Some((v.vertex, *categories.entry(value).or_insert(next)))
+        })
+        .collect();
+
+    // only the diagonal and the marginals of the mixing matrix are needed
+    let mut diagonal: FxHashMap<usize, f64> = FxHashMap::default();
+    let mut row_sums: FxHashMap<usize, f64> = FxHashMap::default();
+    let mut col_sums: FxHashMap<usize, f64> = FxHashMap::default();
+    let mut total = 0.0;
+    let mut count = |a: usize, b: usize| {
+        if a == b {
+            *diagonal.entry(a).or_default() += 1.0;
+        }
+        *row_sums.entry(a).or_default() += 1.0;
+        *col_sums.entry(b).or_default() += 1.0;
+        total += 1.0;
+    };
+    for e in graph.edges() {
+        if let (Some(&a), Some(&b)) = (values.get(&e.src().vertex), values.get(&e.dst().vertex)) {
+            count(a, b);
+            if !directed {
+                count(b, a);
+            }
+        }
+    }
+    if total == 0.0 {
+        return None;
+    }
+    let trace: f64 = diagonal.values().map(|m| m / total).sum();
+    let expected: f64 = row_sums
+        .iter()
+        .filter_map(|(i, a)| Some(a / total * col_sums.get(i)? / total))
+        .sum();
+    let r = (trace - expected) / (1.0 - expected);
+    r.is_finite().then_some(r)
+}
+
+#[cfg(test)]
+mod assortativity_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.unwrap();
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn degree_assortativity_of_stars_and_pairs() {
+        // a star only connects the hub to leaves
+        let star = Graph::new();
+        for i in 1..=5u64 {
+            star.add_edge(i as i64, 0, i, NO_PROPS, None).unwrap();
+        }
+        assert_close(
+            degree_assortativity(&star, Direction::BOTH, Direction::BOTH),
+            -1.0,
+        );
+        // the hub is always the source, so out-degrees of sources are constant
+        assert_eq!(
+            degree_assortativity(&star, Direction::OUT, Direction::IN),
+            None
+        );
+
+        // adding a triangle, where all degrees are 2, only adds edges at the mean degree
+        let graph = Graph::new();
+        for (src, dst) in [(0, 1), (0, 2), (0, 3), (4, 5), (5, 6), (6, 4)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        assert_close(
+            degree_assortativity(&graph, Direction::BOTH, Direction::BOTH),
+            -1.0,
+        );
+        // pairs of source out-degree and destination in-degree:
+        // (3, 1), (3, 2), (3, 1), (1, 1), (1, 1), (1, 1), (1, 2)
+        graph.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        assert_close(
+            degree_assortativity(&graph, Direction::OUT, Direction::IN),
+            14.0 / (336.0f64 * 70.0).sqrt(),
+        );
+        assert_eq!(
+            degree_assortativity(&Graph::new(), Direction::BOTH, Direction::BOTH),
+            None
+        );
+    }
+
+    #[test]
+    fn property_assortativity_of_groups() {
+        let graph = Graph::new();
+        for (v, group) in [(1, "a"), (2, "a"), (3, "b"), (4, "b"), (5, "c")] {
+            graph.add_vertex(0, v, [("group", group)]).unwrap();
+        }
+        for (src, dst) in [(1, 2), (3, 4), (4, 3)] {
+            graph.add_edge(1, src, dst, NO_PROPS, None).unwrap();
+        }
+        assert_close(property_assortativity(&graph, "group", true), 1.0);
+        assert_close(property_assortativity(&graph, "group", false), 1.0);
+
+        for (src, dst) in [(1, 3), (2, 4), (3, 5)] {
+            graph.add_edge(2, src, dst, NO_PROPS, None).unwrap();
+        }
+        // undirected mixing matrix / 12: [[2, 2, 0], [2, 4, 1], [0, 1, 0]]
+        // trace = 6 / 12, expected = (16 + 49 + 1) / 144
+        let expected = (0.5 - 66.0 / 144.0) / (1.0 - 66.0 / 144.0);
+        assert_close(property_assortativity(&graph, "group", false), expected);
+        // only the edges in the window
+        assert_close(
+            property_assortativity(&graph.window(0, 2), "group", false),
+            1.0,
+        );
+        assert_eq!(property_assortativity(&graph, "missing", false), None);
+    }
+}
@@ -11,12 +11,14 @@
 //! - min_out_degree - The minimum out degree of any vertex in the graph.
 //! - min_in_degree - The minimum in degree of any vertex in the graph.
 //! - average_degree - The average degree of all vertices in the graph.
+//! - degree_distribution - The number of vertices with each in, out or total degree.
 //!
 //!
 //! # Examples
 //!
 //! ```rust
 //! use raphtory::algorithms::metrics::degree::*;
+//! use raphtory::core::Direction;
 //! use raphtory::prelude::*;
 //!
 //! let g = Graph::new();
@@ -41,9 +43,11 @@
 //! print!("Max degree: {:?}", min_degree(&windowed_graph));
 //! print!("Min degree: {:?}", max_degree(&windowed_graph));
 //! print!("Average degree: {:?}", average_degree(&windowed_graph));
+//! print!("Degree distribution: {:?}", degree_distribution(&windowed_graph, Direction::BOTH));
 //! ```
 //!
-use crate::db::api::view::*;
+use crate::{core::Direction, db::api::view::*};
+use std::collections::BTreeMap;
 
 /// The maximum degree of any vertex in the graph
 pub fn max_degree<G: GraphViewOps>(graph: &G) -> usize {
@@ -111,15 +115,47 @@ pub fn average_degree<G: GraphViewOps>(graph: &G) -> f64 {
     degree_totals.0 / degree_totals.1
 }
 
+/// The degree distribution of the graph as a histogram
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `direction` - Whether to use the out-, in- or total degree
+///
+/// Returns:
+///
+/// The number of vertices with each degree, degrees without vertices are omitted
+pub fn degree_distribution<G: GraphViewOps>(
+    graph: &G,
+    direction: Direction,
+) -> BTreeMap<usize, usize> {
+    let mut histogram = BTreeMap::new();
+    for v in graph.vertices() {
+        let degree = match direction {
+            Direction::OUT => v.out_degree(),
+            Direction::IN => v.in_degree(),
+            Direction::BOTH => v.degree(),
+        };
+        *histogram.entry(degree).or_insert(0) += 1;
+    }
+    histogram
+}
+
 #[cfg(test)]
 mod degree_test {
     use crate::{
         algorithms::metrics::degree::{
-            average_degree, max_degree, max_in_degree, min_degree, min_in_degree, min_out_degree,
+            average_degree, degree_distribution, max_degree, max_in_degree, min_degree,
+            min_in_degree, min_out_degree,
+        },
+        core::Direction,
+        db::{
+            api::{mutation::AdditionOps, view::*},
+            graph::graph::Graph,
         },
-        db::{api::mutation::AdditionOps, graph::graph::Graph},
         prelude::NO_PROPS,
     };
+    use std::collections::BTreeMap;
 
     use super::max_out_degree;
 
@@ -168,4 +204,36 @@ mod degree_test {
         assert_eq!(expected_max_degree, actual_max_degree);
         assert_eq!(expected_min_degree, actual_min_degree);
     }
+
+    #[test]
+    fn degree_distribution_test() {
+        let g = Graph::new();
+        for (t, src, dst) in [
+            (1, 1, 2),
+            (2, 1, 3),
+            (3, 2, 1),
+            (4, 3, 2),
+            (5, 1, 4),
+            (6, 4, 5),
+        ] {
+            g.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        assert_eq!(
+            degree_distribution(&g, Direction::OUT),
+            BTreeMap::from([(0, 1), (1, 3), (3, 1)])
+        );
+        assert_eq!(
+            degree_distribution(&g, Direction::IN),
+            BTreeMap::from([(1, 4), (2, 1)])
+        );
+        assert_eq!(
+            degree_distribution(&g, Direction::BOTH),
+            BTreeMap::from([(1, 1), (2, 3), (3, 1)])
+        );
+        assert_eq!(
+            degree_distribution(&g.window(0, 3), Direction::BOTH),
+            BTreeMap::from([(1, 2), (2, 1)])
+        );
+        assert!(degree_distribution(&Graph::new(), Direction::BOTH).is_empty());
+    }
 }
@@ -0,0 +1,263 @@
+//! Distance based metrics: eccentricity, diameter and radius
+//!
+//! Distances are the number of hops on shortest paths following edges in the given direction.
+//! The eccentricity of a vertex is the largest distance to any vertex it can reach, so
+//! distances between vertices that cannot reach each other are ignored rather than infinite.
+//! This keeps the metrics meaningful on views that are not connected, e.g. windows of a
+//! temporal graph.
+//!
+//! - `eccentricity` - The eccentricity of every vertex
+//! - `diameter` - The largest eccentricity
+//! - `radius` - The smallest eccentricity of a vertex that can reach another vertex
+//! - `approximate_diameter` - A lower bound of the diameter by repeated double sweeps, which
+//!   only needs a few breadth-first searches instead of one per vertex
+//!
+//! # Examples
+//!
+//! ```rust
+//! use raphtory::algorithms::metrics::eccentricity::*;
+//! use raphtory::core::Direction;
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! for (t, src, dst) in [(1, 1, 2), (2, 2, 3), (3, 3, 4), (4, 2, 5)] {
+//!     g.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+//! }
+//!
+//! assert_eq!(diameter(&g, Direction::BOTH, None), 3);
+//! assert_eq!(radius(&g, Direction::BOTH, None), 2);
+//! for window in g.rolling(2, None).unwrap() {
+//!     println!("diameter: {}", diameter(&window, Direction::BOTH, None));
+//! }
+//! ```
+use crate::{
+    algorithms::algorithm_result::AlgorithmResult,
+    core::{entities::VID, Direction},
+    db::task::{custom_pool, POOL},
+    prelude::{GraphViewOps, VertexViewOps},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+use std::collections::{HashMap, VecDeque};
+
+/// The neighbours of every vertex in the given direction as indices
+fn adjacency<G: GraphViewOps>(graph: &G, direction: Direction) -> (Vec<String>, Vec<Vec<usize>>) {
+    let vertices: Vec<_> = graph.vertices().iter().collect();
+    let index: FxHashMap<VID, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.vertex, i))
+        .collect();
+    let names = vertices.iter().map(|v| v.name()).collect();
+    let adj = vertices
+        .iter()
+        .map(|v| {
+            let neighbours = match direction {
+                Direction::OUT => v.out_neighbours(),
+                Direction::IN => v.in_neighbours(),
+                Direction::BOTH => v.neighbours(),
+            };
+            let mut neighbours: Vec<usize> = neighbours.iter().map(|n| index[&n.vertex]).collect();
+            neighbours.sort();
+            neighbours.dedup();
+            neighbours
+        })
+        .collect();
+    (names, adj)
+}
+
+/// The farthest vertex reachable from `source` and its distance
+fn farthest(adj: &[Vec<usize>], source: usize) -> (usize, usize) {
+    let mut distance = vec![usize::MAX; adj.len()];
+    let mut queue = VecDeque::from([source]);
+    distance[source] = 0;
+    let mut last = source;
+    while let Some(v) = queue.pop_front() {
+        last = v;
+        for &n in adj[v].iter() {
+            if distance[n] == usize::MAX {
+                distance[n] = distance[v] + 1;
+                queue.push_back(n);
+            }
+        }
+    }
+    (last, distance[last])
+}
+
+fn eccentricities<G: GraphViewOps>(
+    graph: &G,
+    direction: Direction,
+    threads: Option<usize>,
+) -> (Vec<String>, Vec<usize>) {
+    let (names, adj) = adjacency(graph, direction);
+    let pool = threads.map(custom_pool).unwrap_or_else(|| POOL.clone());
+    let eccentricities = pool.install(|| {
+        (0..adj.len())
+            .into_par_iter()
+            .map(|v| farthest(&adj, v).1)
+            .collect()
+    });
+    (names, eccentricities)
+}
+
+/// Computes the eccentricity of every vertex, the largest distance to a vertex it can reach
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `direction` - Which edges are followed, `Direction::BOTH` treats the graph as undirected
+/// * `threads` - Number of threads to use
+///
+/// Returns:
+///
+/// An AlgorithmResult mapping vertex names to their eccentricity
+///
+pub fn eccentricity<G: GraphViewOps>(
+    graph: &G,
+    direction: Direction,
+    threads: Option<usize>,
+) -> AlgorithmResult<String, u64> {
+    let (names, eccentricities) = eccentricities(graph, direction, threads);
+    let results: HashMap<String, u64> = names
+        .into_iter()
+        .zip(eccentricities)
+        .map(|(name, e)| (name, e as u64))
+        .collect();
+    let results_type = std::any::type_name::<HashMap<String, u64>>();
+    AlgorithmResult::new("Eccentricity", results_type, results)
+}
+
+/// Computes the diameter of a graph, the largest distance between two vertices that are
+/// connected by a path
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `direction` - Which edges are followed, `Direction::BOTH` treats the graph as undirected
+/// * `threads` - Number of threads to use
+///
+/// Returns:
+///
+/// The diameter, 0 for a graph without edges
+///
+pub fn diameter<G: GraphViewOps>(graph: &G, direction: Direction, threads: Option<usize>) -> usize {
+    let (_, eccentricities) = eccentricities(graph, direction, threads);
+    eccentricities.into_iter().max().unwrap_or(0)
+}
+
+/// Computes the radius of a graph, the smallest eccentricity of a vertex that can reach at
+/// least one other vertex
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `direction` - Which edges are followed, `Direction::BOTH` treats the graph as undirected
+/// * `threads` - Number of threads to use
+///
+/// Returns:
+///
+/// The radius, 0 for a graph without edges
+///
+pub fn radius<G: GraphViewOps>(graph: &G, direction: Direction, threads: Option<usize>) -> usize {
+    let (_, eccentricities) = eccentricities(graph, direction, threads);
+    eccentricities
+        .into_iter()
+        .filter(|e| *e > 0)
+        .min()
+        .unwrap_or(0)
+}
+
+/// Approximates the diameter of a graph with double sweeps
+///
+/// Every sweep runs a breadth-first search from a random vertex and a second one from the
+/// farthest vertex found. The largest distance found is a lower bound of the diameter, which
+/// is exact for trees and usually very close on real-world graphs.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `direction` - Which edges are followed, `Direction::BOTH` treats the graph as undirected
+/// * `sweeps` - The number of double sweeps
+/// * `seed` - The seed of the random number generator
+///
+/// Returns:
+///
+/// A lower bound of the diameter
+///
+pub fn approximate_diameter<G: GraphViewOps>(
+    graph: &G,
+    direction: Direction,
+    sweeps: usize,
+    seed: Option<u64>,
+) -> usize {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let (_, adj) = adjacency(graph, direction);
+    if adj.is_empty() {
+        return 0;
+    }
+    let mut best = 0;
+    for _ in 0..sweeps {
+        let (far, d) = farthest(&adj, rng.gen_range(0..adj.len()));
+        let (_, d2) = farthest(&adj, far);
+        best = best.max(d).max(d2);
+    }
+    best
+}
+
+#[cfg(test)]
+mod eccentricity_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn graph() -> Graph {
+        let graph = Graph::new();
+        // the path 1 -> 2 -> 3 -> 4 with a branch 2 -> 5 and a separate edge 6 -> 7
+        for (t, src, dst) in [(1, 1, 2), (2, 2, 3), (3, 3, 4), (4, 2, 5), (5, 6, 7)] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn undirected_distances() {
+        let graph = graph();
+        let result = eccentricity(&graph, Direction::BOTH, None);
+        let expected: HashMap<String, u64> = [
+            ("1", 3),
+            ("2", 2),
+            ("3", 2),
+            ("4", 3),
+            ("5", 3),
+            ("6", 1),
+            ("7", 1),
+        ]
+        .into_iter()
+        .map(|(v, e)| (v.to_string(), e))
+        .collect();
+        assert_eq!(result.get_all(), &expected);
+        assert_eq!(diameter(&graph, Direction::BOTH, None), 3);
+        assert_eq!(radius(&graph, Direction::BOTH, Some(2)), 1);
+        assert_eq!(approximate_diameter(&graph, Direction::BOTH, 5, Some(1)), 3);
+    }
+
+    #[test]
+    fn directed_distances_and_windows() {
+        let graph = graph();
+        let result = eccentricity(&graph, Direction::OUT, None);
+        assert_eq!(result.get(&"1".to_string()), Some(&3));
+        assert_eq!(result.get(&"4".to_string()), Some(&0));
+        assert_eq!(
+            eccentricity(&graph, Direction::IN, None).get(&"4".to_string()),
+            Some(&3)
+        );
+        assert_eq!(radius(&graph, Direction::OUT, None), 1);
+
+        assert_eq!(diameter(&graph.window(2, 4), Direction::BOTH, None), 2);
+        assert_eq!(diameter(&Graph::new(), Direction::BOTH, None), 0);
+        assert_eq!(
+            approximate_diameter(&Graph::new(), Direction::BOTH, 5, None),
+            0
+        );
+    }
+}
@@ -1,6 +1,8 @@
+pub mod assortativity;
 pub mod balance;
 pub mod clustering_coefficient;
 pub mod degree;
 pub mod directed_graph_density;
+pub mod eccentricity;
 pub mod local_clustering_coefficient;
 pub mod reciprocity;
@@ -30,6 +30,9 @@ pub mod algorithm_result;
 pub mod centrality;
 pub mod community_detection;
 pub mod cores;
+pub mod dynamics;
+pub mod link_prediction;
 pub mod metrics;
 pub mod motifs;
 pub mod pathing;
+pub mod sampling;
@@ -1,4 +1,5 @@
 pub mod local_triangle_count;
+pub mod temporal_cycles;
 pub mod three_node_local_single_thread;
 pub mod three_node_motifs;
 pub mod three_node_temporal_motifs;
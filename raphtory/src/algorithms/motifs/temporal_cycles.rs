@@ -0,0 +1,266 @@
+//! Time-respecting cycles, e.g. money moving in a ring between accounts
+//!
+//! A temporal cycle is a sequence of exploded edges `(v_1, v_2, t_1), (v_2, v_3, t_2), ...,
+//! (v_k, v_1, t_k)` between distinct vertices where every edge happens strictly after the
+//! previous one, i.e. `t_1 < t_2 < ... < t_k`. As the times increase, every cycle is found
+//! exactly once, starting from its earliest edge.
+use crate::{
+    core::entities::VID,
+    prelude::{EdgeListOps, EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use rustc_hash::FxHashMap;
+
+/// An exploded edge as `(src, dst, time)` with the indices of the vertices
+type CycleEdge = (usize, usize, i64);
+
+struct CycleSearch<'a> {
+    /// outgoing exploded edges of each vertex sorted by time as `(time, dst)`
+    out: Vec<Vec<(i64, usize)>>,
+    max_length: usize,
+    max_duration: Option<i64>,
+    limit: Option<usize>,
+    found: usize,
+    on_path: Vec<bool>,
+    path: Vec<CycleEdge>,
+    visit: &'a mut dyn FnMut(&[CycleEdge]),
+}
+
+impl<'a> CycleSearch<'a> {
+    fn done(&self) -> bool {
+        self.limit.map_or(false, |limit| self.found >= limit)
+    }
+
+    /// Extend the path ending at `v` at time `t` back to `start`
+    fn extend(&mut self, start: usize, deadline: i64, v: usize, t: i64) {
+        let first = self.out[v].partition_point(|(s, _)| *s <= t);
+        let mut i = first;
+        while i < self.out[v].len() && !self.done() {
+            let (s, w) = self.out[v][i];
+            i += 1;
+            if s > deadline {
+                break;
+            }
+            if w == start {
+                self.path.push((v, w, s));
+                self.found += 1;
+                (self.visit)(&self.path);
+                self.path.pop();
+            } else if !self.on_path[w] && self.path.len() + 1 < self.max_length {
+                self.on_path[w] = true;
+                self.path.push((v, w, s));
+                self.extend(start, deadline, w, s);
+                self.path.pop();
+                self.on_path[w] = false;
+            }
+        }
+    }
+
+    fn run(&mut self) {
+        for v in 0..self.out.len() {
+            for i in 0..self.out[v].len() {
+                if self.done() {
+                    return;
+                }
+                let (t, w) = self.out[v][i];
+                let deadline = self.max_duration.map_or(i64::MAX, |d| t.saturating_add(d));
+                if w == v {
+                    self.path.push((v, w, t));
+                    self.found += 1;
+                    (self.visit)(&self.path);
+                    self.path.pop();
+                } else if self.max_length > 1 {
+                    self.on_path[v] = true;
+                    self.on_path[w] = true;
+                    self.path.push((v, w, t));
+                    self.extend(v, deadline, w, t);
+                    self.path.pop();
+                    self.on_path[w] = false;
+                    self.on_path[v] = false;
+                }
+            }
+        }
+    }
+}
+
+/// Run `visit` on every temporal cycle, returns the number of cycles found
+fn search_cycles<G: GraphViewOps>(
+    graph: &G,
+    max_length: usize,
+    max_duration: Option<i64>,
+    limit: Option<usize>,
+    names: &mut Vec<String>,
+    visit: &mut dyn FnMut(&[CycleEdge]),
+) -> usize {
+    let vertices: Vec<_> = graph.vertices().iter().collect();
+    let index: FxHashMap<VID, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.vertex, i))
+        .collect();
+    *names = vertices.iter().map(|v| v.name()).collect();
+    let mut out: Vec<Vec<(i64, usize)>> = vec![vec![]; vertices.len()];
+    for e in graph.edges().explode() {
+        if let Some(t) = e.time() {
+            out[index[&e.src().vertex]].push((t, index[&e.dst().vertex]));
+        }
+    }
+    for edges in out.iter_mut() {
+        edges.sort();
+    }
+    let mut search = CycleSearch {
+        out,
+        max_length,
+        max_duration,
+        limit,
+        found: 0,
+        on_path: vec![false; vertices.len()],
+        path: vec![],
+        visit,
+    };
+    search.run();
+    search.found
+}
+
+/// Finds the temporal cycles of a graph
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `max_length` - The maximum number of edges in a cycle
+/// * `max_duration` - The maximum time between the first and the last edge of a cycle
+/// * `limit` - Stop after this many cycles were found
+///
+/// Returns:
+///
+/// The cycles as lists of exploded edges `(src, dst, time)` in time order
+///
+pub fn temporal_cycles<G: GraphViewOps>(
+    graph: &G,
+    max_length: usize,
+    max_duration: Option<i64>,
+    limit: Option<usize>,
+) -> Vec<Vec<(String, String, i64)>> {
+    let mut cycles: Vec<Vec<CycleEdge>> = vec![];
+    let mut names = vec![];
+    search_cycles(
+        graph,
+        max_length,
+        max_duration,
+        limit,
+        &mut names,
+        &mut |path| cycles.push(path.to_vec()),
+    );
+    cycles
+        .into_iter()
+        .map(|cycle| {
+            cycle
+                .into_iter()
+                .map(|(src, dst, t)| (names[src].clone(), names[dst].clone(), t))
+                .collect()
+        })
+        .collect()
+}
+
+/// Counts the temporal cycles of a graph without storing them
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `max_length` - The maximum number of edges in a cycle
+/// * `max_duration` - The maximum time between the first and the last edge of a cycle
+/// * `limit` - Stop counting at this number of cycles
+///
+/// Returns:
+///
+/// The number of cycles, at most `limit`
+///
+pub fn count_temporal_cycles<G: GraphViewOps>(
+    graph: &G,
+    max_length: usize,
+    max_duration: Option<i64>,
+    limit: Option<usize>,
+) -> usize {
+    search_cycles(
+        graph,
+        max_length,
+        max_duration,
+        limit,
+        &mut vec![],
+        &mut |_| {},
+    )
+}
+
+#[cfg(test)]
+mod temporal_cycles_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst) in [
+            (1, "a", "b"),
+            (2, "b", "c"),
+            (3, "c", "a"),
+            // d -> e -> f -> d goes back in time
+            (3, "d", "e"),
+            (2, "e", "f"),
+            (1, "f", "d"),
+            (4, "a", "b"),
+            (5, "b", "a"),
+            (10, "c", "a"),
+        ] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    fn cycle(edges: &[(&str, &str, i64)]) -> Vec<(String, String, i64)> {
+        edges
+            .iter()
+            .map(|(src, dst, t)| (src.to_string(), dst.to_string(), *t))
+            .collect()
+    }
+
+    #[test]
+    fn finds_time_respecting_cycles() {
+        let graph = graph();
+        let mut cycles = temporal_cycles(&graph, 3, None, None);
+        cycles.sort();
+        assert_eq!(
+            cycles,
+            vec![
+                cycle(&[("a", "b", 1), ("b", "a", 5)]),
+                cycle(&[("a", "b", 1), ("b", "c", 2), ("c", "a", 3)]),
+                cycle(&[("a", "b", 1), ("b", "c", 2), ("c", "a", 10)]),
+                cycle(&[("a", "b", 4), ("b", "a", 5)]),
+                cycle(&[("b", "c", 2), ("c", "a", 3), ("a", "b", 4)]),
+            ]
+        );
+        assert_eq!(count_temporal_cycles(&graph, 3, None, None), 5);
+        assert_eq!(count_temporal_cycles(&graph, 2, None, None), 2);
+        assert_eq!(count_temporal_cycles(&graph, 3, None, Some(3)), 3);
+        assert_eq!(temporal_cycles(&graph, 3, None, Some(3)).len(), 3);
+    }
+
+    #[test]
+    fn duration_windows_and_self_loops() {
+        let graph = graph();
+        let mut cycles = temporal_cycles(&graph, 3, Some(2), None);
+        cycles.sort();
+        assert_eq!(
+            cycles,
+            vec![
+                cycle(&[("a", "b", 1), ("b", "c", 2), ("c", "a", 3)]),
+                cycle(&[("a", "b", 4), ("b", "a", 5)]),
+                cycle(&[("b", "c", 2), ("c", "a", 3), ("a", "b", 4)]),
+            ]
+        );
+        assert_eq!(count_temporal_cycles(&graph.window(0, 4), 3, None, None), 1);
+
+        graph.add_edge(7, "x", "x", NO_PROPS, None).unwrap();
+        assert_eq!(
+            temporal_cycles(&graph.window(6, 8), 3, None, None),
+            vec![cycle(&[("x", "x", 7)])]
+        );
+    }
+}
@@ -1,3 +1,5 @@
 pub mod dijkstra;
+pub mod random_walks;
 pub mod single_source_shortest_path;
+pub mod temporal_paths;
 pub mod temporal_reachability;
@@ -0,0 +1,379 @@
+//! Random walks for generating vertex sequences, e.g. to train vertex embeddings
+//!
+//! Walks are generated in parallel, every walk uses its own random number generator derived
+//! from the seed, so the walks only depend on the seed and not on the number of threads.
+use crate::{
+    algorithms::centrality::pagerank::{edge_weight, WeightAggregation},
+    core::{entities::VID, Direction},
+    db::task::{custom_pool, POOL},
+    prelude::{EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+
+/// How the next vertex of a walk is chosen
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WalkType {
+    /// Choose a neighbour proportionally to the edge weight
+    Uniform,
+    /// Bias the choice by the previous vertex of the walk (node2vec). Returning to the previous
+    /// vertex is weighted by `1 / p`, moving to a neighbour of the previous vertex by 1 and
+    /// moving further away by `1 / q`.
+    Node2Vec { p: f64, q: f64 },
+    /// Follow the exploded edges forward in time (CTDNE), every step uses an edge that is not
+    /// earlier than the edge of the previous step
+    Temporal,
+}
+
+/// Sample an index proportionally to the positive weights
+fn sample<R: Rng>(rng: &mut R, weights: impl Iterator<Item = f64> + Clone) -> Option<usize> {
+    let total: f64 = weights.clone().filter(|w| *w > 0.0).sum();
+    if total <= 0.0 || !total.is_finite() {
+        return None;
+    }
+    let mut x = rng.gen::<f64>() * total;
+    let mut last = None;
+    for (i, w) in weights.enumerate() {
+        if w <= 0.0 {
+            continue;
+        }
+        if x < w {
+            return Some(i);
+        }
+        x -= w;
+        last = Some(i);
+    }
+    last
+}
+
+struct WalkGraph {
+    names: Vec<String>,
+    /// neighbours of each vertex sorted by index with the edge weights
+    adj: Vec<Vec<(usize, f64)>>,
+    /// exploded edges of each vertex sorted by time as `(time, neighbour, weight)`
+    events: Vec<Vec<(i64, usize, f64)>>,
+}
+
+impl WalkGraph {
+    fn from_view<G: GraphViewOps>(
+        graph: &G,
+        direction: Direction,
+        weight: Option<&str>,
+        temporal: bool,
+    ) -> Self {
+        let vertices: Vec<_> = graph.vertices().iter().collect();
+        let index: FxHashMap<VID, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.vertex, i))
+            .collect();
+        let names = vertices.iter().map(|v| v.name()).collect();
+        let mut adj = Vec::with_capacity(vertices.len());
+        let mut events = Vec::with_capacity(vertices.len());
+        for v in vertices.iter() {
+            let edges = match direction {
+                Direction::OUT => v.out_edges(),
+                Direction::IN => v.in_edges(),
+                Direction::BOTH => v.edges(),
+            };
+            let mut nbrs: FxHashMap<usize, f64> = FxHashMap::default();
+            let mut vertex_events = vec![];
+            for e in edges {
+                let nbr = if e.src().vertex == v.vertex {
+                    e.dst().vertex
+                } else {
+                    e.src().vertex
+                };
+                let nbr = index[&nbr];
+                if temporal {
+                    for ee in e.explode() {
+                        let w = weight.map_or(1.0, |w| edge_weight(&ee, w, WeightAggregation::Sum));
+                        if let Some(t) = ee.time() {
+                            vertex_events.push((t, nbr, w));
+                        }
+                    }
+                } else {
+                    let w = weight.map_or(1.0, |w| edge_weight(&e, w, WeightAggregation::Sum));
+                    *nbrs.entry(nbr).or_insert(0.0) += w;
+                }
+            }
+            let mut nbrs: Vec<(usize, f64)> = nbrs.into_iter().collect();
+            nbrs.sort_by_key(|(n, _)| *n);
+            vertex_events.sort_by_key(|(t, n, _)| (*t, *n));
+            adj.push(nbrs);
+            events.push(vertex_events);
+        }
+        Self { names, adj, events }
+    }
+
+    fn is_neighbour(&self, v: usize, n: usize) -> bool {
+        self.adj[v].binary_search_by_key(&n, |(m, _)| *m).is_ok()
+    }
+
+    fn walk<R: Rng>(
+        &self,
+        rng: &mut R,
+        start: usize,
+        length: usize,
+        walk_type: WalkType,
+    ) -> Vec<String> {
+        let mut walk = vec![start];
+        let mut time = i64::MIN;
+        while walk.len() < length {
+            let current = walk[walk.len() - 1];
+            let next = match walk_type {
+                WalkType::Uniform => {
+                    let nbrs = &self.adj[current];
+                    sample(rng, nbrs.iter().map(|(_, w)| *w)).map(|i| nbrs[i].0)
+                }
+                WalkType::Node2Vec { p, q } => {
+                    let nbrs = &self.adj[current];
+                    let prev = (walk.len() > 1).then(|| walk[walk.len() - 2]);
+                    let weights = nbrs.iter().map(|(n, w)| match prev {
+                        None => *w,
+                        Some(prev) if *n == prev => w / p,
+                        Some(prev) if self.is_neighbour(prev, *n) => *w,
+                        Some(_) => w / q,
+                    });
+                    sample(rng, weights).map(|i| nbrs[i].0)
+                }
+                WalkType::Temporal => {
+                    let events = &self.events[current];
+                    let first = events.partition_point(|(t, _, _)| *t < time);
+                    let events = &events[first..];
+                    sample(rng, events.iter().map(|(_, _, w)| *w)).map(|i| {
+                        time = events[i].0;
+                        events[i].1
+                    })
+                }
+            };
+            match next {
+                Some(next) => walk.push(next),
+                None => break,
+            }
+        }
+        walk.into_iter().map(|v| self.names[v].clone()).collect()
+    }
+}
+
+/// Generates random walks starting from every vertex of a graph
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `walk_length` - The maximum number of vertices in a walk, walks end early if there is no
+///   edge to follow
+/// * `walks_per_vertex` - The number of walks starting from each vertex
+/// * `walk_type` - How the next vertex of a walk is chosen
+/// * `direction` - Which edges of a vertex can be followed
+/// * `weight` - The name of a numeric edge property to choose edges proportionally to, all
+///   edges have weight 1 if `None`. For static walks the values of the property in the view
+///   are summed.
+/// * `seed` - The seed of the random number generator
+/// * `threads` - Number of threads to use
+///
+/// Returns:
+///
+/// The walks as sequences of vertex names, the `i`-th walk of every vertex comes after the
+/// `(i - 1)`-th walk of all vertices
+///
+#[allow(clippy::too_many_arguments)]
+pub fn random_walks<G: GraphViewOps>(
+    graph: &G,
+    walk_length: usize,
+    walks_per_vertex: usize,
+    walk_type: WalkType,
+    direction: Direction,
+    weight: Option<&str>,
+    seed: Option<u64>,
+    threads: Option<usize>,
+) -> Vec<Vec<String>> {
+    let g = WalkGraph::from_view(graph, direction, weight, walk_type == WalkType::Temporal);
+    let seed = seed.unwrap_or_else(|| StdRng::from_entropy().gen());
+    let n = g.names.len();
+    let pool = threads.map(custom_pool).unwrap_or_else(|| POOL.clone());
+    pool.install(|| {
+        (0..n * walks_per_vertex)
+            .into_par_iter()
+            .map(|i| {
+                let mut rng = StdRng::seed_from_u64(walk_seed(seed, i as u64));
+                g.walk(&mut rng, i % n, walk_length, walk_type)
+            })
+            .collect()
+    })
+}
+
+/// SplitMix64 finaliser, spreads nearby inputs over the whole `u64` range
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Seed for walk `i`, so that runs with neighbouring seeds do not share walks
+fn walk_seed(seed: u64, i: u64) -> u64 {
+    splitmix64(splitmix64(seed) ^ i)
+}
+
+#[cfg(test)]
+mod random_walks_test {
+    use super::*;
+    use crate::{db::api::view::Layer, prelude::*};
+
+    fn path_graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst) in [(1, "a", "b"), (2, "b", "c"), (3, "c", "d")] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    fn walks(graph: &Graph, walk_type: WalkType, direction: Direction) -> Vec<Vec<String>> {
+        random_walks(graph, 10, 2, walk_type, direction, None, Some(7), None)
+    }
+
+    #[test]
+    fn uniform_walks_follow_edges() {
+        let graph = path_graph();
+        let walks = walks(&graph, WalkType::Uniform, Direction::OUT);
+        assert_eq!(walks.len(), 8);
+        assert_eq!(walks[0], vec!["a", "b", "c", "d"]);
+        assert_eq!(walks[3], vec!["d"]);
+        assert_eq!(walks[4..], walks[..4]);
+
+        let walks = random_walks(
+            &graph,
+            10,
+            1,
+            WalkType::Uniform,
+            Direction::BOTH,
+            None,
+            Some(7),
+            None,
+        );
+        for walk in walks {
+            assert_eq!(walk.len(), 10);
+            for pair in walk.windows(2) {
+                let (a, b) = (pair[0].as_str(), pair[1].as_str());
+                assert!(graph.has_edge(a, b, Layer::All) || graph.has_edge(b, a, Layer::All));
+            }
+        }
+    }
+
+    #[test]
+    fn walks_are_reproducible() {
+        let graph = Graph::new();
+        for i in 0..20u64 {
+            graph
+                .add_edge(0, i, (i * 7 + 3) % 20, NO_PROPS, None)
+                .unwrap();
+            graph
+                .add_edge(0, i, (i * 3 + 1) % 20, NO_PROPS, None)
+                .unwrap();
+        }
+        let node2vec = WalkType::Node2Vec { p: 0.5, q: 2.0 };
+        let a = random_walks(
+            &graph,
+            20,
+            3,
+            node2vec,
+            Direction::BOTH,
+            None,
+            Some(1),
+            Some(1),
+        );
+        let b = random_walks(
+            &graph,
+            20,
+            3,
+            node2vec,
+            Direction::BOTH,
+            None,
+            Some(1),
+            Some(4),
+        );
+        assert_eq!(a, b);
+        let c = random_walks(
+            &graph,
+            20,
+            3,
+            node2vec,
+            Direction::BOTH,
+            None,
+            Some(2),
+            Some(4),
+        );
+        assert_ne!(a, c);
+        // neighbouring seeds must not produce shifted copies of the same walks
+        assert_ne!(a[1..], c[..c.len() - 1]);
+        for i in 0..100 {
+            assert_ne!(walk_seed(1, i + 1), walk_seed(2, i));
+        }
+    }
+
+    #[test]
+    fn weighted_and_node2vec_walks() {
+        let graph = Graph::new();
+        graph.add_edge(0, "a", "b", [("w", 1.0)], None).unwrap();
+        graph.add_edge(0, "a", "c", [("w", 0.0)], None).unwrap();
+        let walks = random_walks(
+            &graph,
+            2,
+            20,
+            WalkType::Uniform,
+            Direction::OUT,
+            Some("w"),
+            Some(3),
+            None,
+        );
+        assert!(walks
+            .iter()
+            .filter(|w| w[0] == "a")
+            .all(|w| w == &vec!["a", "b"]));
+
+        // a small p makes walks return to the previous vertex
+        let walks = random_walks(
+            &graph,
+            3,
+            20,
+            WalkType::Node2Vec { p: 1e-9, q: 1.0 },
+            Direction::BOTH,
+            None,
+            Some(3),
+            None,
+        );
+        for walk in walks.iter().filter(|w| w[0] == "b") {
+            assert_eq!(walk, &vec!["b", "a", "b"]);
+        }
+    }
+
+    #[test]
+    fn temporal_walks_go_forward_in_time() {
+        let graph = Graph::new();
+        // b -> c happens before a -> b, so walks from a stop at b
+        graph.add_edge(1, "b", "c", NO_PROPS, None).unwrap();
+        graph.add_edge(2, "a", "b", NO_PROPS, None).unwrap();
+        graph.add_edge(3, "b", "d", NO_PROPS, None).unwrap();
+        let walks = walks(&graph, WalkType::Temporal, Direction::OUT);
+        for walk in walks.iter().filter(|w| w[0] == "a") {
+            assert_eq!(walk, &vec!["a", "b", "d"]);
+        }
+
+        let walks = random_walks(
+            &graph.window(0, 3),
+            10,
+            2,
+            WalkType::Temporal,
+            Direction::OUT,
+            None,
+            Some(7),
+            None,
+        );
+        for walk in walks.iter().filter(|w| w[0] == "a") {
+            assert_eq!(walk, &vec!["a", "b"]);
+        }
+        assert!(walks.iter().all(|w| !w.contains(&"d".to_string())));
+    }
+}
@@ -0,0 +1,533 @@
+//! Time-respecting paths from a single source
+//!
+//! A path is a sequence of exploded edges `(v_1, v_2, t_1), (v_2, v_3, t_2), ...` where every edge
+//! departs after the previous edge arrived, i.e. `t_i + delay_i <= t_i+1`.
+use crate::{
+    core::{
+        entities::{vertices::input_vertex::InputVertex, VID},
+        utils::errors::GraphError,
+    },
+    prelude::{EdgeListOps, EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+};
+
+/// The criterion used to pick the path to each vertex
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TemporalPathType {
+    /// The path that arrives first
+    EarliestArrival,
+    /// The path that leaves the source last
+    LatestDeparture,
+    /// The path with the smallest difference between arrival and departure
+    Fastest,
+    /// The path with the fewest edges
+    Shortest,
+}
+
+/// A time-respecting path
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemporalPath {
+    /// The time the path leaves the source
+    pub departure: i64,
+    /// The time the path arrives at its last vertex, including the delay of the last edge
+    pub arrival: i64,
+    /// The exploded edges of the path as `(src, dst, time)`
+    pub edges: Vec<(String, String, i64)>,
+}
+
+impl TemporalPath {
+    pub fn duration(&self) -> i64 {
+        self.arrival - self.departure
+    }
+
+    pub fn hops(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+struct Event {
+    src: usize,
+    dst: usize,
+    time: i64,
+    arrival: i64,
+}
+
+/// The best ways of reaching the end of an exploded edge from the source
+#[derive(Clone, Copy, PartialEq)]
+struct Label {
+    /// latest departure from the source, with the fewest edges among those
+    start: i64,
+    start_hops: usize,
+    start_pred: Option<usize>,
+    /// fewest edges
+    hops: usize,
+    hops_pred: Option<usize>,
+}
+
+impl Label {
+    fn improves(&self, other: &Option<Label>) -> bool {
+        match other {
+            None => true,
+            Some(other) => {
+                (self.start, other.start_hops) > (other.start, self.start_hops)
+                    || self.hops < other.hops
+            }
+        }
+    }
+}
+
+/// The best value of `K` among the arrivals at a vertex that are still in the waiting window
+///
+/// Arrivals have to be pushed in order of arrival time. Entries that are worse than a later
+/// arrival can never be the best again and are dropped, so the front is always the best.
+struct BestArrival<K> {
+    /// `(arrival, edge, key)` with non-increasing keys
+    entries: VecDeque<(i64, usize, K)>,
+}
+
+impl<K: Ord + Copy> BestArrival<K> {
+    fn new() -> Self {
+        Self {
+            entries: VecDeque::new(),
+        }
+    }
+
+    fn push(&mut self, arrival: i64, edge: usize, key: K) {
+        while self.entries.back().map_or(false, |(_, _, k)| *k < key) {
+            self.entries.pop_back();
+        }
+        self.entries.push_back((arrival, edge, key));
+    }
+
+    /// Drop the arrivals before `earliest`
+    fn expire(&mut self, earliest: i64) {
+        while self
+            .entries
+            .front()
+            .map_or(false, |(a, _, _)| *a < earliest)
+        {
+            self.entries.pop_front();
+        }
+    }
+
+    fn best(&self) -> Option<(usize, K)> {
+        self.entries.front().map(|(_, edge, key)| (*edge, *key))
+    }
+}
+
+/// The reachable edges arriving at a vertex
+struct Arrivals {
+    /// arrivals that are later than the current time as `(arrival, edge)`
+    pending: BinaryHeap<Reverse<(i64, usize)>>,
+    /// latest departure from the source, with the fewest edges among those
+    start: BestArrival<(i64, Reverse<usize>)>,
+    /// fewest edges
+    hops: BestArrival<Reverse<usize>>,
+}
+
+impl Arrivals {
+    fn new() -> Self {
+        Self {
+            pending: BinaryHeap::new(),
+            start: BestArrival::new(),
+            hops: BestArrival::new(),
+        }
+    }
+
+    /// Move the arrivals up to `time` out of `pending` and drop those before `earliest`
+    fn advance(&mut self, time: i64, earliest: i64, labels: &[Option<Label>]) {
+        while let Some(&Reverse((arrival, edge))) = self.pending.peek() {
+            if arrival > time {
+                break;
+            }
+            self.pending.pop();
+            let label = labels[edge].expect("arrivals are reachable");
+            self.start
+                .push(arrival, edge, (label.start, Reverse(label.start_hops)));
+            self.hops.push(arrival, edge, Reverse(label.hops));
+        }
+        self.start.expire(earliest);
+        self.hops.expire(earliest);
+    }
+
+    /// The label of an edge leaving the vertex now
+    fn label(&self) -> Option<Label> {
+        let (start_pred, (start, Reverse(start_hops))) = self.start.best()?;
+        let (hops_pred, Reverse(hops)) = self.hops.best()?;
+        Some(Label {
+            start,
+            start_hops: start_hops + 1,
+            start_pred: Some(start_pred),
+            hops: hops + 1,
+            hops_pred: Some(hops_pred),
+        })
+    }
+}
+
+/// Computes the time-respecting paths from a source to all vertices it can reach
+///
+/// The exploded edges of the view are the possible hops. The source can be left at any time,
+/// afterwards an edge can be followed if it departs after the previous edge arrived and, if
+/// `max_wait` is given, at most `max_wait` after the previous edge arrived.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `source` - The vertex the paths start from
+/// * `path_type` - Which of the paths to each vertex to return
+/// * `delay` - The name of a numeric edge property holding the time it takes to traverse an
+///   edge, edges are traversed instantly if `None` or the property is missing
+/// * `max_wait` - The maximum time to wait at a vertex between two edges
+///
+/// Returns:
+///
+/// The path to each vertex reachable from the source, excluding the source itself, or an error
+/// if the source is not in the graph
+///
+pub fn temporal_paths<G: GraphViewOps, T: InputVertex>(
+    graph: &G,
+    source: T,
+    path_type: TemporalPathType,
+    delay: Option<&str>,
+    max_wait: Option<i64>,
+) -> Result<HashMap<String, TemporalPath>, GraphError> {
+    let source = match graph.vertex(source.clone()) {
+        Some(v) => v,
+        None => {
+            return Err(match source.id_str() {
+                Some(name) => GraphError::VertexNameError(name.to_string()),
+                None => GraphError::VertexIdError(source.id()),
+            })
+        }
+    };
+    let vertices: Vec<_> = graph.vertices().iter().collect();
+    let index: FxHashMap<VID, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.vertex, i))
+        .collect();
+    let names: Vec<String> = vertices.iter().map(|v| v.name()).collect();
+    let source = index[&source.vertex];
+
+    let mut events: Vec<Event> = graph
+        .edges()
+        .explode()
+        .filter_map(|e| {
+            let time = e.time()?;
+            let delay = delay
+                .and_then(|name| e.properties().get(name))
+                .and_then(|p| p.as_f64())
+                .map_or(0, |d| d.max(0.0) as i64);
+            Some(Event {
+                src: index[&e.src().vertex],
+                dst: index[&e.dst().vertex],
+                time,
+                arrival: time.saturating_add(delay),
+            })
+        })
+        .collect();
+    events.sort_by_key(|e| e.time);
+
+    let mut arrivals: Vec<Arrivals> = (0..names.len()).map(|_| Arrivals::new()).collect();
+    let mut labels: Vec<Option<Label>> = vec![None; events.len()];
+    let mut group_start = 0;
+    while group_start < events.len() {
+        let time = events[group_start].time;
+        let group_end = group_start
+            + events[group_start..]
+                .iter()
+                .take_while(|e| e.time == time)
+                .count();
+        let earliest = max_wait.map_or(i64::MIN, |w| time.saturating_sub(w));
+        let mut by_src: FxHashMap<usize, Vec<usize>> = FxHashMap::default();
+        for (i, event) in events.iter().enumerate().take(group_end).skip(group_start) {
+            by_src.entry(event.src).or_default().push(i);
+        }
+        // edges without delay can extend each other within the same time, so the edges
+        // leaving a vertex are updated again whenever an edge arriving now improves
+        let mut queue: Vec<usize> = by_src.keys().copied().collect();
+        let mut queued: FxHashSet<usize> = queue.iter().copied().collect();
+        while let Some(v) = queue.pop() {
+            queued.remove(&v);
+            let label = if v == source {
+                Some(Label {
+                    start: time,
+                    start_hops: 1,
+                    start_pred: None,
+                    hops: 1,
+                    hops_pred: None,
+                })
+            } else {
+                arrivals[v].advance(time, earliest, &labels);
+                arrivals[v].label()
+            };
+            let Some(label) = label else {
+                continue;
+            };
+            for &i in &by_src[&v] {
+                if label.improves(&labels[i]) {
+                    labels[i] = Some(label);
+                    let event = &events[i];
+                    arrivals[event.dst]
+                        .pending
+                        .push(Reverse((event.arrival, i)));
+                    if event.arrival <= time
+                        && by_src.contains_key(&event.dst)
+                        && queued.insert(event.dst)
+                    {
+                        queue.push(event.dst);
+                    }
+                }
+            }
+        }
+        group_start = group_end;
+    }
+
+    // the last edge of the best path to each vertex
+    let mut best: HashMap<usize, usize> = HashMap::new();
+    for (i, event) in events.iter().enumerate() {
+        if labels[i].is_none() || event.dst == source {
+            continue;
+        }
+        let key = |j: usize| {
+            let (l, e) = (labels[j].expect("reachable"), &events[j]);
+            match path_type {
+                TemporalPathType::EarliestArrival => (e.arrival, l.hops as i64, 0),
+                TemporalPathType::LatestDeparture => (-l.start, l.start_hops as i64, e.arrival),
+                TemporalPathType::Fastest => (e.arrival - l.start, e.arrival, 0),
+                TemporalPathType::Shortest => (l.hops as i64, e.arrival, 0),
+            }
+        };
+        best.entry(event.dst)
+            .and_modify(|j| {
+                if key(i) < key(*j) {
+                    *j = i
+                }
+            })
+            .or_insert(i);
+    }
+
+    let res = best
+        .into_iter()
+        .map(|(v, last)| {
+            let mut path = vec![];
+            let mut current = Some(last);
+            while let Some(i) = current {
+                path.push(i);
+                let label = labels[i].expect("reachable");
+                current = match path_type {
+                    TemporalPathType::LatestDeparture | TemporalPathType::Fastest => {
+                        label.start_pred
+                    }
+                    TemporalPathType::EarliestArrival | TemporalPathType::Shortest => {
+                        label.hops_pred
+                    }
+                };
+            }
+            path.reverse();
+            let edges = path
+                .iter()
+                .map(|i| {
+                    let e = &events[*i];
+                    (names[e.src].clone(), names[e.dst].clone(), e.time)
+                })
+                .collect();
+            let path = TemporalPath {
+                departure: events[path[0]].time,
+                arrival: events[last].arrival,
+                edges,
+            };
+            (names[v].clone(), path)
+        })
+        .collect();
+    Ok(res)
+}
+
+#[cfg(test)]
+mod temporal_paths_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn edges(path: &TemporalPath) -> Vec<(&str, &str, i64)> {
+        path.edges
+            .iter()
+            .map(|(src, dst, t)| (src.as_str(), dst.as_str(), *t))
+            .collect()
+    }
+
+    /// a -> d directly late, or via b and c early and slowly
+    fn graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst) in [
+            (1, "a", "b"),
+            (2, "b", "c"),
+            (3, "a", "c"),
+            (5, "c", "d"),
+            (6, "b", "c"),
+            (8, "a", "d"),
+            (10, "c", "a"),
+        ] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn path_types() {
+        let graph = graph();
+
+        let paths =
+            temporal_paths(&graph, "a", TemporalPathType::EarliestArrival, None, None).unwrap();
+        assert_eq!(paths.len(), 3);
+        assert_eq!(edges(&paths["b"]), vec![("a", "b", 1)]);
+        assert_eq!(edges(&paths["c"]), vec![("a", "b", 1), ("b", "c", 2)]);
+        assert_eq!(edges(&paths["d"]), vec![("a", "c", 3), ("c", "d", 5)]);
+        assert_eq!(paths["d"].arrival, 5);
+
+        let paths =
+            temporal_paths(&graph, "a", TemporalPathType::LatestDeparture, None, None).unwrap();
+        assert_eq!(edges(&paths["c"]), vec![("a", "c", 3)]);
+        assert_eq!(edges(&paths["d"]), vec![("a", "d", 8)]);
+
+        let paths = temporal_paths(&graph, "a", TemporalPathType::Fastest, None, None).unwrap();
+        assert_eq!(paths["c"].duration(), 0);
+        assert_eq!(edges(&paths["d"]), vec![("a", "d", 8)]);
+
+        let paths = temporal_paths(&graph, "a", TemporalPathType::Shortest, None, None).unwrap();
+        assert_eq!(edges(&paths["c"]), vec![("a", "c", 3)]);
+        assert_eq!(paths["d"].hops(), 1);
+
+        // nothing can be reached from a vertex without outgoing edges
+        let paths =
+            temporal_paths(&graph, "d", TemporalPathType::EarliestArrival, None, None).unwrap();
+        assert!(paths.is_empty());
+        let paths = temporal_paths(
+            &graph.window(0, 8),
+            "b",
+            TemporalPathType::EarliestArrival,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(edges(&paths["d"]), vec![("b", "c", 2), ("c", "d", 5)]);
+        assert!(!paths.contains_key("a"));
+    }
+
+    #[test]
+    fn delays_and_waiting() {
+        let graph = Graph::new();
+        graph
+            .add_edge(1, "a", "b", [("delay", 3i64)], None)
+            .unwrap();
+        graph
+            .add_edge(2, "b", "c", [("delay", 0i64)], None)
+            .unwrap();
+        graph
+            .add_edge(4, "b", "c", [("delay", 1i64)], None)
+            .unwrap();
+        graph
+            .add_edge(9, "b", "d", [("delay", 0i64)], None)
+            .unwrap();
+
+        let paths =
+            temporal_paths(&graph, "a", TemporalPathType::EarliestArrival, None, None).unwrap();
+        assert_eq!(edges(&paths["c"]), vec![("a", "b", 1), ("b", "c", 2)]);
+
+        let paths = temporal_paths(
+            &graph,
+            "a",
+            TemporalPathType::EarliestArrival,
+            Some("delay"),
+            None,
+        )
+        .unwrap();
+        assert_eq!(edges(&paths["c"]), vec![("a", "b", 1), ("b", "c", 4)]);
+        assert_eq!(paths["c"].arrival, 5);
+        assert_eq!(paths["c"].duration(), 4);
+        assert!(paths.contains_key("d"));
+
+        let paths = temporal_paths(
+            &graph,
+            "a",
+            TemporalPathType::EarliestArrival,
+            Some("delay"),
+            Some(2),
+        )
+        .unwrap();
+        assert!(paths.contains_key("c"));
+        assert!(!paths.contains_key("d"));
+    }
+
+    #[test]
+    fn earliest_arrival_matches_relaxation() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let graph = Graph::new();
+        let mut updates = vec![];
+        for _ in 0..500 {
+            // a small number of vertices and times gives hubs and simultaneous edges
+            let (t, src, dst, delay) = (
+                rng.gen_range(0..50i64),
+                rng.gen_range(0..20u64),
+                rng.gen_range(0..20u64),
+                rng.gen_range(0..3i64),
+            );
+            graph
+                .add_edge(t, src, dst, [("delay", delay)], None)
+                .unwrap();
+            updates.push((t, src, dst, delay));
+        }
+
+        // relax all edges until no arrival time changes
+        let mut expected: HashMap<u64, i64> = HashMap::new();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &(t, src, dst, delay) in &updates {
+                let reachable = src == 0 || expected.get(&src).map_or(false, |a| *a <= t);
+                if reachable && dst != 0 && expected.get(&dst).map_or(true, |a| *a > t + delay) {
+                    expected.insert(dst, t + delay);
+                    changed = true;
+                }
+            }
+        }
+
+        let paths = temporal_paths(
+            &graph,
+            0,
+            TemporalPathType::EarliestArrival,
+            Some("delay"),
+            None,
+        )
+        .unwrap();
+        let actual: HashMap<u64, i64> = paths
+            .iter()
+            .map(|(name, path)| (name.parse().unwrap(), path.arrival))
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn simultaneous_edges_and_missing_source() {
+        let graph = Graph::new();
+        // added out of order, all at the same time
+        graph.add_edge(1, "c", "d", NO_PROPS, None).unwrap();
+        graph.add_edge(1, "b", "c", NO_PROPS, None).unwrap();
+        graph.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        let paths = temporal_paths(&graph, "a", TemporalPathType::Shortest, None, None).unwrap();
+        assert_eq!(
+            edges(&paths["d"]),
+            vec![("a", "b", 1), ("b", "c", 1), ("c", "d", 1)]
+        );
+
+        assert!(matches!(
+            temporal_paths(&graph, "x", TemporalPathType::Shortest, None, None),
+            Err(GraphError::VertexNameError(name)) if name == "x"
+        ));
+    }
+}
@@ -0,0 +1,125 @@
+//! Forest fire sampling
+//!
+//! Starting from a random vertex, a fire spreads to a geometrically distributed number of the
+//! unburned neighbours of every burning vertex. When the fire dies out, it restarts from a new
+//! random vertex until enough vertices are burned. Samples keep the degree distribution and
+//! the densification of the original graph well, see Leskovec, Jure, and Christos Faloutsos.
+//! "Sampling from large graphs." Proceedings of the 12th ACM SIGKDD (2006): 631-636.
+use crate::{
+    core::entities::VID,
+    db::graph::views::vertex_subgraph::VertexSubgraph,
+    prelude::{GraphViewOps, VertexViewOps},
+};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use rustc_hash::FxHashSet;
+use std::collections::VecDeque;
+
+/// Samples vertices of a graph by forest fire sampling
+///
+/// The fire spreads over edges in both directions. Each burning vertex burns on average
+/// `burn_probability / (1 - burn_probability)` of its neighbours.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `sample_size` - The number of vertices to sample, all vertices are kept if the graph is
+///   smaller
+/// * `burn_probability` - The forward burning probability, needs to be smaller than 1
+/// * `seed` - The seed of the random number generator
+///
+/// Returns:
+///
+/// The subgraph induced by the burned vertices
+///
+pub fn forest_fire_sample<G: GraphViewOps>(
+    graph: &G,
+    sample_size: usize,
+    burn_probability: f64,
+    seed: Option<u64>,
+) -> VertexSubgraph<G> {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let mut ambassadors: Vec<VID> = graph.vertices().iter().map(|v| v.vertex).collect();
+    ambassadors.sort();
+    ambassadors.shuffle(&mut rng);
+    let sample_size = sample_size.min(ambassadors.len());
+
+    let mut burned: FxHashSet<VID> = FxHashSet::default();
+    let mut queue: VecDeque<VID> = VecDeque::new();
+    let mut ambassadors = ambassadors.into_iter();
+    while burned.len() < sample_size {
+        if queue.is_empty() {
+            match ambassadors.find(|v| !burned.contains(v)) {
+                Some(v) => {
+                    burned.insert(v);
+                    queue.push_back(v);
+                }
+                None => break,
+            }
+            continue;
+        }
+        let v = queue.pop_front().expect("queue is not empty");
+        let mut to_burn = 0;
+        while rng.gen::<f64>() < burn_probability {
+            to_burn += 1;
+        }
+        let mut neighbours: Vec<VID> = match graph.vertex(v) {
+            Some(vertex) => vertex
+                .neighbours()
+                .iter()
+                .map(|n| n.vertex)
+                .filter(|n| !burned.contains(n))
+                .collect(),
+            None => vec![],
+        };
+        neighbours.sort();
+        neighbours.dedup();
+        for n in neighbours.choose_multiple(&mut rng, to_burn) {
+            if burned.len() >= sample_size {
+                break;
+            }
+            burned.insert(*n);
+            queue.push_back(*n);
+        }
+    }
+    graph.subgraph(burned)
+}
+
+#[cfg(test)]
+mod forest_fire_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn ids<G: GraphViewOps>(graph: &G) -> Vec<u64> {
+        let mut ids: Vec<u64> = graph.vertices().id().collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn burns_connected_vertices() {
+        let graph = Graph::new();
+        // two separate rings
+        for i in 0..50u64 {
+            graph.add_edge(0, i, (i + 1) % 50, NO_PROPS, None).unwrap();
+            graph
+                .add_edge(0, 100 + i, 100 + (i + 1) % 50, NO_PROPS, None)
+                .unwrap();
+        }
+        let sample = forest_fire_sample(&graph, 20, 0.9, Some(2));
+        assert_eq!(sample.count_vertices(), 20);
+        // the fire spreads along the rings, so most sampled vertices are connected
+        assert!(sample.count_edges() >= 10);
+        assert_eq!(
+            ids(&sample),
+            ids(&forest_fire_sample(&graph, 20, 0.9, Some(2)))
+        );
+
+        // without burning every vertex is an ambassador
+        let sample = forest_fire_sample(&graph, 10, 0.0, Some(2));
+        assert_eq!(sample.count_vertices(), 10);
+        assert_eq!(
+            forest_fire_sample(&graph, 500, 0.5, Some(2)).count_vertices(),
+            100
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod forest_fire;
+pub mod random_sampling;
+pub mod snowball;
+pub mod time_stratified;
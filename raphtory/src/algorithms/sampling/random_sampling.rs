@@ -0,0 +1,133 @@
+//! Uniform random samples of the vertices or edges of a graph
+//!
+//! Vertex samples are induced subgraphs, i.e. they include all edges between the sampled
+//! vertices. Edge samples include the sampled edges and the vertices they connect.
+use crate::{
+    db::graph::views::{edge_subgraph::EdgeSubgraph, vertex_subgraph::VertexSubgraph},
+    prelude::GraphViewOps,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+/// Samples vertices of a graph uniformly at random without replacement
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `sample_size` - The number of vertices to sample, all vertices are kept if the graph is
+///   smaller
+/// * `seed` - The seed of the random number generator
+///
+/// Returns:
+///
+/// The subgraph induced by the sampled vertices
+///
+pub fn random_vertex_sample<G: GraphViewOps>(
+    graph: &G,
+    sample_size: usize,
+    seed: Option<u64>,
+) -> VertexSubgraph<G> {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let mut vertices: Vec<_> = graph.vertices().iter().map(|v| v.vertex).collect();
+    vertices.sort();
+    let sample: Vec<_> = vertices
+        .choose_multiple(&mut rng, sample_size)
+        .copied()
+        .collect();
+    graph.subgraph(sample)
+}
+
+/// Samples edges of a graph uniformly at random without replacement
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `sample_size` - The number of edges to sample, all edges are kept if the graph is smaller
+/// * `seed` - The seed of the random number generator
+///
+/// Returns:
+///
+/// The subgraph of the sampled edges and the vertices they connect
+///
+pub fn random_edge_sample<G: GraphViewOps>(
+    graph: &G,
+    sample_size: usize,
+    seed: Option<u64>,
+) -> EdgeSubgraph<G> {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let mut edges: Vec<_> = graph.edges().map(|e| e.edge).collect();
+    edges.sort_by_key(|e| e.pid());
+    let sample: Vec<_> = edges
+        .choose_multiple(&mut rng, sample_size)
+        .copied()
+        .collect();
+    EdgeSubgraph::new(graph.clone(), sample)
+}
+
+#[cfg(test)]
+mod random_sampling_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn graph() -> Graph {
+        let graph = Graph::new();
+        for i in 0..100u64 {
+            graph
+                .add_edge(i as i64, i, (i * 7 + 1) % 100, NO_PROPS, None)
+                .unwrap();
+        }
+        graph
+    }
+
+    fn ids<G: GraphViewOps>(graph: &G) -> Vec<u64> {
+        let mut ids: Vec<u64> = graph.vertices().id().collect();
+        ids.sort();
+        ids
+    }
+
+    fn edges<G: GraphViewOps>(graph: &G) -> Vec<(u64, u64)> {
+        let mut edges: Vec<_> = graph
+            .edges()
+            .map(|e| (e.src().id(), e.dst().id()))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn vertex_samples() {
+        let graph = graph();
+        let sample = random_vertex_sample(&graph, 30, Some(1));
+        assert_eq!(sample.count_vertices(), 30);
+        for e in sample.edges() {
+            assert!(sample.has_vertex(e.src().id()) && sample.has_vertex(e.dst().id()));
+        }
+        assert_eq!(
+            ids(&sample),
+            ids(&random_vertex_sample(&graph, 30, Some(1)))
+        );
+        assert_ne!(
+            ids(&sample),
+            ids(&random_vertex_sample(&graph, 30, Some(2)))
+        );
+        assert_eq!(
+            random_vertex_sample(&graph, 1000, Some(1)).count_vertices(),
+            100
+        );
+    }
+
+    #[test]
+    fn edge_samples() {
+        let graph = graph();
+        let sample = random_edge_sample(&graph.window(0, 50), 20, Some(1));
+        assert_eq!(sample.count_edges(), 20);
+        assert!(sample.count_vertices() <= 40);
+        for e in sample.edges() {
+            assert!(e.earliest_time().unwrap() < 50);
+            assert!(graph.has_edge(e.src().id(), e.dst().id(), Layer::All));
+        }
+        assert_eq!(
+            edges(&sample),
+            edges(&random_edge_sample(&graph.window(0, 50), 20, Some(1)))
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! Snowball sampling, expanding a sample from seed vertices in waves
+//!
+//! Every wave adds the neighbours of the vertices added in the previous wave. Limiting the
+//! number of neighbours followed from each vertex keeps the sample from covering the whole
+//! graph after a few waves when there are hubs.
+use crate::{
+    core::{
+        entities::{vertices::input_vertex::InputVertex, VID},
+        utils::errors::GraphError,
+    },
+    db::graph::views::vertex_subgraph::VertexSubgraph,
+    prelude::{GraphViewOps, VertexViewOps},
+};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use rustc_hash::FxHashSet;
+
+/// Samples the vertices reached from the seeds in a number of waves
+///
+/// Edges are followed in both directions.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `seeds` - The vertices to start from
+/// * `waves` - The number of times the sample is expanded
+/// * `max_neighbours` - The maximum number of new neighbours added for each vertex in a wave,
+///   chosen uniformly at random. All neighbours are added if `None`
+/// * `seed` - The seed of the random number generator
+///
+/// Returns:
+///
+/// The subgraph induced by the sampled vertices, or an error if a seed is not in the graph
+///
+pub fn snowball_sample<G: GraphViewOps, T: InputVertex>(
+    graph: &G,
+    seeds: Vec<T>,
+    waves: usize,
+    max_neighbours: Option<usize>,
+    seed: Option<u64>,
+) -> Result<VertexSubgraph<G>, GraphError> {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let mut sampled: FxHashSet<VID> = FxHashSet::default();
+    let mut frontier: Vec<VID> = vec![];
+    for s in seeds {
+        match graph.vertex(s.clone()) {
+            Some(v) => {
+                if sampled.insert(v.vertex) {
+                    frontier.push(v.vertex);
+                }
+            }
+            None => {
+                return Err(match s.id_str() {
+                    Some(name) => GraphError::VertexNameError(name.to_string()),
+                    None => GraphError::VertexIdError(s.id()),
+                })
+            }
+        }
+    }
+
+    for _ in 0..waves {
+        let mut next = vec![];
+        for v in frontier {
+            let vertex = match graph.vertex(v) {
+                Some(vertex) => vertex,
+                None => continue,
+            };
+            let mut neighbours: Vec<VID> = vertex
+                .neighbours()
+                .iter()
+                .map(|n| n.vertex)
+                .filter(|n| !sampled.contains(n))
+                .collect();
+            neighbours.sort();
+            neighbours.dedup();
+            if let Some(k) = max_neighbours {
+                neighbours.shuffle(&mut rng);
+                neighbours.truncate(k);
+            }
+            for n in neighbours {
+                if sampled.insert(n) {
+                    next.push(n);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        frontier = next;
+    }
+    Ok(graph.subgraph(sampled))
+}
+
+#[cfg(test)]
+mod snowball_test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn ids<G: GraphViewOps>(graph: &G) -> Vec<u64> {
+        let mut ids: Vec<u64> = graph.vertices().id().collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn waves_follow_edges_both_ways() {
+        let graph = Graph::new();
+        for (t, src, dst) in [(1, 1, 2), (2, 3, 2), (3, 3, 4), (4, 4, 5), (5, 6, 7)] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        let sample = |waves| snowball_sample(&graph, vec![1u64], waves, None, Some(1)).unwrap();
+        assert_eq!(ids(&sample(0)), vec![1]);
+        assert_eq!(ids(&sample(1)), vec![1, 2]);
+        assert_eq!(ids(&sample(2)), vec![1, 2, 3]);
+        assert_eq!(ids(&sample(10)), vec![1, 2, 3, 4, 5]);
+        assert_eq!(sample(2).count_edges(), 2);
+
+        assert!(matches!(
+            snowball_sample(&graph, vec![9u64], 1, None, None),
+            Err(GraphError::VertexIdError(9))
+        ));
+    }
+
+    #[test]
+    fn limited_neighbours() {
+        let graph = Graph::new();
+        for i in 1..=20u64 {
+            graph.add_edge(i as i64, 0, i, NO_PROPS, None).unwrap();
+            graph
+                .add_edge(i as i64, i, i + 100, NO_PROPS, None)
+                .unwrap();
+        }
+        let sample = snowball_sample(&graph, vec![0u64], 2, Some(3), Some(4)).unwrap();
+        // the hub, 3 of its neighbours and one further neighbour of each
+        assert_eq!(sample.count_vertices(), 7);
+        assert_eq!(
+            ids(&sample),
+            ids(&snowball_sample(&graph, vec![0u64], 2, Some(3), Some(4)).unwrap())
+        );
+    }
+}
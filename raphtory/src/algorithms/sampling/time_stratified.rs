@@ -0,0 +1,182 @@
+//! Time-stratified sampling of exploded edges
+//!
+//! The time span of a view is split into strata of equal length and the same fraction of the
+//! edge updates is sampled within every stratum, so bursts and quiet periods keep their share
+//! of the sample. As a view can only hide whole edges but not single updates, the sample is
+//! returned as a new graph.
+use crate::{
+    core::utils::errors::GraphError,
+    db::{
+        api::{mutation::AdditionOps, view::*},
+        graph::graph::Graph,
+    },
+    prelude::NO_PROPS,
+};
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+use std::collections::HashSet;
+
+/// Samples a fraction of the exploded edges of a graph within each time stratum
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph
+/// * `strata` - The number of time intervals of equal length between the earliest and latest
+///   time of the view
+/// * `fraction` - The fraction of the updates to sample in each stratum, rounded to the nearest
+///   number of updates
+/// * `seed` - The seed of the random number generator
+///
+/// Returns:
+///
+/// A new graph with the sampled updates, including their layer and properties at the time of
+/// the update. The sampled edges keep their constant properties and the vertices keep their
+/// ids, names, types and constant properties. Temporal vertex properties are not copied, as
+/// the vertex updates they belong to are not part of the sample.
+///
+pub fn time_stratified_sample<G: GraphViewOps>(
+    graph: &G,
+    strata: usize,
+    fraction: f64,
+    seed: Option<u64>,
+) -> Result<Graph, GraphError> {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let sampled = Graph::new();
+    let (start, end) = match (graph.earliest_time(), graph.latest_time()) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return Ok(sampled),
+    };
+    let strata = strata.max(1);
+    let span = (end - start) as i128 + 1;
+
+    let mut buckets = vec![vec![]; strata];
+    for e in graph.edges() {
+        for layer_edge in e.explode_layers() {
+            for ee in layer_edge.explode() {
+                if let Some(t) = ee.time() {
+                    let stratum = ((t - start) as i128 * strata as i128 / span) as usize;
+                    buckets[stratum].push(ee);
+                }
+            }
+        }
+    }
+    let mut copied_edges = HashSet::new();
+    for bucket in buckets {
+        let amount =
+            ((bucket.len() as f64 * fraction.clamp(0.0, 1.0)).round() as usize).min(bucket.len());
+        let mut chosen = sample(&mut rng, bucket.len(), amount).into_vec();
+        chosen.sort();
+        for i in chosen {
+            let ee = &bucket[i];
+            let t = ee.time().expect("exploded edge");
+            let layer = ee.layer_name().map(|l| l.to_string());
+            let (src, dst) = (ee.src(), ee.dst());
+            // names such as "0" do not map back to the id of the vertex, so only vertices
+            // with a name of their own are added by name
+            for v in [&src, &dst] {
+                let name = v.name();
+                if name != v.id().to_string() {
+                    sampled.add_vertex(t, name, NO_PROPS)?;
+                }
+            }
+            let new_edge = sampled.add_edge(
+                t,
+                src.id(),
+                dst.id(),
+                ee.properties().temporal().collect_properties(),
+                layer.as_deref(),
+            )?;
+            if copied_edges.insert((src.id(), dst.id(), layer.clone())) {
+                new_edge.add_constant_properties(ee.properties().constant(), layer.as_deref())?;
+            }
+        }
+    }
+    for v in sampled.vertices().iter() {
+        if let Some(original) = graph.vertex(v.id()) {
+            v.add_constant_properties(original.properties().constant())?;
+            if let Some(vertex_type) = original.vertex_type() {
+                v.set_vertex_type(&vertex_type)?;
+            }
+        }
+    }
+    Ok(sampled)
+}
+
+#[cfg(test)]
+mod time_stratified_test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn strata_keep_their_share() {
+        let graph = Graph::new();
+        // a burst of 100 updates in [0, 10) and 2 updates in each later stratum
+        for i in 0..100u64 {
+            graph
+                .add_edge((i / 10) as i64, i % 7, i % 5 + 10, [("i", i as i64)], None)
+                .unwrap();
+        }
+        for i in 0..18i64 {
+            graph
+                .add_edge(15 + 10 * (i / 2), "a", "b", [("i", i)], Some("quiet"))
+                .unwrap();
+        }
+        let sample = time_stratified_sample(&graph, 10, 0.5, Some(1)).unwrap();
+        assert_eq!(sample.count_temporal_edges(), 59);
+        assert_eq!(sample.window(0, 10).count_temporal_edges(), 50);
+        assert_eq!(sample.layer("quiet").unwrap().count_temporal_edges(), 9);
+        for e in sample.edges().explode() {
+            let i = e.properties().get("i").unwrap_i64();
+            if e.src().name() != "a" {
+                assert_eq!(e.time(), Some(i / 10));
+            }
+        }
+
+        let other = time_stratified_sample(&graph, 10, 0.5, Some(1)).unwrap();
+        assert_eq!(sample, other);
+        assert_eq!(
+            time_stratified_sample(&graph.window(50, 100), 3, 1.0, Some(1))
+                .unwrap()
+                .count_temporal_edges(),
+            10
+        );
+    }
+
+    #[test]
+    fn vertices_and_constant_properties_are_kept() {
+        let graph = Graph::new();
+        graph.add_edge(1, 0, 1, NO_PROPS, None).unwrap();
+        graph
+            .add_edge(2, "1", "Alice", NO_PROPS, Some("friends"))
+            .unwrap();
+        graph
+            .edge(0, 1)
+            .unwrap()
+            .add_constant_properties([("kind", "transfer")], None)
+            .unwrap();
+        graph
+            .edge("1", "Alice")
+            .unwrap()
+            .add_constant_properties([("since", 2010i64)], Some("friends"))
+            .unwrap();
+        let alice = graph.vertex("Alice").unwrap();
+        alice.add_constant_properties([("age", 30i64)]).unwrap();
+        alice.set_vertex_type("person").unwrap();
+
+        let sample = time_stratified_sample(&graph, 1, 1.0, Some(1)).unwrap();
+        assert_eq!(
+            sample.vertices().id().collect::<Vec<_>>(),
+            vec![0, 1, alice.id()]
+        );
+        let transfer = sample.edge(0, 1).unwrap().layer("_default").unwrap();
+        assert_eq!(
+            transfer.properties().get("kind"),
+            Some(Prop::str("transfer"))
+        );
+        let friends = sample.edge("1", "Alice").unwrap().layer("friends").unwrap();
+        assert_eq!(friends.properties().get("since"), Some(Prop::I64(2010)));
+        let new_alice = sample.vertex("Alice").unwrap();
+        assert_eq!(new_alice.id(), alice.id());
+        assert_eq!(new_alice.properties().get("age"), Some(Prop::I64(30)));
+        assert_eq!(new_alice.vertex_type().unwrap(), "person");
+    }
+}
@@ -18,8 +18,8 @@ mod test {
         let l_btc = g.resolve_layer(Some("btc"));
         let l_eth = g.resolve_layer(Some("eth"));
         let l_tether = g.resolve_layer(Some("tether"));
-        let v1 = g.resolve_vertex(1, None);
-        let v2 = g.resolve_vertex(2, None);
+        let v1 = g.resolve_vertex(1, None).unwrap();
+        let v2 = g.resolve_vertex(2, None).unwrap();
         let tx_sent_id = g
             .resolve_edge_property("tx_sent", PropType::I32, false)
             .unwrap();
@@ -55,9 +55,9 @@ mod test {
     #[test]
     fn simple_triangle() {
         let g: InnerTemporalGraph<2> = InnerTemporalGraph::default();
-        let v1 = g.resolve_vertex(1, None);
-        let v2 = g.resolve_vertex(2, None);
-        let v3 = g.resolve_vertex(3, None);
+        let v1 = g.resolve_vertex(1, None).unwrap();
+        let v2 = g.resolve_vertex(2, None).unwrap();
+        let v3 = g.resolve_vertex(3, None).unwrap();
         let vs = vec![(1, v1, v2), (2, v1, v3), (3, v2, v1), (4, v3, v2)];
 
         let empty: Vec<(usize, Prop)> = vec![];
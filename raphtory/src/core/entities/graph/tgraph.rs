@@ -7,12 +7,12 @@ use crate::{
                 edge_store::{EdgeLayer, EdgeStore},
             },
             graph::{
-                tgraph_storage::{GraphStorage, LockedIter},
+                tgraph_storage::{GraphStorage, GraphStorageV1, LockedIter},
                 timer::{MaxCounter, MinCounter, TimeCounterTrait},
             },
             properties::{
                 graph_props::GraphProps,
-                props::{ArcReadLockedVec, Meta},
+                props::{ArcReadLockedVec, Meta, MetaV1},
                 tprop::TProp,
             },
             vertices::{
@@ -33,9 +33,12 @@ use crate::{
             errors::{GraphError, IllegalMutate, MutateGraphError},
             time::TryIntoTime,
         },
-        ArcStr, Direction, Prop, PropUnwrap,
+        ArcStr, Direction, Prop, PropType, PropUnwrap,
+    },
+    db::api::{
+        mutation::internal::EdgeUpdate,
+        view::{internal::EdgeFilter, BoxedIter, Layer},
     },
-    db::api::view::{internal::EdgeFilter, BoxedIter, Layer},
 };
 use dashmap::{DashMap, DashSet};
 use itertools::Itertools;
@@ -93,6 +96,36 @@ pub struct TemporalGraph<const N: usize> {
     pub(crate) graph_props: GraphProps,
 }
 
+/// Layout of `TemporalGraph` in format version 1 and in unversioned files
+#[derive(Deserialize, Debug)]
+pub(crate) struct TemporalGraphV1<const N: usize> {
+    logical_to_physical: FxDashMap<u64, VID>,
+    string_pool: FxDashSet<ArcStr>,
+    storage: GraphStorageV1<N>,
+    event_counter: AtomicUsize,
+    earliest_time: MinCounter,
+    latest_time: MaxCounter,
+    vertex_meta: MetaV1,
+    edge_meta: MetaV1,
+    graph_props: GraphProps,
+}
+
+impl<const N: usize> From<TemporalGraphV1<N>> for InnerTemporalGraph<N> {
+    fn from(value: TemporalGraphV1<N>) -> Self {
+        Self(Arc::new(TemporalGraph {
+            logical_to_physical: value.logical_to_physical,
+            string_pool: value.string_pool,
+            storage: value.storage.into(),
+            event_counter: value.event_counter,
+            earliest_time: value.earliest_time,
+            latest_time: value.latest_time,
+            vertex_meta: Arc::new(value.vertex_meta.into()),
+            edge_meta: Arc::new(value.edge_meta.into()),
+            graph_props: value.graph_props,
+        }))
+    }
+}
+
 impl<const N: usize> std::fmt::Display for InnerTemporalGraph<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -421,6 +454,13 @@ impl<const N: usize> TemporalGraph<N> {
         Ok(())
     }
 
+    pub(crate) fn delete_vertex(&self, t: TimeIndexEntry, v_id: VID) -> Result<(), GraphError> {
+        self.update_time(t);
+        let mut node = self.storage.get_node_mut(v_id);
+        node.delete(t);
+        Ok(())
+    }
+
     fn get_or_allocate_layer(&self, layer: Option<&str>) -> usize {
         layer
             .map(|layer| self.edge_meta.get_or_create_layer_id(layer))
@@ -435,8 +475,21 @@ impl<const N: usize> TemporalGraph<N> {
         layer: usize,
         edge_fn: F,
     ) -> Result<EID, GraphError> {
-        let mut node_pair = self.storage.pair_node_mut(src_id.into(), dst_id.into());
         self.update_time(t);
+        self.link_nodes_at(src_id, dst_id, &[t], &[layer], edge_fn)
+    }
+
+    /// link `src_id` and `dst_id` in all `layers` and record all `times` on both vertices,
+    /// locking the vertices and the edge only once
+    fn link_nodes_at<F: FnOnce(&mut EdgeStore) -> Result<(), GraphError>>(
+        &self,
+        src_id: VID,
+        dst_id: VID,
+        times: &[TimeIndexEntry],
+        layers: &[usize],
+        edge_fn: F,
+    ) -> Result<EID, GraphError> {
+        let mut node_pair = self.storage.pair_node_mut(src_id, dst_id);
         let src = node_pair.get_mut_i();
 
         let edge_id = match src.find_edge(dst_id, &LayerIds::All) {
@@ -452,11 +505,19 @@ impl<const N: usize> TemporalGraph<N> {
             }
         };
 
-        src.add_edge(dst_id, Direction::OUT, layer, edge_id);
-        src.update_time(t);
+        for &layer in layers {
+            src.add_edge(dst_id, Direction::OUT, layer, edge_id);
+        }
+        for &t in times {
+            src.update_time(t);
+        }
         let dst = node_pair.get_mut_j();
-        dst.add_edge(src_id, Direction::IN, layer, edge_id);
-        dst.update_time(t);
+        for &layer in layers {
+            dst.add_edge(src_id, Direction::IN, layer, edge_id);
+        }
+        for &t in times {
+            dst.update_time(t);
+        }
         Ok(edge_id)
     }
 
@@ -479,6 +540,75 @@ impl<const N: usize> TemporalGraph<N> {
         })
     }
 
+    /// Add a batch of edge updates `(time, src, dst, layer, props)`.
+    ///
+    /// Event ids are allocated for the whole batch at once and updates are grouped by edge so
+    /// that every edge and its endpoints are locked only once. Groups are added in parallel.
+    /// The property values are checked against the types of their properties first, so that
+    /// adding them can not fail and an invalid batch is not added at all.
+    pub(crate) fn add_edges_bulk(&self, updates: Vec<EdgeUpdate>) -> Result<(), GraphError> {
+        if updates.is_empty() {
+            return Ok(());
+        }
+        let prop_meta = self.edge_meta.temporal_prop_meta();
+        for (_, _, _, _, props) in &updates {
+            for (prop_id, prop) in props {
+                let expected = prop_meta.get_dtype(*prop_id).unwrap_or(PropType::Empty);
+                if expected != prop.dtype() {
+                    return Err(GraphError::PropertyTypeError {
+                        name: self.edge_meta.get_prop_name(*prop_id, false).to_string(),
+                        expected,
+                        actual: prop.dtype(),
+                    });
+                }
+            }
+        }
+        let first_event = self
+            .event_counter
+            .fetch_add(updates.len(), std::sync::atomic::Ordering::Relaxed);
+        let mut updates: Vec<_> = updates
+            .into_iter()
+            .enumerate()
+            .map(|(i, (t, src, dst, layer, props))| {
+                (TimeIndexEntry(t, first_event + i), src, dst, layer, props)
+            })
+            .collect();
+        if let Some((min, max)) = updates.iter().map(|u| u.0).minmax().into_option() {
+            self.update_time(min);
+            self.update_time(max);
+        }
+
+        updates.sort_by_key(|(_, src, dst, _, _)| (*src, *dst));
+        let groups: Vec<Vec<_>> = updates
+            .into_iter()
+            .group_by(|(_, src, dst, _, _)| (*src, *dst))
+            .into_iter()
+            .map(|(_, group)| group.collect())
+            .collect();
+
+        groups.into_par_iter().try_for_each(|group| {
+            let (_, src_id, dst_id, _, _) = group[0];
+            let times: Vec<_> = group.iter().map(|(t, ..)| *t).collect();
+            let layers: Vec<_> = group
+                .iter()
+                .map(|(_, _, _, layer, _)| *layer)
+                .sorted()
+                .dedup()
+                .collect();
+            self.link_nodes_at(src_id, dst_id, &times, &layers, move |edge| {
+                for (t, _, _, layer, props) in group {
+                    edge.additions_mut(layer).insert(t);
+                    let mut edge_layer = edge.layer_mut(layer);
+                    for (prop_id, prop_value) in props {
+                        edge_layer.add_prop(t, prop_id, prop_value)?;
+                    }
+                }
+                Ok(())
+            })
+            .map(|_| ())
+        })
+    }
+
     #[inline]
     pub(crate) fn vertex_ids(&self) -> impl Iterator<Item = VID> {
         (0..self.storage.nodes.len()).map(|i| i.into())
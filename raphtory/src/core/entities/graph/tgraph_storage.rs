@@ -1,7 +1,7 @@
 use crate::core::{
     entities::{
         edges::{edge_ref::EdgeRef, edge_store::EdgeStore},
-        vertices::vertex_store::VertexStore,
+        vertices::vertex_store::{VertexStore, VertexStoreV1},
         LayerIds, EID, VID,
     },
     storage::{self, ArcEntry, Entry, EntryMut, PairEntryMut},
@@ -23,6 +23,22 @@ pub(crate) struct GraphStorage<const N: usize> {
     pub(crate) edges: storage::RawStorage<EdgeStore, N>,
 }
 
+/// Layout of `GraphStorage` in format version 1 and in unversioned files
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphStorageV1<const N: usize> {
+    nodes: storage::RawStorage<VertexStoreV1, N>,
+    edges: storage::RawStorage<EdgeStore, N>,
+}
+
+impl<const N: usize> From<GraphStorageV1<N>> for GraphStorage<N> {
+    fn from(value: GraphStorageV1<N>) -> Self {
+        Self {
+            nodes: value.nodes.map(VertexStore::from),
+            edges: value.edges,
+        }
+    }
+}
+
 impl<const N: usize> GraphStorage<N> {
     pub(crate) fn new() -> Self {
         Self {
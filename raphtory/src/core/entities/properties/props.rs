@@ -1,12 +1,14 @@
-use crate::core::{
-    entities::{graph::tgraph::FxDashMap, properties::tprop::TProp},
-    storage::{
-        lazy_vec::{IllegalSet, LazyVec},
-        locked_view::LockedView,
-        timeindex::TimeIndexEntry,
+use crate::{
+    core::{
+        entities::{graph::tgraph::FxDashMap, properties::tprop::TProp},
+        storage::{
+            lazy_vec::{IllegalSet, LazyVec},
+            locked_view::LockedView,
+            timeindex::TimeIndexEntry,
+        },
+        utils::errors::{GraphError, IllegalMutate, MutateGraphError},
+        ArcStr, Prop, PropType,
     },
-    utils::errors::{GraphError, IllegalMutate, MutateGraphError},
-    ArcStr, Prop, PropType,
 };
 use lock_api;
 use parking_lot::{RwLock, RwLockReadGuard};
@@ -116,6 +118,32 @@ pub struct Meta {
     meta_prop_temporal: PropMapper,
     meta_prop_constant: PropMapper,
     meta_layer: DictMapper,
+    meta_vertex_type: DictMapper,
+}
+
+/// Layout of `Meta` in format version 1 and in unversioned files, before vertex types were added
+#[derive(Deserialize, Debug)]
+pub(crate) struct MetaV1 {
+    meta_prop_temporal: PropMapper,
+    meta_prop_constant: PropMapper,
+    meta_layer: DictMapper,
+}
+
+impl From<MetaV1> for Meta {
+    fn from(value: MetaV1) -> Self {
+        Self {
+            meta_prop_temporal: value.meta_prop_temporal,
+            meta_prop_constant: value.meta_prop_constant,
+            meta_layer: value.meta_layer,
+            meta_vertex_type: new_vertex_type_mapper(),
+        }
+    }
+}
+
+fn new_vertex_type_mapper() -> DictMapper {
+    let mapper = DictMapper::default();
+    mapper.get_or_create_id("_default"); // type 0 marks vertices without a type
+    mapper
 }
 
 impl Meta {
@@ -138,9 +166,14 @@ impl Meta {
             meta_prop_temporal: PropMapper::default(),
             meta_prop_constant: PropMapper::default(),
             meta_layer, // layer 0 is the default layer
+            meta_vertex_type: new_vertex_type_mapper(),
         }
     }
 
+    pub fn vertex_type_meta(&self) -> &DictMapper {
+        &self.meta_vertex_type
+    }
+
     #[inline]
     pub fn resolve_prop_id(
         &self,
@@ -180,6 +213,21 @@ impl Meta {
         self.meta_layer.get_name(id)
     }
 
+    #[inline]
+    pub fn get_or_create_vertex_type_id(&self, name: &str) -> usize {
+        self.meta_vertex_type.get_or_create_id(name)
+    }
+
+    #[inline]
+    pub fn get_vertex_type_id(&self, name: &str) -> Option<usize> {
+        self.meta_vertex_type.get_id(name)
+    }
+
+    /// The name of the vertex type with id `id`, `None` for vertices without a type
+    pub fn get_vertex_type_name_by_id(&self, id: usize) -> Option<ArcStr> {
+        (id != 0).then(|| self.meta_vertex_type.get_name(id))
+    }
+
     pub fn get_all_layers(&self) -> Vec<usize> {
         self.meta_layer
             .map
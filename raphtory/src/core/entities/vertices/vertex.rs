@@ -73,6 +73,16 @@ impl<'a, const N: usize> Vertex<'a, N> {
         }
     }
 
+    pub(crate) fn deletions(self) -> Option<LockedView<'a, TimeIndex<TimeIndexEntry>>> {
+        match self.node {
+            VRef::Entry(entry) => {
+                let t_index = entry.map(|entry| entry.deletions());
+                Some(t_index)
+            }
+            _ => None,
+        }
+    }
+
     pub(crate) fn temporal_property(self, prop_id: usize) -> Option<LockedView<'a, TProp>> {
         match self.node {
             VRef::Entry(entry) => {
@@ -32,10 +32,41 @@ pub struct VertexStore {
     pub(crate) vid: VID,
     // all the timestamps that have been seen by this vertex
     timestamps: TimeIndex<i64>,
+    // all the timestamps at which this vertex was deleted
+    deletions: TimeIndex<TimeIndexEntry>,
     // each layer represents a separate view of the graph
     pub(crate) layers: Vec<Adj>,
     // props for vertex
     pub(crate) props: Option<Props>,
+    // id of the vertex type, 0 if the vertex has no type
+    pub(crate) vertex_type: usize,
+}
+
+/// Layout of `VertexStore` in format version 1 and in unversioned files,
+/// before vertex deletions and vertex types were added
+#[derive(Deserialize, Debug, Default)]
+pub(crate) struct VertexStoreV1 {
+    global_id: u64,
+    name: Option<String>,
+    vid: VID,
+    timestamps: TimeIndex<i64>,
+    layers: Vec<Adj>,
+    props: Option<Props>,
+}
+
+impl From<VertexStoreV1> for VertexStore {
+    fn from(value: VertexStoreV1) -> Self {
+        Self {
+            global_id: value.global_id,
+            name: value.name,
+            vid: value.vid,
+            timestamps: value.timestamps,
+            deletions: TimeIndex::Empty,
+            layers: value.layers,
+            props: value.props,
+            vertex_type: 0,
+        }
+    }
 }
 
 impl VertexStore {
@@ -47,8 +78,10 @@ impl VertexStore {
             name: None,
             vid: 0.into(),
             timestamps: TimeIndex::one(*t.t()),
+            deletions: TimeIndex::Empty,
             layers,
             props: None,
+            vertex_type: 0,
         }
     }
 
@@ -60,8 +93,10 @@ impl VertexStore {
             name,
             vid: VID(0),
             timestamps: TimeIndex::Empty,
+            deletions: TimeIndex::Empty,
             layers,
             props: None,
+            vertex_type: 0,
         }
     }
 
@@ -77,6 +112,16 @@ impl VertexStore {
         self.timestamps.insert(*t.t());
     }
 
+    pub fn deletions(&self) -> &TimeIndex<TimeIndexEntry> {
+        &self.deletions
+    }
+
+    /// mark the vertex as deleted at time `t` (the deletion also counts as activity of the vertex)
+    pub fn delete(&mut self, t: TimeIndexEntry) {
+        self.update_time(t);
+        self.deletions.insert(t);
+    }
+
     pub fn update_name(&mut self, name: &str) {
         match &self.name {
             None => {
@@ -86,6 +131,16 @@ impl VertexStore {
         }
     }
 
+    /// set the type of the vertex, the type of a vertex can not be changed once it is set
+    pub fn set_vertex_type(&mut self, vertex_type: usize) -> Result<(), usize> {
+        if self.vertex_type == 0 || self.vertex_type == vertex_type {
+            self.vertex_type = vertex_type;
+            Ok(())
+        } else {
+            Err(self.vertex_type)
+        }
+    }
+
     pub fn add_prop(
         &mut self,
         t: TimeIndexEntry,
@@ -209,6 +209,21 @@ impl Prop {
         }
     }
 
+    /// The value of a numeric property as `f64`, `None` for all other properties
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Prop::U8(v) => Some(*v as f64),
+            Prop::U16(v) => Some(*v as f64),
+            Prop::I32(v) => Some(*v as f64),
+            Prop::I64(v) => Some(*v as f64),
+            Prop::U32(v) => Some(*v as f64),
+            Prop::U64(v) => Some(*v as f64),
+            Prop::F32(v) => Some(*v as f64),
+            Prop::F64(v) => Some(*v),
+            _ => None,
+        }
+    }
+
     pub fn divide(self, other: Prop) -> Option<Prop> {
         match (self, other) {
             (Prop::U8(a), Prop::U8(b)) if b != 0 => Some(Prop::U8(a / b)),
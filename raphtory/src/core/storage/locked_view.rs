@@ -14,6 +14,8 @@ pub enum LockedView<'a, T> {
     LockMapped(parking_lot::MappedRwLockReadGuard<'a, T>),
     Locked(parking_lot::RwLockReadGuard<'a, T>),
     DashMap(Ref<'a, usize, T, BuildHasherDefault<rustc_hash::FxHasher>>),
+    /// A value that is not backed by in-memory storage, e.g. decoded from a file
+    Owned(T),
 }
 
 impl<'a, T, O> AsRef<T> for LockedView<'a, O>
@@ -94,6 +96,7 @@ impl<'a, T> Deref for LockedView<'a, T> {
             LockedView::LockMapped(guard) => guard.deref(),
             LockedView::DashMap(r) => (*r).deref(),
             LockedView::Locked(guard) => guard.deref(),
+            LockedView::Owned(value) => value,
         }
     }
 }
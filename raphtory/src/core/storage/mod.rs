@@ -159,6 +159,24 @@ impl<T: Default, const N: usize> RawStorage<T, N> {
         }
     }
 
+    /// Convert every entry with `f`, keeping the position of the entries
+    pub(crate) fn map<U: Default>(self, f: impl Fn(T) -> U) -> RawStorage<U, N> {
+        let data: Vec<LockVec<U>> = self
+            .data
+            .iter()
+            .map(|bucket| {
+                let values = std::mem::take(&mut *bucket.data.write());
+                LockVec {
+                    data: Arc::new(RwLock::new(values.into_iter().map(&f).collect())),
+                }
+            })
+            .collect();
+        RawStorage {
+            data: data.into_boxed_slice(),
+            len: AtomicUsize::new(self.len()),
+        }
+    }
+
     pub fn push<F: Fn(usize, &mut T)>(&self, mut value: T, f: F) -> usize {
         let index = self.len.fetch_add(1, Ordering::SeqCst);
         let (bucket, offset) = resolve::<N>(index);
@@ -275,6 +293,17 @@ impl<T> Clone for ArcEntry<T> {
     }
 }
 
+impl<T> ArcEntry<T> {
+    /// Wrap a value that is not part of a storage vector
+    pub(crate) fn new(value: T) -> Self {
+        let data = Arc::new(RwLock::new(vec![value]));
+        Self {
+            guard: Arc::new(RwLock::read_arc(&data)),
+            i: 0,
+        }
+    }
+}
+
 impl<T> Deref for ArcEntry<T> {
     type Target = T;
 
@@ -30,6 +30,13 @@ pub enum GraphError {
     #[error("Tried to mutate constant property {name}: old value {old:?}, new value {new:?}")]
     ConstantPropertyMutationError { name: ArcStr, old: Prop, new: Prop },
 
+    #[error("Vertex {vertex} already has type {existing} and can not be changed to type {new}")]
+    VertexTypeError {
+        vertex: String,
+        existing: ArcStr,
+        new: ArcStr,
+    },
+
     #[error("Failed to parse time string")]
     ParseTime {
         #[from]
@@ -61,22 +68,68 @@ pub enum GraphError {
     #[error("The loaded graph is of the wrong kind")]
     GraphLoadError,
 
+    #[error("Deletions are not supported by this graph, use a GraphWithDeletions instead")]
+    DeletionsNotSupported,
+
+    #[error("Graphs with deletion semantics can not be written as a memory-mapped graph")]
+    MmapDeletionsNotSupported,
+
+    #[error("Not a valid graph file")]
+    InvalidFileFormat,
+
+    #[error("Unsupported graph file format version {version}, the latest supported version is {supported}")]
+    UnsupportedFormatVersion { version: u32, supported: u32 },
+
+    #[error(
+        "Graph file is corrupted: expected checksum {expected:#018x} but found {actual:#018x}"
+    )]
+    ChecksumMismatch { expected: u64, actual: u64 },
+
+    #[error("Failed to load graph file without a format header, it is either not a graph file or was written by an incompatible version")]
+    LegacyFormatError { source: Box<bincode::ErrorKind> },
+
+    #[error("Failed to parse {format}: {reason}")]
+    ParseError {
+        format: &'static str,
+        reason: String,
+    },
+
+    #[error("Column {column} of the batch has {actual} values but the batch has {expected} rows")]
+    BatchLengthMismatch {
+        column: String,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("{algorithm} did not converge within {iterations} iterations")]
+    NotConverged {
+        algorithm: &'static str,
+        iterations: usize,
+    },
+
     #[error("IO operation failed")]
     IOError {
         #[from]
         source: std::io::Error,
     },
 
-    #[cfg(feature = "python")]
+    #[cfg(feature = "arrow")]
     #[error("Failed to load graph: {0}")]
     LoadFailure(String),
 
-    #[cfg(feature = "python")]
+    #[cfg(feature = "arrow")]
     #[error(
         "Failed to load graph as the following columns are not present within the dataframe: {0}"
     )]
     ColumnDoesNotExist(String),
 
+    #[cfg(feature = "arrow")]
+    #[error("Arrow operation failed")]
+    ArrowError {
+        #[from]
+        source: arrow2::error::Error,
+    },
+
     #[cfg(feature = "search")]
     #[error("Index operation failed")]
     IndexError {
@@ -1,9 +1,9 @@
 use crate::{
     core::{
-        entities::{edges::edge_ref::EdgeRef, vertices::input_vertex::InputVertex},
+        entities::{edges::edge_ref::EdgeRef, vertices::input_vertex::InputVertex, VID},
         storage::timeindex::TimeIndexEntry,
         utils::{errors::GraphError, time::IntoTimeWithFormat},
-        Prop,
+        Prop, PropType,
     },
     db::{
         api::mutation::{internal::InternalAdditionOps, CollectProperties, TryIntoInputTime},
@@ -11,6 +11,96 @@ use crate::{
     },
     prelude::GraphViewOps,
 };
+use rustc_hash::FxHashMap;
+
+/// A batch of edge updates given as columns, added with [`AdditionOps::add_edges`].
+///
+/// All columns need to have one value per update. Property columns hold `None` for updates
+/// that do not set the property.
+///
+/// # Example
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::db::api::mutation::EdgeBatch;
+///
+/// let graph = Graph::new();
+/// let batch = EdgeBatch::new(&[1, 2, 3], &["Alice", "Bob", "Alice"], &["Bob", "Carol", "Bob"])
+///     .with_property("weight", vec![Some(Prop::F64(1.0)), None, Some(Prop::F64(2.0))]);
+/// graph.add_edges(batch).unwrap();
+/// assert_eq!(graph.edge("Alice", "Bob").unwrap().history(), vec![1, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EdgeBatch<'a, V: InputVertex> {
+    times: &'a [i64],
+    src: &'a [V],
+    dst: &'a [V],
+    layer: Option<&'a str>,
+    layers: Option<Vec<&'a str>>,
+    properties: Vec<(String, Vec<Option<Prop>>)>,
+}
+
+impl<'a, V: InputVertex> EdgeBatch<'a, V> {
+    pub fn new(times: &'a [i64], src: &'a [V], dst: &'a [V]) -> Self {
+        Self {
+            times,
+            src,
+            dst,
+            layer: None,
+            layers: None,
+            properties: vec![],
+        }
+    }
+
+    /// Add all updates to `layer`
+    pub fn with_layer(mut self, layer: &'a str) -> Self {
+        self.layer = Some(layer);
+        self.layers = None;
+        self
+    }
+
+    /// Add each update to its own layer
+    pub fn with_layers<L: AsRef<str>>(mut self, layers: &'a [L]) -> Self {
+        self.layers = Some(layers.iter().map(|l| l.as_ref()).collect());
+        self.layer = None;
+        self
+    }
+
+    /// Add a temporal property column
+    pub fn with_property<P: Into<Prop>>(mut self, name: &str, values: Vec<Option<P>>) -> Self {
+        self.properties.push((
+            name.to_owned(),
+            values.into_iter().map(|v| v.map(|v| v.into())).collect(),
+        ));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.times.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.times.is_empty()
+    }
+
+    fn check_lengths(&self) -> Result<(), GraphError> {
+        let expected = self.times.len();
+        let columns = [("src", self.src.len()), ("dst", self.dst.len())]
+            .into_iter()
+            .chain(self.layers.iter().map(|l| ("layer", l.len())))
+            .chain(self.properties.iter().map(|(n, v)| (n.as_str(), v.len())));
+        for (column, actual) in columns {
+            if actual != expected {
+                return Err(GraphError::BatchLengthMismatch {
+                    column: column.to_owned(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(())
+    }
+}
 
 pub trait AdditionOps: GraphViewOps {
     // TODO: Probably add vector reference here like add
@@ -41,6 +131,34 @@ pub trait AdditionOps: GraphViewOps {
         props: PI,
     ) -> Result<VertexView<Self>, GraphError>;
 
+    /// Add a vertex with a type to the graph
+    ///
+    /// The type of a vertex is set the first time it is given and can not be changed afterwards,
+    /// passing `None` leaves the type of an existing vertex unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The time
+    /// * `v` - The vertex (can be a string or integer)
+    /// * `props` - The properties of the vertex
+    /// * `vertex_type` - The type of the vertex
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use raphtory::prelude::*;
+    /// let g = Graph::new();
+    /// let v = g.add_vertex_with_type(0, "Alice", NO_PROPS, Some("user")).unwrap();
+    /// assert_eq!(v.vertex_type().unwrap(), "user");
+    /// ```
+    fn add_vertex_with_type<V: InputVertex, T: TryIntoInputTime, PI: CollectProperties>(
+        &self,
+        t: T,
+        v: V,
+        props: PI,
+        vertex_type: Option<&str>,
+    ) -> Result<VertexView<Self>, GraphError>;
+
     fn add_vertex_with_custom_time_format<V: InputVertex, PI: CollectProperties>(
         &self,
         t: &str,
@@ -93,6 +211,19 @@ pub trait AdditionOps: GraphViewOps {
         let time: i64 = t.parse_time(fmt)?;
         self.add_edge(time, src, dst, props, layer)
     }
+
+    /// Adds a batch of edge updates at once.
+    ///
+    /// Vertices, layers and property names are resolved once for the whole batch and the updates
+    /// are added grouped by edge, which is much faster than calling `add_edge` for every update.
+    /// For `Graph` and `GraphWithDeletions` the batch is validated before the graph is changed,
+    /// so either all updates are added or, if the batch is invalid, none of them. Other graphs
+    /// may add the updates one by one, in which case the updates before a failing one are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch` - The columns of the updates, see [`EdgeBatch`]
+    fn add_edges<V: InputVertex>(&self, batch: EdgeBatch<V>) -> Result<(), GraphError>;
 }
 
 impl<G: InternalAdditionOps + GraphViewOps> AdditionOps for G {
@@ -101,13 +232,26 @@ impl<G: InternalAdditionOps + GraphViewOps> AdditionOps for G {
         t: T,
         v: V,
         props: PI,
+    ) -> Result<VertexView<G>, GraphError> {
+        self.add_vertex_with_type(t, v, props, None)
+    }
+
+    fn add_vertex_with_type<V: InputVertex, T: TryIntoInputTime, PI: CollectProperties>(
+        &self,
+        t: T,
+        v: V,
+        props: PI,
+        vertex_type: Option<&str>,
     ) -> Result<VertexView<G>, GraphError> {
         let properties = props.collect_properties(
             |name, dtype| self.resolve_vertex_property(name, dtype, false),
             |prop| self.process_prop_value(prop),
         )?;
         let ti = TimeIndexEntry::from_input(self, t)?;
-        let v_id = self.resolve_vertex(v.id(), v.id_str());
+        let v_id = self.resolve_vertex(v.id(), v.id_str())?;
+        if let Some(vertex_type) = vertex_type {
+            self.internal_set_vertex_type(v_id, self.resolve_vertex_type(vertex_type))?;
+        }
         self.internal_add_vertex(ti, v_id, properties)?;
         Ok(VertexView::new_internal(self.clone(), v_id))
     }
@@ -121,8 +265,8 @@ impl<G: InternalAdditionOps + GraphViewOps> AdditionOps for G {
         layer: Option<&str>,
     ) -> Result<EdgeView<G>, GraphError> {
         let ti = TimeIndexEntry::from_input(self, t)?;
-        let src_id = self.resolve_vertex(src.id(), src.id_str());
-        let dst_id = self.resolve_vertex(dst.id(), dst.id_str());
+        let src_id = self.resolve_vertex(src.id(), src.id_str())?;
+        let dst_id = self.resolve_vertex(dst.id(), dst.id_str())?;
         let layer_id = self.resolve_layer(layer);
 
         let properties: Vec<(usize, Prop)> = props.collect_properties(
@@ -135,4 +279,104 @@ impl<G: InternalAdditionOps + GraphViewOps> AdditionOps for G {
             EdgeRef::new_outgoing(eid, src_id, dst_id).at_layer(layer_id),
         ))
     }
+
+    fn add_edges<V: InputVertex>(&self, batch: EdgeBatch<V>) -> Result<(), GraphError> {
+        batch.check_lengths()?;
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        // check every property before anything is resolved, so that an invalid batch leaves
+        // the graph untouched
+        let meta = self.edge_meta().temporal_prop_meta();
+        let mut checked = Vec::with_capacity(batch.properties.len());
+        for (name, values) in batch.properties {
+            let Some(dtype) = values.iter().flatten().next().map(|v| v.dtype()) else {
+                continue;
+            };
+            if let Some(other) = values.iter().flatten().find(|v| v.dtype() != dtype) {
+                return Err(GraphError::PropertyTypeError {
+                    name,
+                    expected: dtype,
+                    actual: other.dtype(),
+                });
+            }
+            let existing = meta
+                .get_id(&name)
+                .and_then(|id| meta.get_dtype(id))
+                .filter(|existing| *existing != PropType::Empty);
+            if let Some(existing) = existing.filter(|existing| *existing != dtype) {
+                return Err(GraphError::PropertyTypeError {
+                    name,
+                    expected: existing,
+                    actual: dtype,
+                });
+            }
+            checked.push((name, dtype, values));
+        }
+        let mut properties = Vec::with_capacity(checked.len());
+        for (name, dtype, values) in checked {
+            let prop_id = self.resolve_edge_property(&name, dtype, false)?;
+            properties.push((prop_id, values));
+        }
+
+        let mut vertices: FxHashMap<u64, VID> = FxHashMap::default();
+        let mut resolve = |v: &V| -> Result<VID, GraphError> {
+            match vertices.get(&v.id()) {
+                Some(vid) => Ok(*vid),
+                None => {
+                    let vid = self.resolve_vertex(v.id(), v.id_str())?;
+                    vertices.insert(v.id(), vid);
+                    Ok(vid)
+                }
+            }
+        };
+        let src: Vec<_> = batch
+            .src
+            .iter()
+            .map(&mut resolve)
+            .collect::<Result<_, _>>()?;
+        let dst: Vec<_> = batch
+            .dst
+            .iter()
+            .map(&mut resolve)
+            .collect::<Result<_, _>>()?;
+
+        let layers: Vec<usize> = match batch.layers {
+            Some(names) => {
+                let mut ids: FxHashMap<&str, usize> = FxHashMap::default();
+                names
+                    .into_iter()
+                    .map(|name| {
+                        *ids.entry(name)
+                            .or_insert_with(|| self.resolve_layer(Some(name)))
+                    })
+                    .collect()
+            }
+            None => vec![self.resolve_layer(batch.layer); batch.times.len()],
+        };
+
+        let mut properties: Vec<_> = properties
+            .into_iter()
+            .map(|(id, values)| (id, values.into_iter()))
+            .collect();
+        let updates = batch
+            .times
+            .iter()
+            .zip(src)
+            .zip(dst)
+            .zip(layers)
+            .map(|(((&t, src), dst), layer)| {
+                let props = properties
+                    .iter_mut()
+                    .filter_map(|(id, values)| {
+                        let value = values.next().flatten()?;
+                        Some((*id, self.process_prop_value(value)))
+                    })
+                    .collect();
+                (t, src, dst, layer, props)
+            })
+            .collect();
+        self.internal_add_edges(updates)
+    }
 }
@@ -30,6 +30,26 @@ pub trait DeletionOps {
         let time: i64 = t.parse_time(fmt)?;
         self.delete_edge(time, src, dst, layer)
     }
+
+    /// Mark vertex `v` as deleted at time `t`.
+    ///
+    /// For graphs with deletion semantics, the vertex and all its incident edges are no longer
+    /// considered alive after the deletion (unless the vertex is updated again later).
+    fn delete_vertex<V: InputVertex, T: TryIntoInputTime>(
+        &self,
+        t: T,
+        v: V,
+    ) -> Result<(), GraphError>;
+
+    fn delete_vertex_with_custom_time_format<V: InputVertex>(
+        &self,
+        t: &str,
+        fmt: &str,
+        v: V,
+    ) -> Result<(), GraphError> {
+        let time: i64 = t.parse_time(fmt)?;
+        self.delete_vertex(time, v)
+    }
 }
 
 impl<G: InternalDeletionOps + InternalAdditionOps> DeletionOps for G {
@@ -41,9 +61,19 @@ impl<G: InternalDeletionOps + InternalAdditionOps> DeletionOps for G {
         layer: Option<&str>,
     ) -> Result<(), GraphError> {
         let ti = TimeIndexEntry::from_input(self, t)?;
-        let src_id = self.resolve_vertex(src.id(), src.id_str());
-        let dst_id = self.resolve_vertex(dst.id(), src.id_str());
+        let src_id = self.resolve_vertex(src.id(), src.id_str())?;
+        let dst_id = self.resolve_vertex(dst.id(), src.id_str())?;
         let layer = self.resolve_layer(layer);
         self.internal_delete_edge(ti, src_id, dst_id, layer)
     }
+
+    fn delete_vertex<V: InputVertex, T: TryIntoInputTime>(
+        &self,
+        t: T,
+        v: V,
+    ) -> Result<(), GraphError> {
+        let ti = TimeIndexEntry::from_input(self, t)?;
+        let v_id = self.resolve_vertex(v.id(), v.id_str())?;
+        self.internal_delete_vertex(ti, v_id)
+    }
 }
@@ -9,16 +9,25 @@ use crate::{
 };
 use enum_dispatch::enum_dispatch;
 
+/// An edge update `(time, src, dst, layer, props)` with resolved ids
+pub type EdgeUpdate = (i64, VID, VID, usize, Vec<(usize, Prop)>);
+
 #[enum_dispatch]
 pub trait InternalAdditionOps {
     /// get the sequence id for the next event
     fn next_event_id(&self) -> usize;
 
+    /// make sure all future sequence ids are at least `id`, the counter never moves backwards
+    fn set_next_event_id(&self, id: usize);
+
     /// map layer name to id and allocate a new layer if needed
     fn resolve_layer(&self, layer: Option<&str>) -> usize;
 
     /// map external vertex id to internal id, allocating a new empty vertex if needed
-    fn resolve_vertex(&self, id: u64, name: Option<&str>) -> VID;
+    fn resolve_vertex(&self, id: u64, name: Option<&str>) -> Result<VID, GraphError>;
+
+    /// map vertex type name to id, allocating a new vertex type if needed
+    fn resolve_vertex_type(&self, vertex_type: &str) -> usize;
 
     /// map property key to internal id, allocating new property if needed
     fn resolve_graph_property(&self, prop: &str, is_static: bool) -> usize;
@@ -49,6 +58,9 @@ pub trait InternalAdditionOps {
         props: Vec<(usize, Prop)>,
     ) -> Result<(), GraphError>;
 
+    /// set the type of a vertex, fails if the vertex already has a different type
+    fn internal_set_vertex_type(&self, v: VID, vertex_type: usize) -> Result<(), GraphError>;
+
     /// add edge update
     fn internal_add_edge(
         &self,
@@ -58,6 +70,23 @@ pub trait InternalAdditionOps {
         props: Vec<(usize, Prop)>,
         layer: usize,
     ) -> Result<EID, GraphError>;
+
+    /// add a batch of edge updates `(time, src, dst, layer, props)`, allocating event ids in order
+    ///
+    /// the default adds the updates one at a time and stops at the first error, keeping the
+    /// updates that were already added
+    fn internal_add_edges(&self, updates: Vec<EdgeUpdate>) -> Result<(), GraphError> {
+        for (t, src, dst, layer, props) in updates {
+            self.internal_add_edge(
+                TimeIndexEntry(t, self.next_event_id()),
+                src,
+                dst,
+                props,
+                layer,
+            )?;
+        }
+        Ok(())
+    }
 }
 
 pub trait InheritAdditionOps: Base {}
@@ -84,16 +113,26 @@ impl<G: DelegateAdditionOps> InternalAdditionOps for G {
         self.graph().next_event_id()
     }
 
+    #[inline]
+    fn set_next_event_id(&self, id: usize) {
+        self.graph().set_next_event_id(id)
+    }
+
     #[inline]
     fn resolve_layer(&self, layer: Option<&str>) -> usize {
         self.graph().resolve_layer(layer)
     }
 
     #[inline]
-    fn resolve_vertex(&self, id: u64, name: Option<&str>) -> VID {
+    fn resolve_vertex(&self, id: u64, name: Option<&str>) -> Result<VID, GraphError> {
         self.graph().resolve_vertex(id, name)
     }
 
+    #[inline]
+    fn resolve_vertex_type(&self, vertex_type: &str) -> usize {
+        self.graph().resolve_vertex_type(vertex_type)
+    }
+
     #[inline]
     fn resolve_graph_property(&self, prop: &str, is_static: bool) -> usize {
         self.graph().resolve_graph_property(prop, is_static)
@@ -134,6 +173,11 @@ impl<G: DelegateAdditionOps> InternalAdditionOps for G {
         self.graph().internal_add_vertex(t, v, props)
     }
 
+    #[inline(always)]
+    fn internal_set_vertex_type(&self, v: VID, vertex_type: usize) -> Result<(), GraphError> {
+        self.graph().internal_set_vertex_type(v, vertex_type)
+    }
+
     #[inline(always)]
     fn internal_add_edge(
         &self,
@@ -145,4 +189,9 @@ impl<G: DelegateAdditionOps> InternalAdditionOps for G {
     ) -> Result<EID, GraphError> {
         self.graph().internal_add_edge(t, src, dst, props, layer)
     }
+
+    #[inline(always)]
+    fn internal_add_edges(&self, updates: Vec<EdgeUpdate>) -> Result<(), GraphError> {
+        self.graph().internal_add_edges(updates)
+    }
 }
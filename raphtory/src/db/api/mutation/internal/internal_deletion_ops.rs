@@ -11,6 +11,8 @@ pub trait InternalDeletionOps {
         dst: VID,
         layer: usize,
     ) -> Result<(), GraphError>;
+
+    fn internal_delete_vertex(&self, t: TimeIndexEntry, v: VID) -> Result<(), GraphError>;
 }
 
 pub trait InheritDeletionOps: Base {}
@@ -43,4 +45,9 @@ impl<G: DelegateDeletionOps> InternalDeletionOps for G {
     ) -> Result<(), GraphError> {
         self.graph().internal_delete_edge(t, src, dst, layer)
     }
+
+    #[inline(always)]
+    fn internal_delete_vertex(&self, t: TimeIndexEntry, v: VID) -> Result<(), GraphError> {
+        self.graph().internal_delete_vertex(t, v)
+    }
 }
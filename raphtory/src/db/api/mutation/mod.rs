@@ -14,7 +14,7 @@ mod deletion_ops;
 pub mod internal;
 mod property_addition_ops;
 
-pub use addition_ops::AdditionOps;
+pub use addition_ops::{AdditionOps, EdgeBatch};
 pub use deletion_ops::DeletionOps;
 pub use property_addition_ops::PropertyAdditionOps;
 
@@ -0,0 +1,564 @@
+//! Export graph views as Arrow tables.
+//!
+//! Any view (windowed, layered, subgraph, ...) can be written out as
+//!
+//! * an exploded edge table with one row per edge update: `time`, `src`, `dst`, `layer`
+//!   and one column per temporal edge property,
+//! * a vertex table with one row per vertex: `id`, `name` and one column per constant vertex
+//!   property,
+//! * an edge property table with one row per edge and layer: `src`, `dst`, `layer` and one
+//!   column per constant edge property.
+//!
+//! Tables can be written in the Arrow IPC or the Parquet file format. Vertices are referenced
+//! by name in the edge tables so the output can be loaded back with the loaders in
+//! `graph_loader::source::arrow_loader` and `graph_loader::source::parquet_loader`. Properties of type list, map or graph
+//! have no tabular representation and are skipped. Property columns whose name collides with
+//! one of the fixed columns are prefixed with `prop_`, e.g. a property called `time` is
+//! exported as `prop_time`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use raphtory::db::api::view::ExportOps;
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! g.add_edge(1, 1, 2, [("weight", Prop::F64(1.0))], None).unwrap();
+//! g.window(0, 10)
+//!     .edges_table()
+//!     .write_ipc("edges.arrow")
+//!     .unwrap();
+//! g.vertices_table().write_parquet("vertices.parquet").unwrap();
+//! ```
+
+use crate::{
+    core::{utils::errors::GraphError, ArcStr, PropType, PropUnwrap},
+    db::api::view::EdgeViewInternalOps,
+    prelude::*,
+};
+use arrow2::{
+    array::{Array, BooleanArray, PrimitiveArray, Utf8Array},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema, TimeUnit},
+    io::{
+        ipc::write::{FileWriter, WriteOptions},
+        parquet::write::{
+            transverse, CompressionOptions, Encoding, FileWriter as ParquetWriter,
+            RowGroupIterator, Version, WriteOptions as ParquetOptions,
+        },
+    },
+};
+use std::{collections::HashSet, fs::File, path::Path};
+
+/// A table of equal length Arrow columns
+#[derive(Debug, Clone)]
+pub struct ArrowTable {
+    pub schema: Schema,
+    pub chunk: Chunk<Box<dyn Array>>,
+}
+
+impl ArrowTable {
+    fn new(columns: Vec<(Field, Box<dyn Array>)>) -> Self {
+        let (fields, arrays): (Vec<_>, Vec<_>) = columns.into_iter().unzip();
+        Self {
+            schema: Schema::from(fields),
+            chunk: Chunk::new(arrays),
+        }
+    }
+
+    /// Number of rows in the table
+    pub fn num_rows(&self) -> usize {
+        self.chunk.len()
+    }
+
+    /// Names of the columns in the table
+    pub fn column_names(&self) -> Vec<&str> {
+        self.schema.fields.iter().map(|f| f.name.as_str()).collect()
+    }
+
+    /// Get the column called `name`
+    pub fn column(&self, name: &str) -> Option<&dyn Array> {
+        let index = self.schema.fields.iter().position(|f| f.name == name)?;
+        Some(self.chunk.arrays()[index].as_ref())
+    }
+
+    /// Write the table to `path` in the Arrow IPC file format
+    pub fn write_ipc<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
+        let file = File::create(path)?;
+        let mut writer = FileWriter::try_new(
+            file,
+            self.schema.clone(),
+            None,
+            WriteOptions { compression: None },
+        )?;
+        writer.write(&self.chunk, None)?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Write the table to `path` as a Parquet file with a single row group
+    pub fn write_parquet<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
+        let options = ParquetOptions {
+            write_statistics: true,
+            compression: CompressionOptions::Snappy,
+            version: Version::V2,
+            data_pagesize_limit: None,
+        };
+        let encodings = self
+            .schema
+            .fields
+            .iter()
+            .map(|f| transverse(&f.data_type, |_| Encoding::Plain))
+            .collect();
+        let row_groups = RowGroupIterator::try_new(
+            std::iter::once(Ok(self.chunk.clone())),
+            &self.schema,
+            options,
+            encodings,
+        )?;
+        let file = File::create(path)?;
+        let mut writer = ParquetWriter::try_new(file, self.schema.clone(), options)?;
+        for group in row_groups {
+            writer.write(group?)?;
+        }
+        writer.end(None)?;
+        Ok(())
+    }
+}
+
+/// Operations for exporting a graph view as Arrow tables
+pub trait ExportOps: GraphViewOps {
+    /// One row per edge update with the values of the temporal properties set by that update
+    fn edges_table(&self) -> ArrowTable {
+        let meta = self.edge_meta().temporal_prop_meta();
+        let props = exportable_props(meta.get_keys().iter(), |id| meta.get_dtype(id));
+
+        let mut times = vec![];
+        let mut srcs = vec![];
+        let mut dsts = vec![];
+        let mut layers = vec![];
+        let mut values: Vec<Vec<Option<Prop>>> = vec![vec![]; props.len()];
+        for edge in self.edges().explode() {
+            let Some(ti) = edge.time_and_index() else {
+                continue;
+            };
+            let layer_ids = self.layer_ids().constrain_from_edge(edge.eref());
+            times.push(ti.0);
+            srcs.push(edge.src().name());
+            dsts.push(edge.dst().name());
+            layers.push(edge.layer_name());
+            for ((id, _, _), column) in props.iter().zip(values.iter_mut()) {
+                column.push(
+                    self.temporal_edge_prop(edge.eref(), *id, layer_ids.clone())
+                        .and_then(|p| p.at(&ti)),
+                );
+            }
+        }
+
+        let columns = vec![
+            (
+                Field::new("time", DataType::Int64, false),
+                PrimitiveArray::<i64>::from_vec(times).boxed(),
+            ),
+            (
+                Field::new("src", DataType::Utf8, false),
+                Utf8Array::<i32>::from_slice(srcs).boxed(),
+            ),
+            (
+                Field::new("dst", DataType::Utf8, false),
+                Utf8Array::<i32>::from_slice(dsts).boxed(),
+            ),
+            (
+                Field::new("layer", DataType::Utf8, true),
+                Utf8Array::<i32>::from_iter(layers.iter().map(|l| l.as_ref().map(|l| l.as_ref())))
+                    .boxed(),
+            ),
+        ];
+        with_prop_columns(columns, props, values)
+    }
+
+    /// One row per vertex with its id, name and constant properties
+    fn vertices_table(&self) -> ArrowTable {
+        let meta = self.vertex_meta().const_prop_meta();
+        let props = exportable_props(meta.get_keys().iter(), |id| meta.get_dtype(id));
+
+        let mut ids = vec![];
+        let mut names = vec![];
+        let mut values: Vec<Vec<Option<Prop>>> = vec![vec![]; props.len()];
+        for vertex in self.vertices() {
+            ids.push(vertex.id());
+            names.push(vertex.name());
+            let constants = vertex.properties().constant();
+            for ((_, name, _), column) in props.iter().zip(values.iter_mut()) {
+                column.push(constants.get(name));
+            }
+        }
+
+        let columns = vec![
+            (
+                Field::new("id", DataType::UInt64, false),
+                PrimitiveArray::<u64>::from_vec(ids).boxed(),
+            ),
+            (
+                Field::new("name", DataType::Utf8, false),
+                Utf8Array::<i32>::from_slice(names).boxed(),
+            ),
+        ];
+        with_prop_columns(columns, props, values)
+    }
+
+    /// One row per edge and layer with its constant properties
+    fn edge_properties_table(&self) -> ArrowTable {
+        let meta = self.edge_meta().const_prop_meta();
+        let props = exportable_props(meta.get_keys().iter(), |id| meta.get_dtype(id));
+
+        let mut srcs = vec![];
+        let mut dsts = vec![];
+        let mut layers = vec![];
+        let mut values: Vec<Vec<Option<Prop>>> = vec![vec![]; props.len()];
+        for edge in self.edges().flat_map(|e| e.explode_layers()) {
+            srcs.push(edge.src().name());
+            dsts.push(edge.dst().name());
+            layers.push(edge.layer_name());
+            let constants = edge.properties().constant();
+            for ((_, name, _), column) in props.iter().zip(values.iter_mut()) {
+                column.push(constants.get(name));
+            }
+        }
+
+        let columns = vec![
+            (
+                Field::new("src", DataType::Utf8, false),
+                Utf8Array::<i32>::from_slice(srcs).boxed(),
+            ),
+            (
+                Field::new("dst", DataType::Utf8, false),
+                Utf8Array::<i32>::from_slice(dsts).boxed(),
+            ),
+            (
+                Field::new("layer", DataType::Utf8, true),
+                Utf8Array::<i32>::from_iter(layers.iter().map(|l| l.as_ref().map(|l| l.as_ref())))
+                    .boxed(),
+            ),
+        ];
+        with_prop_columns(columns, props, values)
+    }
+}
+
+impl<G: GraphViewOps> ExportOps for G {}
+
+/// Property ids, names and types for all properties that can be represented in Arrow
+fn exportable_props<'a>(
+    keys: impl Iterator<Item = &'a ArcStr>,
+    dtype: impl Fn(usize) -> Option<PropType>,
+) -> Vec<(usize, ArcStr, PropType)> {
+    keys.enumerate()
+        .filter_map(|(id, name)| {
+            let dtype = dtype(id)?;
+            arrow_type(dtype).map(|_| (id, name.clone(), dtype))
+        })
+        .collect()
+}
+
+fn arrow_type(dtype: PropType) -> Option<DataType> {
+    match dtype {
+        PropType::Str => Some(DataType::Utf8),
+        PropType::U8 => Some(DataType::UInt8),
+        PropType::U16 => Some(DataType::UInt16),
+        PropType::U32 => Some(DataType::UInt32),
+        PropType::U64 => Some(DataType::UInt64),
+        PropType::I32 => Some(DataType::Int32),
+        PropType::I64 => Some(DataType::Int64),
+        PropType::F32 => Some(DataType::Float32),
+        PropType::F64 => Some(DataType::Float64),
+        PropType::Bool => Some(DataType::Boolean),
+        PropType::DTime => Some(DataType::Timestamp(TimeUnit::Millisecond, None)),
+        PropType::Empty | PropType::List | PropType::Map | PropType::Graph => None,
+    }
+}
+
+/// Build the table from the fixed `columns` followed by the property columns
+///
+/// Property columns are renamed with a `prop_` prefix until their name does not collide with
+/// any other column, as Arrow and Parquet readers reject duplicate field names.
+fn with_prop_columns(
+    mut columns: Vec<(Field, Box<dyn Array>)>,
+    props: Vec<(usize, ArcStr, PropType)>,
+    values: Vec<Vec<Option<Prop>>>,
+) -> ArrowTable {
+    let mut names: HashSet<String> = columns.iter().map(|(f, _)| f.name.clone()).collect();
+    names.extend(props.iter().map(|(_, name, _)| name.to_string()));
+    for ((_, name, dtype), values) in props.into_iter().zip(values) {
+        let (Some(data_type), Some(array)) = (arrow_type(dtype), prop_array(dtype, values)) else {
+            continue;
+        };
+        let mut column_name = name.to_string();
+        if columns.iter().any(|(f, _)| f.name == column_name) {
+            while names.contains(&column_name) {
+                column_name = format!("prop_{column_name}");
+            }
+            names.insert(column_name.clone());
+        }
+        columns.push((Field::new(column_name, data_type, true), array));
+    }
+    ArrowTable::new(columns)
+}
+
+fn prop_array(dtype: PropType, values: Vec<Option<Prop>>) -> Option<Box<dyn Array>> {
+    let values = values.into_iter();
+    let array = match dtype {
+        PropType::Str => Utf8Array::<i32>::from_iter(values.map(|v| v.into_str())).boxed(),
+        PropType::U8 => PrimitiveArray::from_iter(values.map(|v| v.into_u8())).boxed(),
+        PropType::U16 => PrimitiveArray::from_iter(values.map(|v| v.into_u16())).boxed(),
+        PropType::U32 => PrimitiveArray::from_iter(values.map(|v| v.into_u32())).boxed(),
+        PropType::U64 => PrimitiveArray::from_iter(values.map(|v| v.into_u64())).boxed(),
+        PropType::I32 => PrimitiveArray::from_iter(values.map(|v| v.into_i32())).boxed(),
+        PropType::I64 => PrimitiveArray::from_iter(values.map(|v| v.into_i64())).boxed(),
+        PropType::F32 => PrimitiveArray::from_iter(values.map(|v| v.into_f32())).boxed(),
+        PropType::F64 => PrimitiveArray::from_iter(values.map(|v| v.into_f64())).boxed(),
+        PropType::Bool => BooleanArray::from_iter(values.map(|v| v.into_bool())).boxed(),
+        PropType::DTime => {
+            PrimitiveArray::from_iter(values.map(|v| v.into_dtime().map(|t| t.timestamp_millis())))
+                .to(DataType::Timestamp(TimeUnit::Millisecond, None))
+                .boxed()
+        }
+        PropType::Empty | PropType::List | PropType::Map | PropType::Graph => return None,
+    };
+    Some(array)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        db::api::view::ExportOps,
+        graph_loader::source::{
+            arrow_loader::{load_edge_props_from_ipc, load_edges_from_ipc},
+            parquet_loader::{load_edges_from_parquet, load_vertex_props_from_parquet},
+        },
+        prelude::*,
+    };
+    use arrow2::array::{PrimitiveArray, Utf8Array};
+    use tempdir::TempDir;
+
+    fn graph() -> Graph {
+        let g = Graph::new();
+        g.add_edge(1, "a", "b", [("weight", Prop::F64(1.0))], Some("x"))
+            .unwrap();
+        g.add_edge(2, "a", "b", [("label", Prop::str("l"))], Some("y"))
+            .unwrap();
+        g.add_edge(5, "b", "c", [("weight", Prop::F64(3.0))], None)
+            .unwrap();
+        g.add_vertex(1, "a", [("kind", Prop::str("user"))]).unwrap();
+        g.vertex("a")
+            .unwrap()
+            .add_constant_properties([("age", Prop::I64(3))])
+            .unwrap();
+        g.edge("a", "b")
+            .unwrap()
+            .add_constant_properties([("since", Prop::I64(2000))], Some("x"))
+            .unwrap();
+        g
+    }
+
+    #[test]
+    fn exploded_edges_of_window() {
+        let g = graph();
+        let table = g.window(0, 3).edges_table();
+        assert_eq!(
+            table.column_names(),
+            vec!["time", "src", "dst", "layer", "weight", "label"]
+        );
+        assert_eq!(table.num_rows(), 2);
+
+        let time = table
+            .column("time")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i64>>()
+            .unwrap();
+        assert_eq!(time.values().as_slice(), &[1, 2]);
+        let weight = table
+            .column("weight")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+        assert_eq!(weight.iter().collect::<Vec<_>>(), vec![Some(&1.0), None]);
+        let label = table
+            .column("label")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap();
+        assert_eq!(label.iter().collect::<Vec<_>>(), vec![None, Some("l")]);
+    }
+
+    #[test]
+    fn vertices_of_subgraph() {
+        let g = graph();
+        let table = g.subgraph(["a", "b"]).vertices_table();
+        assert_eq!(table.column_names(), vec!["id", "name", "age"]);
+        assert_eq!(table.num_rows(), 2);
+        let age = table
+            .column("age")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i64>>()
+            .unwrap();
+        assert_eq!(age.iter().collect::<Vec<_>>(), vec![Some(&3), None]);
+    }
+
+    #[test]
+    fn colliding_property_names_are_prefixed() {
+        let g = Graph::new();
+        g.add_edge(
+            1,
+            "a",
+            "b",
+            [("time", Prop::I64(7)), ("src", Prop::str("s"))],
+            None,
+        )
+        .unwrap();
+        g.add_edge(2, "a", "b", [("prop_time", Prop::I64(8))], None)
+            .unwrap();
+        g.vertex("a")
+            .unwrap()
+            .add_constant_properties([("name", Prop::str("n"))])
+            .unwrap();
+
+        let table = g.edges_table();
+        assert_eq!(
+            table.column_names(),
+            vec![
+                "time",
+                "src",
+                "dst",
+                "layer",
+                "prop_prop_time",
+                "prop_src",
+                "prop_time"
+            ]
+        );
+        let time = table
+            .column("prop_prop_time")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i64>>()
+            .unwrap();
+        assert_eq!(time.iter().collect::<Vec<_>>(), vec![Some(&7), None]);
+        assert_eq!(
+            g.vertices_table().column_names(),
+            vec!["id", "name", "prop_name"]
+        );
+
+        let dir = TempDir::new("export").unwrap();
+        table
+            .write_parquet(dir.path().join("edges.parquet"))
+            .unwrap();
+    }
+
+    #[test]
+    fn round_trip_through_ipc() {
+        let g = graph();
+        let dir = TempDir::new("export").unwrap();
+        let edges = dir.path().join("edges.arrow");
+        let edge_props = dir.path().join("edge_props.arrow");
+        g.edges_table().write_ipc(&edges).unwrap();
+        g.edge_properties_table().write_ipc(&edge_props).unwrap();
+
+        let loaded = Graph::new();
+        load_edges_from_ipc(
+            &loaded,
+            &edges,
+            "src",
+            "dst",
+            "time",
+            Some(vec!["weight", "label"]),
+            None,
+            None,
+            Some("layer"),
+            true,
+        )
+        .unwrap();
+        load_edge_props_from_ipc(
+            &loaded,
+            &edge_props,
+            "src",
+            "dst",
+            Some(vec!["since"]),
+            None,
+            Some("layer"),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(loaded.count_edges(), g.count_edges());
+        let e = loaded.edge("a", "b").unwrap();
+        assert_eq!(e.layer("x").unwrap().history(), vec![1]);
+        assert_eq!(
+            e.layer("x").unwrap().properties().constant().get("since"),
+            Some(Prop::I64(2000))
+        );
+        assert_eq!(
+            loaded
+                .edge("b", "c")
+                .unwrap()
+                .properties()
+                .temporal()
+                .get("weight")
+                .and_then(|p| p.latest()),
+            Some(Prop::F64(3.0))
+        );
+    }
+
+    #[test]
+    fn round_trip_through_parquet() {
+        let g = graph();
+        let dir = TempDir::new("export").unwrap();
+        let edges = dir.path().join("edges.parquet");
+        let vertices = dir.path().join("vertices.parquet");
+        g.edges_table().write_parquet(&edges).unwrap();
+        g.vertices_table().write_parquet(&vertices).unwrap();
+
+        let loaded = Graph::new();
+        load_edges_from_parquet(
+            &loaded,
+            &edges,
+            "src",
+            "dst",
+            "time",
+            Some(vec!["weight", "label"]),
+            None,
+            None,
+            Some("layer"),
+            true,
+        )
+        .unwrap();
+        load_vertex_props_from_parquet(&loaded, &vertices, "id", Some(vec!["age"]), None).unwrap();
+
+        assert_eq!(loaded.count_edges(), g.count_edges());
+        assert_eq!(
+            loaded.edge("a", "b").unwrap().layer("y").unwrap().history(),
+            vec![2]
+        );
+        assert_eq!(
+            loaded
+                .edge("a", "b")
+                .unwrap()
+                .properties()
+                .temporal()
+                .get("label")
+                .and_then(|p| p.latest()),
+            Some(Prop::str("l"))
+        );
+        assert_eq!(
+            loaded
+                .vertex("a")
+                .unwrap()
+                .properties()
+                .constant()
+                .get("age"),
+            Some(Prop::I64(3))
+        );
+    }
+}
@@ -17,14 +17,15 @@ use crate::{
             vertex::VertexView,
             vertices::Vertices,
             views::{
-                layer_graph::LayeredGraph, vertex_subgraph::VertexSubgraph,
-                window_graph::WindowedGraph,
+                edge_subgraph::EdgeSubgraph, layer_graph::LayeredGraph,
+                vertex_subgraph::VertexSubgraph, window_graph::WindowedGraph,
             },
         },
     },
     prelude::{DeletionOps, NO_PROPS},
 };
 use rustc_hash::FxHashSet;
+use std::collections::BTreeSet;
 
 /// This trait GraphViewOps defines operations for accessing
 /// information about a graph. The trait has associated types
@@ -35,8 +36,22 @@ pub trait GraphViewOps: BoxableGraphView + Clone + Sized {
         &self,
         vertices: I,
     ) -> VertexSubgraph<Self>;
+    /// Return a view of the graph that only includes the given edges `(src, dst)`
+    /// (and the vertices they connect)
+    fn edge_subgraph<I: IntoIterator<Item = (V, V)>, V: Into<VertexRef>>(
+        &self,
+        edges: I,
+    ) -> EdgeSubgraph<Self>;
+    /// Return a view of the graph that only includes vertices of the given types
+    /// (and the edges between them)
+    fn type_filter<I: IntoIterator<Item = V>, V: AsRef<str>>(
+        &self,
+        vertex_types: I,
+    ) -> VertexSubgraph<Self>;
     /// Return all the layer ids in the graph
     fn unique_layers(&self) -> BoxedIter<ArcStr>;
+    /// Return the types of the vertices in the graph
+    fn vertex_types(&self) -> Vec<ArcStr>;
     /// Timestamp of earliest activity in the graph
     fn earliest_time(&self) -> Option<i64>;
     /// Timestamp of latest activity in the graph
@@ -103,11 +118,55 @@ impl<G: BoxableGraphView + Sized + Clone> GraphViewOps for G {
         VertexSubgraph::new(self.clone(), vertices)
     }
 
+    fn edge_subgraph<I: IntoIterator<Item = (V, V)>, V: Into<VertexRef>>(
+        &self,
+        edges: I,
+    ) -> EdgeSubgraph<G> {
+        let filter = self.edge_filter();
+        let layer_ids = self.layer_ids();
+        let edges: Vec<_> = edges
+            .into_iter()
+            .flat_map(|(src, dst)| {
+                let src = self.internal_vertex_ref(src.into(), &layer_ids, filter)?;
+                let dst = self.internal_vertex_ref(dst.into(), &layer_ids, filter)?;
+                self.edge_ref(src, dst, &layer_ids, filter)
+            })
+            .collect();
+        EdgeSubgraph::new(self.clone(), edges)
+    }
+
+    fn type_filter<I: IntoIterator<Item = V>, V: AsRef<str>>(
+        &self,
+        vertex_types: I,
+    ) -> VertexSubgraph<G> {
+        let meta = self.vertex_meta();
+        let type_ids: FxHashSet<usize> = vertex_types
+            .into_iter()
+            .filter_map(|vertex_type| meta.get_vertex_type_id(vertex_type.as_ref()))
+            .collect();
+        let vertices: FxHashSet<VID> = self
+            .vertex_refs(self.layer_ids(), self.edge_filter())
+            .filter(|v| type_ids.contains(&self.vertex_type_id(*v)))
+            .collect();
+        VertexSubgraph::new(self.clone(), vertices)
+    }
+
     /// Return all the layer ids in the graph
     fn unique_layers(&self) -> BoxedIter<ArcStr> {
         self.get_layer_names_from_ids(self.layer_ids())
     }
 
+    fn vertex_types(&self) -> Vec<ArcStr> {
+        let type_ids: BTreeSet<usize> = self
+            .vertex_refs(self.layer_ids(), self.edge_filter())
+            .map(|v| self.vertex_type_id(v))
+            .collect();
+        type_ids
+            .into_iter()
+            .filter_map(|id| self.vertex_meta().get_vertex_type_name_by_id(id))
+            .collect()
+    }
+
     fn earliest_time(&self) -> Option<i64> {
         self.earliest_time_global()
     }
@@ -223,9 +282,16 @@ impl<G: BoxableGraphView + Sized + Clone> GraphViewOps for G {
                     g.add_vertex(t, v.name(), [(name.clone(), prop)])?;
                 }
             }
-            g.vertex(v.id())
-                .expect("vertex added")
-                .add_constant_properties(v.properties().constant())?;
+            if self.include_deletions() {
+                for t in self.vertex_deletion_history(v.vertex) {
+                    g.delete_vertex(t, v.name())?;
+                }
+            }
+            let new_v = g.vertex(v.id()).expect("vertex added");
+            new_v.add_constant_properties(v.properties().constant())?;
+            if let Some(vertex_type) = v.vertex_type() {
+                new_v.set_vertex_type(&vertex_type)?;
+            }
         }
 
         g.add_constant_properties(self.properties().constant())?;
@@ -1,7 +1,10 @@
 use crate::{
     core::{
-        entities::{edges::edge_ref::EdgeRef, LayerIds},
-        storage::timeindex::{LockedLayeredIndex, TimeIndexEntry},
+        entities::{edges::edge_ref::EdgeRef, LayerIds, VID},
+        storage::{
+            locked_view::LockedView,
+            timeindex::{LockedLayeredIndex, TimeIndex, TimeIndexEntry},
+        },
     },
     db::api::view::internal::Base,
 };
@@ -16,6 +19,10 @@ pub trait CoreDeletionOps {
         eref: EdgeRef,
         layer_ids: LayerIds,
     ) -> LockedLayeredIndex<'_, TimeIndexEntry>;
+
+    /// Get all the deletion timestamps for a vertex
+    /// (this should always be global and not affected by windowing as deletion semantics may need information outside the current view!)
+    fn vertex_deletions(&self, v: VID) -> LockedView<'_, TimeIndex<TimeIndexEntry>>;
 }
 
 pub trait InheritCoreDeletionOps: Base {}
@@ -45,4 +52,8 @@ impl<G: DelegateCoreDeletionOps> CoreDeletionOps for G {
     ) -> LockedLayeredIndex<'_, TimeIndexEntry> {
         self.graph().edge_deletions(eref, layer_ids)
     }
+
+    fn vertex_deletions(&self, v: VID) -> LockedView<'_, TimeIndex<TimeIndexEntry>> {
+        self.graph().vertex_deletions(v)
+    }
 }
@@ -46,6 +46,9 @@ pub trait CoreGraphOps {
     /// Returns the string name for a vertex
     fn vertex_name(&self, v: VID) -> String;
 
+    /// Returns the id of the type of a vertex, 0 if the vertex has no type
+    fn vertex_type_id(&self, v: VID) -> usize;
+
     /// Get all the addition timestamps for an edge
     /// (this should always be global and not affected by windowing as deletion semantics may need information outside the current view!)
     fn edge_additions(
@@ -267,6 +270,11 @@ impl<G: DelegateCoreOps + ?Sized> CoreGraphOps for G {
         self.graph().vertex_name(v)
     }
 
+    #[inline]
+    fn vertex_type_id(&self, v: VID) -> usize {
+        self.graph().vertex_type_id(v)
+    }
+
     #[inline]
     fn edge_additions(
         &self,
@@ -1,7 +1,8 @@
 use crate::db::{
     api::view::{internal::DynamicGraph, GraphViewOps},
     graph::views::{
-        layer_graph::LayeredGraph, vertex_subgraph::VertexSubgraph, window_graph::WindowedGraph,
+        edge_subgraph::EdgeSubgraph, layer_graph::LayeredGraph, vertex_subgraph::VertexSubgraph,
+        window_graph::WindowedGraph,
     },
 };
 use enum_dispatch::enum_dispatch;
@@ -34,3 +35,9 @@ impl<G: GraphViewOps> IntoDynamic for VertexSubgraph<G> {
         DynamicGraph::new(self)
     }
 }
+
+impl<G: GraphViewOps> IntoDynamic for EdgeSubgraph<G> {
+    fn into_dynamic(self) -> DynamicGraph {
+        DynamicGraph::new(self)
+    }
+}
@@ -2,6 +2,7 @@ use crate::{
     core::{
         entities::{
             edges::{edge_ref::EdgeRef, edge_store::EdgeStore},
+            graph::tgraph::TemporalGraphV1,
             properties::{
                 graph_props::GraphProps,
                 props::Meta,
@@ -20,14 +21,14 @@ use crate::{
     },
     db::{
         api::{
-            mutation::internal::{InternalAdditionOps, InternalPropertyAdditionOps},
+            mutation::internal::{EdgeUpdate, InternalAdditionOps, InternalPropertyAdditionOps},
             properties::internal::{
                 ConstPropertiesOps, TemporalPropertiesOps, TemporalPropertyViewOps,
             },
             view::{internal::*, BoxedIter},
         },
         graph::{
-            graph::{Graph, InternalGraph},
+            graph::{Graph, InternalGraph, SEG},
             views::deletion_graph::GraphWithDeletions,
         },
     },
@@ -35,7 +36,12 @@ use crate::{
 };
 use enum_dispatch::enum_dispatch;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::{
+    hash::Hasher,
+    io::{Cursor, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+use twox_hash::XxHash64;
 
 #[enum_dispatch(CoreGraphOps)]
 #[enum_dispatch(GraphOps)]
@@ -69,26 +75,278 @@ impl MaterializedGraph {
         }
     }
 
+    /// The kind of graph stored in this `MaterializedGraph`
+    pub fn kind(&self) -> GraphKind {
+        match self {
+            MaterializedGraph::EventGraph(_) => GraphKind::Event,
+            MaterializedGraph::PersistentGraph(_) => GraphKind::Persistent,
+        }
+    }
+
+    /// Read only the header of a saved graph file.
+    ///
+    /// Returns `None` for files written before the format was versioned.
+    pub fn read_header<P: AsRef<Path>>(path: P) -> Result<Option<GraphFileHeader>, GraphError> {
+        let f = std::fs::File::open(path)?;
+        let mut reader = std::io::BufReader::new(f);
+        GraphFileHeader::read(&mut reader)
+    }
+
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, GraphError> {
         let f = std::fs::File::open(path)?;
         let mut reader = std::io::BufReader::new(f);
-        Ok(bincode::deserialize_from(&mut reader)?)
+        Self::load_from_reader(&mut reader)
+    }
+
+    /// Load a graph written by `save_to_file` from any seekable reader
+    pub fn load_from_reader<R: Read + Seek>(reader: &mut R) -> Result<Self, GraphError> {
+        Self::decode(reader)
     }
 
     pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
         let f = std::fs::File::create(path)?;
         let mut writer = std::io::BufWriter::new(f);
-        Ok(bincode::serialize_into(&mut writer, self)?)
+        self.encode(&mut writer)?;
+        Ok(writer.flush()?)
     }
 
     pub fn bincode(&self) -> Result<Vec<u8>, GraphError> {
-        let encoded = bincode::serialize(self)?;
-        Ok(encoded)
+        let mut encoded = Cursor::new(vec![]);
+        self.encode(&mut encoded)?;
+        Ok(encoded.into_inner())
     }
 
     pub fn from_bincode(b: &[u8]) -> Result<Self, GraphError> {
-        let g = bincode::deserialize(b)?;
-        Ok(g)
+        Self::decode(&mut Cursor::new(b))
+    }
+
+    /// Write the header and the graph, the graph is only encoded once and the payload length
+    /// in the header is filled in after the graph is written
+    fn encode<W: Write + Seek>(&self, writer: &mut W) -> Result<(), GraphError> {
+        let header_start = writer.stream_position()?;
+        let mut header = GraphFileHeader {
+            version: FORMAT_VERSION,
+            kind: self.kind(),
+            payload_len: 0,
+        };
+        header.write(writer)?;
+        let mut payload = ChecksumWriter::new(&mut *writer);
+        bincode::serialize_into(&mut payload, self)?;
+        let checksum = payload.checksum();
+        header.payload_len = payload.len;
+        writer.write_all(&checksum.to_le_bytes())?;
+        let end = writer.stream_position()?;
+        writer.seek(SeekFrom::Start(header_start))?;
+        header.write(writer)?;
+        writer.seek(SeekFrom::Start(end))?;
+        Ok(())
+    }
+
+    fn decode<R: Read + Seek>(reader: &mut R) -> Result<Self, GraphError> {
+        let start = reader.stream_position()?;
+        let header = match GraphFileHeader::read(reader)? {
+            Some(header) => header,
+            None => {
+                reader.seek(SeekFrom::Start(start))?;
+                return bincode::deserialize_from::<_, MaterializedGraphV1>(reader)
+                    .map(|graph| graph.into())
+                    .map_err(|source| GraphError::LegacyFormatError { source });
+            }
+        };
+        // verify the checksum before decoding as corrupted lengths can cause huge allocations
+        let payload_start = reader.stream_position()?;
+        let mut hasher = ChecksumWriter::new(std::io::sink());
+        let copied = std::io::copy(&mut reader.take(header.payload_len), &mut hasher)?;
+        if copied != header.payload_len {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+        }
+        let mut checksum = [0u8; 8];
+        reader.read_exact(&mut checksum)?;
+        let expected = u64::from_le_bytes(checksum);
+        let actual = hasher.checksum();
+        if expected != actual {
+            return Err(GraphError::ChecksumMismatch { expected, actual });
+        }
+        reader.seek(SeekFrom::Start(payload_start))?;
+        let graph = Self::decode_payload(header.version, reader)?;
+        if graph.kind() != header.kind {
+            return Err(GraphError::InvalidFileFormat);
+        }
+        Ok(graph)
+    }
+
+    /// Decode the serialised graph for a given format version, migrating older layouts
+    /// to the current one. Add a case here whenever `FORMAT_VERSION` is bumped.
+    ///
+    /// Files without a header predate versioning and share the layout of version 1.
+    fn decode_payload<R: Read>(version: u32, reader: &mut R) -> Result<Self, GraphError> {
+        match version {
+            1 => Ok(bincode::deserialize_from::<_, MaterializedGraphV1>(reader)?.into()),
+            2 => Ok(bincode::deserialize_from(reader)?),
+            _ => Err(GraphError::UnsupportedFormatVersion {
+                version,
+                supported: FORMAT_VERSION,
+            }),
+        }
+    }
+}
+
+/// Layout of `MaterializedGraph` in format version 1 and in unversioned files.
+///
+/// `Graph`, `GraphWithDeletions` and the `Arc`s around the graph storage are transparent to
+/// bincode, so only the storage itself needs a separate layout.
+#[derive(Deserialize)]
+enum MaterializedGraphV1 {
+    EventGraph(TemporalGraphV1<SEG>),
+    PersistentGraph(TemporalGraphV1<SEG>),
+}
+
+impl From<MaterializedGraphV1> for MaterializedGraph {
+    fn from(value: MaterializedGraphV1) -> Self {
+        match value {
+            MaterializedGraphV1::EventGraph(g) => {
+                MaterializedGraph::EventGraph(InternalGraph::from(g).into())
+            }
+            MaterializedGraphV1::PersistentGraph(g) => {
+                MaterializedGraph::PersistentGraph(InternalGraph::from(g).into())
+            }
+        }
+    }
+}
+
+/// Magic bytes at the start of every saved graph file
+const MAGIC: &[u8; 8] = b"RAPHTORY";
+
+/// Current version of the on-disk graph format
+///
+/// * 1 - initial versioned format, same layout as unversioned files
+/// * 2 - adds vertex deletions and vertex types
+pub const FORMAT_VERSION: u32 = 2;
+
+/// The kind of graph stored in a file
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GraphKind {
+    Event,
+    Persistent,
+}
+
+impl GraphKind {
+    fn to_byte(self) -> u8 {
+        match self {
+            GraphKind::Event => 0,
+            GraphKind::Persistent => 1,
+        }
+    }
+
+    fn from_byte(b: u8) -> Option<Self> {
+        match b {
+            0 => Some(GraphKind::Event),
+            1 => Some(GraphKind::Persistent),
+            _ => None,
+        }
+    }
+}
+
+/// Header written at the start of saved graph files.
+///
+/// The layout is the magic bytes `RAPHTORY`, the format version as a little-endian `u32`,
+/// the graph kind as a single byte and the length of the encoded graph as a little-endian `u64`,
+/// followed by the bincode encoded graph and an xxHash64 checksum of the encoded graph as a
+/// little-endian `u64`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GraphFileHeader {
+    pub version: u32,
+    pub kind: GraphKind,
+    /// length of the encoded graph in bytes
+    pub payload_len: u64,
+}
+
+impl GraphFileHeader {
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), GraphError> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&self.version.to_le_bytes())?;
+        writer.write_all(&[self.kind.to_byte()])?;
+        writer.write_all(&self.payload_len.to_le_bytes())?;
+        Ok(())
+    }
+
+    fn read<R: Read>(reader: &mut R) -> Result<Option<Self>, GraphError> {
+        let mut magic = [0u8; 8];
+        let n = read_up_to(reader, &mut magic)?;
+        if &magic[..n] != MAGIC {
+            return Ok(None);
+        }
+        Self::read_after_magic(reader).map(Some)
+    }
+
+    fn read_after_magic<R: Read>(reader: &mut R) -> Result<Self, GraphError> {
+        let mut version = [0u8; 4];
+        reader.read_exact(&mut version)?;
+        let version = u32::from_le_bytes(version);
+        if version == 0 || version > FORMAT_VERSION {
+            return Err(GraphError::UnsupportedFormatVersion {
+                version,
+                supported: FORMAT_VERSION,
+            });
+        }
+        let mut kind = [0u8; 1];
+        reader.read_exact(&mut kind)?;
+        let kind = GraphKind::from_byte(kind[0]).ok_or(GraphError::InvalidFileFormat)?;
+        let mut payload_len = [0u8; 8];
+        reader.read_exact(&mut payload_len)?;
+        Ok(Self {
+            version,
+            kind,
+            payload_len: u64::from_le_bytes(payload_len),
+        })
+    }
+}
+
+/// Fill as much of `buf` as possible, returning the number of bytes read
+fn read_up_to<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<usize, GraphError> {
+    let mut n = 0;
+    while n < buf.len() {
+        match reader.read(&mut buf[n..]) {
+            Ok(0) => break,
+            Ok(read) => n += read,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(n)
+}
+
+struct ChecksumWriter<W> {
+    inner: W,
+    hasher: XxHash64,
+    /// number of bytes written so far
+    len: u64,
+}
+
+impl<W> ChecksumWriter<W> {
+    fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: XxHash64::default(),
+            len: 0,
+        }
+    }
+
+    fn checksum(&self) -> u64 {
+        self.hasher.finish()
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.write(&buf[..n]);
+        self.len += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
     }
 }
 
@@ -116,6 +374,7 @@ where
 
 #[cfg(test)]
 mod test_materialised_graph_dispatch {
+    use super::*;
     use crate::{
         core::entities::LayerIds,
         db::api::view::internal::{
@@ -169,4 +428,159 @@ mod test_materialised_graph_dispatch {
         let v = mg.add_vertex(0, 1, NO_PROPS).unwrap();
         assert_eq!(v.id(), 1)
     }
+
+    #[test]
+    fn saved_graph_has_header() {
+        let g = GraphWithDeletions::new();
+        g.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        let bytes = MaterializedGraph::from(g.clone()).bincode().unwrap();
+        assert_eq!(&bytes[..8], MAGIC);
+
+        let header = GraphFileHeader::read(&mut &bytes[..]).unwrap().unwrap();
+        assert_eq!(header.version, FORMAT_VERSION);
+        assert_eq!(header.kind, GraphKind::Persistent);
+
+        let loaded = MaterializedGraph::from_bincode(&bytes).unwrap();
+        assert_eq!(loaded.into_persistent().unwrap(), g);
+    }
+
+    #[test]
+    fn corrupted_graph_fails_checksum() {
+        let g = Graph::new();
+        g.add_edge(0, 1, 2, [("name", Prop::str("edge"))], None)
+            .unwrap();
+        let mut bytes = MaterializedGraph::from(g).bincode().unwrap();
+        let last = bytes.len() - 10;
+        bytes[last] ^= 0xff;
+        assert!(matches!(
+            MaterializedGraph::from_bincode(&bytes),
+            Err(GraphError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn newer_format_version_is_rejected() {
+        let mut bytes = MaterializedGraph::from(Graph::new()).bincode().unwrap();
+        bytes[8..12].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            MaterializedGraph::from_bincode(&bytes),
+            Err(GraphError::UnsupportedFormatVersion { version, supported })
+                if version == FORMAT_VERSION + 1 && supported == FORMAT_VERSION
+        ));
+    }
+
+    // written by `save_to_file` before the format was versioned
+    const LEGACY_EVENT_GRAPH: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/resources/test/legacy_event_graph.bin"
+    ));
+    const LEGACY_PERSISTENT_GRAPH: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/resources/test/legacy_persistent_graph.bin"
+    ));
+
+    fn check_legacy_event_graph(g: &Graph) {
+        assert_eq!(g.count_vertices(), 4);
+        assert_eq!(g.count_edges(), 3);
+        assert_eq!(g.earliest_time(), Some(0));
+        assert_eq!(g.latest_time(), Some(4));
+
+        let v = g.vertex(1).unwrap();
+        assert_eq!(v.properties().get("age"), Some(Prop::I64(30)));
+        assert_eq!(v.properties().get("type"), Some(Prop::str("user")));
+        assert_eq!(v.vertex_type(), None);
+        assert_eq!(v.history(), vec![0, 2, 4]);
+        assert!(g.vertex(0).is_some());
+        assert_eq!(g.vertex("Alice").unwrap().name(), "Alice");
+
+        let e = g.edge(1, 2).unwrap();
+        assert_eq!(e.properties().get("weight"), Some(Prop::F64(1.5)));
+        assert_eq!(
+            e.layer("_default").unwrap().properties().get("kind"),
+            Some(Prop::str("transfer"))
+        );
+        let friends = g.layer("friends").unwrap();
+        assert_eq!(friends.count_edges(), 1);
+        assert!(friends.edges().all(|e| e.dst().name() == "Alice"));
+        assert_eq!(g.properties().get("version"), Some(Prop::I64(1)));
+        assert_eq!(g.properties().get("name"), Some(Prop::str("fixture")));
+
+        // the migrated graph supports features added after version 1
+        v.set_vertex_type("host").unwrap();
+        assert_eq!(v.vertex_type().unwrap(), "host");
+        g.add_vertex_with_type(6, "Bob", NO_PROPS, Some("user"))
+            .unwrap();
+        assert_eq!(g.count_vertices(), 5);
+    }
+
+    /// wrap a legacy payload in a version 1 header
+    fn with_v1_header(kind: GraphKind, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![];
+        GraphFileHeader {
+            version: 1,
+            kind,
+            payload_len: payload.len() as u64,
+        }
+        .write(&mut bytes)
+        .unwrap();
+        let mut checksum = ChecksumWriter::new(std::io::sink());
+        checksum.write_all(payload).unwrap();
+        bytes.extend(payload);
+        bytes.extend(checksum.checksum().to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn legacy_graph_without_header_can_be_loaded() {
+        assert!(GraphFileHeader::read(&mut &LEGACY_EVENT_GRAPH[..])
+            .unwrap()
+            .is_none());
+        let g = MaterializedGraph::from_bincode(LEGACY_EVENT_GRAPH)
+            .unwrap()
+            .into_events()
+            .unwrap();
+        check_legacy_event_graph(&g);
+
+        let g = MaterializedGraph::from_bincode(LEGACY_PERSISTENT_GRAPH)
+            .unwrap()
+            .into_persistent()
+            .unwrap();
+        assert_eq!(g.count_edges(), 2);
+        assert!(g.window(0, 5).has_edge(1, 2, Layer::All));
+        assert!(!g.window(6, 10).has_edge(1, 2, Layer::All));
+        assert!(g.window(6, 10).has_edge(2, 3, Layer::All));
+
+        assert!(matches!(
+            MaterializedGraph::from_bincode(b"not a graph"),
+            Err(GraphError::LegacyFormatError { .. })
+        ));
+    }
+
+    #[test]
+    fn version_1_graph_is_migrated() {
+        let bytes = with_v1_header(GraphKind::Event, LEGACY_EVENT_GRAPH);
+        let g = MaterializedGraph::from_bincode(&bytes)
+            .unwrap()
+            .into_events()
+            .unwrap();
+        check_legacy_event_graph(&g);
+
+        // the migrated graph is saved in the current format
+        let saved = MaterializedGraph::from(g.clone()).bincode().unwrap();
+        let header = GraphFileHeader::read(&mut &saved[..]).unwrap().unwrap();
+        assert_eq!(header.version, FORMAT_VERSION);
+        let reloaded = MaterializedGraph::from_bincode(&saved)
+            .unwrap()
+            .into_events()
+            .unwrap();
+        assert_eq!(reloaded, g);
+        assert_eq!(reloaded.vertex(1).unwrap().vertex_type().unwrap(), "host");
+
+        let bytes = with_v1_header(GraphKind::Persistent, LEGACY_PERSISTENT_GRAPH);
+        let g = MaterializedGraph::from_bincode(&bytes)
+            .unwrap()
+            .into_persistent()
+            .unwrap();
+        assert!(!g.window(6, 10).has_edge(1, 2, Layer::All));
+    }
 }
@@ -140,6 +140,12 @@ pub trait TimeSemantics: GraphOps + CoreGraphOps {
         layer_ids: LayerIds,
     ) -> Vec<i64>;
 
+    /// Get the vertex deletions for use with materialize
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64>;
+
+    /// Get the vertex deletions for use with materialize restricted to window `w`
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64>;
+
     /// Check if graph has temporal property with the given id
     ///
     /// # Arguments
@@ -469,6 +475,16 @@ impl<G: DelegateTimeSemantics + ?Sized> TimeSemantics for G {
         self.graph().edge_deletion_history_window(e, w, layer_ids)
     }
 
+    #[inline]
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64> {
+        self.graph().vertex_deletion_history(v)
+    }
+
+    #[inline]
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        self.graph().vertex_deletion_history_window(v, w)
+    }
+
     #[inline]
     fn has_temporal_prop(&self, prop_id: usize) -> bool {
         self.graph().has_temporal_prop(prop_id)
@@ -1,6 +1,8 @@
 //! Defines the `ViewApi` trait, which represents the API for querying a view of the graph.
 
 mod edge;
+#[cfg(feature = "arrow")]
+mod export;
 mod graph;
 pub mod internal;
 mod layer;
@@ -8,6 +10,8 @@ mod time;
 mod vertex;
 
 pub use edge::*;
+#[cfg(feature = "arrow")]
+pub use export::*;
 pub use graph::*;
 pub use layer::*;
 pub use time::*;
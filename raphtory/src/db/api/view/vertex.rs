@@ -1,9 +1,12 @@
-use crate::db::{
-    api::{
-        properties::Properties,
-        view::{edge::EdgeListOps, GraphViewOps, TimeOps},
+use crate::{
+    core::ArcStr,
+    db::{
+        api::{
+            properties::Properties,
+            view::{edge::EdgeListOps, GraphViewOps, TimeOps},
+        },
+        graph::vertex::VertexView,
     },
-    graph::vertex::VertexView,
 };
 
 /// Operations defined for a vertex
@@ -25,6 +28,9 @@ pub trait VertexViewOps: TimeOps {
     /// The name of the vertex if one exists, otherwise the ID as a string.
     fn name(&self) -> Self::ValueType<String>;
 
+    /// Get the type of this vertex, `None` if the vertex has no type
+    fn vertex_type(&self) -> Self::ValueType<Option<ArcStr>>;
+
     /// Get the timestamp for the earliest activity of the vertex
     fn earliest_time(&self) -> Self::ValueType<Option<i64>>;
 
@@ -140,6 +146,9 @@ pub trait VertexListOps:
     fn id(self) -> Self::IterType<u64>;
     fn name(self) -> Self::IterType<String>;
 
+    /// Returns the types of the vertices in the list.
+    fn vertex_type(self) -> Self::IterType<Option<ArcStr>>;
+
     /// Returns an iterator over properties of the vertices
     fn properties(self) -> Self::IterType<Properties<VertexView<Self::Graph>>>;
 
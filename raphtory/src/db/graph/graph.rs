@@ -32,7 +32,7 @@ use std::{
     path::Path,
     sync::Arc,
 };
-const SEG: usize = 16;
+pub(crate) const SEG: usize = 16;
 pub(crate) type InternalGraph = InnerTemporalGraph<SEG>;
 
 #[repr(transparent)]
@@ -154,8 +154,11 @@ mod db_tests {
             ArcStr, Prop,
         },
         db::{
-            api::view::{
-                EdgeListOps, EdgeViewOps, GraphViewOps, Layer, LayerOps, TimeOps, VertexViewOps,
+            api::{
+                mutation::EdgeBatch,
+                view::{
+                    EdgeListOps, EdgeViewOps, GraphViewOps, Layer, LayerOps, TimeOps, VertexViewOps,
+                },
             },
             graph::{edge::EdgeView, path::PathFromVertex},
         },
@@ -1561,6 +1564,158 @@ mod db_tests {
         correct
     }
 
+    #[quickcheck]
+    fn add_edges_matches_add_edge(edges: Vec<(i64, u8, u8)>) -> bool {
+        let times = edges.iter().map(|(t, _, _)| *t).collect_vec();
+        let src = edges.iter().map(|(_, s, _)| *s as u64).collect_vec();
+        let dst = edges.iter().map(|(_, _, d)| *d as u64).collect_vec();
+        let expected = Graph::new();
+        for (t, s, d) in &edges {
+            expected
+                .add_edge(*t, *s as u64, *d as u64, NO_PROPS, None)
+                .unwrap();
+        }
+        let g = Graph::new();
+        g.add_edges(EdgeBatch::new(&times, &src, &dst)).unwrap();
+
+        g.count_edges() == expected.count_edges()
+            && g.count_vertices() == expected.count_vertices()
+            && g.start() == expected.start()
+            && g.end() == expected.end()
+            && g.edges().all(|e| {
+                expected.edge(e.src().id(), e.dst().id()).unwrap().history() == e.history()
+            })
+            && g.vertices()
+                .into_iter()
+                .all(|v| expected.vertex(v.id()).unwrap().history() == v.history())
+    }
+
+    #[test]
+    fn add_edges_with_layers_and_properties() {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", [("weight", 0.5)], Some("x"))
+            .unwrap();
+        let batch = EdgeBatch::new(&[1, 2, 3, 4], &["a", "a", "b", "c"], &["b", "b", "b", "a"])
+            .with_layers(&["x", "y", "x", "y"])
+            .with_property("weight", vec![Some(1.0), None, Some(3.0), Some(4.0)])
+            .with_property::<i64>("unset", vec![None, None, None, None]);
+        g.add_edges(batch).unwrap();
+
+        let e = g.edge("a", "b").unwrap();
+        assert_eq!(e.history(), vec![0, 1, 2]);
+        assert_eq!(e.layer("y").unwrap().history(), vec![2]);
+        assert_eq!(
+            e.properties().temporal().get("weight").unwrap().values(),
+            vec![Prop::F64(0.5), Prop::F64(1.0)]
+        );
+        assert!(g.edge("b", "b").unwrap().layer("x").is_some());
+        assert_eq!(
+            g.edge("c", "a").unwrap().layer_names().collect_vec(),
+            vec!["y"]
+        );
+        assert!(!g.edge("a", "b").unwrap().properties().contains("unset"));
+        assert_eq!(g.end(), Some(5));
+    }
+
+    #[test]
+    fn add_edges_rejects_invalid_batches() {
+        use crate::db::api::{mutation::internal::InternalAdditionOps, view::internal::CoreGraphOps};
+
+        let g = Graph::new();
+        let short = EdgeBatch::new(&[1, 2], &[1, 2], &[3]);
+        assert!(matches!(
+            g.add_edges(short),
+            Err(GraphError::BatchLengthMismatch {
+                expected: 2,
+                actual: 1,
+                ..
+            })
+        ));
+        let mixed = EdgeBatch::new(&[1, 2], &[1, 2], &[3, 4])
+            .with_property("p", vec![Some(Prop::I64(1)), Some(Prop::str("x"))]);
+        assert!(matches!(
+            g.add_edges(mixed),
+            Err(GraphError::PropertyTypeError { .. })
+        ));
+        assert_eq!(g.count_edges(), 0);
+
+        // a type that conflicts with the graph is rejected before anything is added
+        g.add_edge(0, 1, 2, [("weight", 1.0)], None).unwrap();
+        let conflicting = EdgeBatch::new(&[1, 2], &[3, 4], &[5, 6])
+            .with_property("name", vec![Some(Prop::str("x")), None])
+            .with_property("weight", vec![Some(Prop::I64(1)), Some(Prop::I64(2))]);
+        assert!(matches!(
+            g.add_edges(conflicting),
+            Err(GraphError::PropertyTypeError { .. })
+        ));
+        assert_eq!(g.count_edges(), 1);
+        assert_eq!(g.count_vertices(), 2);
+        assert_eq!(g.end(), Some(1));
+        assert!(g.edge_meta().get_prop_id("name", false).is_none());
+
+        // invalid updates are not added, even if other edges of the batch are valid
+        let weight = g.edge_meta().get_prop_id("weight", false).unwrap();
+        let (a, b) = (
+            g.resolve_vertex(1, None).unwrap(),
+            g.resolve_vertex(2, None).unwrap(),
+        );
+        let updates = vec![
+            (1, a, b, 0, vec![(weight, Prop::F64(2.0))]),
+            (2, b, a, 0, vec![(weight, Prop::I64(2))]),
+        ];
+        assert!(matches!(
+            g.internal_add_edges(updates),
+            Err(GraphError::PropertyTypeError { .. })
+        ));
+        assert_eq!(g.edge(1, 2).unwrap().history(), vec![0]);
+        assert!(g.edge(2, 1).is_none());
+        assert_eq!(g.end(), Some(1));
+    }
+
+    #[test]
+    fn vertex_types() {
+        let g = Graph::new();
+        g.add_vertex_with_type(0, "alice", NO_PROPS, Some("user"))
+            .unwrap();
+        g.add_vertex_with_type(0, "bob", NO_PROPS, Some("user"))
+            .unwrap();
+        g.add_vertex_with_type(1, "server", NO_PROPS, Some("host"))
+            .unwrap();
+        g.add_vertex(2, "alice", NO_PROPS).unwrap();
+        g.add_edge(3, "alice", "bob", NO_PROPS, None).unwrap();
+        g.add_edge(4, "alice", "server", NO_PROPS, None).unwrap();
+        g.add_edge(5, "carol", "server", NO_PROPS, None).unwrap();
+
+        assert_eq!(g.vertex("alice").unwrap().vertex_type().unwrap(), "user");
+        assert_eq!(g.vertex("carol").unwrap().vertex_type(), None);
+        assert!(matches!(
+            g.add_vertex_with_type(6, "alice", NO_PROPS, Some("host")),
+            Err(GraphError::VertexTypeError { .. })
+        ));
+        g.vertex("carol").unwrap().set_vertex_type("user").unwrap();
+        assert_eq!(g.vertex_types(), vec!["user", "host"]);
+
+        let users = g.type_filter(["user"]);
+        assert_eq!(users.count_vertices(), 3);
+        assert_eq!(users.count_edges(), 1);
+        assert_eq!(users.vertex_types(), vec!["user"]);
+        let all = g.type_filter(["user", "host"]);
+        assert_eq!(all.count_edges(), 3);
+        assert_eq!(g.type_filter(["missing"]).count_vertices(), 0);
+
+        let materialized = users.materialize().unwrap().into_events().unwrap();
+        assert_eq!(
+            materialized.vertex("bob").unwrap().vertex_type().unwrap(),
+            "user"
+        );
+        let bytes = MaterializedGraph::from(g).bincode().unwrap();
+        let loaded = MaterializedGraph::from_bincode(&bytes).unwrap();
+        assert_eq!(
+            loaded.vertex("server").unwrap().vertex_type().unwrap(),
+            "host"
+        );
+    }
+
     // non overlaping time intervals
     #[derive(Clone, Debug)]
     struct Intervals(Vec<(i64, i64)>);
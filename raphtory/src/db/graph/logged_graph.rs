@@ -0,0 +1,1082 @@
+//! Incremental on-disk persistence for graphs.
+//!
+//! A [`LoggedGraph`] wraps a [`Graph`] or [`GraphWithDeletions`] and records every
+//! successful mutation in an append-only event log next to a bincode snapshot of the graph.
+//! Opening the directory loads the snapshot and replays the log on top of it. Calling
+//! [`LoggedGraph::compact`] writes a fresh snapshot and starts a new log, so the cost of a
+//! save is proportional to the number of updates since the last compaction rather than the
+//! size of the graph.
+//!
+//! Every event is appended to the log before it is applied and removed again if applying it
+//! fails, so the log never holds updates that were rejected by the graph. New vertices are
+//! logged as their own event before they are created. The GraphQL server loads directories
+//! written by [`LoggedGraph::create`] and persists updates to these graphs through their log.
+//!
+//! Events are stored using external vertex ids, layer names and property names and keep
+//! their original [`TimeIndexEntry`]. Replaying an event twice is not safe in general (adding
+//! a constant property fails the second time), so a snapshot never overlaps with the log it
+//! is loaded with. Compaction commits by moving the old log aside, and [`LoggedGraph::open`]
+//! finishes a compaction that was interrupted after that point.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use raphtory::prelude::*;
+//! use raphtory::db::graph::logged_graph::LoggedGraph;
+//!
+//! let g = LoggedGraph::create(Graph::new(), "path/to/graph").unwrap();
+//! g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+//! g.flush().unwrap();
+//!
+//! let g = LoggedGraph::<Graph>::open("path/to/graph").unwrap();
+//! assert!(g.has_edge(1, 2, Layer::All));
+//! ```
+
+use crate::{
+    core::{
+        entities::{LayerIds, EID, VID},
+        storage::timeindex::TimeIndexEntry,
+        utils::errors::GraphError,
+        ArcStr, PropType,
+    },
+    db::{
+        api::{
+            mutation::{
+                internal::{InternalAdditionOps, InternalDeletionOps, InternalPropertyAdditionOps},
+                CollectProperties,
+            },
+            view::internal::{DynamicGraph, InheritViewOps, IntoDynamic, MaterializedGraph},
+        },
+        graph::{graph::Graph, views::deletion_graph::GraphWithDeletions},
+    },
+    prelude::*,
+};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufReader, BufWriter, ErrorKind, Read, Write},
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+const SNAPSHOT_FILE: &str = "graph";
+const SNAPSHOT_TMP_FILE: &str = "graph.tmp";
+const LOG_FILE: &str = "events.log";
+const COMPACTED_LOG_FILE: &str = "events.log.compacted";
+
+/// Whether `path` is a directory written by [`LoggedGraph::create`]
+pub fn is_logged_graph<P: AsRef<Path>>(path: P) -> bool {
+    let dir = path.as_ref();
+    dir.join(SNAPSHOT_FILE).is_file()
+        && (dir.join(LOG_FILE).is_file() || dir.join(COMPACTED_LOG_FILE).is_file())
+}
+
+/// A vertex as it is written to the event log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoggedVertex {
+    pub id: u64,
+    pub name: Option<String>,
+}
+
+/// A single mutation as it is written to the event log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GraphEvent {
+    ResolveVertex {
+        vertex: LoggedVertex,
+    },
+    AddVertex {
+        t: TimeIndexEntry,
+        vertex: LoggedVertex,
+        props: Vec<(ArcStr, Prop)>,
+    },
+    AddEdge {
+        t: TimeIndexEntry,
+        src: LoggedVertex,
+        dst: LoggedVertex,
+        layer: Option<ArcStr>,
+        props: Vec<(ArcStr, Prop)>,
+    },
+    DeleteVertex {
+        t: TimeIndexEntry,
+        vertex: LoggedVertex,
+    },
+    DeleteEdge {
+        t: TimeIndexEntry,
+        src: LoggedVertex,
+        dst: LoggedVertex,
+        layer: Option<ArcStr>,
+    },
+    AddGraphProperties {
+        t: TimeIndexEntry,
+        props: Vec<(ArcStr, Prop)>,
+    },
+    AddConstantGraphProperties {
+        props: Vec<(ArcStr, Prop)>,
+    },
+    UpdateConstantGraphProperties {
+        props: Vec<(ArcStr, Prop)>,
+    },
+    AddConstantVertexProperties {
+        vertex: LoggedVertex,
+        props: Vec<(ArcStr, Prop)>,
+    },
+    UpdateConstantVertexProperties {
+        vertex: LoggedVertex,
+        props: Vec<(ArcStr, Prop)>,
+    },
+    AddConstantEdgeProperties {
+        src: LoggedVertex,
+        dst: LoggedVertex,
+        layer: Option<ArcStr>,
+        props: Vec<(ArcStr, Prop)>,
+    },
+    UpdateConstantEdgeProperties {
+        src: LoggedVertex,
+        dst: LoggedVertex,
+        layer: Option<ArcStr>,
+        props: Vec<(ArcStr, Prop)>,
+    },
+    SetVertexType {
+        vertex: LoggedVertex,
+        vertex_type: ArcStr,
+    },
+}
+
+impl GraphEvent {
+    /// The sequence id of the event, if it has a timestamp
+    fn event_id(&self) -> Option<usize> {
+        match self {
+            GraphEvent::AddVertex { t, .. }
+            | GraphEvent::AddEdge { t, .. }
+            | GraphEvent::DeleteVertex { t, .. }
+            | GraphEvent::DeleteEdge { t, .. }
+            | GraphEvent::AddGraphProperties { t, .. } => Some(t.1),
+            _ => None,
+        }
+    }
+
+    /// Apply the event to `graph`
+    pub fn apply<G: LoggableGraph>(self, graph: &G) -> Result<(), GraphError> {
+        match self {
+            GraphEvent::ResolveVertex { vertex } => {
+                resolve_logged_vertex(graph, &vertex)?;
+                Ok(())
+            }
+            GraphEvent::AddVertex { t, vertex, props } => {
+                let v = resolve_logged_vertex(graph, &vertex)?;
+                let props = props.collect_properties(
+                    |name, dtype| graph.resolve_vertex_property(name, dtype, false),
+                    |prop| graph.process_prop_value(prop),
+                )?;
+                graph.internal_add_vertex(t, v, props)
+            }
+            GraphEvent::AddEdge {
+                t,
+                src,
+                dst,
+                layer,
+                props,
+            } => {
+                let src = resolve_logged_vertex(graph, &src)?;
+                let dst = resolve_logged_vertex(graph, &dst)?;
+                let props = props.collect_properties(
+                    |name, dtype| graph.resolve_edge_property(name, dtype, false),
+                    |prop| graph.process_prop_value(prop),
+                )?;
+                let layer = graph.resolve_layer(layer.as_ref().map(|l| l.as_ref()));
+                graph.internal_add_edge(t, src, dst, props, layer)?;
+                Ok(())
+            }
+            GraphEvent::DeleteVertex { t, vertex } => {
+                let v = resolve_logged_vertex(graph, &vertex)?;
+                graph.deletions()?.internal_delete_vertex(t, v)
+            }
+            GraphEvent::DeleteEdge { t, src, dst, layer } => {
+                let src = resolve_logged_vertex(graph, &src)?;
+                let dst = resolve_logged_vertex(graph, &dst)?;
+                let layer = graph.resolve_layer(layer.as_ref().map(|l| l.as_ref()));
+                graph.deletions()?.internal_delete_edge(t, src, dst, layer)
+            }
+            GraphEvent::AddGraphProperties { t, props } => {
+                let props = props.collect_properties(
+                    |name, _| Ok(graph.resolve_graph_property(name, false)),
+                    |prop| graph.process_prop_value(prop),
+                )?;
+                graph.internal_add_properties(t, props)
+            }
+            GraphEvent::AddConstantGraphProperties { props } => {
+                let props = props.collect_properties(
+                    |name, _| Ok(graph.resolve_graph_property(name, true)),
+                    |prop| graph.process_prop_value(prop),
+                )?;
+                graph.internal_add_static_properties(props)
+            }
+            GraphEvent::UpdateConstantGraphProperties { props } => {
+                let props = props.collect_properties(
+                    |name, _| Ok(graph.resolve_graph_property(name, true)),
+                    |prop| graph.process_prop_value(prop),
+                )?;
+                graph.internal_update_static_properties(props)
+            }
+            GraphEvent::AddConstantVertexProperties { vertex, props } => {
+                let v = resolve_logged_vertex(graph, &vertex)?;
+                let props = props.collect_properties(
+                    |name, dtype| graph.resolve_vertex_property(name, dtype, true),
+                    |prop| graph.process_prop_value(prop),
+                )?;
+                graph.internal_add_constant_vertex_properties(v, props)
+            }
+            GraphEvent::UpdateConstantVertexProperties { vertex, props } => {
+                let v = resolve_logged_vertex(graph, &vertex)?;
+                let props = props.collect_properties(
+                    |name, dtype| graph.resolve_vertex_property(name, dtype, true),
+                    |prop| graph.process_prop_value(prop),
+                )?;
+                graph.internal_update_constant_vertex_properties(v, props)
+            }
+            GraphEvent::AddConstantEdgeProperties {
+                src,
+                dst,
+                layer,
+                props,
+            } => {
+                let (eid, layer) =
+                    resolve_logged_edge(graph, &src, &dst, layer.as_ref().map(|l| l.as_ref()))?;
+                let props = props.collect_properties(
+                    |name, dtype| graph.resolve_edge_property(name, dtype, true),
+                    |prop| graph.process_prop_value(prop),
+                )?;
+                graph.internal_add_constant_edge_properties(eid, layer, props)
+            }
+            GraphEvent::UpdateConstantEdgeProperties {
+                src,
+                dst,
+                layer,
+                props,
+            } => {
+                let (eid, layer) =
+                    resolve_logged_edge(graph, &src, &dst, layer.as_ref().map(|l| l.as_ref()))?;
+                let props = props.collect_properties(
+                    |name, dtype| graph.resolve_edge_property(name, dtype, true),
+                    |prop| graph.process_prop_value(prop),
+                )?;
+                graph.internal_update_constant_edge_properties(eid, layer, props)
+            }
+            GraphEvent::SetVertexType {
+                vertex,
+                vertex_type,
+            } => {
+                let v = resolve_logged_vertex(graph, &vertex)?;
+                let vertex_type = graph.resolve_vertex_type(&vertex_type);
+                graph.internal_set_vertex_type(v, vertex_type)
+            }
+        }
+    }
+}
+
+fn resolve_logged_vertex<G: InternalAdditionOps>(
+    graph: &G,
+    vertex: &LoggedVertex,
+) -> Result<VID, GraphError> {
+    graph.resolve_vertex(vertex.id, vertex.name.as_deref())
+}
+
+fn resolve_logged_edge<G: LoggableGraph>(
+    graph: &G,
+    src: &LoggedVertex,
+    dst: &LoggedVertex,
+    layer: Option<&str>,
+) -> Result<(EID, usize), GraphError> {
+    let layer = graph.resolve_layer(layer);
+    let src_id = graph.internal_vertex_ref(src.id.into(), &LayerIds::All, None);
+    let dst_id = graph.internal_vertex_ref(dst.id.into(), &LayerIds::All, None);
+    src_id
+        .zip(dst_id)
+        .and_then(|(src_id, dst_id)| graph.edge_ref(src_id, dst_id, &LayerIds::All, None))
+        .map(|e| (e.pid(), layer))
+        .ok_or(GraphError::EdgeIdError {
+            src: src.id,
+            dst: dst.id,
+        })
+}
+
+/// Graph types that can be persisted as a snapshot plus an event log
+pub trait LoggableGraph:
+    GraphViewOps + InternalAdditionOps + InternalPropertyAdditionOps + Into<MaterializedGraph>
+{
+    /// Convert a loaded snapshot back into this graph type
+    fn from_materialized(graph: MaterializedGraph) -> Option<Self>;
+
+    /// Access to deletions, fails if the graph type does not support deleting
+    fn deletions(&self) -> Result<&dyn InternalDeletionOps, GraphError>;
+}
+
+impl LoggableGraph for Graph {
+    fn from_materialized(graph: MaterializedGraph) -> Option<Self> {
+        graph.into_events()
+    }
+
+    fn deletions(&self) -> Result<&dyn InternalDeletionOps, GraphError> {
+        Err(GraphError::DeletionsNotSupported)
+    }
+}
+
+impl LoggableGraph for GraphWithDeletions {
+    fn from_materialized(graph: MaterializedGraph) -> Option<Self> {
+        graph.into_persistent()
+    }
+
+    fn deletions(&self) -> Result<&dyn InternalDeletionOps, GraphError> {
+        Ok(self)
+    }
+}
+
+struct LogWriter {
+    writer: BufWriter<File>,
+    /// length of the log including buffered events
+    len: u64,
+}
+
+impl LogWriter {
+    fn open(dir: &Path) -> Result<Self, GraphError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(dir.join(LOG_FILE))?;
+        let len = file.metadata()?.len();
+        Ok(Self {
+            writer: BufWriter::new(file),
+            len,
+        })
+    }
+}
+
+struct EventLog {
+    dir: PathBuf,
+    writer: Mutex<LogWriter>,
+}
+
+impl EventLog {
+    fn open(dir: &Path) -> Result<Self, GraphError> {
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            writer: Mutex::new(LogWriter::open(dir)?),
+        })
+    }
+
+    /// Append `event` to the log and then apply it with `mutate`.
+    ///
+    /// If `mutate` fails the event is removed from the log again. The log stays locked while
+    /// `mutate` runs, so events are logged in the order they are applied.
+    fn record<T>(
+        &self,
+        event: &GraphEvent,
+        mutate: impl FnOnce() -> Result<T, GraphError>,
+    ) -> Result<T, GraphError> {
+        let bytes = bincode::serialize(event)?;
+        let mut log = self.writer.lock();
+        let start = log.len;
+        let appended = log
+            .writer
+            .write_all(&(bytes.len() as u64).to_le_bytes())
+            .and_then(|_| log.writer.write_all(&bytes));
+        let result = appended.map_err(GraphError::from).and_then(|_| mutate());
+        match result {
+            Ok(value) => {
+                log.len = start + 8 + bytes.len() as u64;
+                Ok(value)
+            }
+            Err(error) => {
+                log.writer.flush()?;
+                log.writer.get_ref().set_len(start)?;
+                Err(error)
+            }
+        }
+    }
+
+    fn flush(&self) -> Result<(), GraphError> {
+        let mut log = self.writer.lock();
+        log.writer.flush()?;
+        log.writer.get_ref().sync_data()?;
+        Ok(())
+    }
+
+    /// Read all complete events from the log file, dropping a partially written record at the end
+    fn read(dir: &Path) -> Result<Vec<GraphEvent>, GraphError> {
+        let path = dir.join(LOG_FILE);
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => Err(e)?,
+        };
+        let mut reader = BufReader::new(file);
+        let mut events = vec![];
+        let mut valid_len = 0u64;
+        let mut len_bytes = [0u8; 8];
+        loop {
+            if !read_exact_or_eof(&mut reader, &mut len_bytes)? {
+                break;
+            }
+            let len = u64::from_le_bytes(len_bytes);
+            let mut buf = vec![0u8; len as usize];
+            if !read_exact_or_eof(&mut reader, &mut buf)? {
+                break;
+            }
+            events.push(bincode::deserialize(&buf)?);
+            valid_len += 8 + len;
+        }
+        let file = OpenOptions::new().write(true).open(&path)?;
+        if file.metadata()?.len() > valid_len {
+            file.set_len(valid_len)?;
+        }
+        Ok(events)
+    }
+}
+
+/// Bring the directory back to a consistent state after an interrupted [`LoggedGraph::compact`].
+///
+/// Once the old log is moved aside the new snapshot is complete and replaces the old snapshot
+/// and log, otherwise a leftover snapshot may be partially written and is discarded.
+fn recover_compaction(dir: &Path) -> Result<(), GraphError> {
+    let tmp_path = dir.join(SNAPSHOT_TMP_FILE);
+    let compacted_path = dir.join(COMPACTED_LOG_FILE);
+    if compacted_path.is_file() {
+        if tmp_path.is_file() {
+            std::fs::rename(&tmp_path, dir.join(SNAPSHOT_FILE))?;
+        }
+        std::fs::remove_file(&compacted_path)?;
+    } else if tmp_path.is_file() {
+        std::fs::remove_file(&tmp_path)?;
+    }
+    Ok(())
+}
+
+/// Returns `false` if the reader ran out of bytes before `buf` was filled
+fn read_exact_or_eof<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<bool, GraphError> {
+    match reader.read_exact(buf) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// A graph that persists its updates to an append-only event log on disk
+#[derive(Clone)]
+pub struct LoggedGraph<G> {
+    graph: G,
+    log: Arc<EventLog>,
+}
+
+impl<G> Deref for LoggedGraph<G> {
+    type Target = G;
+
+    fn deref(&self) -> &Self::Target {
+        &self.graph
+    }
+}
+
+impl<G: GraphViewOps> IntoDynamic for LoggedGraph<G> {
+    fn into_dynamic(self) -> DynamicGraph {
+        DynamicGraph::new(self)
+    }
+}
+
+impl<G: GraphViewOps> InheritViewOps for LoggedGraph<G> {}
+
+impl<G: LoggableGraph> LoggedGraph<G> {
+    /// Persist `graph` to the directory `path` and log all further updates to it.
+    ///
+    /// The directory is created if it does not exist. Any existing snapshot and event log
+    /// in the directory are overwritten.
+    pub fn create<P: AsRef<Path>>(graph: G, path: P) -> Result<Self, GraphError> {
+        let dir = path.as_ref();
+        std::fs::create_dir_all(dir)?;
+        graph.clone().into().save_to_file(dir.join(SNAPSHOT_FILE))?;
+        File::create(dir.join(LOG_FILE))?;
+        let log = Arc::new(EventLog::open(dir)?);
+        Ok(Self { graph, log })
+    }
+
+    /// Load a graph from the directory `path` by reading the snapshot and replaying the event log
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, GraphError> {
+        let dir = path.as_ref();
+        recover_compaction(dir)?;
+        let graph = MaterializedGraph::load_from_file(dir.join(SNAPSHOT_FILE))?;
+        let graph = G::from_materialized(graph).ok_or(GraphError::GraphLoadError)?;
+        let events = EventLog::read(dir)?;
+        let max_event_id = events.iter().filter_map(|e| e.event_id()).max();
+        for event in events {
+            event.apply(&graph)?;
+        }
+        // make sure new events are ordered after the replayed ones
+        if let Some(max_event_id) = max_event_id {
+            graph.set_next_event_id(max_event_id + 1);
+        }
+        let log = Arc::new(EventLog::open(dir)?);
+        Ok(Self { graph, log })
+    }
+
+    /// Write a new snapshot of the graph and start a new event log
+    pub fn compact(&self) -> Result<(), GraphError> {
+        let dir = &self.log.dir;
+        let mut log = self.log.writer.lock();
+        log.writer.flush()?;
+        let tmp_path = dir.join(SNAPSHOT_TMP_FILE);
+        self.graph.clone().into().save_to_file(&tmp_path)?;
+        File::open(&tmp_path)?.sync_all()?;
+        // moving the old log aside commits the compaction, if we crash after this point
+        // `open` finishes it instead of replaying the old log on top of the new snapshot
+        std::fs::rename(dir.join(LOG_FILE), dir.join(COMPACTED_LOG_FILE))?;
+        *log = LogWriter::open(dir)?;
+        recover_compaction(dir)
+    }
+
+    /// Flush buffered events to disk
+    pub fn flush(&self) -> Result<(), GraphError> {
+        self.log.flush()
+    }
+
+    /// The underlying graph
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    fn logged_vertex(&self, v: VID) -> LoggedVertex {
+        let id = self.graph.vertex_id(v);
+        let name = self.graph.vertex_name(v);
+        LoggedVertex {
+            id,
+            name: (name != id.to_string()).then_some(name),
+        }
+    }
+
+    fn logged_layer(&self, layer: usize) -> Option<ArcStr> {
+        (layer != 0).then(|| self.graph.get_layer_name(layer))
+    }
+
+    fn logged_vertex_props(&self, props: &[(usize, Prop)], is_static: bool) -> Vec<(ArcStr, Prop)> {
+        props
+            .iter()
+            .map(|(id, prop)| {
+                let name = self.graph.vertex_meta().get_prop_name(*id, is_static);
+                (name, prop.clone())
+            })
+            .collect()
+    }
+
+    fn logged_edge_props(&self, props: &[(usize, Prop)], is_static: bool) -> Vec<(ArcStr, Prop)> {
+        props
+            .iter()
+            .map(|(id, prop)| {
+                let name = self.graph.edge_meta().get_prop_name(*id, is_static);
+                (name, prop.clone())
+            })
+            .collect()
+    }
+
+    fn logged_graph_props(&self, props: &[(usize, Prop)], is_static: bool) -> Vec<(ArcStr, Prop)> {
+        let meta = self.graph.graph_meta();
+        props
+            .iter()
+            .map(|(id, prop)| {
+                let name = if is_static {
+                    meta.get_const_prop_name(*id)
+                } else {
+                    meta.get_temporal_name(*id)
+                };
+                (name, prop.clone())
+            })
+            .collect()
+    }
+
+    fn logged_edge(&self, eid: EID, layer: usize) -> (LoggedVertex, LoggedVertex, Option<ArcStr>) {
+        let edge = self.graph.core_edge(eid);
+        (
+            self.logged_vertex(edge.src()),
+            self.logged_vertex(edge.dst()),
+            self.logged_layer(layer),
+        )
+    }
+}
+
+impl<G: LoggableGraph> InternalAdditionOps for LoggedGraph<G> {
+    #[inline]
+    fn next_event_id(&self) -> usize {
+        self.graph.next_event_id()
+    }
+
+    #[inline]
+    fn set_next_event_id(&self, id: usize) {
+        self.graph.set_next_event_id(id)
+    }
+
+    #[inline]
+    fn resolve_layer(&self, layer: Option<&str>) -> usize {
+        self.graph.resolve_layer(layer)
+    }
+
+    fn resolve_vertex(&self, id: u64, name: Option<&str>) -> Result<VID, GraphError> {
+        if let Some(v) = self
+            .graph
+            .internal_vertex_ref(id.into(), &LayerIds::All, None)
+        {
+            return Ok(v);
+        }
+        // a concurrent update may log the same vertex, replaying it twice is harmless
+        let event = GraphEvent::ResolveVertex {
+            vertex: LoggedVertex {
+                id,
+                name: name.map(|name| name.to_owned()),
+            },
+        };
+        self.log
+            .record(&event, || self.graph.resolve_vertex(id, name))
+    }
+
+    #[inline]
+    fn resolve_vertex_type(&self, vertex_type: &str) -> usize {
+        self.graph.resolve_vertex_type(vertex_type)
+    }
+
+    #[inline]
+    fn resolve_graph_property(&self, prop: &str, is_static: bool) -> usize {
+        self.graph.resolve_graph_property(prop, is_static)
+    }
+
+    #[inline]
+    fn resolve_vertex_property(
+        &self,
+        prop: &str,
+        dtype: PropType,
+        is_static: bool,
+    ) -> Result<usize, GraphError> {
+        self.graph.resolve_vertex_property(prop, dtype, is_static)
+    }
+
+    #[inline]
+    fn resolve_edge_property(
+        &self,
+        prop: &str,
+        dtype: PropType,
+        is_static: bool,
+    ) -> Result<usize, GraphError> {
+        self.graph.resolve_edge_property(prop, dtype, is_static)
+    }
+
+    #[inline]
+    fn process_prop_value(&self, prop: Prop) -> Prop {
+        self.graph.process_prop_value(prop)
+    }
+
+    fn internal_add_vertex(
+        &self,
+        t: TimeIndexEntry,
+        v: VID,
+        props: Vec<(usize, Prop)>,
+    ) -> Result<(), GraphError> {
+        let event = GraphEvent::AddVertex {
+            t,
+            vertex: self.logged_vertex(v),
+            props: self.logged_vertex_props(&props, false),
+        };
+        self.log
+            .record(&event, || self.graph.internal_add_vertex(t, v, props))
+    }
+
+    fn internal_set_vertex_type(&self, v: VID, vertex_type: usize) -> Result<(), GraphError> {
+        let event = GraphEvent::SetVertexType {
+            vertex: self.logged_vertex(v),
+            vertex_type: self
+                .graph
+                .vertex_meta()
+                .vertex_type_meta()
+                .get_name(vertex_type),
+        };
+        self.log.record(&event, || {
+            self.graph.internal_set_vertex_type(v, vertex_type)
+        })
+    }
+
+    fn internal_add_edge(
+        &self,
+        t: TimeIndexEntry,
+        src: VID,
+        dst: VID,
+        props: Vec<(usize, Prop)>,
+        layer: usize,
+    ) -> Result<EID, GraphError> {
+        let event = GraphEvent::AddEdge {
+            t,
+            src: self.logged_vertex(src),
+            dst: self.logged_vertex(dst),
+            layer: self.logged_layer(layer),
+            props: self.logged_edge_props(&props, false),
+        };
+        self.log.record(&event, || {
+            self.graph.internal_add_edge(t, src, dst, props, layer)
+        })
+    }
+}
+
+impl<G: LoggableGraph + InternalDeletionOps> InternalDeletionOps for LoggedGraph<G> {
+    fn internal_delete_edge(
+        &self,
+        t: TimeIndexEntry,
+        src: VID,
+        dst: VID,
+        layer: usize,
+    ) -> Result<(), GraphError> {
+        let event = GraphEvent::DeleteEdge {
+            t,
+            src: self.logged_vertex(src),
+            dst: self.logged_vertex(dst),
+            layer: self.logged_layer(layer),
+        };
+        self.log.record(&event, || {
+            self.graph.internal_delete_edge(t, src, dst, layer)
+        })
+    }
+
+    fn internal_delete_vertex(&self, t: TimeIndexEntry, v: VID) -> Result<(), GraphError> {
+        let event = GraphEvent::DeleteVertex {
+            t,
+            vertex: self.logged_vertex(v),
+        };
+        self.log
+            .record(&event, || self.graph.internal_delete_vertex(t, v))
+    }
+}
+
+impl<G: LoggableGraph> InternalPropertyAdditionOps for LoggedGraph<G> {
+    fn internal_add_properties(
+        &self,
+        t: TimeIndexEntry,
+        props: Vec<(usize, Prop)>,
+    ) -> Result<(), GraphError> {
+        let event = GraphEvent::AddGraphProperties {
+            t,
+            props: self.logged_graph_props(&props, false),
+        };
+        self.log
+            .record(&event, || self.graph.internal_add_properties(t, props))
+    }
+
+    fn internal_add_static_properties(&self, props: Vec<(usize, Prop)>) -> Result<(), GraphError> {
+        let event = GraphEvent::AddConstantGraphProperties {
+            props: self.logged_graph_props(&props, true),
+        };
+        self.log
+            .record(&event, || self.graph.internal_add_static_properties(props))
+    }
+
+    fn internal_update_static_properties(
+        &self,
+        props: Vec<(usize, Prop)>,
+    ) -> Result<(), GraphError> {
+        let event = GraphEvent::UpdateConstantGraphProperties {
+            props: self.logged_graph_props(&props, true),
+        };
+        self.log.record(&event, || {
+            self.graph.internal_update_static_properties(props)
+        })
+    }
+
+    fn internal_add_constant_vertex_properties(
+        &self,
+        vid: VID,
+        props: Vec<(usize, Prop)>,
+    ) -> Result<(), GraphError> {
+        let event = GraphEvent::AddConstantVertexProperties {
+            vertex: self.logged_vertex(vid),
+            props: self.logged_vertex_props(&props, true),
+        };
+        self.log.record(&event, || {
+            self.graph
+                .internal_add_constant_vertex_properties(vid, props)
+        })
+    }
+
+    fn internal_update_constant_vertex_properties(
+        &self,
+        vid: VID,
+        props: Vec<(usize, Prop)>,
+    ) -> Result<(), GraphError> {
+        let event = GraphEvent::UpdateConstantVertexProperties {
+            vertex: self.logged_vertex(vid),
+            props: self.logged_vertex_props(&props, true),
+        };
+        self.log.record(&event, || {
+            self.graph
+                .internal_update_constant_vertex_properties(vid, props)
+        })
+    }
+
+    fn internal_add_constant_edge_properties(
+        &self,
+        eid: EID,
+        layer: usize,
+        props: Vec<(usize, Prop)>,
+    ) -> Result<(), GraphError> {
+        let (src, dst, logged_layer) = self.logged_edge(eid, layer);
+        let event = GraphEvent::AddConstantEdgeProperties {
+            src,
+            dst,
+            layer: logged_layer,
+            props: self.logged_edge_props(&props, true),
+        };
+        self.log.record(&event, || {
+            self.graph
+                .internal_add_constant_edge_properties(eid, layer, props)
+        })
+    }
+
+    fn internal_update_constant_edge_properties(
+        &self,
+        eid: EID,
+        layer: usize,
+        props: Vec<(usize, Prop)>,
+    ) -> Result<(), GraphError> {
+        let (src, dst, logged_layer) = self.logged_edge(eid, layer);
+        let event = GraphEvent::UpdateConstantEdgeProperties {
+            src,
+            dst,
+            layer: logged_layer,
+            props: self.logged_edge_props(&props, true),
+        };
+        self.log.record(&event, || {
+            self.graph
+                .internal_update_constant_edge_properties(eid, layer, props)
+        })
+    }
+}
+
+#[cfg(test)]
+mod logged_graph_test {
+    use super::*;
+    use crate::db::api::mutation::DeletionOps;
+    use tempdir::TempDir;
+
+    #[test]
+    fn test_reopen_replays_log() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = LoggedGraph::create(Graph::new(), dir.path()).unwrap();
+        g.add_vertex(0, "Ben", [("age", Prop::I64(3))]).unwrap();
+        g.add_edge(
+            1,
+            "Ben",
+            "Hamza",
+            [("weight", Prop::F64(1.5))],
+            Some("follows"),
+        )
+        .unwrap();
+        g.add_edge(2, 1, 2, NO_PROPS, None).unwrap();
+        g.add_properties(3, [("state", Prop::I64(1))]).unwrap();
+        g.add_constant_properties([("name", Prop::str("test"))])
+            .unwrap();
+        g.vertex("Ben")
+            .unwrap()
+            .add_constant_properties([("kind", Prop::str("person"))])
+            .unwrap();
+        g.edge("Ben", "Hamza")
+            .unwrap()
+            .add_constant_properties([("since", Prop::I64(2020))], Some("follows"))
+            .unwrap();
+        g.add_vertex_with_type(4, "Hamza", NO_PROPS, Some("person"))
+            .unwrap();
+        g.flush().unwrap();
+
+        let g2 = LoggedGraph::<Graph>::open(dir.path()).unwrap();
+        assert_eq!(g2.graph(), g.graph());
+        assert_eq!(g2.vertex("Hamza").unwrap().vertex_type().unwrap(), "person");
+        assert_eq!(
+            g2.edge("Ben", "Hamza")
+                .unwrap()
+                .layer("follows")
+                .unwrap()
+                .properties()
+                .get("since"),
+            Some(Prop::I64(2020))
+        );
+        assert_eq!(
+            g2.vertex("Ben").unwrap().properties().get("kind"),
+            Some(Prop::str("person"))
+        );
+        assert_eq!(g2.properties().get("name"), Some(Prop::str("test")));
+    }
+
+    #[test]
+    fn test_compact() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = LoggedGraph::create(Graph::new(), dir.path()).unwrap();
+        g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        g.compact().unwrap();
+        assert_eq!(
+            std::fs::metadata(dir.path().join(LOG_FILE)).unwrap().len(),
+            0
+        );
+        g.add_edge(2, 2, 3, NO_PROPS, None).unwrap();
+        g.flush().unwrap();
+
+        let g2 = LoggedGraph::<Graph>::open(dir.path()).unwrap();
+        assert_eq!(g2.graph(), g.graph());
+        assert_eq!(g2.count_edges(), 2);
+    }
+
+    #[test]
+    fn test_interrupted_compaction() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = LoggedGraph::create(Graph::new(), dir.path()).unwrap();
+        g.add_vertex(1, "Ben", NO_PROPS)
+            .unwrap()
+            .add_constant_properties([("kind", Prop::str("person"))])
+            .unwrap();
+        g.flush().unwrap();
+
+        // crash while writing the snapshot, the partial snapshot is ignored
+        std::fs::write(dir.path().join(SNAPSHOT_TMP_FILE), b"RAPH").unwrap();
+        let g2 = LoggedGraph::<Graph>::open(dir.path()).unwrap();
+        assert_eq!(g2.graph(), g.graph());
+        assert!(!dir.path().join(SNAPSHOT_TMP_FILE).exists());
+
+        // crash after the old log was moved aside, the new snapshot is used without the old log
+        MaterializedGraph::from(g.graph().clone())
+            .save_to_file(dir.path().join(SNAPSHOT_TMP_FILE))
+            .unwrap();
+        std::fs::rename(
+            dir.path().join(LOG_FILE),
+            dir.path().join(COMPACTED_LOG_FILE),
+        )
+        .unwrap();
+        assert!(is_logged_graph(dir.path()));
+        let g3 = LoggedGraph::<Graph>::open(dir.path()).unwrap();
+        assert_eq!(g3.graph(), g.graph());
+        assert!(!dir.path().join(COMPACTED_LOG_FILE).exists());
+        assert_eq!(
+            std::fs::metadata(dir.path().join(LOG_FILE)).unwrap().len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_truncated_log() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = LoggedGraph::create(Graph::new(), dir.path()).unwrap();
+        g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        g.add_edge(2, 2, 3, NO_PROPS, None).unwrap();
+        g.flush().unwrap();
+        drop(g);
+
+        let log_path = dir.path().join(LOG_FILE);
+        let len = std::fs::metadata(&log_path).unwrap().len();
+        let file = OpenOptions::new().write(true).open(&log_path).unwrap();
+        file.set_len(len - 3).unwrap();
+
+        let g2 = LoggedGraph::<Graph>::open(dir.path()).unwrap();
+        assert!(g2.has_edge(1, 2, Layer::All));
+        assert!(!g2.has_edge(2, 3, Layer::All));
+
+        // the partial record is dropped so new events are readable again
+        g2.add_edge(3, 3, 4, NO_PROPS, None).unwrap();
+        g2.flush().unwrap();
+        let g3 = LoggedGraph::<Graph>::open(dir.path()).unwrap();
+        assert_eq!(g3.graph(), g2.graph());
+    }
+
+    #[test]
+    fn test_deletions_are_logged() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = LoggedGraph::create(GraphWithDeletions::new(), dir.path()).unwrap();
+        g.add_edge(1, 1, 2, NO_PROPS, Some("a")).unwrap();
+        g.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+        g.delete_edge(3, 1, 2, Some("a")).unwrap();
+        g.delete_vertex(5, 3).unwrap();
+        g.flush().unwrap();
+
+        let g2 = LoggedGraph::<GraphWithDeletions>::open(dir.path()).unwrap();
+        assert_eq!(g2.graph(), g.graph());
+        assert!(!g2.window(4, 5).has_edge(1, 2, Layer::All));
+        assert!(!g2.window(6, 7).has_vertex(3));
+
+        assert!(LoggedGraph::<Graph>::open(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_failed_mutations_are_not_logged() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = LoggedGraph::create(Graph::new(), dir.path()).unwrap();
+        g.add_vertex(1, "Ben", NO_PROPS).unwrap();
+        let v = g.vertex("Ben").unwrap();
+        v.add_constant_properties([("kind", Prop::str("person"))])
+            .unwrap();
+        g.flush().unwrap();
+        let log_path = dir.path().join(LOG_FILE);
+        let len = std::fs::metadata(&log_path).unwrap().len();
+
+        assert!(v
+            .add_constant_properties([("kind", Prop::str("robot"))])
+            .is_err());
+        g.add_vertex_with_type(2, "Ben", NO_PROPS, Some("person"))
+            .unwrap();
+        assert!(g
+            .add_vertex_with_type(3, "Ben", NO_PROPS, Some("robot"))
+            .is_err());
+        g.flush().unwrap();
+        assert!(std::fs::metadata(&log_path).unwrap().len() > len);
+
+        let g2 = LoggedGraph::<Graph>::open(dir.path()).unwrap();
+        assert_eq!(g2.graph(), g.graph());
+        assert_eq!(g2.vertex("Ben").unwrap().vertex_type().unwrap(), "person");
+    }
+
+    #[test]
+    fn test_vertices_of_failed_mutations_are_logged() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = LoggedGraph::create(Graph::new(), dir.path()).unwrap();
+        g.add_edge(1, 1, 2, [("weight", Prop::I64(1))], None)
+            .unwrap();
+        // the vertices are created before the property type check fails
+        assert!(g
+            .add_edge(2, 3, 4, [("weight", Prop::str("heavy"))], None)
+            .is_err());
+        assert!(g.has_vertex(3));
+        g.flush().unwrap();
+
+        let g2 = LoggedGraph::<Graph>::open(dir.path()).unwrap();
+        assert_eq!(g2.graph(), g.graph());
+        assert!(g2.has_vertex(4));
+        assert!(!g2.has_edge(3, 4, Layer::All));
+    }
+
+    #[test]
+    fn test_event_ids_continue_after_replay() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = LoggedGraph::create(Graph::new(), dir.path()).unwrap();
+        g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        g.add_edge(1, 1, 2, [("weight", Prop::I64(1))], None)
+            .unwrap();
+        g.flush().unwrap();
+
+        let g2 = LoggedGraph::<Graph>::open(dir.path()).unwrap();
+        assert_eq!(g2.next_event_id(), 2);
+        g2.add_edge(1, 1, 2, [("weight", Prop::I64(2))], None)
+            .unwrap();
+        assert_eq!(
+            g2.edge(1, 2).unwrap().properties().get("weight"),
+            Some(Prop::I64(2))
+        );
+    }
+
+    #[test]
+    fn test_deletions_on_event_graph() {
+        let event = GraphEvent::DeleteVertex {
+            t: TimeIndexEntry(1, 0),
+            vertex: LoggedVertex { id: 1, name: None },
+        };
+        assert!(matches!(
+            event.apply(&Graph::new()),
+            Err(GraphError::DeletionsNotSupported)
+        ));
+    }
+}
@@ -0,0 +1,1700 @@
+//! Read-only, memory-mapped columnar graph storage.
+//!
+//! A [`MmapGraph`] stores a graph as flat columns in a directory, which are memory-mapped
+//! when the graph is opened. It implements the same view traits as the in-memory graph, so
+//! windows, layers, subgraphs and algorithms all work on it, and only the pages that are
+//! touched by a query are loaded, so graphs larger than memory can be traversed.
+//!
+//! Vertices are assigned dense ids in order of their global id and edges are sorted
+//! by source and destination, so the adjacency lists are stored in compressed sparse
+//! row form. Columns hold 8-byte little-endian values, blobs are stored back to back in
+//! a data file with a column of offsets into it:
+//!
+//! | file                 | content                                                  |
+//! |----------------------|----------------------------------------------------------|
+//! | `meta`               | property, layer and vertex type names and graph properties |
+//! | `vertex_ids`         | sorted global vertex ids                                 |
+//! | `vertex_types`       | type id of each vertex                                   |
+//! | `vertex_names`       | name of each vertex (blob)                               |
+//! | `vertex_time_offsets`| start of the timestamps of each vertex in `vertex_times`  |
+//! | `vertex_times`       | sorted timestamps for each vertex                        |
+//! | `vertex_data`        | deletions and properties of each vertex (blob)           |
+//! | `out_offsets`        | start of the out-edges of each vertex                    |
+//! | `edge_src`           | source of each edge                                      |
+//! | `edge_dst`           | destination of each edge                                 |
+//! | `edge_data`          | layers, updates, deletions and properties of each edge (blob) |
+//! | `in_offsets`         | start of the in-edges of each vertex                     |
+//! | `in_edges`           | edge ids sorted by destination and source                |
+//!
+//! Blobs are only decoded for the vertex or edge that is queried, and a bounded number of
+//! decoded edges is cached so that repeated queries for the same edge only decode it once.
+//! Graphs are written one vertex and one adjacency list at a time, so writing never holds
+//! more than the vertex ids and a single adjacency list in memory.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use raphtory::prelude::*;
+//! use raphtory::db::graph::mmap_graph::MmapGraph;
+//!
+//! let g = Graph::new();
+//! g.add_edge(1, 1, 2, [("weight", 1.0)], Some("friends")).unwrap();
+//!
+//! MmapGraph::write(&g, "path/to/graph").unwrap();
+//! let mg = MmapGraph::open("path/to/graph").unwrap();
+//! assert_eq!(mg.vertex(1).unwrap().out_degree(), 1);
+//! assert_eq!(mg.window(2, 3).count_edges(), 0);
+//! ```
+
+use crate::{
+    core::{
+        entities::{
+            edges::{
+                edge_ref::{Dir, EdgeRef},
+                edge_store::EdgeStore,
+            },
+            properties::{
+                graph_props::GraphProps,
+                props::{Meta, Props},
+                tprop::{LockedLayeredTProp, TProp},
+            },
+            vertices::{vertex::ArcEdge, vertex_ref::VertexRef, vertex_store::VertexStore},
+            LayerIds, EID, VID,
+        },
+        storage::{
+            locked_view::LockedView,
+            timeindex::{
+                AsTime, LayeredIndex, LockedLayeredIndex, TimeIndex, TimeIndexEntry, TimeIndexOps,
+            },
+            ArcEntry,
+        },
+        utils::errors::GraphError,
+        ArcStr, Direction,
+    },
+    db::{
+        api::{
+            properties::internal::{
+                ConstPropertiesOps, TemporalPropertiesOps, TemporalPropertyViewOps,
+            },
+            view::{
+                internal::{
+                    CoreGraphOps, EdgeFilter, EdgeFilterOps, GraphOps, InternalLayerOps,
+                    InternalMaterialize, MaterializedGraph, Static, TimeSemantics,
+                },
+                BoxedIter, GraphViewOps,
+            },
+        },
+        graph::graph::{Graph, InternalGraph},
+    },
+    prelude::{Layer, Prop},
+};
+use genawaiter::sync::GenBoxed;
+use itertools::Itertools;
+use memmap2::Mmap;
+use parking_lot::Mutex;
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    iter,
+    ops::Range,
+    path::Path,
+    sync::Arc,
+};
+
+const META: &str = "meta";
+const VERTEX_IDS: &str = "vertex_ids";
+const VERTEX_TYPES: &str = "vertex_types";
+const VERTEX_NAME_OFFSETS: &str = "vertex_name_offsets";
+const VERTEX_NAMES: &str = "vertex_names";
+const VERTEX_TIME_OFFSETS: &str = "vertex_time_offsets";
+const VERTEX_TIMES: &str = "vertex_times";
+const VERTEX_DATA_OFFSETS: &str = "vertex_data_offsets";
+const VERTEX_DATA: &str = "vertex_data";
+const OUT_OFFSETS: &str = "out_offsets";
+const EDGE_SRC: &str = "edge_src";
+const EDGE_DST: &str = "edge_dst";
+const EDGE_DATA_OFFSETS: &str = "edge_data_offsets";
+const EDGE_DATA: &str = "edge_data";
+const IN_OFFSETS: &str = "in_offsets";
+const IN_EDGES: &str = "in_edges";
+
+/// Number of decoded edges kept in memory
+const EDGE_CACHE_SLOTS: usize = 4096;
+
+fn map_file(dir: &Path, name: &str) -> Result<Option<Mmap>, GraphError> {
+    let file = File::open(dir.join(name))?;
+    // mapping a zero-length file fails so empty files have no mapping
+    if file.metadata()?.len() == 0 {
+        Ok(None)
+    } else {
+        // SAFETY: the files are only ever written by `MmapGraph::write` and are treated
+        // as immutable once written.
+        Ok(Some(unsafe { Mmap::map(&file)? }))
+    }
+}
+
+/// A memory-mapped column of 8-byte little-endian values
+struct Column {
+    mmap: Option<Mmap>,
+}
+
+impl Column {
+    fn open(dir: &Path, name: &str) -> Result<Self, GraphError> {
+        let mmap = map_file(dir, name)?;
+        if mmap.as_ref().map(|m| m.len() % 8 != 0).unwrap_or(false) {
+            return Err(GraphError::InvalidFileFormat);
+        }
+        Ok(Self { mmap })
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.mmap.as_ref().map(|m| m.len() / 8).unwrap_or(0)
+    }
+
+    #[inline]
+    fn get_u64(&self, i: usize) -> u64 {
+        let bytes = &self.mmap.as_ref().expect("index out of bounds")[i * 8..(i + 1) * 8];
+        u64::from_le_bytes(bytes.try_into().unwrap())
+    }
+
+    #[inline]
+    fn get_i64(&self, i: usize) -> i64 {
+        self.get_u64(i) as i64
+    }
+
+    #[inline]
+    fn get_usize(&self, i: usize) -> usize {
+        self.get_u64(i) as usize
+    }
+
+    /// The range stored between `offsets[i]` and `offsets[i + 1]`
+    #[inline]
+    fn offset_range(&self, i: usize) -> Range<usize> {
+        self.get_usize(i)..self.get_usize(i + 1)
+    }
+
+    /// First index in `range` for which `pred` is false, assuming the values are partitioned
+    fn partition_point<F: Fn(u64) -> bool>(&self, range: Range<usize>, pred: F) -> usize {
+        let (mut lo, mut hi) = (range.start, range.end);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(self.get_u64(mid)) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    /// Returns true if any of the sorted timestamps in `range` fall inside `w`
+    fn has_time_in(&self, range: Range<usize>, w: Range<i64>) -> bool {
+        let end = range.end;
+        let first = self.partition_point(range, |t| (t as i64) < w.start);
+        first < end && self.get_i64(first) < w.end
+    }
+
+    /// The sorted timestamps in `range` that fall inside `w`
+    fn times_in(&self, range: Range<usize>, w: Range<i64>) -> Range<usize> {
+        let start = self.partition_point(range.clone(), |t| (t as i64) < w.start);
+        let end = self.partition_point(start..range.end, |t| (t as i64) < w.end);
+        start..end
+    }
+}
+
+/// Memory-mapped variable length entries, stored back to back with a column of offsets
+struct Blobs {
+    offsets: Column,
+    data: Option<Mmap>,
+}
+
+impl Blobs {
+    fn open(dir: &Path, offsets_name: &str, data_name: &str) -> Result<Self, GraphError> {
+        let offsets = Column::open(dir, offsets_name)?;
+        let data = map_file(dir, data_name)?;
+        let data_len = data.as_ref().map(|d| d.len()).unwrap_or(0);
+        if offsets.len() == 0 || offsets.get_usize(offsets.len() - 1) != data_len {
+            return Err(GraphError::InvalidFileFormat);
+        }
+        Ok(Self { offsets, data })
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    #[inline]
+    fn get(&self, i: usize) -> &[u8] {
+        match &self.data {
+            Some(data) => &data[self.offsets.offset_range(i)],
+            None => &[],
+        }
+    }
+}
+
+/// Streams 8-byte little-endian values to a column file
+struct ColumnWriter {
+    writer: BufWriter<File>,
+    len: u64,
+}
+
+impl ColumnWriter {
+    fn create(dir: &Path, name: &str) -> Result<Self, GraphError> {
+        let writer = BufWriter::new(File::create(dir.join(name))?);
+        Ok(Self { writer, len: 0 })
+    }
+
+    fn push(&mut self, value: u64) -> Result<(), GraphError> {
+        self.writer.write_all(&value.to_le_bytes())?;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<(), GraphError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Streams variable length entries to a data file and their offsets to a column
+struct BlobWriter {
+    offsets: ColumnWriter,
+    data: BufWriter<File>,
+    len: u64,
+}
+
+impl BlobWriter {
+    fn create(dir: &Path, offsets_name: &str, data_name: &str) -> Result<Self, GraphError> {
+        let mut offsets = ColumnWriter::create(dir, offsets_name)?;
+        offsets.push(0)?;
+        let data = BufWriter::new(File::create(dir.join(data_name))?);
+        Ok(Self {
+            offsets,
+            data,
+            len: 0,
+        })
+    }
+
+    fn push(&mut self, bytes: &[u8]) -> Result<(), GraphError> {
+        self.data.write_all(bytes)?;
+        self.len += bytes.len() as u64;
+        self.offsets.push(self.len)
+    }
+
+    fn finish(mut self) -> Result<(), GraphError> {
+        self.data.flush()?;
+        self.offsets.finish()
+    }
+}
+
+/// Direct-mapped cache of decoded edges, each edge can only be stored in the slot `eid % slots`
+struct EdgeCache {
+    slots: Vec<Mutex<Option<ArcEntry<EdgeStore>>>>,
+}
+
+impl EdgeCache {
+    fn new(slots: usize) -> Self {
+        Self {
+            slots: (0..slots).map(|_| Mutex::new(None)).collect(),
+        }
+    }
+
+    fn get_or_decode(&self, e: EID, decode: impl FnOnce() -> EdgeStore) -> ArcEntry<EdgeStore> {
+        let mut slot = self.slots[e.0 % self.slots.len()].lock();
+        match slot.as_ref() {
+            Some(edge) if edge.e_id() == e => edge.clone(),
+            _ => {
+                let edge = ArcEntry::new(decode());
+                *slot = Some(edge.clone());
+                edge
+            }
+        }
+    }
+}
+
+/// The edge from `src` to `dst` in the outgoing adjacency lists
+fn find_edge(out_offsets: &Column, edge_dst: &Column, src: usize, dst: usize) -> Option<usize> {
+    let range = out_offsets.offset_range(src);
+    let end = range.end;
+    let e = edge_dst.partition_point(range, |d| (d as usize) < dst);
+    (e < end && edge_dst.get_usize(e) == dst).then_some(e)
+}
+
+/// Deletions and properties of a vertex, everything else is stored in columns
+#[derive(Serialize, Deserialize, Default)]
+struct VertexData {
+    deletions: TimeIndex<TimeIndexEntry>,
+    props: Option<Props>,
+}
+
+impl VertexData {
+    fn from_view<G: GraphViewOps>(graph: &G, v: VID) -> Result<Self, GraphError> {
+        let mut data = Self::default();
+        let w = view_window(graph);
+        for t in graph.core_vertex(v).deletions().range_iter(w.clone()) {
+            data.deletions.insert(*t);
+        }
+        for id in graph.temporal_vertex_prop_ids(v) {
+            let Some(tprop) = graph.temporal_vertex_prop(v, id) else {
+                continue;
+            };
+            let props = data.props.get_or_insert_with(Props::new);
+            for (t, prop) in tprop.iter_window(TimeIndexEntry::range(w.clone())) {
+                props.add_prop(t, id, prop)?;
+            }
+        }
+        for id in graph.constant_vertex_prop_ids(v) {
+            if let Some(prop) = graph.constant_vertex_prop(v, id) {
+                let props = data.props.get_or_insert_with(Props::new);
+                props.update_constant_prop(id, prop)?;
+            }
+        }
+        Ok(data)
+    }
+}
+
+/// The time range of `graph`, updates outside of it are not written
+fn view_window<G: GraphViewOps>(graph: &G) -> Range<i64> {
+    graph.view_start().unwrap_or(i64::MIN)..graph.view_end().unwrap_or(i64::MAX)
+}
+
+/// Collect the layers, updates, deletions and properties of the edge `e` of `graph`
+fn edge_store<G: GraphViewOps>(
+    graph: &G,
+    e: EdgeRef,
+    eid: usize,
+    src: usize,
+    dst: usize,
+) -> Result<EdgeStore, GraphError> {
+    let mut store = EdgeStore::new(VID(src), VID(dst));
+    store.eid = EID(eid);
+    let core = graph.core_edge(e.pid());
+    let w = view_window(graph);
+    for el in graph.edge_layers(e, graph.layer_ids()) {
+        let layer = *el.layer().expect("exploded layers");
+        let layer_ids = LayerIds::One(layer);
+        let prop_ids = graph
+            .temporal_edge_prop_ids(e, layer_ids.clone())
+            .collect_vec();
+        for ee in graph.edge_exploded(e, layer_ids.clone()) {
+            let t = ee.time().expect("exploded edge");
+            store.additions_mut(layer).insert(t);
+            for &id in prop_ids.iter() {
+                if let Some((_, prop)) = graph
+                    .temporal_edge_prop_vec(ee, id, layer_ids.clone())
+                    .pop()
+                {
+                    store.layer_mut(layer).add_prop(t, id, prop)?;
+                }
+            }
+        }
+        if let Some(deletions) = core.deletions().get(layer) {
+            for t in deletions.range_iter(w.clone()) {
+                store.deletions_mut(layer).insert(*t);
+            }
+        }
+        for id in graph.const_edge_prop_ids(e, layer_ids.clone()) {
+            if let Some(prop) = graph.get_const_edge_prop(e, id, layer_ids.clone()) {
+                store.layer_mut(layer).update_constant_prop(id, prop)?;
+            }
+        }
+    }
+    Ok(store)
+}
+
+struct MmapStorage {
+    vertex_ids: Column,
+    vertex_types: Column,
+    vertex_names: Blobs,
+    vertex_time_offsets: Column,
+    vertex_times: Column,
+    vertex_data: Blobs,
+    out_offsets: Column,
+    edge_src: Column,
+    edge_dst: Column,
+    edge_data: Blobs,
+    in_offsets: Column,
+    in_edges: Column,
+    edge_cache: EdgeCache,
+    vertex_meta: Arc<Meta>,
+    edge_meta: Arc<Meta>,
+    graph_props: GraphProps,
+    earliest_time: Option<i64>,
+    latest_time: Option<i64>,
+}
+
+/// Immutable graph backed by memory-mapped columnar files
+#[derive(Clone)]
+pub struct MmapGraph(Arc<MmapStorage>);
+
+impl Static for MmapGraph {}
+
+impl MmapGraph {
+    /// Write `graph` to the directory `path`
+    ///
+    /// Only graphs with event semantics can be written, views of a [`GraphWithDeletions`]
+    /// are rejected with [`GraphError::MmapDeletionsNotSupported`].
+    ///
+    /// [`GraphWithDeletions`]: crate::db::graph::views::deletion_graph::GraphWithDeletions
+    pub fn write<G: GraphViewOps, P: AsRef<Path>>(graph: &G, path: P) -> Result<(), GraphError> {
+        if graph.include_deletions() {
+            return Err(GraphError::MmapDeletionsNotSupported);
+        }
+        let dir = path.as_ref();
+        std::fs::create_dir_all(dir)?;
+        let layer_ids = graph.layer_ids();
+        let filter = graph.edge_filter();
+
+        let mut vertices: Vec<(u64, VID)> = graph
+            .vertex_refs(layer_ids.clone(), filter)
+            .map(|v| (graph.vertex_id(v), v))
+            .collect();
+        vertices.sort_unstable();
+        let dense: FxHashMap<VID, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, (_, v))| (*v, i))
+            .collect();
+
+        let mut ids = ColumnWriter::create(dir, VERTEX_IDS)?;
+        let mut types = ColumnWriter::create(dir, VERTEX_TYPES)?;
+        let mut names = BlobWriter::create(dir, VERTEX_NAME_OFFSETS, VERTEX_NAMES)?;
+        let mut time_offsets = ColumnWriter::create(dir, VERTEX_TIME_OFFSETS)?;
+        let mut times = ColumnWriter::create(dir, VERTEX_TIMES)?;
+        let mut data = BlobWriter::create(dir, VERTEX_DATA_OFFSETS, VERTEX_DATA)?;
+        let mut earliest_time: Option<i64> = None;
+        let mut latest_time: Option<i64> = None;
+        time_offsets.push(0)?;
+        for &(id, v) in vertices.iter() {
+            ids.push(id)?;
+            types.push(graph.vertex_type_id(v) as u64)?;
+            names.push(graph.vertex_name(v).as_bytes())?;
+            let history = graph.vertex_history(v);
+            if let (Some(first), Some(last)) = (history.first(), history.last()) {
+                earliest_time = Some(earliest_time.map_or(*first, |t| t.min(*first)));
+                latest_time = Some(latest_time.map_or(*last, |t| t.max(*last)));
+            }
+            for t in history {
+                times.push(t as u64)?;
+            }
+            time_offsets.push(times.len)?;
+            data.push(&bincode::serialize(&VertexData::from_view(graph, v)?)?)?;
+        }
+        ids.finish()?;
+        types.finish()?;
+        names.finish()?;
+        time_offsets.finish()?;
+        times.finish()?;
+        data.finish()?;
+
+        let mut out_offsets = ColumnWriter::create(dir, OUT_OFFSETS)?;
+        let mut edge_src = ColumnWriter::create(dir, EDGE_SRC)?;
+        let mut edge_dst = ColumnWriter::create(dir, EDGE_DST)?;
+        let mut edge_data = BlobWriter::create(dir, EDGE_DATA_OFFSETS, EDGE_DATA)?;
+        out_offsets.push(0)?;
+        for (src, &(_, v)) in vertices.iter().enumerate() {
+            let mut out: Vec<(usize, EdgeRef)> = graph
+                .vertex_edges(v, Direction::OUT, layer_ids.clone(), filter)
+                .filter_map(|e| dense.get(&e.dst()).map(|&dst| (dst, e)))
+                .collect();
+            out.sort_unstable_by_key(|(dst, _)| *dst);
+            for (dst, e) in out {
+                let store = edge_store(graph, e, edge_src.len as usize, src, dst)?;
+                edge_src.push(src as u64)?;
+                edge_dst.push(dst as u64)?;
+                edge_data.push(&bincode::serialize(&store)?)?;
+            }
+            out_offsets.push(edge_src.len)?;
+        }
+        out_offsets.finish()?;
+        edge_src.finish()?;
+        edge_dst.finish()?;
+        edge_data.finish()?;
+
+        // the ids of the incoming edges are looked up in the outgoing adjacency lists
+        let written_offsets = Column::open(dir, OUT_OFFSETS)?;
+        let written_dst = Column::open(dir, EDGE_DST)?;
+        let mut in_offsets = ColumnWriter::create(dir, IN_OFFSETS)?;
+        let mut in_edges = ColumnWriter::create(dir, IN_EDGES)?;
+        in_offsets.push(0)?;
+        for (dst, &(_, v)) in vertices.iter().enumerate() {
+            let mut sources: Vec<usize> = graph
+                .vertex_edges(v, Direction::IN, layer_ids.clone(), filter)
+                .filter_map(|e| dense.get(&e.src()).copied())
+                .collect();
+            sources.sort_unstable();
+            for src in sources {
+                let e = find_edge(&written_offsets, &written_dst, src, dst)
+                    .expect("incoming edge was written as an outgoing edge");
+                in_edges.push(e as u64)?;
+            }
+            in_offsets.push(in_edges.len)?;
+        }
+        in_offsets.finish()?;
+        in_edges.finish()?;
+
+        let mut meta = BufWriter::new(File::create(dir.join(META))?);
+        bincode::serialize_into(
+            &mut meta,
+            &(
+                graph.vertex_meta(),
+                graph.edge_meta(),
+                graph.graph_meta(),
+                earliest_time,
+                latest_time,
+            ),
+        )?;
+        meta.flush()?;
+        Ok(())
+    }
+
+    /// Open a graph previously written with [`MmapGraph::write`]
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, GraphError> {
+        let dir = path.as_ref();
+        let (vertex_meta, edge_meta, graph_props, earliest_time, latest_time): (
+            Meta,
+            Meta,
+            GraphProps,
+            Option<i64>,
+            Option<i64>,
+        ) = bincode::deserialize_from(BufReader::new(File::open(dir.join(META))?))?;
+        let storage = MmapStorage {
+            vertex_ids: Column::open(dir, VERTEX_IDS)?,
+            vertex_types: Column::open(dir, VERTEX_TYPES)?,
+            vertex_names: Blobs::open(dir, VERTEX_NAME_OFFSETS, VERTEX_NAMES)?,
+            vertex_time_offsets: Column::open(dir, VERTEX_TIME_OFFSETS)?,
+            vertex_times: Column::open(dir, VERTEX_TIMES)?,
+            vertex_data: Blobs::open(dir, VERTEX_DATA_OFFSETS, VERTEX_DATA)?,
+            out_offsets: Column::open(dir, OUT_OFFSETS)?,
+            edge_src: Column::open(dir, EDGE_SRC)?,
+            edge_dst: Column::open(dir, EDGE_DST)?,
+            edge_data: Blobs::open(dir, EDGE_DATA_OFFSETS, EDGE_DATA)?,
+            in_offsets: Column::open(dir, IN_OFFSETS)?,
+            in_edges: Column::open(dir, IN_EDGES)?,
+            edge_cache: EdgeCache::new(EDGE_CACHE_SLOTS),
+            vertex_meta: Arc::new(vertex_meta),
+            edge_meta: Arc::new(edge_meta),
+            graph_props,
+            earliest_time,
+            latest_time,
+        };
+        let n = storage.vertex_ids.len();
+        let m = storage.edge_src.len();
+        let consistent = storage.vertex_types.len() == n
+            && storage.vertex_names.len() == n
+            && storage.vertex_data.len() == n
+            && storage.vertex_time_offsets.len() == n + 1
+            && storage.out_offsets.len() == n + 1
+            && storage.in_offsets.len() == n + 1
+            && storage.edge_dst.len() == m
+            && storage.edge_data.len() == m
+            && storage.in_edges.len() == m
+            && storage.vertex_time_offsets.get_usize(n) == storage.vertex_times.len()
+            && storage.out_offsets.get_usize(n) == m
+            && storage.in_offsets.get_usize(n) == m;
+        if !consistent {
+            return Err(GraphError::InvalidFileFormat);
+        }
+        Ok(Self(Arc::new(storage)))
+    }
+
+    #[inline]
+    fn num_edges(&self) -> usize {
+        self.0.edge_src.len()
+    }
+
+    #[inline]
+    fn src(&self, e: EID) -> VID {
+        VID(self.0.edge_src.get_usize(e.0))
+    }
+
+    #[inline]
+    fn dst(&self, e: EID) -> VID {
+        VID(self.0.edge_dst.get_usize(e.0))
+    }
+
+    /// Find the internal id of a vertex from its global id
+    fn find_vertex(&self, id: u64) -> Option<VID> {
+        let n = self.0.vertex_ids.len();
+        let vid = self.0.vertex_ids.partition_point(0..n, |v| v < id);
+        (vid < n && self.0.vertex_ids.get_u64(vid) == id).then_some(VID(vid))
+    }
+
+    /// Position of the timestamps of `v` in `vertex_times`
+    #[inline]
+    fn time_range(&self, v: VID) -> Range<usize> {
+        self.0.vertex_time_offsets.offset_range(v.0)
+    }
+
+    fn vertex_data(&self, v: VID) -> VertexData {
+        bincode::deserialize(self.0.vertex_data.get(v.0)).expect("valid vertex data")
+    }
+
+    fn edge_store(&self, e: EID) -> ArcEntry<EdgeStore> {
+        self.0.edge_cache.get_or_decode(e, || {
+            bincode::deserialize(self.0.edge_data.get(e.0)).expect("valid edge data")
+        })
+    }
+
+    /// Returns true if the edge is in `layers` and passes `filter`
+    fn include_edge(&self, e: EID, layers: &LayerIds, filter: Option<&EdgeFilter>) -> bool {
+        match (filter, layers) {
+            (None, LayerIds::All) => true,
+            (None, layers) => self.edge_store(e).has_layer(layers),
+            (Some(filter), layers) => filter(&self.edge_store(e), layers),
+        }
+    }
+
+    fn edge_deletions(
+        &self,
+        e: EdgeRef,
+        layer_ids: LayerIds,
+    ) -> LockedLayeredIndex<'_, TimeIndexEntry> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let edge = self.edge_store(e.pid());
+        LayeredIndex::new(layer_ids, LockedView::Owned(edge.deletions().clone()))
+    }
+}
+
+impl CoreGraphOps for MmapGraph {
+    #[inline]
+    fn unfiltered_num_vertices(&self) -> usize {
+        self.0.vertex_ids.len()
+    }
+
+    #[inline]
+    fn vertex_meta(&self) -> &Meta {
+        &self.0.vertex_meta
+    }
+
+    #[inline]
+    fn edge_meta(&self) -> &Meta {
+        &self.0.edge_meta
+    }
+
+    #[inline]
+    fn graph_meta(&self) -> &GraphProps {
+        &self.0.graph_props
+    }
+
+    #[inline]
+    fn get_layer_name(&self, layer_id: usize) -> ArcStr {
+        self.0.edge_meta.layer_meta().get_name(layer_id)
+    }
+
+    #[inline]
+    fn get_layer_id(&self, name: &str) -> Option<usize> {
+        self.0.edge_meta.get_layer_id(name)
+    }
+
+    fn get_layer_names_from_ids(&self, layer_ids: LayerIds) -> BoxedIter<ArcStr> {
+        match layer_ids {
+            LayerIds::None => Box::new(iter::empty()),
+            LayerIds::All => Box::new(self.0.edge_meta.layer_meta().get_keys().into_iter()),
+            LayerIds::One(id) => Box::new(iter::once(self.get_layer_name(id))),
+            LayerIds::Multiple(ids) => {
+                let keys = self.0.edge_meta.layer_meta().get_keys();
+                Box::new((0..ids.len()).map(move |index| keys[ids[index]].clone()))
+            }
+        }
+    }
+
+    #[inline]
+    fn vertex_id(&self, v: VID) -> u64 {
+        self.0.vertex_ids.get_u64(v.0)
+    }
+
+    #[inline]
+    fn vertex_name(&self, v: VID) -> String {
+        String::from_utf8_lossy(self.0.vertex_names.get(v.0)).into_owned()
+    }
+
+    #[inline]
+    fn vertex_type_id(&self, v: VID) -> usize {
+        self.0.vertex_types.get_usize(v.0)
+    }
+
+    fn edge_additions(
+        &self,
+        eref: EdgeRef,
+        layer_ids: LayerIds,
+    ) -> LockedLayeredIndex<'_, TimeIndexEntry> {
+        let layer_ids = layer_ids.constrain_from_edge(eref);
+        let edge = self.edge_store(eref.pid());
+        LayeredIndex::new(layer_ids, LockedView::Owned(edge.additions().clone()))
+    }
+
+    fn vertex_additions(&self, v: VID) -> LockedView<'_, TimeIndex<i64>> {
+        let mut additions = TimeIndex::Empty;
+        for t in self.vertex_history(v) {
+            additions.insert(t);
+        }
+        LockedView::Owned(additions)
+    }
+
+    #[inline]
+    fn internalise_vertex(&self, v: VertexRef) -> Option<VID> {
+        match v {
+            VertexRef::Internal(l) => (l.0 < self.unfiltered_num_vertices()).then_some(l),
+            VertexRef::External(id) => self.find_vertex(id),
+        }
+    }
+
+    #[inline]
+    fn internalise_vertex_unchecked(&self, v: VertexRef) -> VID {
+        match v {
+            VertexRef::Internal(l) => l,
+            VertexRef::External(id) => self.find_vertex(id).unwrap(),
+        }
+    }
+
+    #[inline]
+    fn constant_prop(&self, id: usize) -> Option<Prop> {
+        self.0.graph_props.get_constant(id)
+    }
+
+    #[inline]
+    fn temporal_prop(&self, id: usize) -> Option<LockedView<'_, TProp>> {
+        self.0.graph_props.get_temporal_prop(id)
+    }
+
+    fn constant_vertex_prop(&self, v: VID, id: usize) -> Option<Prop> {
+        self.vertex_data(v)
+            .props
+            .and_then(|props| props.const_prop(id).cloned())
+    }
+
+    fn constant_vertex_prop_ids(&self, v: VID) -> Box<dyn Iterator<Item = usize> + '_> {
+        let ids = self
+            .vertex_data(v)
+            .props
+            .map(|props| props.const_prop_ids().collect_vec())
+            .unwrap_or_default();
+        Box::new(ids.into_iter())
+    }
+
+    fn temporal_vertex_prop(&self, v: VID, id: usize) -> Option<LockedView<'_, TProp>> {
+        self.vertex_data(v)
+            .props
+            .and_then(|props| props.temporal_prop(id).cloned())
+            .map(LockedView::Owned)
+    }
+
+    fn temporal_vertex_prop_ids(&self, v: VID) -> Box<dyn Iterator<Item = usize> + '_> {
+        let ids = self
+            .vertex_data(v)
+            .props
+            .map(|props| props.temporal_prop_ids().collect_vec())
+            .unwrap_or_default();
+        Box::new(ids.into_iter())
+    }
+
+    fn get_const_edge_prop(&self, e: EdgeRef, id: usize, layer_ids: LayerIds) -> Option<Prop> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let edge = self.edge_store(e.pid());
+        match layer_ids {
+            LayerIds::None => None,
+            LayerIds::One(layer) => edge.layer(layer).and_then(|l| l.const_prop(id).cloned()),
+            LayerIds::All if self.0.edge_meta.layer_meta().len() == 1 => edge
+                .layer_iter()
+                .next()
+                .and_then(|l| l.const_prop(id).cloned()),
+            layer_ids => {
+                let prop_map: HashMap<_, _> = edge
+                    .layer_iter()
+                    .enumerate()
+                    .filter(|(layer, _)| layer_ids.contains(layer))
+                    .flat_map(|(layer, l)| {
+                        l.const_prop(id)
+                            .map(|p| (self.get_layer_name(layer), p.clone()))
+                    })
+                    .collect();
+                (!prop_map.is_empty()).then(|| prop_map.into())
+            }
+        }
+    }
+
+    fn const_edge_prop_ids(
+        &self,
+        e: EdgeRef,
+        layer_ids: LayerIds,
+    ) -> Box<dyn Iterator<Item = usize> + '_> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let edge = self.edge_store(e.pid());
+        let ids = edge
+            .layer_iter()
+            .enumerate()
+            .filter(|(layer, _)| layer_ids.contains(layer))
+            .map(|(_, l)| l.const_prop_ids())
+            .kmerge()
+            .dedup()
+            .collect_vec();
+        Box::new(ids.into_iter())
+    }
+
+    fn temporal_edge_prop(
+        &self,
+        e: EdgeRef,
+        id: usize,
+        layer_ids: LayerIds,
+    ) -> Option<LockedLayeredTProp<'_>> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let edge = self.edge_store(e.pid());
+        let props = edge
+            .layer_ids_iter()
+            .filter(|layer| layer_ids.contains(layer))
+            .filter_map(|layer| edge.temporal_prop_layer(layer, id))
+            .map(|prop| LockedView::Owned(prop.clone()))
+            .collect_vec();
+        (!props.is_empty()).then(|| LockedLayeredTProp::new(props))
+    }
+
+    fn temporal_edge_prop_ids(
+        &self,
+        e: EdgeRef,
+        layer_ids: LayerIds,
+    ) -> Box<dyn Iterator<Item = usize> + '_> {
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let edge = self.edge_store(e.pid());
+        let ids = edge
+            .layer_ids_iter()
+            .filter(|layer| layer_ids.contains(layer))
+            .map(|layer| edge.temp_prop_ids(Some(layer)))
+            .kmerge()
+            .dedup()
+            .collect_vec();
+        Box::new(ids.into_iter())
+    }
+
+    fn core_edges(&self) -> Box<dyn Iterator<Item = ArcEntry<EdgeStore>>> {
+        let g = self.clone();
+        Box::new((0..self.num_edges()).map(move |e| g.core_edge(EID(e))))
+    }
+
+    fn core_edge(&self, eid: EID) -> ArcEntry<EdgeStore> {
+        self.edge_store(eid)
+    }
+
+    fn core_vertices(&self) -> Box<dyn Iterator<Item = ArcEntry<VertexStore>>> {
+        let g = self.clone();
+        Box::new((0..self.unfiltered_num_vertices()).map(move |v| g.core_vertex(VID(v))))
+    }
+
+    fn core_vertex(&self, vid: VID) -> ArcEntry<VertexStore> {
+        let data = self.vertex_data(vid);
+        let mut vertex = VertexStore::empty(self.vertex_id(vid), Some(self.vertex_name(vid)));
+        vertex.vid = vid;
+        vertex.vertex_type = self.vertex_type_id(vid);
+        vertex.props = data.props;
+        for t in self.vertex_history(vid) {
+            vertex.update_time(t.into());
+        }
+        for t in data.deletions.iter() {
+            vertex.delete(*t);
+        }
+        for e in self.vertex_edges(vid, Direction::BOTH, LayerIds::All, None) {
+            for layer in self.edge_store(e.pid()).layer_ids_iter() {
+                match e.dir() {
+                    Dir::Out => vertex.add_edge(e.dst(), Direction::OUT, layer, e.pid()),
+                    Dir::Into => vertex.add_edge(e.src(), Direction::IN, layer, e.pid()),
+                }
+            }
+        }
+        ArcEntry::new(vertex)
+    }
+}
+
+impl GraphOps for MmapGraph {
+    fn internal_vertex_ref(
+        &self,
+        v: VertexRef,
+        _layer_ids: &LayerIds,
+        _filter: Option<&EdgeFilter>,
+    ) -> Option<VID> {
+        self.internalise_vertex(v)
+    }
+
+    fn find_edge_id(
+        &self,
+        e_id: EID,
+        layer_ids: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Option<EdgeRef> {
+        (e_id.0 < self.num_edges() && self.include_edge(e_id, layer_ids, filter))
+            .then(|| EdgeRef::new_outgoing(e_id, self.src(e_id), self.dst(e_id)))
+    }
+
+    fn vertices_len(&self, _layer_ids: LayerIds, _filter: Option<&EdgeFilter>) -> usize {
+        self.unfiltered_num_vertices()
+    }
+
+    fn edges_len(&self, layers: LayerIds, filter: Option<&EdgeFilter>) -> usize {
+        match (&layers, filter) {
+            (LayerIds::All, None) => self.num_edges(),
+            _ => (0..self.num_edges())
+                .into_par_iter()
+                .filter(|&e| self.include_edge(EID(e), &layers, filter))
+                .count(),
+        }
+    }
+
+    fn degree(
+        &self,
+        v: VID,
+        d: Direction,
+        layers: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> usize {
+        match (d, layers, filter) {
+            (Direction::OUT, LayerIds::All, None) => self.0.out_offsets.offset_range(v.0).len(),
+            (Direction::IN, LayerIds::All, None) => self.0.in_offsets.offset_range(v.0).len(),
+            _ => self
+                .vertex_edges(v, d, layers.clone(), filter)
+                .dedup_by(|e1, e2| e1.remote() == e2.remote())
+                .count(),
+        }
+    }
+
+    fn vertex_refs(
+        &self,
+        _layers: LayerIds,
+        _filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = VID> + Send> {
+        Box::new((0..self.unfiltered_num_vertices()).map(VID))
+    }
+
+    fn edge_ref(
+        &self,
+        src: VID,
+        dst: VID,
+        layer: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Option<EdgeRef> {
+        find_edge(&self.0.out_offsets, &self.0.edge_dst, src.0, dst.0)
+            .map(EID)
+            .filter(|&e| self.include_edge(e, layer, filter))
+            .map(|e| EdgeRef::new_outgoing(e, src, dst))
+    }
+
+    fn edge_refs(
+        &self,
+        layers: LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = EdgeRef> + Send> {
+        let g = self.clone();
+        let filter = filter.cloned();
+        let graph = self.clone();
+        Box::new(
+            (0..self.num_edges())
+                .map(EID)
+                .filter(move |&e| graph.include_edge(e, &layers, filter.as_ref()))
+                .map(move |e| EdgeRef::new_outgoing(e, g.src(e), g.dst(e))),
+        )
+    }
+
+    fn vertex_edges(
+        &self,
+        v: VID,
+        d: Direction,
+        layers: LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = EdgeRef> + Send> {
+        let g = self.clone();
+        match d {
+            Direction::OUT => {
+                let filter = filter.cloned();
+                Box::new(self.0.out_offsets.offset_range(v.0).filter_map(move |e| {
+                    let e = EID(e);
+                    g.include_edge(e, &layers, filter.as_ref())
+                        .then(|| EdgeRef::new_outgoing(e, v, g.dst(e)))
+                }))
+            }
+            Direction::IN => {
+                let filter = filter.cloned();
+                Box::new(self.0.in_offsets.offset_range(v.0).filter_map(move |i| {
+                    let e = EID(g.0.in_edges.get_usize(i));
+                    g.include_edge(e, &layers, filter.as_ref())
+                        .then(|| EdgeRef::new_incoming(e, g.src(e), v))
+                }))
+            }
+            Direction::BOTH => Box::new(
+                self.vertex_edges(v, Direction::IN, layers.clone(), filter)
+                    .merge(self.vertex_edges(v, Direction::OUT, layers, filter)),
+            ),
+        }
+    }
+
+    fn neighbours(
+        &self,
+        v: VID,
+        d: Direction,
+        layers: LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = VID> + Send> {
+        let iter = self.vertex_edges(v, d, layers, filter).map(|e| e.remote());
+        if matches!(d, Direction::BOTH) {
+            Box::new(iter.dedup())
+        } else {
+            Box::new(iter)
+        }
+    }
+}
+
+impl EdgeFilterOps for MmapGraph {
+    #[inline]
+    fn edge_filter(&self) -> Option<&EdgeFilter> {
+        None
+    }
+}
+
+impl InternalLayerOps for MmapGraph {
+    fn layer_ids(&self) -> LayerIds {
+        LayerIds::All
+    }
+
+    fn layer_ids_from_names(&self, key: Layer) -> LayerIds {
+        match key {
+            Layer::All => LayerIds::All,
+            Layer::Default => LayerIds::One(0),
+            Layer::One(name) => match self.get_layer_id(&name) {
+                Some(id) => LayerIds::One(id),
+                None => LayerIds::None,
+            },
+            Layer::Multiple(names) => {
+                let mut ids = names
+                    .iter()
+                    .filter_map(|name| self.get_layer_id(name))
+                    .collect_vec();
+                ids.sort_unstable();
+                ids.dedup();
+                match ids.len() {
+                    0 => LayerIds::None,
+                    1 => LayerIds::One(ids[0]),
+                    n if n == self.0.edge_meta.layer_meta().len() => LayerIds::All,
+                    _ => LayerIds::Multiple(ids.into()),
+                }
+            }
+        }
+    }
+
+    fn edge_layer_ids(&self, e: &EdgeStore) -> LayerIds {
+        e.layer_ids()
+    }
+}
+
+impl TimeSemantics for MmapGraph {
+    fn vertex_earliest_time(&self, v: VID) -> Option<i64> {
+        let range = self.time_range(v);
+        (!range.is_empty()).then(|| self.0.vertex_times.get_i64(range.start))
+    }
+
+    fn vertex_latest_time(&self, v: VID) -> Option<i64> {
+        let range = self.time_range(v);
+        (!range.is_empty()).then(|| self.0.vertex_times.get_i64(range.end - 1))
+    }
+
+    fn earliest_time_global(&self) -> Option<i64> {
+        self.0.earliest_time
+    }
+
+    fn latest_time_global(&self) -> Option<i64> {
+        self.0.latest_time
+    }
+
+    fn earliest_time_window(&self, start: i64, end: i64) -> Option<i64> {
+        (0..self.unfiltered_num_vertices())
+            .into_par_iter()
+            .filter_map(|v| self.vertex_earliest_time_window(VID(v), start, end))
+            .min()
+    }
+
+    fn latest_time_window(&self, start: i64, end: i64) -> Option<i64> {
+        (0..self.unfiltered_num_vertices())
+            .into_par_iter()
+            .filter_map(|v| self.vertex_latest_time_window(VID(v), start, end))
+            .max()
+    }
+
+    fn vertex_earliest_time_window(&self, v: VID, start: i64, end: i64) -> Option<i64> {
+        let range = self.0.vertex_times.times_in(self.time_range(v), start..end);
+        (!range.is_empty()).then(|| self.0.vertex_times.get_i64(range.start))
+    }
+
+    fn vertex_latest_time_window(&self, v: VID, start: i64, end: i64) -> Option<i64> {
+        let range = self.0.vertex_times.times_in(self.time_range(v), start..end);
+        (!range.is_empty()).then(|| self.0.vertex_times.get_i64(range.end - 1))
+    }
+
+    fn include_vertex_window(
+        &self,
+        v: VID,
+        w: Range<i64>,
+        layer_ids: &LayerIds,
+        edge_filter: Option<&EdgeFilter>,
+    ) -> bool {
+        if !self
+            .0
+            .vertex_times
+            .has_time_in(self.time_range(v), w.clone())
+        {
+            return false;
+        }
+        match (layer_ids, edge_filter) {
+            (LayerIds::All, None) => true,
+            // the vertex needs an update in one of the layers inside the window
+            _ => self
+                .vertex_edges(v, Direction::BOTH, layer_ids.clone(), edge_filter)
+                .any(|e| self.edge_store(e.pid()).active(layer_ids, w.clone())),
+        }
+    }
+
+    #[inline]
+    fn include_edge_window(&self, e: &EdgeStore, w: Range<i64>, layer_ids: &LayerIds) -> bool {
+        e.active(layer_ids, w)
+    }
+
+    fn vertex_history(&self, v: VID) -> Vec<i64> {
+        self.time_range(v)
+            .map(|i| self.0.vertex_times.get_i64(i))
+            .collect()
+    }
+
+    fn vertex_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        self.0
+            .vertex_times
+            .times_in(self.time_range(v), w)
+            .map(|i| self.0.vertex_times.get_i64(i))
+            .collect()
+    }
+
+    fn edge_exploded(&self, e: EdgeRef, layer_ids: LayerIds) -> BoxedIter<EdgeRef> {
+        let arc = ArcEdge::from_entry(self.core_edge(e.pid()), self.0.edge_meta.clone());
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let iter: GenBoxed<EdgeRef> = GenBoxed::new_boxed(|co| async move {
+            for (l, t) in arc.timestamps_and_layers(layer_ids) {
+                co.yield_(e.at(*t).at_layer(l)).await;
+            }
+        });
+        Box::new(iter.into_iter())
+    }
+
+    fn edge_layers(&self, e: EdgeRef, layer_ids: LayerIds) -> BoxedIter<EdgeRef> {
+        let arc = ArcEdge::from_entry(self.core_edge(e.pid()), self.0.edge_meta.clone());
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let iter: GenBoxed<EdgeRef> = GenBoxed::new_boxed(|co| async move {
+            for l in arc.layers() {
+                if layer_ids.contains(&l) {
+                    co.yield_(e.at_layer(l)).await;
+                }
+            }
+        });
+        Box::new(iter.into_iter())
+    }
+
+    fn edge_window_exploded(
+        &self,
+        e: EdgeRef,
+        w: Range<i64>,
+        layer_ids: LayerIds,
+    ) -> BoxedIter<EdgeRef> {
+        let arc = ArcEdge::from_entry(self.core_edge(e.pid()), self.0.edge_meta.clone());
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let iter: GenBoxed<EdgeRef> = GenBoxed::new_boxed(|co| async move {
+            for (l, t) in arc.timestamps_and_layers_window(layer_ids, w) {
+                co.yield_(e.at(*t).at_layer(l)).await;
+            }
+        });
+        Box::new(iter.into_iter())
+    }
+
+    fn edge_window_layers(
+        &self,
+        e: EdgeRef,
+        w: Range<i64>,
+        layer_ids: LayerIds,
+    ) -> BoxedIter<EdgeRef> {
+        let arc = ArcEdge::from_entry(self.core_edge(e.pid()), self.0.edge_meta.clone());
+        let layer_ids = layer_ids.constrain_from_edge(e);
+        let iter: GenBoxed<EdgeRef> = GenBoxed::new_boxed(|co| async move {
+            for l in arc.layers_window(w) {
+                if layer_ids.contains(&l) {
+                    co.yield_(e.at_layer(l)).await;
+                }
+            }
+        });
+        Box::new(iter.into_iter())
+    }
+
+    fn edge_earliest_time(&self, e: EdgeRef, layer_ids: LayerIds) -> Option<i64> {
+        e.time_t()
+            .or_else(|| self.edge_additions(e, layer_ids).first_t())
+    }
+
+    fn edge_earliest_time_window(
+        &self,
+        e: EdgeRef,
+        w: Range<i64>,
+        layer_ids: LayerIds,
+    ) -> Option<i64> {
+        e.time_t()
+            .or_else(|| self.edge_additions(e, layer_ids).range(w).first_t())
+    }
+
+    fn edge_latest_time(&self, e: EdgeRef, layer_ids: LayerIds) -> Option<i64> {
+        e.time_t()
+            .or_else(|| self.edge_additions(e, layer_ids).last_t())
+    }
+
+    fn edge_latest_time_window(
+        &self,
+        e: EdgeRef,
+        w: Range<i64>,
+        layer_ids: LayerIds,
+    ) -> Option<i64> {
+        e.time_t()
+            .or_else(|| self.edge_additions(e, layer_ids).range(w).last_t())
+    }
+
+    fn edge_deletion_history(&self, e: EdgeRef, layer_ids: LayerIds) -> Vec<i64> {
+        self.edge_deletions(e, layer_ids)
+            .iter_t()
+            .copied()
+            .collect()
+    }
+
+    fn edge_deletion_history_window(
+        &self,
+        e: EdgeRef,
+        w: Range<i64>,
+        layer_ids: LayerIds,
+    ) -> Vec<i64> {
+        self.edge_deletions(e, layer_ids)
+            .range(w)
+            .iter_t()
+            .copied()
+            .collect()
+    }
+
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64> {
+        self.vertex_data(v).deletions.iter_t().copied().collect()
+    }
+
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        self.vertex_data(v)
+            .deletions
+            .range(w)
+            .iter_t()
+            .copied()
+            .collect()
+    }
+
+    fn has_temporal_prop(&self, prop_id: usize) -> bool {
+        prop_id < self.0.graph_props.temporal_prop_meta().len()
+    }
+
+    fn temporal_prop_vec(&self, prop_id: usize) -> Vec<(i64, Prop)> {
+        self.temporal_prop(prop_id)
+            .map(|prop| prop.iter().collect())
+            .unwrap_or_default()
+    }
+
+    fn has_temporal_prop_window(&self, prop_id: usize, w: Range<i64>) -> bool {
+        self.temporal_prop(prop_id)
+            .filter(|p| p.iter_window_t(w).next().is_some())
+            .is_some()
+    }
+
+    fn temporal_prop_vec_window(&self, prop_id: usize, start: i64, end: i64) -> Vec<(i64, Prop)> {
+        self.temporal_prop(prop_id)
+            .map(|prop| prop.iter_window_t(start..end).collect())
+            .unwrap_or_default()
+    }
+
+    fn has_temporal_vertex_prop(&self, v: VID, prop_id: usize) -> bool {
+        self.temporal_vertex_prop(v, prop_id).is_some()
+    }
+
+    fn temporal_vertex_prop_vec(&self, v: VID, prop_id: usize) -> Vec<(i64, Prop)> {
+        self.temporal_vertex_prop(v, prop_id)
+            .map(|prop| prop.iter().collect())
+            .unwrap_or_default()
+    }
+
+    fn has_temporal_vertex_prop_window(&self, v: VID, prop_id: usize, w: Range<i64>) -> bool {
+        self.temporal_vertex_prop(v, prop_id)
+            .filter(|p| p.iter_window_t(w).next().is_some())
+            .is_some()
+    }
+
+    fn temporal_vertex_prop_vec_window(
+        &self,
+        v: VID,
+        prop_id: usize,
+        start: i64,
+        end: i64,
+    ) -> Vec<(i64, Prop)> {
+        self.temporal_vertex_prop(v, prop_id)
+            .map(|prop| prop.iter_window_t(start..end).collect())
+            .unwrap_or_default()
+    }
+
+    fn has_temporal_edge_prop_window(
+        &self,
+        e: EdgeRef,
+        prop_id: usize,
+        w: Range<i64>,
+        layer_ids: LayerIds,
+    ) -> bool {
+        self.edge_store(e.pid())
+            .has_temporal_prop_window(layer_ids, prop_id, w)
+    }
+
+    fn temporal_edge_prop_vec_window(
+        &self,
+        e: EdgeRef,
+        prop_id: usize,
+        start: i64,
+        end: i64,
+        layer_ids: LayerIds,
+    ) -> Vec<(i64, Prop)> {
+        self.temporal_edge_prop(e, prop_id, layer_ids)
+            .map(|p| match e.time() {
+                Some(t) => {
+                    if *t.t() >= start && *t.t() < end {
+                        p.at(&t).map(|v| vec![(*t.t(), v)]).unwrap_or_default()
+                    } else {
+                        vec![]
+                    }
+                }
+                None => p.iter_window(start..end).collect(),
+            })
+            .unwrap_or_default()
+    }
+
+    fn has_temporal_edge_prop(&self, e: EdgeRef, prop_id: usize, layer_ids: LayerIds) -> bool {
+        self.edge_store(e.pid())
+            .has_temporal_prop(&layer_ids, prop_id)
+    }
+
+    fn temporal_edge_prop_vec(
+        &self,
+        e: EdgeRef,
+        prop_id: usize,
+        layer_ids: LayerIds,
+    ) -> Vec<(i64, Prop)> {
+        self.temporal_edge_prop(e, prop_id, layer_ids)
+            .map(|p| match e.time() {
+                Some(t) => p.at(&t).map(|v| vec![(*t.t(), v)]).unwrap_or_default(),
+                None => p.iter().collect(),
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl InternalMaterialize for MmapGraph {
+    fn new_base_graph(&self, graph: InternalGraph) -> MaterializedGraph {
+        MaterializedGraph::EventGraph(Graph::new_from_inner(Arc::new(graph)))
+    }
+
+    fn include_deletions(&self) -> bool {
+        false
+    }
+}
+
+impl ConstPropertiesOps for MmapGraph {
+    fn get_const_prop_id(&self, name: &str) -> Option<usize> {
+        self.0.graph_props.get_const_prop_id(name)
+    }
+
+    fn get_const_prop_name(&self, id: usize) -> ArcStr {
+        self.0.graph_props.get_const_prop_name(id)
+    }
+
+    fn const_prop_ids(&self) -> Box<dyn Iterator<Item = usize>> {
+        Box::new(self.0.graph_props.const_prop_ids())
+    }
+
+    fn const_prop_keys(&self) -> Box<dyn Iterator<Item = ArcStr>> {
+        Box::new(self.0.graph_props.constant_names().into_iter())
+    }
+
+    fn get_const_prop(&self, prop_id: usize) -> Option<Prop> {
+        self.0.graph_props.get_constant(prop_id)
+    }
+}
+
+impl TemporalPropertyViewOps for MmapGraph {
+    fn temporal_value(&self, id: usize) -> Option<Prop> {
+        self.temporal_prop(id)
+            .and_then(|prop| prop.last_before(i64::MAX).map(|(_, v)| v))
+    }
+
+    fn temporal_history(&self, id: usize) -> Vec<i64> {
+        self.temporal_prop(id)
+            .map(|prop| prop.iter().map(|(t, _)| t).collect())
+            .unwrap_or_default()
+    }
+
+    fn temporal_values(&self, id: usize) -> Vec<Prop> {
+        self.temporal_prop(id)
+            .map(|prop| prop.iter().map(|(_, v)| v).collect())
+            .unwrap_or_default()
+    }
+
+    fn temporal_value_at(&self, id: usize, t: i64) -> Option<Prop> {
+        self.temporal_prop(id)
+            .and_then(|prop| prop.last_before(t.saturating_add(1)).map(|(_, v)| v))
+    }
+}
+
+impl TemporalPropertiesOps for MmapGraph {
+    fn get_temporal_prop_id(&self, name: &str) -> Option<usize> {
+        self.0.graph_props.get_temporal_id(name)
+    }
+
+    fn get_temporal_prop_name(&self, id: usize) -> ArcStr {
+        self.0.graph_props.get_temporal_name(id)
+    }
+
+    fn temporal_prop_ids(&self) -> Box<dyn Iterator<Item = usize> + '_> {
+        Box::new(self.0.graph_props.temporal_ids())
+    }
+
+    fn temporal_prop_keys(&self) -> Box<dyn Iterator<Item = ArcStr> + '_> {
+        Box::new(self.0.graph_props.temporal_names().into_iter())
+    }
+}
+
+#[cfg(test)]
+mod mmap_graph_test {
+    use super::*;
+    use crate::{
+        algorithms::community_detection::connected_components::weakly_connected_components,
+        db::{
+            api::{
+                mutation::{AdditionOps, DeletionOps},
+                view::*,
+            },
+            graph::views::deletion_graph::GraphWithDeletions,
+        },
+        prelude::*,
+    };
+    use tempdir::TempDir;
+
+    fn test_graph() -> Graph {
+        let g = Graph::new();
+        g.add_edge(3, 1, 2, [("weight", 3)], None).unwrap();
+        g.add_edge(1, 1, 2, [("weight", 1)], None).unwrap();
+        g.add_edge(2, 2, 3, NO_PROPS, Some("friends")).unwrap();
+        g.add_edge(5, 3, 1, NO_PROPS, None).unwrap();
+        g.add_edge(4, 1, 3, NO_PROPS, Some("friends")).unwrap();
+        g.add_vertex(10, 4, [("age", 42)]).unwrap();
+        g.add_vertex(11, "Ben", NO_PROPS).unwrap();
+        g.vertex(1)
+            .unwrap()
+            .add_constant_properties([("name", "one")])
+            .unwrap();
+        g.edge(1, 3)
+            .unwrap()
+            .add_constant_properties([("since", 2020)], Some("friends"))
+            .unwrap();
+        g.add_constant_properties([("kind", "test")]).unwrap();
+        g
+    }
+
+    fn write_and_open(g: &Graph, dir: &TempDir) -> MmapGraph {
+        MmapGraph::write(g, dir.path()).unwrap();
+        MmapGraph::open(dir.path()).unwrap()
+    }
+
+    #[test]
+    fn test_topology() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let mg = write_and_open(&test_graph(), &dir);
+
+        assert_eq!(mg.count_vertices(), 5);
+        assert_eq!(mg.count_edges(), 4);
+        assert!(!mg.has_vertex(5));
+
+        let v1 = mg.vertex(1).unwrap();
+        assert_eq!(v1.out_neighbours().id().collect_vec(), vec![2, 3]);
+        assert_eq!(v1.in_neighbours().id().collect_vec(), vec![3]);
+        assert_eq!(v1.degree(), 2);
+        assert_eq!(mg.vertex(3).unwrap().in_degree(), 2);
+        assert_eq!(mg.vertex(4).unwrap().out_degree(), 0);
+
+        assert_eq!(mg.edge(1, 2).unwrap().history(), vec![1, 3]);
+        assert!(mg.edge(3, 2).is_none());
+    }
+
+    #[test]
+    fn test_names_props_and_layers() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let mg = write_and_open(&test_graph(), &dir);
+
+        assert_eq!(mg.vertex("Ben").unwrap().name(), "Ben");
+        assert_eq!(
+            mg.vertex(4).unwrap().properties().get("age"),
+            Some(Prop::I32(42))
+        );
+        assert_eq!(
+            mg.vertex(1).unwrap().properties().get("name"),
+            Some(Prop::str("one"))
+        );
+        assert_eq!(
+            mg.edge(1, 2)
+                .unwrap()
+                .properties()
+                .temporal()
+                .get("weight")
+                .unwrap()
+                .values(),
+            vec![Prop::I32(1), Prop::I32(3)]
+        );
+        assert_eq!(mg.properties().get("kind"), Some(Prop::str("test")));
+
+        let mut layers = mg.unique_layers().collect_vec();
+        layers.sort();
+        assert_eq!(layers, vec!["_default", "friends"]);
+        let friends = mg.layer("friends").unwrap();
+        assert_eq!(friends.count_edges(), 2);
+        assert_eq!(
+            friends.edge(1, 3).unwrap().properties().get("since"),
+            Some(Prop::I32(2020))
+        );
+        assert_eq!(mg.default_layer().count_edges(), 2);
+    }
+
+    #[test]
+    fn test_time_queries() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let mg = write_and_open(&test_graph(), &dir);
+
+        let e = mg.edge(1, 2).unwrap();
+        assert_eq!(e.window(1, 2).history(), vec![1]);
+        assert_eq!(mg.vertex(1).unwrap().history(), vec![1, 3, 4, 5]);
+        assert_eq!(mg.earliest_time(), Some(1));
+        assert_eq!(mg.latest_time(), Some(11));
+
+        let w = mg.window(0, 3);
+        assert_eq!(w.count_edges(), 2);
+        assert_eq!(w.count_vertices(), 3);
+        assert!(!w.has_vertex(4));
+        assert_eq!(w.edge(1, 2).unwrap().history(), vec![1]);
+        assert_eq!(w.latest_time(), Some(2));
+        assert_eq!(w.vertex(1).unwrap().out_degree(), 1);
+
+        // vertex 1 only has updates in the default layer before time 3
+        let friends = mg.layer("friends").unwrap().window(0, 3);
+        assert_eq!(friends.count_vertices(), 2);
+        assert!(!friends.has_vertex(1));
+        assert!(friends.has_vertex(2));
+    }
+
+    #[test]
+    fn test_decoded_edges_are_cached() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let mg = write_and_open(&test_graph(), &dir);
+        let e = mg.edge(1, 2).unwrap().edge.pid();
+        assert!(std::ptr::eq(&*mg.core_edge(e), &*mg.core_edge(e)));
+        assert_eq!(mg.core_edge(e).e_id(), e);
+    }
+
+    #[test]
+    fn test_algorithms_match_in_memory_graph() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = test_graph();
+        let mg = write_and_open(&g, &dir);
+
+        assert_eq!(
+            weakly_connected_components(&mg, 20, None).get_all(),
+            weakly_connected_components(&g, 20, None).get_all()
+        );
+        assert_eq!(
+            weakly_connected_components(&mg.window(0, 3), 20, None).get_all(),
+            weakly_connected_components(&g.window(0, 3), 20, None).get_all()
+        );
+    }
+
+    #[test]
+    fn test_write_view() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = test_graph();
+        MmapGraph::write(&g.window(0, 3), dir.path()).unwrap();
+        let mg = MmapGraph::open(dir.path()).unwrap();
+        assert_eq!(mg.count_vertices(), 3);
+        assert_eq!(mg.count_edges(), 2);
+        assert_eq!(mg.edge(1, 2).unwrap().history(), vec![1]);
+    }
+
+    #[test]
+    fn test_materialize_roundtrip() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = test_graph();
+        let mg = write_and_open(&g, &dir);
+        assert_eq!(mg.materialize().unwrap().into_events().unwrap(), g);
+    }
+
+    #[test]
+    fn test_update_order_is_kept() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = test_graph();
+        g.add_vertex(10, 4, [("age", 43)]).unwrap();
+        let mg = write_and_open(&g, &dir);
+        let updates = |graph: &dyn CoreGraphOps| {
+            let v = graph.internalise_vertex(4.into()).unwrap();
+            let id = graph.vertex_meta().get_prop_id("age", false).unwrap();
+            graph
+                .temporal_vertex_prop(v, id)
+                .unwrap()
+                .iter_window(TimeIndexEntry::MIN..TimeIndexEntry::MAX)
+                .collect_vec()
+        };
+        assert_eq!(updates(&mg), updates(&g));
+        assert_eq!(
+            mg.vertex(4).unwrap().properties().get("age"),
+            Some(Prop::I32(43))
+        );
+    }
+
+    #[test]
+    fn test_deletion_graphs_are_rejected() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let g = GraphWithDeletions::new();
+        g.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        g.delete_edge(3, 1, 2, None).unwrap();
+        assert!(matches!(
+            MmapGraph::write(&g, dir.path()),
+            Err(GraphError::MmapDeletionsNotSupported)
+        ));
+        assert!(MmapGraph::write(&g.window(0, 2), dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let dir = TempDir::new("raphtory").unwrap();
+        let mg = write_and_open(&Graph::new(), &dir);
+        assert_eq!(mg.count_vertices(), 0);
+        assert_eq!(mg.count_edges(), 0);
+        assert!(mg.earliest_time().is_none());
+    }
+
+    #[test]
+    fn test_inconsistent_files_are_rejected() {
+        let dir = TempDir::new("raphtory").unwrap();
+        MmapGraph::write(&test_graph(), dir.path()).unwrap();
+        let mut edge_dst = ColumnWriter::create(dir.path(), EDGE_DST).unwrap();
+        edge_dst.push(0).unwrap();
+        edge_dst.finish().unwrap();
+        assert!(matches!(
+            MmapGraph::open(dir.path()),
+            Err(GraphError::InvalidFileFormat)
+        ));
+    }
+}
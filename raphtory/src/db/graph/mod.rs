@@ -1,5 +1,7 @@
 pub mod edge;
 pub mod graph;
+pub mod logged_graph;
+pub mod mmap_graph;
 pub mod path;
 pub mod vertex;
 pub mod vertices;
@@ -2,7 +2,7 @@ use crate::{
     core::{
         entities::{vertices::vertex_ref::VertexRef, VID},
         utils::time::IntoTime,
-        Direction,
+        ArcStr, Direction,
     },
     db::{
         api::{
@@ -87,7 +87,10 @@ impl<G: GraphViewOps> PathFromGraph<G> {
 impl<G: GraphViewOps> VertexViewOps for PathFromGraph<G> {
     type Graph = G;
     type ValueType<T> = Box<dyn Iterator<Item = Box<dyn Iterator<Item = T> + Send>> + Send>;
-    type PathType<'a> = Self where Self: 'a;
+    type PathType<'a>
+        = Self
+    where
+        Self: 'a;
     type EList = Box<dyn Iterator<Item = Box<dyn Iterator<Item = EdgeView<G>> + Send>> + Send>;
 
     fn id(&self) -> Box<dyn Iterator<Item = Box<dyn Iterator<Item = u64> + Send>> + Send> {
@@ -98,6 +101,10 @@ impl<G: GraphViewOps> VertexViewOps for PathFromGraph<G> {
         Box::new(self.iter().map(|it| it.name()))
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<ArcStr>> {
+        Box::new(self.iter().map(|it| it.vertex_type()))
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         Box::new(self.iter().map(|it| it.earliest_time()))
     }
@@ -270,7 +277,10 @@ impl<G: GraphViewOps> PathFromVertex<G> {
 impl<G: GraphViewOps> VertexViewOps for PathFromVertex<G> {
     type Graph = G;
     type ValueType<T> = BoxedIter<T>;
-    type PathType<'a> = Self where Self: 'a;
+    type PathType<'a>
+        = Self
+    where
+        Self: 'a;
     type EList = BoxedIter<EdgeView<G>>;
 
     fn id(&self) -> Self::ValueType<u64> {
@@ -281,6 +291,10 @@ impl<G: GraphViewOps> VertexViewOps for PathFromVertex<G> {
         self.iter().name()
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<ArcStr>> {
+        self.iter().vertex_type()
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.iter().earliest_time()
     }
@@ -149,7 +149,10 @@ impl<G: GraphViewOps> Static for VertexView<G> {}
 impl<G: GraphViewOps> VertexViewOps for VertexView<G> {
     type Graph = G;
     type ValueType<T> = T;
-    type PathType<'a> = PathFromVertex<G> where Self: 'a;
+    type PathType<'a>
+        = PathFromVertex<G>
+    where
+        Self: 'a;
     type EList = BoxedIter<EdgeView<G>>;
 
     fn id(&self) -> u64 {
@@ -160,6 +163,12 @@ impl<G: GraphViewOps> VertexViewOps for VertexView<G> {
         self.graph.vertex_name(self.vertex)
     }
 
+    fn vertex_type(&self) -> Option<ArcStr> {
+        self.graph
+            .vertex_meta()
+            .get_vertex_type_name_by_id(self.graph.vertex_type_id(self.vertex))
+    }
+
     fn earliest_time(&self) -> Option<i64> {
         self.graph.vertex_earliest_time(self.vertex)
     }
@@ -329,6 +338,13 @@ impl<G: GraphViewOps + InternalPropertyAdditionOps + InternalAdditionOps> Vertex
             .internal_update_constant_vertex_properties(self.vertex, properties)
     }
 
+    /// Set the type of the vertex, fails if the vertex already has a different type
+    pub fn set_vertex_type(&self, vertex_type: &str) -> Result<(), GraphError> {
+        let vertex_type = self.graph.resolve_vertex_type(vertex_type);
+        self.graph
+            .internal_set_vertex_type(self.vertex, vertex_type)
+    }
+
     pub fn add_updates<C: CollectProperties, T: TryIntoInputTime>(
         &self,
         time: T,
@@ -376,6 +392,10 @@ impl<G: GraphViewOps> VertexListOps for Box<dyn Iterator<Item = VertexView<G>> +
         Box::new(self.map(|v| v.name()))
     }
 
+    fn vertex_type(self) -> BoxedIter<Option<ArcStr>> {
+        Box::new(self.map(|v| v.vertex_type()))
+    }
+
     fn properties(self) -> BoxedIter<Properties<VertexView<G>>> {
         Box::new(self.map(move |v| v.properties()))
     }
@@ -456,6 +476,10 @@ impl<G: GraphViewOps> VertexListOps for BoxedIter<BoxedIter<VertexView<G>>> {
         Box::new(self.map(|it| it.name()))
     }
 
+    fn vertex_type(self) -> BoxedIter<Self::ValueType<Option<ArcStr>>> {
+        Box::new(self.map(|it| it.vertex_type()))
+    }
+
     fn properties(self) -> BoxedIter<Self::ValueType<Properties<VertexView<G>>>> {
         Box::new(self.map(move |it| it.properties()))
     }
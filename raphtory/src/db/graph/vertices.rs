@@ -1,5 +1,5 @@
 use crate::{
-    core::{entities::vertices::vertex_ref::VertexRef, utils::time::IntoTime, Direction},
+    core::{entities::vertices::vertex_ref::VertexRef, utils::time::IntoTime, ArcStr, Direction},
     db::{
         api::{
             properties::Properties,
@@ -64,6 +64,11 @@ impl<G: GraphViewOps> VertexViewOps for Vertices<G> {
         self.iter().name()
     }
 
+    /// Returns an iterator over the vertices' type
+    fn vertex_type(&self) -> Self::ValueType<Option<ArcStr>> {
+        self.iter().vertex_type()
+    }
+
     /// Returns an iterator over the vertices' earliest time
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.iter().earliest_time()
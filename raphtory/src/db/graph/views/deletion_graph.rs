@@ -21,7 +21,6 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 use std::{
-    cmp::min,
     fmt::{Display, Formatter},
     iter,
     ops::Range,
@@ -30,6 +29,7 @@ use std::{
 };
 
 /// A graph view where an edge remains active from the time it is added until it is explicitly marked as deleted.
+/// Deleting a vertex also deletes all its incident edges.
 ///
 /// Note that the graph will give you access to all edges that were added at any point in time, even those that are marked as deleted.
 /// The deletion only has an effect on the exploded edge view that are returned. An edge is included in a windowed view of the graph if
@@ -119,12 +119,59 @@ impl GraphWithDeletions {
             ),
         };
 
+        // deleting either of the vertices also deletes the edge
+        let last_vertex_deletion_before_start = self.last_vertex_deletion_before(e, t);
+
         // None is less than any value (see test below)
         (first_deletion < first_addition
             && first_deletion
                 .filter(|v| *v >= TimeIndexEntry::start(t))
-                .is_some())
-            || last_addition_before_start > last_deletion_before_start
+                .is_some()
+            && last_vertex_deletion_before_start.is_none())
+            || last_addition_before_start
+                > last_deletion_before_start.max(last_vertex_deletion_before_start)
+    }
+
+    /// Latest deletion of either endpoint of `e` strictly before `t`
+    fn last_vertex_deletion_before(&self, e: &EdgeStore, t: i64) -> Option<TimeIndexEntry> {
+        let src_deletion = self
+            .vertex_deletions(e.src())
+            .range(i64::MIN..t)
+            .last()
+            .copied();
+        let dst_deletion = self
+            .vertex_deletions(e.dst())
+            .range(i64::MIN..t)
+            .last()
+            .copied();
+        src_deletion.max(dst_deletion)
+    }
+
+    /// Time at which edge `e` was last deleted in window `w`, taking into account deletions of its vertices
+    fn last_deletion_window(&self, e: EdgeRef, w: Range<i64>, layer_ids: LayerIds) -> Option<i64> {
+        let last_addition = self
+            .edge_additions(e, layer_ids.clone())
+            .range(w.clone())
+            .last_t();
+        let vertex_deletion =
+            last_addition.and_then(|t| self.first_vertex_deletion(e, t.saturating_add(1)..w.end));
+        match (last_addition, vertex_deletion) {
+            // the edge ends at whichever deletion comes first after the last addition
+            (Some(added), Some(vertex_deleted)) => Some(
+                self.edge_deletions(e, layer_ids)
+                    .range(added.saturating_add(1)..vertex_deleted)
+                    .first_t()
+                    .unwrap_or(vertex_deleted),
+            ),
+            _ => self.edge_deletions(e, layer_ids).range(w).last_t(),
+        }
+    }
+
+    /// Earliest deletion of either endpoint of `e` in window `w`
+    fn first_vertex_deletion(&self, e: EdgeRef, w: Range<i64>) -> Option<i64> {
+        let src_deletion = self.vertex_deletions(e.src()).range(w.clone()).first_t();
+        let dst_deletion = self.vertex_deletions(e.dst()).range(w).first_t();
+        src_deletion.into_iter().chain(dst_deletion).min()
     }
 
     fn vertex_alive_at(
@@ -376,22 +423,28 @@ impl TimeSemantics for GraphWithDeletions {
 
     fn edge_latest_time(&self, e: EdgeRef, layer_ids: LayerIds) -> Option<i64> {
         match e.time().map(|ti| *ti.t()) {
-            Some(t) => Some(min(
+            Some(t) => Some(
                 self.edge_additions(e, layer_ids.clone())
                     .range(t.saturating_add(1)..i64::MAX)
                     .first_t()
-                    .unwrap_or(i64::MAX),
-                self.edge_deletions(e, layer_ids)
-                    .range(t.saturating_add(1)..i64::MAX)
-                    .first_t()
-                    .unwrap_or(i64::MAX),
-            )),
+                    .unwrap_or(i64::MAX)
+                    .min(
+                        self.edge_deletions(e, layer_ids)
+                            .range(t.saturating_add(1)..i64::MAX)
+                            .first_t()
+                            .unwrap_or(i64::MAX),
+                    )
+                    .min(
+                        self.first_vertex_deletion(e, t.saturating_add(1)..i64::MAX)
+                            .unwrap_or(i64::MAX),
+                    ),
+            ),
             None => {
                 let entry = self.core_edge(e.pid());
                 if self.edge_alive_at(&entry, i64::MAX, &layer_ids) {
                     Some(i64::MAX)
                 } else {
-                    self.edge_deletions(e, layer_ids).last_t()
+                    self.last_deletion_window(e, i64::MIN..i64::MAX, layer_ids)
                 }
             }
         }
@@ -404,22 +457,28 @@ impl TimeSemantics for GraphWithDeletions {
         layer_ids: LayerIds,
     ) -> Option<i64> {
         match e.time().map(|ti| *ti.t()) {
-            Some(t) => Some(min(
+            Some(t) => Some(
                 self.edge_additions(e, layer_ids.clone())
                     .range(t.saturating_add(1)..w.end)
                     .first_t()
-                    .unwrap_or(w.end - 1),
-                self.edge_deletions(e, layer_ids)
-                    .range(t.saturating_add(1)..w.end)
-                    .first_t()
-                    .unwrap_or(w.end - 1),
-            )),
+                    .unwrap_or(w.end - 1)
+                    .min(
+                        self.edge_deletions(e, layer_ids)
+                            .range(t.saturating_add(1)..w.end)
+                            .first_t()
+                            .unwrap_or(w.end - 1),
+                    )
+                    .min(
+                        self.first_vertex_deletion(e, t.saturating_add(1)..w.end)
+                            .unwrap_or(w.end - 1),
+                    ),
+            ),
             None => {
                 let entry = self.core_edge(e.pid());
                 if self.edge_alive_at(&entry, w.end - 1, &layer_ids) {
                     Some(w.end - 1)
                 } else {
-                    self.edge_deletions(e, layer_ids).range(w).last_t()
+                    self.last_deletion_window(e, w, layer_ids)
                 }
             }
         }
@@ -445,6 +504,14 @@ impl TimeSemantics for GraphWithDeletions {
             .collect()
     }
 
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64> {
+        self.graph.vertex_deletion_history(v)
+    }
+
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        self.graph.vertex_deletion_history_window(v, w)
+    }
+
     #[inline]
     fn has_temporal_prop(&self, prop_id: usize) -> bool {
         self.graph.has_temporal_prop(prop_id)
@@ -499,6 +566,7 @@ impl TimeSemantics for GraphWithDeletions {
         if entry.has_temporal_prop(&layer_ids, prop_id) {
             let search_start = entry
                 .last_deletion_before(&layer_ids, w.start)
+                .max(self.last_vertex_deletion_before(&entry, w.start))
                 .unwrap_or(TimeIndexEntry::MIN); // if property was added at any point since the last deletion, it is still there
             let search_end = TimeIndexEntry::start(w.end);
             match layer_ids {
@@ -691,4 +759,52 @@ mod test_deletions {
         assert!(g.window(2, 3).has_edge(3, 4, Layer::Default));
         assert!(!g.window(3, 4).has_edge(3, 4, Layer::Default));
     }
+
+    #[test]
+    fn test_vertex_deletion() {
+        let g = GraphWithDeletions::new();
+        g.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        g.add_edge(0, 2, 3, NO_PROPS, None).unwrap();
+        g.delete_vertex(5, 2).unwrap();
+
+        assert!(g.window(1, 2).has_vertex(2));
+        assert!(g.window(1, 2).has_edge(1, 2, Layer::Default));
+        assert!(g.window(5, 6).has_vertex(2));
+
+        let w = g.window(6, 10);
+        assert!(!w.has_vertex(2));
+        assert!(!w.has_edge(1, 2, Layer::Default));
+        assert!(!w.has_edge(2, 3, Layer::Default));
+        assert!(w.is_empty());
+
+        assert_eq!(g.edge(1, 2).unwrap().latest_time(), Some(5));
+        assert_eq!(
+            g.edge(2, 3).unwrap().explode().latest_time().collect_vec(),
+            vec![Some(5)]
+        );
+    }
+
+    #[test]
+    fn test_vertex_deletion_then_readd() {
+        let g = GraphWithDeletions::new();
+        g.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        g.delete_vertex(5, 1).unwrap();
+        g.add_edge(10, 1, 2, NO_PROPS, None).unwrap();
+
+        assert!(!g.window(6, 10).has_edge(1, 2, Layer::Default));
+        assert!(g.window(11, 12).has_edge(1, 2, Layer::Default));
+        assert!(g.window(11, 12).has_vertex(1));
+    }
+
+    #[test]
+    fn test_materialize_vertex_deletion() {
+        let g = GraphWithDeletions::new();
+        g.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        g.delete_vertex(5, 2).unwrap();
+
+        let gm = g.materialize().unwrap().into_persistent().unwrap();
+        assert_eq!(gm, g);
+        assert!(!gm.window(6, 10).has_edge(1, 2, Layer::Default));
+        assert!(gm.window(6, 10).is_empty());
+    }
 }
@@ -0,0 +1,237 @@
+use crate::{
+    core::{
+        entities::{edges::edge_ref::EdgeRef, vertices::vertex_ref::VertexRef, LayerIds, EID, VID},
+        Direction,
+    },
+    db::api::{
+        properties::internal::InheritPropertiesOps,
+        view::internal::{
+            Base, EdgeFilter, EdgeFilterOps, GraphOps, Immutable, InheritCoreOps, InheritLayerOps,
+            InheritMaterialize, InheritTimeSemantics, Static,
+        },
+    },
+    prelude::GraphViewOps,
+};
+use itertools::Itertools;
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+/// A view of a graph that only includes the given edges and their endpoints
+#[derive(Clone)]
+pub struct EdgeSubgraph<G: GraphViewOps> {
+    graph: G,
+    vertices: Arc<FxHashSet<VID>>,
+    edges: Arc<FxHashSet<EID>>,
+    edge_filter: EdgeFilter,
+}
+
+impl<G: GraphViewOps> Static for EdgeSubgraph<G> {}
+
+impl<G: GraphViewOps + Debug> Debug for EdgeSubgraph<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EdgeSubgraph")
+            .field("graph", &self.graph)
+            .field("vertices", &self.vertices)
+            .field("edges", &self.edges)
+            .finish()
+    }
+}
+
+impl<G: GraphViewOps> Base for EdgeSubgraph<G> {
+    type Base = G;
+    #[inline(always)]
+    fn base(&self) -> &Self::Base {
+        &self.graph
+    }
+}
+
+impl<G: GraphViewOps> Immutable for EdgeSubgraph<G> {}
+
+impl<G: GraphViewOps> InheritCoreOps for EdgeSubgraph<G> {}
+impl<G: GraphViewOps> InheritTimeSemantics for EdgeSubgraph<G> {}
+impl<G: GraphViewOps> InheritPropertiesOps for EdgeSubgraph<G> {}
+impl<G: GraphViewOps> InheritMaterialize for EdgeSubgraph<G> {}
+impl<G: GraphViewOps> InheritLayerOps for EdgeSubgraph<G> {}
+
+impl<G: GraphViewOps> EdgeSubgraph<G> {
+    pub fn new<I: IntoIterator<Item = EdgeRef>>(graph: G, edges: I) -> Self {
+        let mut vertices = FxHashSet::default();
+        let mut edge_ids = FxHashSet::default();
+        for e in edges {
+            vertices.insert(e.src());
+            vertices.insert(e.dst());
+            edge_ids.insert(e.pid());
+        }
+        let vertices = Arc::new(vertices);
+        let edges = Arc::new(edge_ids);
+        let edges_cloned = edges.clone();
+        let edge_filter: EdgeFilter = match graph.edge_filter().cloned() {
+            Some(f) => Arc::new(move |e, l| edges_cloned.contains(&e.eid) && f(e, l)),
+            None => Arc::new(move |e, _l| edges_cloned.contains(&e.eid)),
+        };
+        Self {
+            graph,
+            vertices,
+            edges,
+            edge_filter,
+        }
+    }
+}
+
+impl<G: GraphViewOps> EdgeFilterOps for EdgeSubgraph<G> {
+    #[inline]
+    fn edge_filter(&self) -> Option<&EdgeFilter> {
+        Some(&self.edge_filter)
+    }
+}
+
+impl<G: GraphViewOps> GraphOps for EdgeSubgraph<G> {
+    fn internal_vertex_ref(
+        &self,
+        v: VertexRef,
+        layer_ids: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Option<VID> {
+        self.graph
+            .internal_vertex_ref(v, layer_ids, filter)
+            .filter(|v| self.vertices.contains(v))
+    }
+
+    fn find_edge_id(
+        &self,
+        e_id: EID,
+        layer_ids: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Option<EdgeRef> {
+        self.graph
+            .find_edge_id(e_id, layer_ids, filter)
+            .filter(|e| self.edges.contains(&e.pid()))
+    }
+
+    fn vertices_len(&self, _layer_ids: LayerIds, _filter: Option<&EdgeFilter>) -> usize {
+        self.vertices.len()
+    }
+
+    fn edges_len(&self, layer: LayerIds, filter: Option<&EdgeFilter>) -> usize {
+        self.vertices
+            .par_iter()
+            .map(|v| self.degree(*v, Direction::OUT, &layer, filter))
+            .sum()
+    }
+
+    fn has_edge_ref(
+        &self,
+        src: VID,
+        dst: VID,
+        layer: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> bool {
+        self.edge_ref(src, dst, layer, filter).is_some()
+    }
+
+    fn has_vertex_ref(
+        &self,
+        v: VertexRef,
+        layer_ids: &LayerIds,
+        edge_filter: Option<&EdgeFilter>,
+    ) -> bool {
+        self.internal_vertex_ref(v, layer_ids, edge_filter)
+            .is_some()
+    }
+
+    fn degree(&self, v: VID, d: Direction, layer: &LayerIds, filter: Option<&EdgeFilter>) -> usize {
+        self.graph.degree(v, d, layer, filter)
+    }
+
+    fn vertex_ref(&self, v: u64, layers: &LayerIds, filter: Option<&EdgeFilter>) -> Option<VID> {
+        self.internal_vertex_ref(v.into(), layers, filter)
+    }
+
+    fn vertex_refs(
+        &self,
+        _layers: LayerIds,
+        _filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = VID> + Send> {
+        let verts = Vec::from_iter(self.vertices.iter().copied());
+        Box::new(verts.into_iter())
+    }
+
+    fn edge_ref(
+        &self,
+        src: VID,
+        dst: VID,
+        layer: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Option<EdgeRef> {
+        self.graph
+            .edge_ref(src, dst, layer, filter)
+            .filter(|e| self.edges.contains(&e.pid()))
+    }
+
+    fn edge_refs(
+        &self,
+        layer: LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = EdgeRef> + Send> {
+        let g1 = self.clone();
+        let vertices = self.vertices.clone().iter().copied().collect_vec();
+        let filter = filter.cloned();
+        Box::new(
+            vertices.into_iter().flat_map(move |v| {
+                g1.vertex_edges(v, Direction::OUT, layer.clone(), filter.as_ref())
+            }),
+        )
+    }
+
+    fn vertex_edges(
+        &self,
+        v: VID,
+        d: Direction,
+        layer: LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = EdgeRef> + Send> {
+        self.graph.vertex_edges(v, d, layer, filter)
+    }
+
+    fn neighbours(
+        &self,
+        v: VID,
+        d: Direction,
+        layers: LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Box<dyn Iterator<Item = VID> + Send> {
+        self.graph.neighbours(v, d, layers, filter)
+    }
+}
+
+#[cfg(test)]
+mod edge_subgraph_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn only_the_given_edges() {
+        let graph = Graph::new();
+        for (t, src, dst) in [(1, 1, 2), (2, 2, 3), (3, 3, 1), (4, 3, 4), (5, 1, 2)] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        let sg = graph.edge_subgraph([(1, 2), (3, 4), (4, 5)]);
+        assert_eq!(sg.count_vertices(), 4);
+        assert_eq!(sg.count_edges(), 2);
+        assert_eq!(sg.count_temporal_edges(), 3);
+        assert!(sg.has_edge(1, 2, Layer::All));
+        assert!(!sg.has_edge(2, 3, Layer::All));
+        assert!(sg.edge(3, 1).is_none());
+        assert_eq!(sg.vertex(3).unwrap().degree(), 1);
+        assert_eq!(
+            sg.vertex(1).unwrap().neighbours().id().collect::<Vec<_>>(),
+            vec![2]
+        );
+
+        let actual = sg.materialize().unwrap().into_events().unwrap();
+        assert_eq!(actual, sg);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod deletion_graph;
+pub mod edge_subgraph;
 pub mod layer_graph;
 pub mod vertex_subgraph;
 pub mod window_graph;
@@ -335,6 +335,16 @@ impl<G: GraphViewOps> TimeSemantics for WindowedGraph<G> {
         )
     }
 
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64> {
+        self.graph
+            .vertex_deletion_history_window(v, self.start..self.end)
+    }
+
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        self.graph
+            .vertex_deletion_history_window(v, self.actual_start(w.start)..self.actual_end(w.end))
+    }
+
     fn has_temporal_prop(&self, prop_id: usize) -> bool {
         self.graph
             .has_temporal_prop_window(prop_id, self.start..self.end)
@@ -5,7 +5,7 @@ use crate::{
         utils::errors::GraphError,
         PropType,
     },
-    db::api::mutation::internal::InternalAdditionOps,
+    db::api::mutation::internal::{EdgeUpdate, InternalAdditionOps},
     prelude::Prop,
 };
 use std::sync::atomic::Ordering;
@@ -16,6 +16,11 @@ impl<const N: usize> InternalAdditionOps for InnerTemporalGraph<N> {
         self.inner().event_counter.fetch_add(1, Ordering::Relaxed)
     }
 
+    #[inline]
+    fn set_next_event_id(&self, id: usize) {
+        self.inner().event_counter.fetch_max(id, Ordering::Relaxed);
+    }
+
     #[inline]
     fn resolve_layer(&self, layer: Option<&str>) -> usize {
         layer
@@ -24,8 +29,15 @@ impl<const N: usize> InternalAdditionOps for InnerTemporalGraph<N> {
     }
 
     #[inline]
-    fn resolve_vertex(&self, id: u64, name: Option<&str>) -> VID {
-        self.inner().resolve_vertex(id, name)
+    fn resolve_vertex(&self, id: u64, name: Option<&str>) -> Result<VID, GraphError> {
+        Ok(self.inner().resolve_vertex(id, name))
+    }
+
+    #[inline]
+    fn resolve_vertex_type(&self, vertex_type: &str) -> usize {
+        self.inner()
+            .vertex_meta
+            .get_or_create_vertex_type_id(vertex_type)
     }
 
     #[inline]
@@ -75,6 +87,23 @@ impl<const N: usize> InternalAdditionOps for InnerTemporalGraph<N> {
         self.inner().add_vertex_internal(t, v, props)
     }
 
+    fn internal_set_vertex_type(&self, v: VID, vertex_type: usize) -> Result<(), GraphError> {
+        let result = self
+            .inner()
+            .storage
+            .get_node_mut(v)
+            .set_vertex_type(vertex_type);
+        // the vertex lock is released before looking up the name for the error
+        result.map_err(|existing| {
+            let meta = &self.inner().vertex_meta;
+            GraphError::VertexTypeError {
+                vertex: self.inner().vertex_name(v),
+                existing: meta.vertex_type_meta().get_name(existing),
+                new: meta.vertex_type_meta().get_name(vertex_type),
+            }
+        })
+    }
+
     #[inline]
     fn internal_add_edge(
         &self,
@@ -86,4 +115,8 @@ impl<const N: usize> InternalAdditionOps for InnerTemporalGraph<N> {
     ) -> Result<EID, GraphError> {
         self.inner().add_edge_internal(t, src, dst, props, layer)
     }
+
+    fn internal_add_edges(&self, updates: Vec<EdgeUpdate>) -> Result<(), GraphError> {
+        self.inner().add_edges_bulk(updates)
+    }
 }
@@ -70,6 +70,11 @@ impl<const N: usize> CoreGraphOps for InnerTemporalGraph<N> {
         self.inner().vertex_name(v)
     }
 
+    #[inline]
+    fn vertex_type_id(&self, v: VID) -> usize {
+        self.inner().node_entry(v).vertex_type
+    }
+
     #[inline]
     fn edge_additions(
         &self,
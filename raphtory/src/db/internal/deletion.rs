@@ -1,7 +1,10 @@
 use crate::{
     core::{
         entities::{edges::edge_ref::EdgeRef, graph::tgraph::InnerTemporalGraph, LayerIds, VID},
-        storage::timeindex::{LockedLayeredIndex, TimeIndexEntry},
+        storage::{
+            locked_view::LockedView,
+            timeindex::{LockedLayeredIndex, TimeIndex, TimeIndexEntry},
+        },
         utils::errors::GraphError,
     },
     db::api::{mutation::internal::InternalDeletionOps, view::internal::CoreDeletionOps},
@@ -17,6 +20,10 @@ impl<const N: usize> InternalDeletionOps for InnerTemporalGraph<N> {
     ) -> Result<(), GraphError> {
         self.inner().delete_edge(t, src, dst, layer)
     }
+
+    fn internal_delete_vertex(&self, t: TimeIndexEntry, v: VID) -> Result<(), GraphError> {
+        self.inner().delete_vertex(t, v)
+    }
 }
 
 impl<const N: usize> CoreDeletionOps for InnerTemporalGraph<N> {
@@ -28,4 +35,9 @@ impl<const N: usize> CoreDeletionOps for InnerTemporalGraph<N> {
         let edge = self.inner().edge(eref.pid());
         edge.deletions(layer_ids).unwrap()
     }
+
+    fn vertex_deletions(&self, v: VID) -> LockedView<'_, TimeIndex<TimeIndexEntry>> {
+        let vertex = self.inner().vertex(v);
+        vertex.deletions().unwrap()
+    }
 }
@@ -217,6 +217,14 @@ impl<const N: usize> TimeSemantics for InnerTemporalGraph<N> {
             .collect()
     }
 
+    fn vertex_deletion_history(&self, v: VID) -> Vec<i64> {
+        self.vertex_deletions(v).iter_t().copied().collect()
+    }
+
+    fn vertex_deletion_history_window(&self, v: VID, w: Range<i64>) -> Vec<i64> {
+        self.vertex_deletions(v).range(w).iter_t().copied().collect()
+    }
+
     fn has_temporal_prop(&self, prop_id: usize) -> bool {
         prop_id < self.inner().graph_props.temporal_prop_meta().len()
     }
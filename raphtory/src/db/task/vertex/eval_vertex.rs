@@ -3,7 +3,7 @@ use crate::{
         entities::VID,
         state::{accumulator_id::AccId, agg::Accumulator, compute_state::ComputeState, StateType},
         utils::time::IntoTime,
-        Direction,
+        ArcStr, Direction,
     },
     db::{
         api::{
@@ -304,7 +304,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
 {
     type Graph = G;
     type ValueType<T> = BoxedIter<T>;
-    type PathType<'b> = EvalPathFromVertex<'a, G, CS, S> where Self: 'b;
+    type PathType<'b>
+        = EvalPathFromVertex<'a, G, CS, S>
+    where
+        Self: 'b;
     type EList = BoxedIter<EdgeView<G>>;
 
     fn id(&self) -> Self::ValueType<u64> {
@@ -315,6 +318,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
         self.path.name()
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<ArcStr>> {
+        self.path.vertex_type()
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.path.earliest_time()
     }
@@ -402,7 +409,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
 {
     type Graph = G;
     type ValueType<T> = T;
-    type PathType<'b> = EvalPathFromVertex<'a, G, CS, S> where Self: 'b;
+    type PathType<'b>
+        = EvalPathFromVertex<'a, G, CS, S>
+    where
+        Self: 'b;
     type EList = Box<dyn Iterator<Item = EvalEdgeView<'a, G, CS, S>> + 'a>;
 
     fn id(&self) -> Self::ValueType<u64> {
@@ -413,6 +423,12 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
         self.graph.vertex_name(self.vertex)
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<ArcStr>> {
+        self.graph
+            .vertex_meta()
+            .get_vertex_type_name_by_id(self.graph.vertex_type_id(self.vertex))
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.graph.vertex_earliest_time(self.vertex)
     }
@@ -630,6 +646,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexListOps
         Box::new(self.map(|v| v.name()))
     }
 
+    fn vertex_type(self) -> Self::IterType<Option<ArcStr>> {
+        Box::new(self.map(|v| v.vertex_type()))
+    }
+
     fn properties(self) -> Self::IterType<Properties<VertexView<G>>> {
         Box::new(self.map(move |v| v.properties()))
     }
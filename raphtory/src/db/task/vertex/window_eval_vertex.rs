@@ -3,7 +3,7 @@ use crate::{
         entities::VID,
         state::{accumulator_id::AccId, agg::Accumulator, compute_state::ComputeState, StateType},
         utils::time::IntoTime,
-        Direction,
+        ArcStr, Direction,
     },
     db::{
         api::{
@@ -117,7 +117,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
 {
     type Graph = WindowedGraph<G>;
     type ValueType<T> = T;
-    type PathType<'b> = WindowEvalPathFromVertex<'a, G, CS, S> where Self: 'b;
+    type PathType<'b>
+        = WindowEvalPathFromVertex<'a, G, CS, S>
+    where
+        Self: 'b;
     type EList = Box<dyn Iterator<Item = WindowEvalEdgeView<'a, G, CS, S>> + 'a>;
 
     fn id(&self) -> Self::ValueType<u64> {
@@ -128,6 +131,12 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
         self.graph.vertex_name(self.vertex)
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<ArcStr>> {
+        self.graph
+            .vertex_meta()
+            .get_vertex_type_name_by_id(self.graph.vertex_type_id(self.vertex))
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.graph
             .vertex_earliest_time_window(self.vertex, self.start, self.end)
@@ -468,7 +477,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
 
     type ValueType<T> = Box<dyn Iterator<Item = T> + 'a>;
 
-    type PathType<'b> = WindowEvalPathFromVertex<'a, G, CS, S> where Self: 'b;
+    type PathType<'b>
+        = WindowEvalPathFromVertex<'a, G, CS, S>
+    where
+        Self: 'b;
 
     type EList = Box<dyn Iterator<Item = WindowEvalEdgeView<'a, G, CS, S>> + 'a>;
 
@@ -480,6 +492,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexViewOps
         self.path.name()
     }
 
+    fn vertex_type(&self) -> Self::ValueType<Option<ArcStr>> {
+        self.path.vertex_type()
+    }
+
     fn earliest_time(&self) -> Self::ValueType<Option<i64>> {
         self.path.earliest_time()
     }
@@ -588,6 +604,10 @@ impl<'a, G: GraphViewOps, CS: ComputeState, S: 'static> VertexListOps
         Box::new(self.map(|v| v.name()))
     }
 
+    fn vertex_type(self) -> Self::IterType<Option<ArcStr>> {
+        Box::new(self.map(|v| v.vertex_type()))
+    }
+
     fn properties(self) -> Self::IterType<Properties<VertexView<Self::Graph>>> {
         Box::new(self.map(move |v| v.properties()))
     }
@@ -0,0 +1,77 @@
+use crate::core::utils::errors::GraphError;
+use arrow2::{
+    array::{Array, BooleanArray, PrimitiveArray, Utf8Array},
+    offset::Offset,
+    types::NativeType,
+};
+use itertools::Itertools;
+
+pub(crate) struct PretendDF {
+    pub(crate) names: Vec<String>,
+    pub(crate) arrays: Vec<Vec<Box<dyn Array>>>,
+}
+
+impl PretendDF {
+    pub fn check_cols_exist(&self, cols: &[&str]) -> Result<(), GraphError> {
+        let non_cols: Vec<&&str> = cols
+            .iter()
+            .filter(|c| !self.names.contains(&c.to_string()))
+            .collect();
+        if non_cols.len() > 0 {
+            return Err(GraphError::ColumnDoesNotExist(non_cols.iter().join(", ")));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn iter_col<T: NativeType>(
+        &self,
+        name: &str,
+    ) -> Option<impl Iterator<Item = Option<&T>> + '_> {
+        let idx = self.names.iter().position(|n| n == name)?;
+
+        let _ = (&self.arrays[0])[idx]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()?;
+
+        let iter = self.arrays.iter().flat_map(move |arr| {
+            let arr = &arr[idx];
+            let arr = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+            arr.iter()
+        });
+
+        Some(iter)
+    }
+
+    pub fn utf8<O: Offset>(&self, name: &str) -> Option<impl Iterator<Item = Option<&str>> + '_> {
+        let idx = self.names.iter().position(|n| n == name)?;
+        // test that it's actually a utf8 array
+        let _ = (&self.arrays[0])[idx]
+            .as_any()
+            .downcast_ref::<Utf8Array<O>>()?;
+
+        let iter = self.arrays.iter().flat_map(move |arr| {
+            let arr = &arr[idx];
+            let arr = arr.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+            arr.iter()
+        });
+
+        Some(iter)
+    }
+
+    pub fn bool(&self, name: &str) -> Option<impl Iterator<Item = Option<bool>> + '_> {
+        let idx = self.names.iter().position(|n| n == name)?;
+
+        let _ = (&self.arrays[0])[idx]
+            .as_any()
+            .downcast_ref::<BooleanArray>()?;
+
+        let iter = self.arrays.iter().flat_map(move |arr| {
+            let arr = &arr[idx];
+            let arr = arr.as_any().downcast_ref::<BooleanArray>().unwrap();
+            arr.iter()
+        });
+
+        Some(iter)
+    }
+}
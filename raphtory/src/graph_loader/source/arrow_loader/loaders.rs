@@ -1,10 +1,10 @@
 use crate::{
     core::utils::errors::GraphError,
-    prelude::*,
-    python::graph::pandas::{
+    graph_loader::source::arrow_loader::{
         dataframe::PretendDF,
         prop_handler::{get_prop_rows, lift_layer},
     },
+    prelude::*,
 };
 use kdam::tqdm;
 use std::collections::HashMap;
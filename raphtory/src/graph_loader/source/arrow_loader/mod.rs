@@ -0,0 +1,614 @@
+//! Bulk loading of edges, vertices and properties from Arrow data.
+//!
+//! Columns are mapped by name to the time, source, destination, layer and property
+//! fields of the graph. Vertex ids can be either integers or strings and times must be
+//! `i64`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use raphtory::graph_loader::source::arrow_loader::load_edges_from_ipc;
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! load_edges_from_ipc(
+//!     &g,
+//!     "edges.arrow",
+//!     "src",
+//!     "dst",
+//!     "time",
+//!     Some(vec!["weight"]),
+//!     None,
+//!     None,
+//!     Some("layer"),
+//!     true,
+//! )
+//! .unwrap();
+//! ```
+
+pub(crate) mod dataframe;
+pub(crate) mod loaders;
+mod prop_handler;
+
+use crate::{
+    core::utils::errors::GraphError,
+    graph_loader::source::arrow_loader::{
+        dataframe::PretendDF,
+        loaders::{
+            load_edges_from_df, load_edges_props_from_df, load_vertex_props_from_df,
+            load_vertices_from_df,
+        },
+    },
+    prelude::*,
+};
+use arrow2::io::ipc::read::{read_file_metadata, FileReader};
+use itertools::Itertools;
+use std::{collections::HashMap, fs::File, path::Path};
+
+/// Record batches of a file, each holding the requested columns in order
+pub(crate) type Batches = Box<dyn Iterator<Item = Result<PretendDF, GraphError>>>;
+
+/// Stream the given columns of an Arrow IPC file one record batch at a time
+fn ipc_batches(path: &Path, columns: &[&str]) -> Result<Batches, GraphError> {
+    let mut file = File::open(path)?;
+    let metadata = read_file_metadata(&mut file)?;
+    let field_names: Vec<String> = metadata
+        .schema
+        .fields
+        .iter()
+        .map(|f| f.name.clone())
+        .collect();
+    let df = PretendDF {
+        names: field_names.clone(),
+        arrays: vec![],
+    };
+    df.check_cols_exist(columns)?;
+
+    // the same column can be requested more than once, e.g. as both time and property,
+    // but the IPC reader only accepts each column once. Columns are looked up by name so
+    // every requested name still finds its position in the deduplicated batch.
+    let names: Vec<String> = columns.iter().unique().map(|c| c.to_string()).collect();
+    let projection: Vec<usize> = names
+        .iter()
+        .map(|c| field_names.iter().position(|n| n == c).unwrap())
+        .collect();
+    let reader = FileReader::new(file, metadata, Some(projection), None);
+    Ok(Box::new(reader.map(move |chunk| {
+        Ok(PretendDF {
+            names: names.clone(),
+            arrays: vec![chunk?.into_arrays()],
+        })
+    })))
+}
+
+fn num_rows(df: &PretendDF) -> usize {
+    df.arrays
+        .iter()
+        .map(|chunk| chunk.first().map(|a| a.len()).unwrap_or(0))
+        .sum()
+}
+
+fn layer_column(layer: Option<&str>, layer_in_df: bool) -> Option<&str> {
+    layer.filter(|_| layer_in_df)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn load_edges<'a>(
+    graph: &Graph,
+    read: impl FnOnce(&[&str]) -> Result<Batches, GraphError>,
+    src: &str,
+    dst: &str,
+    time: &str,
+    props: Option<Vec<&'a str>>,
+    const_props: Option<Vec<&'a str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    layer: Option<&str>,
+    layer_in_df: bool,
+) -> Result<(), GraphError> {
+    let mut columns = vec![src, dst, time];
+    columns.extend(props.iter().flatten());
+    columns.extend(const_props.iter().flatten());
+    columns.extend(layer_column(layer, layer_in_df));
+    for df in read(&columns)? {
+        let df = df?;
+        load_edges_from_df(
+            &df,
+            num_rows(&df),
+            src,
+            dst,
+            time,
+            props.clone(),
+            const_props.clone(),
+            shared_const_props.clone(),
+            layer,
+            layer_in_df,
+            graph,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn load_vertices<'a>(
+    graph: &Graph,
+    read: impl FnOnce(&[&str]) -> Result<Batches, GraphError>,
+    id: &str,
+    time: &str,
+    props: Option<Vec<&'a str>>,
+    const_props: Option<Vec<&'a str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+) -> Result<(), GraphError> {
+    let mut columns = vec![id, time];
+    columns.extend(props.iter().flatten());
+    columns.extend(const_props.iter().flatten());
+    for df in read(&columns)? {
+        let df = df?;
+        load_vertices_from_df(
+            &df,
+            num_rows(&df),
+            id,
+            time,
+            props.clone(),
+            const_props.clone(),
+            shared_const_props.clone(),
+            graph,
+        )?;
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn load_edge_props(
+    graph: &Graph,
+    read: impl FnOnce(&[&str]) -> Result<Batches, GraphError>,
+    src: &str,
+    dst: &str,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    layer: Option<&str>,
+    layer_in_df: bool,
+) -> Result<(), GraphError> {
+    let mut columns = vec![src, dst];
+    columns.extend(const_props.iter().flatten());
+    columns.extend(layer_column(layer, layer_in_df));
+    for df in read(&columns)? {
+        let df = df?;
+        load_edges_props_from_df(
+            &df,
+            num_rows(&df),
+            src,
+            dst,
+            const_props.clone(),
+            shared_const_props.clone(),
+            layer,
+            layer_in_df,
+            graph,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn load_vertex_props(
+    graph: &Graph,
+    read: impl FnOnce(&[&str]) -> Result<Batches, GraphError>,
+    id: &str,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+) -> Result<(), GraphError> {
+    let mut columns = vec![id];
+    columns.extend(const_props.iter().flatten());
+    for df in read(&columns)? {
+        let df = df?;
+        load_vertex_props_from_df(
+            &df,
+            num_rows(&df),
+            id,
+            const_props.clone(),
+            shared_const_props.clone(),
+            graph,
+        )?;
+    }
+    Ok(())
+}
+
+/// Load edges from the Arrow IPC file at `path`
+///
+/// The file is read one record batch at a time.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to load the edges into
+/// * `path` - The path to the Arrow IPC file
+/// * `src` - The name of the source vertex column
+/// * `dst` - The name of the destination vertex column
+/// * `time` - The name of the time column
+/// * `props` - The names of the temporal property columns
+/// * `const_props` - The names of the constant property columns
+/// * `shared_const_props` - Constant properties added to every edge
+/// * `layer` - The layer name, or the name of the layer column if `layer_in_df` is true
+/// * `layer_in_df` - Whether `layer` refers to a column
+#[allow(clippy::too_many_arguments)]
+pub fn load_edges_from_ipc<P: AsRef<Path>>(
+    graph: &Graph,
+    path: P,
+    src: &str,
+    dst: &str,
+    time: &str,
+    props: Option<Vec<&str>>,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    layer: Option<&str>,
+    layer_in_df: bool,
+) -> Result<(), GraphError> {
+    load_edges(
+        graph,
+        |columns| ipc_batches(path.as_ref(), columns),
+        src,
+        dst,
+        time,
+        props,
+        const_props,
+        shared_const_props,
+        layer,
+        layer_in_df,
+    )
+}
+
+/// Load vertices from the Arrow IPC file at `path`
+///
+/// The file is read one record batch at a time.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to load the vertices into
+/// * `path` - The path to the Arrow IPC file
+/// * `id` - The name of the vertex id column
+/// * `time` - The name of the time column
+/// * `props` - The names of the temporal property columns
+/// * `const_props` - The names of the constant property columns
+/// * `shared_const_props` - Constant properties added to every vertex
+pub fn load_vertices_from_ipc<P: AsRef<Path>>(
+    graph: &Graph,
+    path: P,
+    id: &str,
+    time: &str,
+    props: Option<Vec<&str>>,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+) -> Result<(), GraphError> {
+    load_vertices(
+        graph,
+        |columns| ipc_batches(path.as_ref(), columns),
+        id,
+        time,
+        props,
+        const_props,
+        shared_const_props,
+    )
+}
+
+/// Load constant properties of existing edges from the Arrow IPC file at `path`
+#[allow(clippy::too_many_arguments)]
+pub fn load_edge_props_from_ipc<P: AsRef<Path>>(
+    graph: &Graph,
+    path: P,
+    src: &str,
+    dst: &str,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    layer: Option<&str>,
+    layer_in_df: bool,
+) -> Result<(), GraphError> {
+    load_edge_props(
+        graph,
+        |columns| ipc_batches(path.as_ref(), columns),
+        src,
+        dst,
+        const_props,
+        shared_const_props,
+        layer,
+        layer_in_df,
+    )
+}
+
+/// Load constant properties of existing vertices from the Arrow IPC file at `path`
+pub fn load_vertex_props_from_ipc<P: AsRef<Path>>(
+    graph: &Graph,
+    path: P,
+    id: &str,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+) -> Result<(), GraphError> {
+    load_vertex_props(
+        graph,
+        |columns| ipc_batches(path.as_ref(), columns),
+        id,
+        const_props,
+        shared_const_props,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        core::utils::errors::GraphError,
+        graph_loader::source::arrow_loader::{
+            dataframe::PretendDF,
+            load_edges_from_ipc, load_vertices_from_ipc,
+            loaders::{load_edges_from_df, load_vertices_from_df},
+        },
+        prelude::*,
+    };
+    use arrow2::{
+        array::{Array, PrimitiveArray, Utf8Array},
+        chunk::Chunk,
+        datatypes::{DataType, Field, Schema},
+        io::ipc::write::{FileWriter, WriteOptions},
+    };
+    use std::{fs::File, path::Path};
+    use tempdir::TempDir;
+
+    fn write_ipc(path: &Path, fields: Vec<Field>, chunks: Vec<Vec<Box<dyn Array>>>) {
+        let file = File::create(path).unwrap();
+        let mut writer = FileWriter::try_new(
+            file,
+            Schema::from(fields),
+            None,
+            WriteOptions { compression: None },
+        )
+        .unwrap();
+        for chunk in chunks {
+            writer.write(&Chunk::new(chunk), None).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn load_edges_from_pretend_df() {
+        let df = PretendDF {
+            names: vec!["src", "dst", "time", "prop1", "prop2"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            arrays: vec![
+                vec![
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(1)])),
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(2)])),
+                    Box::new(PrimitiveArray::<i64>::from(vec![Some(1)])),
+                    Box::new(PrimitiveArray::<f64>::from(vec![Some(1.0)])),
+                    Box::new(Utf8Array::<i32>::from(vec![Some("a")])),
+                ],
+                vec![
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(2), Some(3)])),
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(3), Some(4)])),
+                    Box::new(PrimitiveArray::<i64>::from(vec![Some(2), Some(3)])),
+                    Box::new(PrimitiveArray::<f64>::from(vec![Some(2.0), Some(3.0)])),
+                    Box::new(Utf8Array::<i32>::from(vec![Some("b"), Some("c")])),
+                ],
+            ],
+        };
+        let graph = Graph::new();
+        let layer: Option<&str> = None;
+        let layer_in_df: bool = true;
+        load_edges_from_df(
+            &df,
+            5,
+            "src",
+            "dst",
+            "time",
+            Some(vec!["prop1", "prop2"]),
+            None,
+            None,
+            layer,
+            layer_in_df,
+            &graph,
+        )
+        .expect("failed to load edges from pretend df");
+
+        let actual = graph
+            .edges()
+            .map(|e| {
+                (
+                    e.src().id(),
+                    e.dst().id(),
+                    e.latest_time(),
+                    e.properties()
+                        .temporal()
+                        .get("prop1")
+                        .and_then(|v| v.latest()),
+                    e.properties()
+                        .temporal()
+                        .get("prop2")
+                        .and_then(|v| v.latest()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                (1, 2, Some(1), Some(Prop::F64(1.0)), Some(Prop::str("a"))),
+                (2, 3, Some(2), Some(Prop::F64(2.0)), Some(Prop::str("b"))),
+                (3, 4, Some(3), Some(Prop::F64(3.0)), Some(Prop::str("c"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn load_vertices_from_pretend_df() {
+        let df = PretendDF {
+            names: vec!["id", "name", "time"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            arrays: vec![
+                vec![
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(1)])),
+                    Box::new(Utf8Array::<i32>::from(vec![Some("a")])),
+                    Box::new(PrimitiveArray::<i64>::from(vec![Some(1)])),
+                ],
+                vec![
+                    Box::new(PrimitiveArray::<u64>::from(vec![Some(2)])),
+                    Box::new(Utf8Array::<i32>::from(vec![Some("b")])),
+                    Box::new(PrimitiveArray::<i64>::from(vec![Some(2)])),
+                ],
+            ],
+        };
+        let graph = Graph::new();
+
+        load_vertices_from_df(&df, 3, "id", "time", Some(vec!["name"]), None, None, &graph)
+            .expect("failed to load vertices from pretend df");
+
+        let actual = graph
+            .vertices()
+            .iter()
+            .map(|v| {
+                (
+                    v.id(),
+                    v.latest_time(),
+                    v.properties()
+                        .temporal()
+                        .get("name")
+                        .and_then(|v| v.latest()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                (1, Some(1), Some(Prop::str("a"))),
+                (2, Some(2), Some(Prop::str("b"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn load_edges_from_ipc_file() {
+        let dir = TempDir::new("arrow_loader").unwrap();
+        let path = dir.path().join("edges.arrow");
+        write_ipc(
+            &path,
+            vec![
+                Field::new("src", DataType::UInt64, false),
+                Field::new("dst", DataType::UInt64, false),
+                Field::new("time", DataType::Int64, false),
+                Field::new("weight", DataType::Float64, true),
+                Field::new("layer", DataType::Utf8, true),
+            ],
+            vec![
+                vec![
+                    PrimitiveArray::<u64>::from_vec(vec![1, 2]).boxed(),
+                    PrimitiveArray::<u64>::from_vec(vec![2, 3]).boxed(),
+                    PrimitiveArray::<i64>::from_vec(vec![1, 2]).boxed(),
+                    PrimitiveArray::<f64>::from_vec(vec![1.0, 2.0]).boxed(),
+                    Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+                ],
+                vec![
+                    PrimitiveArray::<u64>::from_vec(vec![3]).boxed(),
+                    PrimitiveArray::<u64>::from_vec(vec![4]).boxed(),
+                    PrimitiveArray::<i64>::from_vec(vec![3]).boxed(),
+                    PrimitiveArray::<f64>::from_vec(vec![3.0]).boxed(),
+                    Utf8Array::<i32>::from_slice(["a"]).boxed(),
+                ],
+            ],
+        );
+
+        let graph = Graph::new();
+        load_edges_from_ipc(
+            &graph,
+            &path,
+            "src",
+            "dst",
+            "time",
+            Some(vec!["weight"]),
+            None,
+            None,
+            Some("layer"),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(graph.count_edges(), 3);
+        assert_eq!(graph.layer("a").unwrap().count_edges(), 2);
+        let weight = graph
+            .edge(3, 4)
+            .unwrap()
+            .properties()
+            .temporal()
+            .get("weight")
+            .and_then(|v| v.latest());
+        assert_eq!(weight, Some(Prop::F64(3.0)));
+    }
+
+    #[test]
+    fn load_ipc_file_with_repeated_column() {
+        let dir = TempDir::new("arrow_loader").unwrap();
+        let path = dir.path().join("edges.arrow");
+        write_ipc(
+            &path,
+            vec![
+                Field::new("src", DataType::UInt64, false),
+                Field::new("dst", DataType::UInt64, false),
+                Field::new("time", DataType::Int64, false),
+            ],
+            vec![vec![
+                PrimitiveArray::<u64>::from_vec(vec![1, 2]).boxed(),
+                PrimitiveArray::<u64>::from_vec(vec![2, 3]).boxed(),
+                PrimitiveArray::<i64>::from_vec(vec![1, 2]).boxed(),
+            ]],
+        );
+
+        let graph = Graph::new();
+        load_edges_from_ipc(
+            &graph,
+            &path,
+            "src",
+            "dst",
+            "time",
+            Some(vec!["src"]),
+            Some(vec!["time"]),
+            None,
+            None,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(graph.count_edges(), 2);
+        let e = graph.edge(2, 3).unwrap();
+        assert_eq!(
+            e.properties()
+                .temporal()
+                .get("src")
+                .and_then(|v| v.latest()),
+            Some(Prop::U64(2))
+        );
+        assert_eq!(e.properties().get("time"), Some(Prop::I64(2)));
+    }
+
+    #[test]
+    fn load_vertices_from_ipc_file() {
+        let dir = TempDir::new("arrow_loader").unwrap();
+        let path = dir.path().join("vertices.arrow");
+        write_ipc(
+            &path,
+            vec![
+                Field::new("id", DataType::UInt64, false),
+                Field::new("time", DataType::Int64, false),
+                Field::new("name", DataType::Utf8, true),
+            ],
+            vec![vec![
+                PrimitiveArray::<u64>::from_vec(vec![1, 2]).boxed(),
+                PrimitiveArray::<i64>::from_vec(vec![1, 2]).boxed(),
+                Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+            ]],
+        );
+
+        let graph = Graph::new();
+        load_vertices_from_ipc(&graph, &path, "id", "time", Some(vec!["name"]), None, None)
+            .unwrap();
+        assert_eq!(graph.count_vertices(), 2);
+
+        let missing = load_vertices_from_ipc(&graph, &path, "id", "ts", None, None, None);
+        assert!(matches!(missing, Err(GraphError::ColumnDoesNotExist(_))));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    core::utils::errors::GraphError, prelude::Prop, python::graph::pandas::dataframe::PretendDF,
+    core::utils::errors::GraphError, graph_loader::source::arrow_loader::dataframe::PretendDF,
+    prelude::Prop,
 };
 
 pub struct PropIter<'a> {
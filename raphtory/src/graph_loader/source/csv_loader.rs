@@ -59,7 +59,23 @@
 //!
 
 /// Module for loading CSV files into a graph.
+use crate::{
+    core::{
+        entities::properties::props::Meta,
+        utils::{
+            errors::GraphError,
+            time::{IntoTimeWithFormat, TryIntoTime},
+        },
+        Prop, PropType,
+    },
+    db::api::{
+        mutation::internal::{InternalAdditionOps, InternalPropertyAdditionOps},
+        view::{EdgeViewOps, GraphViewOps, Layer, LayerOps, VertexViewOps},
+    },
+    prelude::AdditionOps,
+};
 use bzip2::read::BzDecoder;
+use chrono::NaiveDateTime;
 use csv::StringRecord;
 use flate2; // 1.0
 use flate2::read::GzDecoder;
@@ -83,6 +99,8 @@ pub enum CsvErr {
     IoError(io::Error),
     /// A CSV parsing error that occurred while parsing the CSV data.
     CsvError(csv::Error),
+    /// The column mapping does not fit the file, e.g. a named column is missing from the header.
+    InvalidMapping { file: PathBuf, reason: String },
 }
 
 impl From<io::Error> for CsvErr {
@@ -99,9 +117,18 @@ impl From<csv::Error> for CsvErr {
 
 impl Display for CsvErr {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self.source() {
-            Some(error) => write!(f, "CSV loader failed with error: {}", error),
-            None => write!(f, "CSV loader failed with unknown error"),
+        match self {
+            CsvErr::InvalidMapping { file, reason } => {
+                write!(
+                    f,
+                    "CSV loader failed to map columns of {:?}: {}",
+                    file, reason
+                )
+            }
+            _ => match self.source() {
+                Some(error) => write!(f, "CSV loader failed with error: {}", error),
+                None => write!(f, "CSV loader failed with unknown error"),
+            },
         }
     }
 }
@@ -111,6 +138,7 @@ impl Error for CsvErr {
         match self {
             CsvErr::IoError(error) => Some(error),
             CsvErr::CsvError(error) => Some(error),
+            CsvErr::InvalidMapping { .. } => None,
         }
     }
 }
@@ -470,16 +498,514 @@ impl CsvLoader {
     }
 }
 
+/// A column of a CSV file, either by header name or by position.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CsvColumn {
+    Name(String),
+    Index(usize),
+}
+
+impl From<&str> for CsvColumn {
+    fn from(name: &str) -> Self {
+        CsvColumn::Name(name.to_owned())
+    }
+}
+
+impl From<String> for CsvColumn {
+    fn from(name: String) -> Self {
+        CsvColumn::Name(name)
+    }
+}
+
+impl From<usize> for CsvColumn {
+    fn from(index: usize) -> Self {
+        CsvColumn::Index(index)
+    }
+}
+
+impl Display for CsvColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvColumn::Name(name) => write!(f, "'{}'", name),
+            CsvColumn::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+/// Declarative description of how the columns of a CSV file map to graph updates.
+///
+/// Every row becomes one edge update (see [`CsvMapping::edges`]) or one vertex update
+/// (see [`CsvMapping::vertices`]). Properties are named after their column header, or after
+/// their position if the file has no header.
+///
+/// # Example
+///
+/// ```no_run
+/// use raphtory::core::PropType;
+/// use raphtory::graph_loader::source::csv_loader::{CsvLoader, CsvMapping};
+/// use raphtory::prelude::*;
+///
+/// let g = Graph::new();
+/// let mapping = CsvMapping::edges("sender", "receiver", "timestamp")
+///     .with_time_format("%Y-%m-%d %H:%M:%S")
+///     .with_layer("currency")
+///     .with_property("amount", PropType::F64);
+/// let report = CsvLoader::new("/path/to/transactions")
+///     .set_header(true)
+///     .load_with_mapping(&g, &mapping)
+///     .unwrap();
+/// for error in &report.errors {
+///     println!("{}", error);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvMapping {
+    time: CsvColumn,
+    time_format: Option<String>,
+    src: CsvColumn,
+    dst: Option<CsvColumn>,
+    layer: Option<CsvColumn>,
+    properties: Vec<(CsvColumn, PropType)>,
+    constant_properties: Vec<(CsvColumn, PropType)>,
+}
+
+impl CsvMapping {
+    /// Load every row as an edge update from `src` to `dst` at `time`
+    pub fn edges<S: Into<CsvColumn>, D: Into<CsvColumn>, T: Into<CsvColumn>>(
+        src: S,
+        dst: D,
+        time: T,
+    ) -> Self {
+        Self {
+            time: time.into(),
+            time_format: None,
+            src: src.into(),
+            dst: Some(dst.into()),
+            layer: None,
+            properties: vec![],
+            constant_properties: vec![],
+        }
+    }
+
+    /// Load every row as an update of vertex `id` at `time`
+    pub fn vertices<I: Into<CsvColumn>, T: Into<CsvColumn>>(id: I, time: T) -> Self {
+        Self {
+            time: time.into(),
+            time_format: None,
+            src: id.into(),
+            dst: None,
+            layer: None,
+            properties: vec![],
+            constant_properties: vec![],
+        }
+    }
+
+    /// Parse the time column with a `chrono` format string instead of as an integer or date
+    pub fn with_time_format(mut self, fmt: &str) -> Self {
+        self.time_format = Some(fmt.to_owned());
+        self
+    }
+
+    /// Read the edge layer from `column`, empty values go to the default layer
+    pub fn with_layer<C: Into<CsvColumn>>(mut self, column: C) -> Self {
+        self.layer = Some(column.into());
+        self
+    }
+
+    /// Add the values of `column` as temporal properties of type `dtype`
+    pub fn with_property<C: Into<CsvColumn>>(mut self, column: C, dtype: PropType) -> Self {
+        self.properties.push((column.into(), dtype));
+        self
+    }
+
+    /// Add the values of `column` as constant properties of type `dtype`
+    pub fn with_constant_property<C: Into<CsvColumn>>(
+        mut self,
+        column: C,
+        dtype: PropType,
+    ) -> Self {
+        self.constant_properties.push((column.into(), dtype));
+        self
+    }
+
+    /// Resolve column names against the header of a file
+    fn resolve(&self, header: Option<&StringRecord>) -> Result<ResolvedMapping, String> {
+        let index = |column: &CsvColumn| match (column, header) {
+            (CsvColumn::Index(i), _) => Ok(*i),
+            (CsvColumn::Name(name), Some(header)) => header
+                .iter()
+                .position(|h| h == name)
+                .ok_or_else(|| format!("column {} is not in the header", column)),
+            (CsvColumn::Name(_), None) => Err(format!(
+                "column {} is referenced by name but the file has no header",
+                column
+            )),
+        };
+        let property = |(column, dtype): &(CsvColumn, PropType)| {
+            if matches!(
+                dtype,
+                PropType::Empty | PropType::List | PropType::Map | PropType::Graph
+            ) {
+                return Err(format!(
+                    "property type {:?} of column {} can not be read from CSV",
+                    dtype, column
+                ));
+            }
+            let i = index(column)?;
+            let name = match column {
+                CsvColumn::Name(name) => name.clone(),
+                CsvColumn::Index(_) => header
+                    .and_then(|h| h.get(i))
+                    .map(|h| h.to_owned())
+                    .unwrap_or_else(|| i.to_string()),
+            };
+            Ok((i, name, *dtype))
+        };
+        Ok(ResolvedMapping {
+            time: index(&self.time)?,
+            time_format: self.time_format.clone(),
+            src: index(&self.src)?,
+            dst: self.dst.as_ref().map(index).transpose()?,
+            layer: self.layer.as_ref().map(index).transpose()?,
+            properties: self
+                .properties
+                .iter()
+                .map(property)
+                .collect::<Result<_, _>>()?,
+            constant_properties: self
+                .constant_properties
+                .iter()
+                .map(property)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// A [`CsvMapping`] with all columns resolved to positions
+struct ResolvedMapping {
+    time: usize,
+    time_format: Option<String>,
+    src: usize,
+    dst: Option<usize>,
+    layer: Option<usize>,
+    properties: Vec<(usize, String, PropType)>,
+    constant_properties: Vec<(usize, String, PropType)>,
+}
+
+impl ResolvedMapping {
+    fn field(record: &StringRecord, i: usize) -> Result<&str, String> {
+        record
+            .get(i)
+            .ok_or_else(|| format!("row has no column {}", i))
+    }
+
+    fn time(&self, record: &StringRecord) -> Result<i64, String> {
+        let value = Self::field(record, self.time)?;
+        let time = match &self.time_format {
+            Some(fmt) => value.parse_time(fmt).ok(),
+            None => value
+                .parse::<i64>()
+                .ok()
+                .or_else(|| value.try_into_time().ok()),
+        };
+        time.ok_or_else(|| format!("invalid time '{}'", value))
+    }
+
+    fn props(
+        record: &StringRecord,
+        columns: &[(usize, String, PropType)],
+    ) -> Result<Vec<(String, Prop)>, String> {
+        let mut props = Vec::with_capacity(columns.len());
+        for (i, name, dtype) in columns {
+            let value = Self::field(record, *i)?;
+            if value.is_empty() {
+                continue;
+            }
+            let prop = parse_prop(value, *dtype)
+                .ok_or_else(|| format!("invalid {:?} value '{}' for '{}'", dtype, value, name))?;
+            props.push((name.clone(), prop));
+        }
+        Ok(props)
+    }
+
+    /// Check that the property types match the graph and that constant properties do not
+    /// change an existing value, so that a row is rejected before anything is added
+    ///
+    /// Only existing property metadata is read, new property names are not registered until
+    /// the row is added.
+    fn check_props(
+        props: &[(String, Prop)],
+        constant_props: &[(String, Prop)],
+        meta: &Meta,
+        existing: impl Fn(&str) -> Option<Prop>,
+    ) -> Result<(), String> {
+        let check_type = |name: &str, prop: &Prop, is_static: bool| {
+            let mapper = if is_static {
+                meta.const_prop_meta()
+            } else {
+                meta.temporal_prop_meta()
+            };
+            let expected = mapper
+                .get_id(name)
+                .and_then(|id| mapper.get_dtype(id))
+                .filter(|dtype| !matches!(dtype, PropType::Empty));
+            match expected {
+                Some(expected) if expected != prop.dtype() => Err(GraphError::PropertyTypeError {
+                    name: name.to_owned(),
+                    expected,
+                    actual: prop.dtype(),
+                }
+                .to_string()),
+                _ => Ok(()),
+            }
+        };
+        for (name, prop) in props {
+            check_type(name, prop, false)?;
+        }
+        for (name, prop) in constant_props {
+            check_type(name, prop, true)?;
+            if let Some(old) = existing(name).filter(|old| old != prop) {
+                return Err(GraphError::ConstantPropertyMutationError {
+                    name: name.as_str().into(),
+                    old,
+                    new: prop.clone(),
+                }
+                .to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Register the property names of an accepted row
+    ///
+    /// Another file may have registered a name with a different type since the row was checked,
+    /// so this can still fail, but it does so before the row is added.
+    fn resolve_props(
+        props: &[(String, Prop)],
+        constant_props: &[(String, Prop)],
+        resolve: impl Fn(&str, PropType, bool) -> Result<usize, GraphError>,
+    ) -> Result<(), String> {
+        for (name, prop) in props {
+            resolve(name, prop.dtype(), false).map_err(|e| e.to_string())?;
+        }
+        for (name, prop) in constant_props {
+            resolve(name, prop.dtype(), true).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    /// Add the update described by `record` to `g`
+    ///
+    /// Every value of the row is parsed and checked first, so a row is either added completely
+    /// or not at all.
+    fn apply<G>(&self, record: &StringRecord, g: &G) -> Result<(), String>
+    where
+        G: GraphViewOps + InternalAdditionOps + InternalPropertyAdditionOps,
+    {
+        let time = self.time(record)?;
+        let src = Self::field(record, self.src)?;
+        let props = Self::props(record, &self.properties)?;
+        let constant_props = Self::props(record, &self.constant_properties)?;
+        match self.dst {
+            Some(dst) => {
+                let dst = Self::field(record, dst)?;
+                let layer = self
+                    .layer
+                    .map(|i| Self::field(record, i))
+                    .transpose()?
+                    .filter(|l| !l.is_empty());
+                let existing = g
+                    .edge(src, dst)
+                    .and_then(|e| e.layer(layer.map_or(Layer::Default, Layer::from)));
+                Self::check_props(&props, &constant_props, g.edge_meta(), |name| {
+                    existing.as_ref()?.properties().constant().get(name)
+                })?;
+                Self::resolve_props(&props, &constant_props, |name, dtype, is_static| {
+                    g.resolve_edge_property(name, dtype, is_static)
+                })?;
+                let edge = g
+                    .add_edge(time, src, dst, props, layer)
+                    .map_err(|e| e.to_string())?;
+                if !constant_props.is_empty() {
+                    edge.add_constant_properties(constant_props, layer)
+                        .map_err(|e| e.to_string())?;
+                }
+            }
+            None => {
+                let existing = g.vertex(src);
+                Self::check_props(&props, &constant_props, g.vertex_meta(), |name| {
+                    existing.as_ref()?.properties().constant().get(name)
+                })?;
+                Self::resolve_props(&props, &constant_props, |name, dtype, is_static| {
+                    g.resolve_vertex_property(name, dtype, is_static)
+                })?;
+                let vertex = g.add_vertex(time, src, props).map_err(|e| e.to_string())?;
+                if !constant_props.is_empty() {
+                    vertex
+                        .add_constant_properties(constant_props)
+                        .map_err(|e| e.to_string())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_prop(value: &str, dtype: PropType) -> Option<Prop> {
+    let prop = match dtype {
+        PropType::Str => Prop::str(value),
+        PropType::U8 => Prop::U8(value.parse().ok()?),
+        PropType::U16 => Prop::U16(value.parse().ok()?),
+        PropType::U32 => Prop::U32(value.parse().ok()?),
+        PropType::U64 => Prop::U64(value.parse().ok()?),
+        PropType::I32 => Prop::I32(value.parse().ok()?),
+        PropType::I64 => Prop::I64(value.parse().ok()?),
+        PropType::F32 => Prop::F32(value.parse().ok()?),
+        PropType::F64 => Prop::F64(value.parse().ok()?),
+        PropType::Bool => Prop::Bool(value.parse().ok()?),
+        PropType::DTime => Prop::DTime(NaiveDateTime::from_timestamp_millis(
+            value.try_into_time().ok()?,
+        )?),
+        PropType::Empty | PropType::List | PropType::Map | PropType::Graph => return None,
+    };
+    Some(prop)
+}
+
+/// A row that could not be loaded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvRowError {
+    /// The file containing the row
+    pub file: PathBuf,
+    /// The line of the row in the file, starting at 1
+    pub line: u64,
+    /// What went wrong
+    pub message: String,
+}
+
+impl Display for CsvRowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.file.display(), self.line, self.message)
+    }
+}
+
+/// Summary of loading files with a [`CsvMapping`].
+#[derive(Debug, Default)]
+pub struct CsvLoadReport {
+    /// Number of rows added to the graph
+    pub rows_loaded: usize,
+    /// Rows that were skipped
+    pub errors: Vec<CsvRowError>,
+}
+
+impl CsvLoadReport {
+    fn merge(mut self, other: Self) -> Self {
+        self.rows_loaded += other.rows_loaded;
+        self.errors.extend(other.errors);
+        self
+    }
+}
+
+impl CsvLoader {
+    /// Load all CSV files into a graph as described by `mapping`.
+    ///
+    /// Files are loaded in parallel. Rows that fail to parse or to be added to the graph are
+    /// skipped and reported with their file and line number in the returned report.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - The graph to load the data into.
+    /// * `mapping` - Which columns hold the time, vertices, layer and properties.
+    ///
+    /// Returns:
+    ///
+    /// A report of the number of loaded rows and the errors of skipped rows.
+    ///
+    /// # Errors
+    ///
+    /// An error of type CsvErr is returned if a file can not be read or if the mapping refers to
+    /// columns that are not in a file.
+    ///
+    pub fn load_with_mapping<G>(&self, g: &G, mapping: &CsvMapping) -> Result<CsvLoadReport, CsvErr>
+    where
+        G: GraphViewOps + InternalAdditionOps + InternalPropertyAdditionOps + Sync,
+    {
+        let paths = self.files_vec()?;
+        let mut report = paths
+            .par_iter()
+            .map(|path| self.load_file_with_mapping(path, g, mapping))
+            .try_reduce(CsvLoadReport::default, |a, b| Ok(a.merge(b)))?;
+        report
+            .errors
+            .sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+        Ok(report)
+    }
+
+    fn load_file_with_mapping<G>(
+        &self,
+        path: &Path,
+        g: &G,
+        mapping: &CsvMapping,
+    ) -> Result<CsvLoadReport, CsvErr>
+    where
+        G: GraphViewOps + InternalAdditionOps + InternalPropertyAdditionOps,
+    {
+        if self.print_file_name {
+            println!("Loading file: {:?}", path);
+        }
+        let mut csv_reader = self.csv_reader(path.to_path_buf())?;
+        let header = if self.header {
+            Some(csv_reader.headers()?.clone())
+        } else {
+            None
+        };
+        let resolved =
+            mapping
+                .resolve(header.as_ref())
+                .map_err(|reason| CsvErr::InvalidMapping {
+                    file: path.to_path_buf(),
+                    reason,
+                })?;
+
+        let mut report = CsvLoadReport::default();
+        let mut record = StringRecord::new();
+        loop {
+            let line = csv_reader.position().line();
+            let result = match csv_reader.read_record(&mut record) {
+                Ok(false) => break,
+                Ok(true) => resolved.apply(&record, g),
+                Err(error) => {
+                    if matches!(error.kind(), csv::ErrorKind::Io(_)) {
+                        return Err(error.into());
+                    }
+                    Err(error.to_string())
+                }
+            };
+            match result {
+                Ok(()) => report.rows_loaded += 1,
+                Err(message) => report.errors.push(CsvRowError {
+                    file: path.to_path_buf(),
+                    line,
+                    message,
+                }),
+            }
+        }
+        Ok(report)
+    }
+}
+
 #[cfg(test)]
 mod csv_loader_test {
     use crate::{
-        core::utils::hashing::calculate_hash, graph_loader::source::csv_loader::CsvLoader,
+        core::{utils::hashing::calculate_hash, PropType},
+        db::api::view::internal::CoreGraphOps,
+        graph_loader::source::csv_loader::{CsvErr, CsvLoader, CsvMapping},
         prelude::*,
     };
     use csv::StringRecord;
     use regex::Regex;
     use serde::Deserialize;
     use std::path::{Path, PathBuf};
+    use tempdir::TempDir;
 
     #[test]
     fn regex_match() {
@@ -651,4 +1177,130 @@ mod csv_loader_test {
         let delimiter = ".";
         lotr_test(g, csv_loader, has_header, delimiter, r);
     }
+
+    #[test]
+    fn load_edges_with_mapping() {
+        let dir = TempDir::new("csv_mapping").unwrap();
+        std::fs::write(
+            dir.path().join("a.csv"),
+            "time,src,dst,amount,currency\n\
+             2023-01-01 10:00:00,alice,bob,1.5,eur\n\
+             2023-01-01 11:00:00,bob,carol,oops,usd\n\
+             2023-01-01 12:00:00,carol,alice,,\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("b.csv"),
+            "time,src,dst,amount,currency\n\
+             not a time,alice,carol,2.0,eur\n\
+             2023-01-02 10:00:00,alice,carol,2.0\n\
+             2023-01-02 11:00:00,alice,carol,3.0,eur\n",
+        )
+        .unwrap();
+
+        let g = Graph::new();
+        let mapping = CsvMapping::edges("src", "dst", "time")
+            .with_time_format("%Y-%m-%d %H:%M:%S")
+            .with_layer("currency")
+            .with_property("amount", PropType::F64);
+        let report = CsvLoader::new(dir.path())
+            .set_header(true)
+            .load_with_mapping(&g, &mapping)
+            .unwrap();
+
+        assert_eq!(report.rows_loaded, 3);
+        let lines: Vec<_> = report
+            .errors
+            .iter()
+            .map(|e| (e.file.file_name().unwrap().to_str().unwrap(), e.line))
+            .collect();
+        assert_eq!(lines, vec![("a.csv", 3), ("b.csv", 2), ("b.csv", 3)]);
+
+        let e = g.edge("alice", "bob").unwrap();
+        assert_eq!(e.layer_names().collect::<Vec<_>>(), vec!["eur"]);
+        assert_eq!(e.properties().get("amount"), Some(Prop::F64(1.5)));
+        assert_eq!(e.earliest_time(), Some(1672567200000));
+        let e = g.edge("carol", "alice").unwrap();
+        assert_eq!(e.layer_names().collect::<Vec<_>>(), vec!["_default"]);
+        assert_eq!(e.properties().get("amount"), None);
+        assert!(g.edge("bob", "carol").is_none());
+    }
+
+    #[test]
+    fn load_vertices_by_index() {
+        let dir = TempDir::new("csv_mapping").unwrap();
+        let path = dir.path().join("vertices.csv");
+        std::fs::write(&path, "1,10,admin\n2,20,user\n").unwrap();
+
+        let g = Graph::new();
+        let mapping = CsvMapping::vertices(0, 1).with_constant_property(2, PropType::Str);
+        let report = CsvLoader::new(&path)
+            .load_with_mapping(&g, &mapping)
+            .unwrap();
+        assert_eq!(report.rows_loaded, 2);
+        assert!(report.errors.is_empty());
+        assert_eq!(
+            g.vertex(2).unwrap().properties().constant().get("2"),
+            Some(Prop::str("user"))
+        );
+        assert_eq!(g.vertex(1).unwrap().history(), vec![10]);
+
+        let missing = CsvMapping::vertices("id", 1);
+        assert!(matches!(
+            CsvLoader::new(&path).load_with_mapping(&g, &missing),
+            Err(CsvErr::InvalidMapping { .. })
+        ));
+    }
+
+    #[test]
+    fn rejected_rows_are_not_added() {
+        let dir = TempDir::new("csv_mapping").unwrap();
+        let path = dir.path().join("edges.csv");
+        std::fs::write(
+            &path,
+            "1,alice,bob,1.5,transfer\n\
+             2,alice,bob,2.5,refund\n\
+             3,bob,carol,1,gift\n",
+        )
+        .unwrap();
+
+        let g = Graph::new();
+        let mapping = CsvMapping::edges(1, 2, 0)
+            .with_property(3, PropType::F64)
+            .with_constant_property(4, PropType::Str);
+        let report = CsvLoader::new(&path)
+            .load_with_mapping(&g, &mapping)
+            .unwrap();
+
+        assert_eq!(report.rows_loaded, 2);
+        let lines: Vec<_> = report.errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![2]);
+        // the update with the conflicting constant property was not added
+        let e = g.edge("alice", "bob").unwrap();
+        assert_eq!(e.history(), vec![1]);
+        assert_eq!(
+            e.layer("_default").unwrap().properties().get("4"),
+            Some(Prop::str("transfer"))
+        );
+
+        // a constant property of the wrong type rejects the vertex before it is added
+        let path = dir.path().join("vertices.csv");
+        std::fs::write(&path, "1,10,admin,5\n").unwrap();
+        let g = Graph::new();
+        g.add_vertex(0, 9, NO_PROPS)
+            .unwrap()
+            .add_constant_properties([("2", 1i64)])
+            .unwrap();
+        let mapping = CsvMapping::vertices(0, 1)
+            .with_constant_property(2, PropType::Str)
+            .with_property(3, PropType::I64);
+        let report = CsvLoader::new(&path)
+            .load_with_mapping(&g, &mapping)
+            .unwrap();
+        assert_eq!(report.rows_loaded, 0);
+        assert_eq!(report.errors.len(), 1);
+        assert!(g.vertex(1).is_none());
+        // the properties of the rejected row are not registered
+        assert!(g.vertex_meta().get_prop_id("3", false).is_none());
+    }
 }
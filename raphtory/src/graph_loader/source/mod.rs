@@ -1,3 +1,7 @@
+#[cfg(feature = "arrow")]
+pub mod arrow_loader;
 pub mod csv_loader;
 pub mod json_loader;
 pub mod neo4j_loader;
+#[cfg(feature = "arrow")]
+pub mod parquet_loader;
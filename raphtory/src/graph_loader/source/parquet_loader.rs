@@ -0,0 +1,317 @@
+//! Bulk loading of edges, vertices and properties from Parquet files.
+//!
+//! The columns are mapped the same way as for the loaders in `arrow_loader`. Files are
+//! read one row group at a time and only the requested columns are decoded.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use raphtory::graph_loader::source::parquet_loader::load_edges_from_parquet;
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! load_edges_from_parquet(
+//!     &g,
+//!     "edges.parquet",
+//!     "src",
+//!     "dst",
+//!     "time",
+//!     Some(vec!["weight"]),
+//!     None,
+//!     None,
+//!     Some("layer"),
+//!     true,
+//! )
+//! .unwrap();
+//! ```
+
+use crate::{
+    core::utils::errors::GraphError,
+    graph_loader::source::arrow_loader::{
+        dataframe::PretendDF, load_edge_props, load_edges, load_vertex_props, load_vertices,
+        Batches,
+    },
+    prelude::*,
+};
+use arrow2::io::parquet::read::{infer_schema, read_metadata, FileReader};
+use std::{collections::HashMap, fs::File, path::Path};
+
+/// Stream the given columns of a Parquet file one row group at a time
+fn parquet_batches(path: &Path, columns: &[&str]) -> Result<Batches, GraphError> {
+    let mut file = File::open(path)?;
+    let metadata = read_metadata(&mut file)?;
+    let schema = infer_schema(&metadata)?;
+    let df = PretendDF {
+        names: schema.fields.iter().map(|f| f.name.clone()).collect(),
+        arrays: vec![],
+    };
+    df.check_cols_exist(columns)?;
+
+    let schema = schema.filter(|_, field| columns.contains(&field.name.as_str()));
+    let names: Vec<String> = schema.fields.iter().map(|f| f.name.clone()).collect();
+    let reader = FileReader::new(file, metadata.row_groups, schema, None, None, None);
+    Ok(Box::new(reader.map(move |chunk| {
+        Ok(PretendDF {
+            names: names.clone(),
+            arrays: vec![chunk?.into_arrays()],
+        })
+    })))
+}
+
+/// Load edges from the Parquet file at `path`
+///
+/// # Arguments
+///
+/// * `graph` - The graph to load the edges into
+/// * `path` - The path to the Parquet file
+/// * `src` - The name of the source vertex column
+/// * `dst` - The name of the destination vertex column
+/// * `time` - The name of the time column
+/// * `props` - The names of the temporal property columns
+/// * `const_props` - The names of the constant property columns
+/// * `shared_const_props` - Constant properties added to every edge
+/// * `layer` - The layer name, or the name of the layer column if `layer_in_df` is true
+/// * `layer_in_df` - Whether `layer` refers to a column
+#[allow(clippy::too_many_arguments)]
+pub fn load_edges_from_parquet<P: AsRef<Path>>(
+    graph: &Graph,
+    path: P,
+    src: &str,
+    dst: &str,
+    time: &str,
+    props: Option<Vec<&str>>,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    layer: Option<&str>,
+    layer_in_df: bool,
+) -> Result<(), GraphError> {
+    load_edges(
+        graph,
+        |columns| parquet_batches(path.as_ref(), columns),
+        src,
+        dst,
+        time,
+        props,
+        const_props,
+        shared_const_props,
+        layer,
+        layer_in_df,
+    )
+}
+
+/// Load vertices from the Parquet file at `path`
+///
+/// # Arguments
+///
+/// * `graph` - The graph to load the vertices into
+/// * `path` - The path to the Parquet file
+/// * `id` - The name of the vertex id column
+/// * `time` - The name of the time column
+/// * `props` - The names of the temporal property columns
+/// * `const_props` - The names of the constant property columns
+/// * `shared_const_props` - Constant properties added to every vertex
+pub fn load_vertices_from_parquet<P: AsRef<Path>>(
+    graph: &Graph,
+    path: P,
+    id: &str,
+    time: &str,
+    props: Option<Vec<&str>>,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+) -> Result<(), GraphError> {
+    load_vertices(
+        graph,
+        |columns| parquet_batches(path.as_ref(), columns),
+        id,
+        time,
+        props,
+        const_props,
+        shared_const_props,
+    )
+}
+
+/// Load constant properties of existing edges from the Parquet file at `path`
+#[allow(clippy::too_many_arguments)]
+pub fn load_edge_props_from_parquet<P: AsRef<Path>>(
+    graph: &Graph,
+    path: P,
+    src: &str,
+    dst: &str,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+    layer: Option<&str>,
+    layer_in_df: bool,
+) -> Result<(), GraphError> {
+    load_edge_props(
+        graph,
+        |columns| parquet_batches(path.as_ref(), columns),
+        src,
+        dst,
+        const_props,
+        shared_const_props,
+        layer,
+        layer_in_df,
+    )
+}
+
+/// Load constant properties of existing vertices from the Parquet file at `path`
+pub fn load_vertex_props_from_parquet<P: AsRef<Path>>(
+    graph: &Graph,
+    path: P,
+    id: &str,
+    const_props: Option<Vec<&str>>,
+    shared_const_props: Option<HashMap<String, Prop>>,
+) -> Result<(), GraphError> {
+    load_vertex_props(
+        graph,
+        |columns| parquet_batches(path.as_ref(), columns),
+        id,
+        const_props,
+        shared_const_props,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        core::utils::errors::GraphError,
+        graph_loader::source::parquet_loader::{
+            load_edges_from_parquet, load_vertex_props_from_parquet,
+        },
+        prelude::*,
+    };
+    use arrow2::{
+        array::{Array, PrimitiveArray, Utf8Array},
+        chunk::Chunk,
+        datatypes::{DataType, Field, Schema},
+        io::parquet::write::{
+            CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+        },
+    };
+    use std::{fs::File, path::Path};
+    use tempdir::TempDir;
+
+    fn write_parquet(path: &Path, fields: Vec<Field>, row_groups: Vec<Vec<Box<dyn Array>>>) {
+        let schema = Schema::from(fields);
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: CompressionOptions::Uncompressed,
+            version: Version::V2,
+            data_pagesize_limit: None,
+        };
+        let encodings = schema
+            .fields
+            .iter()
+            .map(|_| vec![Encoding::Plain])
+            .collect();
+        let row_groups = RowGroupIterator::try_new(
+            row_groups.into_iter().map(|arrays| Ok(Chunk::new(arrays))),
+            &schema,
+            options,
+            encodings,
+        )
+        .unwrap();
+        let mut writer = FileWriter::try_new(File::create(path).unwrap(), schema, options).unwrap();
+        for group in row_groups {
+            writer.write(group.unwrap()).unwrap();
+        }
+        writer.end(None).unwrap();
+    }
+
+    #[test]
+    fn load_edges_from_parquet_file() {
+        let dir = TempDir::new("parquet_loader").unwrap();
+        let path = dir.path().join("edges.parquet");
+        write_parquet(
+            &path,
+            vec![
+                Field::new("time", DataType::Int64, false),
+                Field::new("src", DataType::Utf8, false),
+                Field::new("dst", DataType::Utf8, false),
+                Field::new("weight", DataType::Float64, true),
+                Field::new("layer", DataType::Utf8, true),
+            ],
+            vec![
+                vec![
+                    PrimitiveArray::<i64>::from_vec(vec![1, 2]).boxed(),
+                    Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+                    Utf8Array::<i32>::from_slice(["b", "c"]).boxed(),
+                    PrimitiveArray::<f64>::from(vec![Some(1.0), None]).boxed(),
+                    Utf8Array::<i32>::from_slice(["x", "y"]).boxed(),
+                ],
+                vec![
+                    PrimitiveArray::<i64>::from_vec(vec![3]).boxed(),
+                    Utf8Array::<i32>::from_slice(["c"]).boxed(),
+                    Utf8Array::<i32>::from_slice(["d"]).boxed(),
+                    PrimitiveArray::<f64>::from_vec(vec![3.0]).boxed(),
+                    Utf8Array::<i32>::from_slice(["x"]).boxed(),
+                ],
+            ],
+        );
+
+        let graph = Graph::new();
+        load_edges_from_parquet(
+            &graph,
+            &path,
+            "src",
+            "dst",
+            "time",
+            Some(vec!["weight"]),
+            None,
+            None,
+            Some("layer"),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(graph.count_edges(), 3);
+        assert_eq!(graph.layer("x").unwrap().count_edges(), 2);
+        assert_eq!(graph.edge("c", "d").unwrap().history(), vec![3]);
+        assert_eq!(
+            graph
+                .edge("c", "d")
+                .unwrap()
+                .properties()
+                .temporal()
+                .get("weight")
+                .and_then(|v| v.latest()),
+            Some(Prop::F64(3.0))
+        );
+        assert!(graph
+            .edge("b", "c")
+            .unwrap()
+            .properties()
+            .temporal()
+            .get("weight")
+            .is_none());
+    }
+
+    #[test]
+    fn load_vertex_props_from_parquet_file() {
+        let dir = TempDir::new("parquet_loader").unwrap();
+        let path = dir.path().join("vertices.parquet");
+        write_parquet(
+            &path,
+            vec![
+                Field::new("id", DataType::UInt64, false),
+                Field::new("kind", DataType::Utf8, true),
+            ],
+            vec![vec![
+                PrimitiveArray::<u64>::from_vec(vec![1, 2]).boxed(),
+                Utf8Array::<i32>::from_slice(["user", "item"]).boxed(),
+            ]],
+        );
+
+        let graph = Graph::new();
+        graph.add_vertex(0, 1, NO_PROPS).unwrap();
+        graph.add_vertex(0, 2, NO_PROPS).unwrap();
+        load_vertex_props_from_parquet(&graph, &path, "id", Some(vec!["kind"]), None).unwrap();
+        assert_eq!(
+            graph.vertex(2).unwrap().properties().constant().get("kind"),
+            Some(Prop::str("item"))
+        );
+
+        let missing = load_vertex_props_from_parquet(&graph, &path, "vertex", None, None);
+        assert!(matches!(missing, Err(GraphError::ColumnDoesNotExist(_))));
+    }
+}
@@ -0,0 +1,147 @@
+//! Generates a temporal graph using the activity-driven model
+//!
+//! Every vertex has an activity drawn from a power-law distribution. At every time step each
+//! vertex becomes active with probability equal to its activity and then connects to randomly
+//! chosen vertices. The connections only exist at that time step, so the aggregated graph is
+//! heterogeneous while every snapshot is sparse.
+//!
+//! This function is a graph generation model based upon:
+//! Perra, Nicola, et al. "Activity driven modeling of time varying networks."
+//! Scientific Reports 2.1 (2012): 469.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::activity_driven::activity_driven;
+//! let graph = Graph::new();
+//! activity_driven(&graph, 1000, 100, 2, 2.1, 0.01, Some(42));
+//! ```
+
+use crate::{
+    db::{api::mutation::AdditionOps, graph::graph::Graph},
+    graphgen::add_vertices,
+    prelude::NO_PROPS,
+};
+use rand::{rngs::StdRng, seq::index::sample, Rng, SeedableRng};
+
+/// Sample from a power law `F(a) ~ a^(-exponent)` between `min_activity` and 1
+fn sample_activity<R: Rng>(rng: &mut R, exponent: f64, min_activity: f64) -> f64 {
+    let u: f64 = rng.gen();
+    if (exponent - 1.0).abs() < f64::EPSILON {
+        min_activity.powf(1.0 - u)
+    } else {
+        let e = 1.0 - exponent;
+        let low = min_activity.powf(e);
+        (low + u * (1.0 - low)).powf(1.0 / e)
+    }
+}
+
+/// Adds `vertices_to_add` vertices to the graph and simulates `steps` time steps of the
+/// activity-driven model. At each step, every active vertex adds edges to
+/// `edges_per_activation` distinct other new vertices chosen uniformly at random.
+///
+/// The activity of each vertex is drawn from `F(a) ~ a^(-exponent)` for `min_activity <= a <= 1`.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add vertices and edges to
+/// * `vertices_to_add` - The amount of vertices you wish to add to the graph
+/// * `steps` - The number of time steps to simulate
+/// * `edges_per_activation` - The number of edges an active vertex adds
+/// * `exponent` - The exponent of the activity distribution
+/// * `min_activity` - The smallest possible activity, needs to be larger than 0
+/// * `seed` - The seed of the random number generator
+pub fn activity_driven(
+    graph: &Graph,
+    vertices_to_add: usize,
+    steps: usize,
+    edges_per_activation: usize,
+    exponent: f64,
+    min_activity: f64,
+    seed: Option<u64>,
+) {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let (first_id, mut time) = add_vertices(graph, vertices_to_add);
+    let n = vertices_to_add;
+    if n < 2 {
+        return;
+    }
+    let activities: Vec<f64> = (0..n)
+        .map(|_| sample_activity(&mut rng, exponent, min_activity))
+        .collect();
+    let m = edges_per_activation.min(n - 1);
+
+    for _ in 0..steps {
+        for (v, activity) in activities.iter().enumerate() {
+            if rng.gen::<f64>() >= *activity {
+                continue;
+            }
+            for target in sample(&mut rng, n - 1, m) {
+                // skip over the active vertex itself
+                let target = if target >= v { target + 1 } else { target };
+                graph
+                    .add_edge(
+                        time,
+                        first_id + v as u64,
+                        first_id + target as u64,
+                        NO_PROPS,
+                        None,
+                    )
+                    .expect("Not able to add edge");
+            }
+        }
+        time += 1;
+    }
+}
+
+#[cfg(test)]
+mod activity_driven_test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn activations_per_step() {
+        // every vertex is active at every step
+        let graph = Graph::new();
+        activity_driven(&graph, 10, 5, 3, 2.0, 1.0, Some(1));
+        assert_eq!(graph.count_vertices(), 10);
+        assert_eq!(graph.edges().explode().count(), 10 * 5 * 3);
+        assert_eq!(
+            graph
+                .edges()
+                .explode()
+                .filter(|e| e.time() == Some(3))
+                .count(),
+            30
+        );
+        assert!(graph.edges().all(|e| e.src().id() != e.dst().id()));
+        assert!(graph.vertices().iter().all(|v| v.out_degree() >= 3));
+    }
+
+    #[test]
+    fn activities_and_seeds() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for exponent in [1.0, 2.5] {
+            let activities: Vec<f64> = (0..1000)
+                .map(|_| sample_activity(&mut rng, exponent, 0.01))
+                .collect();
+            assert!(activities.iter().all(|a| (0.01..=1.0).contains(a)));
+            // heavy tailed, most activities are small
+            let small = activities.iter().filter(|a| **a < 0.1).count();
+            assert!(small > 500, "{small}");
+        }
+
+        let graph = Graph::new();
+        activity_driven(&graph, 200, 50, 2, 2.1, 0.01, Some(8));
+        let other = Graph::new();
+        activity_driven(&other, 200, 50, 2, 2.1, 0.01, Some(8));
+        let edges = |g: &Graph| -> Vec<(u64, u64, Option<i64>)> {
+            g.edges()
+                .explode()
+                .map(|e| (e.src().id(), e.dst().id(), e.time()))
+                .collect()
+        };
+        assert_eq!(edges(&graph), edges(&other));
+        assert!(graph.edges().explode().all(|e| e.time().unwrap() <= 50));
+    }
+}
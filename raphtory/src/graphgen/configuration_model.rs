@@ -0,0 +1,90 @@
+//! Generates a graph with a given degree sequence using the configuration model
+//!
+//! Every vertex gets as many edge stubs as its degree and the stubs are matched uniformly at
+//! random, see Newman, Mark EJ. "The structure and function of complex networks." SIAM Review
+//! 45.2 (2003): 167-256.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::configuration_model::configuration_model;
+//! let graph = Graph::new();
+//! configuration_model(&graph, &[3, 2, 2, 2, 1], Some(42));
+//! ```
+
+use crate::{
+    db::{api::mutation::AdditionOps, graph::graph::Graph},
+    graphgen::add_vertices,
+    prelude::NO_PROPS,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+/// Adds a vertex for every entry of `degrees` and connects them by randomly matching edge
+/// stubs, such that the `i`-th new vertex takes part in `degrees[i]` edge updates.
+///
+/// **Note:** The matching can produce self-loops and repeated edges, which are added as
+/// further updates of the same edge at a later time. If the degrees sum to an odd number, one
+/// stub is left unmatched.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add vertices and edges to
+/// * `degrees` - The degree of each new vertex
+/// * `seed` - The seed of the random number generator
+pub fn configuration_model(graph: &Graph, degrees: &[usize], seed: Option<u64>) {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let (first_id, mut time) = add_vertices(graph, degrees.len());
+    let mut stubs: Vec<u64> = degrees
+        .iter()
+        .enumerate()
+        .flat_map(|(i, d)| std::iter::repeat(first_id + i as u64).take(*d))
+        .collect();
+    stubs.shuffle(&mut rng);
+    for pair in stubs.chunks_exact(2) {
+        graph
+            .add_edge(time, pair[0], pair[1], NO_PROPS, None)
+            .expect("Not able to add edge");
+        time += 1;
+    }
+}
+
+#[cfg(test)]
+mod configuration_model_test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn degrees_are_kept() {
+        let degrees = [4, 3, 3, 2, 2, 1, 1, 0];
+        let graph = Graph::new();
+        configuration_model(&graph, &degrees, Some(2));
+        assert_eq!(graph.count_vertices(), 8);
+        let mut updates = vec![0usize; degrees.len()];
+        for e in graph.edges().explode() {
+            updates[e.src().id() as usize - 1] += 1;
+            updates[e.dst().id() as usize - 1] += 1;
+        }
+        assert_eq!(updates, degrees);
+        assert_eq!(graph.edges().explode().count(), 8);
+    }
+
+    #[test]
+    fn odd_degrees_and_seeds() {
+        let degrees: Vec<usize> = (0..50).map(|i| i % 5 + 1).collect();
+        let graph = Graph::new();
+        configuration_model(&graph, &degrees, Some(4));
+        // the degrees sum to 150
+        assert_eq!(graph.edges().explode().count(), 75);
+
+        let other = Graph::new();
+        configuration_model(&other, &degrees, Some(4));
+        let edges = |g: &Graph| -> Vec<(u64, u64)> {
+            g.edges().map(|e| (e.src().id(), e.dst().id())).collect()
+        };
+        assert_eq!(edges(&graph), edges(&other));
+
+        let graph = Graph::new();
+        configuration_model(&graph, &[1, 1, 1], Some(4));
+        assert_eq!(graph.count_edges(), 1);
+    }
+}
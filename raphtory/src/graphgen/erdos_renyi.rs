@@ -0,0 +1,120 @@
+//! Generates a graph using the Erdős–Rényi model
+//!
+//! Every pair of the new vertices is connected independently with the same probability,
+//! see Erdős, Paul, and Alfréd Rényi. "On random graphs I." Publicationes Mathematicae
+//! Debrecen 6 (1959): 290-297.
+//! Pairs are sampled by skipping geometrically distributed gaps as in Batagelj, Vladimir,
+//! and Ulrik Brandes. "Efficient generation of large random networks." Physical Review E
+//! 71.3 (2005): 036113, so the run time is linear in the number of vertices and edges.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::erdos_renyi::erdos_renyi;
+//! let graph = Graph::new();
+//! erdos_renyi(&graph, 1000, 0.01, Some(42));
+//! ```
+
+use crate::{
+    db::{api::mutation::AdditionOps, graph::graph::Graph},
+    graphgen::add_vertices,
+    prelude::NO_PROPS,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Adds `vertices_to_add` vertices to the graph and connects every pair of them with
+/// probability `edge_probability`.
+///
+/// Each pair is added at most once as an edge from the smaller to the larger id. The edges are
+/// added one time step after another in the order they are sampled.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add vertices and edges to
+/// * `vertices_to_add` - The amount of vertices you wish to add to the graph
+/// * `edge_probability` - The probability of an edge between any two new vertices
+/// * `seed` - The seed of the random number generator
+pub fn erdos_renyi(
+    graph: &Graph,
+    vertices_to_add: usize,
+    edge_probability: f64,
+    seed: Option<u64>,
+) {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let (first_id, mut time) = add_vertices(graph, vertices_to_add);
+    if edge_probability <= 0.0 {
+        return;
+    }
+    let log_q = (1.0 - edge_probability).ln();
+    let mut add_edge = |src: usize, dst: usize| {
+        graph
+            .add_edge(
+                time,
+                first_id + src as u64,
+                first_id + dst as u64,
+                NO_PROPS,
+                None,
+            )
+            .expect("Not able to add edge");
+        time += 1;
+    };
+
+    let n = vertices_to_add;
+    let (mut v, mut w) = (1usize, -1i64);
+    while v < n {
+        let skip = if edge_probability >= 1.0 {
+            0
+        } else {
+            ((1.0 - rng.gen::<f64>()).ln() / log_q).floor() as i64
+        };
+        w += 1 + skip;
+        while w >= v as i64 && v < n {
+            w -= v as i64;
+            v += 1;
+        }
+        if v < n {
+            add_edge(w as usize, v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod erdos_renyi_test {
+    use super::*;
+    use crate::db::api::view::*;
+
+    #[test]
+    fn complete_and_empty_graphs() {
+        let graph = Graph::new();
+        erdos_renyi(&graph, 20, 1.0, None);
+        assert_eq!(graph.count_vertices(), 20);
+        assert_eq!(graph.count_edges(), 190);
+
+        let graph = Graph::new();
+        erdos_renyi(&graph, 20, 0.0, None);
+        assert_eq!(graph.count_vertices(), 20);
+        assert_eq!(graph.count_edges(), 0);
+    }
+
+    #[test]
+    fn edge_density_and_seeds() {
+        let graph = Graph::new();
+        erdos_renyi(&graph, 1000, 0.01, Some(3));
+        // 4995 edges are expected with a standard deviation of about 70
+        let edges = graph.count_edges();
+        assert!((4600..5400).contains(&edges), "{edges}");
+        assert!(graph.edges().all(|e| e.src().id() < e.dst().id()));
+
+        let other = Graph::new();
+        erdos_renyi(&other, 1000, 0.01, Some(3));
+        let edges = |g: &Graph| -> Vec<(u64, u64)> {
+            g.edges().map(|e| (e.src().id(), e.dst().id())).collect()
+        };
+        assert_eq!(edges(&graph), edges(&other));
+
+        // new vertices follow the existing ones
+        erdos_renyi(&graph, 10, 0.5, Some(3));
+        assert_eq!(graph.count_vertices(), 1010);
+        assert_eq!(graph.vertices().id().max(), Some(1010));
+    }
+}
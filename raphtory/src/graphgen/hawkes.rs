@@ -0,0 +1,152 @@
+//! Generates bursty temporal edges using Hawkes processes
+//!
+//! The updates of every edge follow a self-exciting Hawkes process with intensity
+//! `mu + sum(alpha * exp(-beta * (t - t_i)))` over the previous updates `t_i`, so every update
+//! makes further updates more likely for a while. This produces the bursts of activity seen in
+//! communication networks, see Hawkes, Alan G. "Spectra of some self-exciting and mutually
+//! exciting point processes." Biometrika 58.1 (1971): 83-90.
+//! The processes are simulated with Ogata's thinning algorithm.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::hawkes::hawkes_edges;
+//! let graph = Graph::new();
+//! hawkes_edges(&graph, 100, 200, 0.01, 0.5, 1.0, 1000, Some(42));
+//! ```
+
+use crate::{
+    db::{api::mutation::AdditionOps, graph::graph::Graph},
+    graphgen::add_vertices,
+    prelude::NO_PROPS,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// Sample the event times of a Hawkes process in `[0, duration)`
+fn hawkes_times<R: Rng>(rng: &mut R, mu: f64, alpha: f64, beta: f64, duration: f64) -> Vec<f64> {
+    let mut times = vec![];
+    let mut t = 0.0;
+    // the excitation of the previous events at time t
+    let mut excitation = 0.0;
+    loop {
+        let bound = mu + excitation;
+        let wait = -(1.0 - rng.gen::<f64>()).ln() / bound;
+        t += wait;
+        if !t.is_finite() || t >= duration {
+            return times;
+        }
+        excitation *= (-beta * wait).exp();
+        if rng.gen::<f64>() * bound <= mu + excitation {
+            times.push(t);
+            excitation += alpha;
+        }
+    }
+}
+
+/// Adds `vertices_to_add` vertices to the graph and picks `edges_to_add` distinct pairs of
+/// them uniformly at random. The updates of every pair follow an independent Hawkes process
+/// with base rate `mu`, excitation `alpha` and decay `beta` over `duration` time units
+/// starting after the latest time of the graph. Times are rounded down to whole time units.
+///
+/// A pair only becomes an edge if its process has at least one update within `duration`, so
+/// the graph ends up with fewer than `edges_to_add` new edges when `mu * duration` is small.
+///
+/// **Note:** The processes are only stable if `alpha < beta`, otherwise the number of updates
+/// grows exponentially with the duration.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add vertices and edges to
+/// * `vertices_to_add` - The amount of vertices you wish to add to the graph
+/// * `edges_to_add` - The amount of distinct vertex pairs to simulate, limited by the number
+///   of vertex pairs
+/// * `mu` - The base rate of updates of an edge
+/// * `alpha` - How much each update increases the rate of further updates
+/// * `beta` - How fast the excitation of an update decays
+/// * `duration` - The length of the simulated time span
+/// * `seed` - The seed of the random number generator
+#[allow(clippy::too_many_arguments)]
+pub fn hawkes_edges(
+    graph: &Graph,
+    vertices_to_add: usize,
+    edges_to_add: usize,
+    mu: f64,
+    alpha: f64,
+    beta: f64,
+    duration: i64,
+    seed: Option<u64>,
+) {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let (first_id, start) = add_vertices(graph, vertices_to_add);
+    let n = vertices_to_add;
+    let edges_to_add = edges_to_add.min(n * n.saturating_sub(1));
+
+    let mut pairs: Vec<(usize, usize)> = Vec::with_capacity(edges_to_add);
+    let mut seen: HashSet<(usize, usize)> = HashSet::new();
+    while pairs.len() < edges_to_add {
+        let src = rng.gen_range(0..n);
+        let dst = rng.gen_range(0..n);
+        if src != dst && seen.insert((src, dst)) {
+            pairs.push((src, dst));
+        }
+    }
+
+    for (src, dst) in pairs {
+        for t in hawkes_times(&mut rng, mu, alpha, beta, duration as f64) {
+            graph
+                .add_edge(
+                    start + t as i64,
+                    first_id + src as u64,
+                    first_id + dst as u64,
+                    NO_PROPS,
+                    None,
+                )
+                .expect("Not able to add edge");
+        }
+    }
+}
+
+#[cfg(test)]
+mod hawkes_test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn event_rates() {
+        let mut rng = StdRng::seed_from_u64(1);
+        // without excitation this is a Poisson process with 10000 expected events
+        let poisson = hawkes_times(&mut rng, 1.0, 0.0, 1.0, 10000.0);
+        assert!((9500..10500).contains(&poisson.len()), "{}", poisson.len());
+        assert!(poisson.windows(2).all(|w| w[0] < w[1]));
+        // the stationary rate is mu / (1 - alpha / beta) = 2
+        let hawkes = hawkes_times(&mut rng, 1.0, 0.5, 1.0, 10000.0);
+        assert!((18000..22000).contains(&hawkes.len()), "{}", hawkes.len());
+        assert!(hawkes.iter().all(|t| (0.0..10000.0).contains(t)));
+    }
+
+    #[test]
+    fn bursty_edges() {
+        let graph = Graph::new();
+        graph.add_edge(10, 1, 2, NO_PROPS, None).unwrap();
+        hawkes_edges(&graph, 20, 30, 0.05, 0.8, 1.0, 500, Some(6));
+        assert_eq!(graph.count_vertices(), 22);
+        let new_edges: Vec<_> = graph.edges().filter(|e| e.src().id() > 2).collect();
+        assert!(new_edges.len() <= 30);
+        assert!(graph
+            .edges()
+            .explode()
+            .all(|e| e.time() == Some(10) || (11..511).contains(&e.time().unwrap())));
+
+        let other = Graph::new();
+        other.add_edge(10, 1, 2, NO_PROPS, None).unwrap();
+        hawkes_edges(&other, 20, 30, 0.05, 0.8, 1.0, 500, Some(6));
+        let edges = |g: &Graph| -> Vec<(u64, u64, Option<i64>)> {
+            g.edges()
+                .explode()
+                .map(|e| (e.src().id(), e.dst().id(), e.time()))
+                .collect()
+        };
+        assert_eq!(edges(&graph), edges(&other));
+    }
+}
@@ -1,4 +1,33 @@
 //! Provides functionality for generating graphs for testing and benchmarking.
 
+use crate::{
+    db::{
+        api::{mutation::AdditionOps, view::*},
+        graph::graph::Graph,
+    },
+    prelude::NO_PROPS,
+};
+
+pub mod activity_driven;
+pub mod configuration_model;
+pub mod erdos_renyi;
+pub mod hawkes;
 pub mod preferential_attachment;
 pub mod random_attachment;
+pub mod stochastic_block_model;
+pub mod watts_strogatz;
+
+/// Adds `n` new vertices to the graph at its latest time, the new vertices get the ids following
+/// the largest id in the graph.
+///
+/// Returns the id of the first new vertex and the time to add the first new edge at
+pub(crate) fn add_vertices(graph: &Graph, n: usize) -> (u64, i64) {
+    let first_id = graph.vertices().id().max().unwrap_or(0) + 1;
+    let time = graph.latest_time().unwrap_or(0) + 1;
+    for id in first_id..first_id + n as u64 {
+        graph
+            .add_vertex(time, id, NO_PROPS)
+            .expect("Not able to add vertex");
+    }
+    (first_id, time)
+}
@@ -0,0 +1,176 @@
+//! Generates a graph using the stochastic block model
+//!
+//! The new vertices are split into blocks and two vertices are connected with a probability
+//! that only depends on their blocks, see Holland, Paul W., Kathryn Blackmond Laskey, and
+//! Samuel Leinhardt. "Stochastic blockmodels: First steps." Social Networks 5.2 (1983):
+//! 109-137.
+//! Pairs are sampled per pair of blocks by skipping geometrically distributed gaps, so the
+//! run time is linear in the number of vertices and edges.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::stochastic_block_model::stochastic_block_model;
+//! let graph = Graph::new();
+//! stochastic_block_model(
+//!     &graph,
+//!     &[50, 50],
+//!     &[vec![0.2, 0.01], vec![0.01, 0.2]],
+//!     Some(42),
+//! );
+//! ```
+
+use crate::{
+    db::{
+        api::{mutation::AdditionOps, view::*},
+        graph::graph::Graph,
+    },
+    graphgen::add_vertices,
+    prelude::NO_PROPS,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Adds a block of new vertices for every entry of `block_sizes` and connects every pair of
+/// new vertices `u`, `v` with probability `edge_probabilities[block(u)][block(v)]`.
+///
+/// Each pair is added at most once as an edge from the smaller to the larger id, so only the
+/// upper triangle of `edge_probabilities` is used. The edges are added one time step after
+/// another, one pair of blocks after the other, and the index of the block of each vertex is
+/// stored in its constant property `block`.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add vertices and edges to
+/// * `block_sizes` - The number of vertices in each block
+/// * `edge_probabilities` - The probability of an edge between vertices of any two blocks
+/// * `seed` - The seed of the random number generator
+pub fn stochastic_block_model(
+    graph: &Graph,
+    block_sizes: &[usize],
+    edge_probabilities: &[Vec<f64>],
+    seed: Option<u64>,
+) {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let n: usize = block_sizes.iter().sum();
+    let (first_id, mut time) = add_vertices(graph, n);
+    let blocks: Vec<usize> = block_sizes
+        .iter()
+        .enumerate()
+        .flat_map(|(b, size)| std::iter::repeat(b).take(*size))
+        .collect();
+    for (i, block) in blocks.iter().enumerate() {
+        if let Some(v) = graph.vertex(first_id + i as u64) {
+            v.add_constant_properties([("block", *block as u64)])
+                .expect("Not able to add block property");
+        }
+    }
+
+    let mut add_edge = |src: usize, dst: usize| {
+        graph
+            .add_edge(
+                time,
+                first_id + src as u64,
+                first_id + dst as u64,
+                NO_PROPS,
+                None,
+            )
+            .expect("Not able to add edge");
+        time += 1;
+    };
+
+    let starts: Vec<usize> = block_sizes
+        .iter()
+        .scan(0, |next, size| {
+            let start = *next;
+            *next += size;
+            Some(start)
+        })
+        .collect();
+    for a in 0..block_sizes.len() {
+        for b in a..block_sizes.len() {
+            let p = edge_probabilities
+                .get(a)
+                .and_then(|row| row.get(b))
+                .copied()
+                .unwrap_or(0.0);
+            let (size_a, size_b) = (block_sizes[a], block_sizes[b]);
+            if a == b {
+                // pair `w < v` of the block has index `v * (v - 1) / 2 + w`
+                let (mut v, mut row_start) = (1, 0);
+                sample_indices(&mut rng, size_a * size_a.saturating_sub(1) / 2, p, |k| {
+                    while k >= row_start + v {
+                        row_start += v;
+                        v += 1;
+                    }
+                    add_edge(starts[a] + k - row_start, starts[a] + v);
+                });
+            } else {
+                sample_indices(&mut rng, size_a * size_b, p, |k| {
+                    add_edge(starts[a] + k / size_b, starts[b] + k % size_b)
+                });
+            }
+        }
+    }
+}
+
+/// Calls `f` in increasing order with every index of `0..total` that is selected
+/// independently with probability `p`, skipping over geometrically distributed gaps
+fn sample_indices(rng: &mut StdRng, total: usize, p: f64, mut f: impl FnMut(usize)) {
+    if p <= 0.0 {
+        return;
+    }
+    let log_q = (1.0 - p).ln();
+    let mut next = 0;
+    loop {
+        let skip = if p >= 1.0 {
+            0.0
+        } else {
+            ((1.0 - rng.gen::<f64>()).ln() / log_q).floor()
+        };
+        if skip >= (total - next) as f64 {
+            break;
+        }
+        let i = next + skip as usize;
+        f(i);
+        next = i + 1;
+    }
+}
+
+#[cfg(test)]
+mod stochastic_block_model_test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn disconnected_blocks() {
+        let graph = Graph::new();
+        stochastic_block_model(&graph, &[5, 3], &[vec![1.0, 0.0], vec![0.0, 1.0]], Some(1));
+        assert_eq!(graph.count_vertices(), 8);
+        assert_eq!(graph.count_edges(), 10 + 3);
+        assert!(graph.edges().all(|e| e.src().id() < e.dst().id()));
+        let block = |id: u64| graph.vertex(id).unwrap().properties().get("block").unwrap();
+        assert_eq!(block(1), Prop::U64(0));
+        assert_eq!(block(6), Prop::U64(1));
+        for e in graph.edges() {
+            assert_eq!(block(e.src().id()), block(e.dst().id()));
+        }
+    }
+
+    #[test]
+    fn seeded_blocks_are_reproducible() {
+        let probabilities = [vec![0.3, 0.05], vec![0.05, 0.3]];
+        let graph = Graph::new();
+        stochastic_block_model(&graph, &[40, 60], &probabilities, Some(9));
+        let other = Graph::new();
+        stochastic_block_model(&other, &[40, 60], &probabilities, Some(9));
+        let edges = |g: &Graph| -> Vec<(u64, u64)> {
+            g.edges().map(|e| (e.src().id(), e.dst().id())).collect()
+        };
+        assert_eq!(edges(&graph), edges(&other));
+        let within = edges(&graph)
+            .into_iter()
+            .filter(|(a, b)| (*a <= 40) == (*b <= 40))
+            .count();
+        assert!(within > graph.count_edges() - within);
+    }
+}
@@ -0,0 +1,131 @@
+//! Generates a graph using the Watts–Strogatz small-world model
+//!
+//! This function is a graph generation model based upon:
+//! Watts, Duncan J., and Steven H. Strogatz. "Collective dynamics of 'small-world' networks."
+//! Nature 393.6684 (1998): 440-442.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::watts_strogatz::watts_strogatz;
+//! let graph = Graph::new();
+//! watts_strogatz(&graph, 1000, 6, 0.1, Some(42));
+//! ```
+
+use crate::{
+    db::{api::mutation::AdditionOps, graph::graph::Graph},
+    graphgen::add_vertices,
+    prelude::NO_PROPS,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// Adds `vertices_to_add` vertices to the graph arranged in a ring, where every vertex is
+/// connected to its `neighbours / 2` nearest neighbours on either side. Afterwards, every edge
+/// is rewired with probability `rewire_probability` to a uniformly chosen vertex, avoiding
+/// self-loops and duplicate edges.
+///
+/// The edges are added one time step after another.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add vertices and edges to
+/// * `vertices_to_add` - The amount of vertices you wish to add to the graph
+/// * `neighbours` - The number of ring neighbours of each vertex, odd values are rounded down
+/// * `rewire_probability` - The probability of rewiring each edge
+/// * `seed` - The seed of the random number generator
+pub fn watts_strogatz(
+    graph: &Graph,
+    vertices_to_add: usize,
+    neighbours: usize,
+    rewire_probability: f64,
+    seed: Option<u64>,
+) {
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let (first_id, mut time) = add_vertices(graph, vertices_to_add);
+    let n = vertices_to_add;
+    let half = (neighbours / 2).min(n.saturating_sub(1) / 2);
+    let key = |a: usize, b: usize| (a.min(b), a.max(b));
+
+    let mut edges: Vec<(usize, usize)> = vec![];
+    let mut existing: HashSet<(usize, usize)> = HashSet::new();
+    let mut degree = vec![0usize; n];
+    for j in 1..=half {
+        for i in 0..n {
+            let target = (i + j) % n;
+            edges.push((i, target));
+            existing.insert(key(i, target));
+            degree[i] += 1;
+            degree[target] += 1;
+        }
+    }
+    for (src, dst) in edges.iter_mut() {
+        if degree[*src] >= n - 1 || rng.gen::<f64>() >= rewire_probability {
+            continue;
+        }
+        let mut target = rng.gen_range(0..n);
+        while target == *src || existing.contains(&key(*src, target)) {
+            target = rng.gen_range(0..n);
+        }
+        existing.remove(&key(*src, *dst));
+        existing.insert(key(*src, target));
+        degree[*dst] -= 1;
+        degree[target] += 1;
+        *dst = target;
+    }
+
+    for (src, dst) in edges {
+        graph
+            .add_edge(
+                time,
+                first_id + src as u64,
+                first_id + dst as u64,
+                NO_PROPS,
+                None,
+            )
+            .expect("Not able to add edge");
+        time += 1;
+    }
+}
+
+#[cfg(test)]
+mod watts_strogatz_test {
+    use super::*;
+    use crate::db::api::view::*;
+
+    #[test]
+    fn ring_lattice() {
+        let graph = Graph::new();
+        watts_strogatz(&graph, 10, 4, 0.0, None);
+        assert_eq!(graph.count_vertices(), 10);
+        assert_eq!(graph.count_edges(), 20);
+        assert!(graph.vertices().iter().all(|v| v.degree() == 4));
+        assert!(graph.has_edge(10u64, 1u64, Layer::All));
+        assert!(graph.has_edge(9u64, 1u64, Layer::All));
+    }
+
+    #[test]
+    fn rewiring_keeps_edges_simple() {
+        let graph = Graph::new();
+        watts_strogatz(&graph, 100, 6, 0.5, Some(5));
+        assert_eq!(graph.count_edges(), 300);
+        assert!(graph.edges().all(|e| e.src().id() != e.dst().id()));
+        let pairs: HashSet<(u64, u64)> = graph
+            .edges()
+            .map(|e| {
+                let (a, b) = (e.src().id(), e.dst().id());
+                (a.min(b), a.max(b))
+            })
+            .collect();
+        assert_eq!(pairs.len(), 300);
+        // most vertices lose or gain some edges
+        assert!(graph.vertices().iter().any(|v| v.degree() != 6));
+
+        let other = Graph::new();
+        watts_strogatz(&other, 100, 6, 0.5, Some(5));
+        let edges = |g: &Graph| -> Vec<(u64, u64)> {
+            g.edges().map(|e| (e.src().id(), e.dst().id())).collect()
+        };
+        assert_eq!(edges(&graph), edges(&other));
+    }
+}
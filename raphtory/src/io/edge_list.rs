@@ -0,0 +1,197 @@
+//! Plain edge list import and export.
+//!
+//! Every line holds one edge update as whitespace separated `src dst time` columns. When
+//! reading, the time column is optional and defaults to 0, so lists written by other tools
+//! as `src dst` load as a single snapshot. Empty lines and lines starting with `#` are
+//! skipped. Vertex names that are empty or contain whitespace, quotes or backslashes or
+//! start with `#` are written in double quotes with `"` and `\` escaped by a backslash.
+//! Properties and layers are not part of the format.
+
+use crate::{core::utils::errors::GraphError, prelude::*};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+/// Write one line per edge update of `graph`
+pub fn write_edge_list<G: GraphViewOps, W: Write>(graph: &G, writer: W) -> Result<(), GraphError> {
+    let mut writer = BufWriter::new(writer);
+    for edge in graph.edges().explode() {
+        writeln!(
+            writer,
+            "{} {} {}",
+            quote(&edge.src().name()),
+            quote(&edge.dst().name()),
+            edge.time().unwrap_or(0)
+        )?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Write one line per edge update of `graph` to the file at `path`
+pub fn save_edge_list<G: GraphViewOps, P: AsRef<Path>>(
+    graph: &G,
+    path: P,
+) -> Result<(), GraphError> {
+    write_edge_list(graph, File::create(path)?)
+}
+
+/// Quote `name` if it would otherwise not be read back as a single column
+fn quote(name: &str) -> String {
+    let needs_quotes = name.is_empty()
+        || name.starts_with('#')
+        || name
+            .chars()
+            .any(|c| c.is_whitespace() || c == '"' || c == '\\');
+    if !needs_quotes {
+        return name.to_string();
+    }
+    let mut quoted = String::with_capacity(name.len() + 2);
+    quoted.push('"');
+    for c in name.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Split a line into whitespace separated columns, unquoting quoted columns
+fn split_columns(line: &str) -> Result<Vec<String>, &'static str> {
+    let mut columns = vec![];
+    let mut chars = line.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let mut column = String::new();
+        if c == '"' {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => column.push(chars.next().ok_or("unterminated escape")?),
+                    Some(c) => column.push(c),
+                    None => return Err("unterminated quote"),
+                }
+            }
+            if chars.peek().map_or(false, |c| !c.is_whitespace()) {
+                return Err("missing whitespace after quote");
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                column.push(c);
+                chars.next();
+            }
+        }
+        columns.push(column);
+    }
+    Ok(columns)
+}
+
+fn parse_error(line: usize, reason: impl std::fmt::Display) -> GraphError {
+    GraphError::ParseError {
+        format: "edge list",
+        reason: format!("{reason} on line {line}"),
+    }
+}
+
+/// Read a graph from an edge list
+pub fn read_edge_list<R: Read>(reader: R) -> Result<Graph, GraphError> {
+    let graph = Graph::new();
+    for (i, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let columns = split_columns(line).map_err(|reason| parse_error(i + 1, reason))?;
+        let (src, dst, t) = match columns.as_slice() {
+            [src, dst] => (src, dst, 0),
+            [src, dst, t] => {
+                let t = t
+                    .parse()
+                    .map_err(|_| parse_error(i + 1, format!("invalid time '{t}'")))?;
+                (src, dst, t)
+            }
+            _ => {
+                return Err(parse_error(
+                    i + 1,
+                    format!("expected 2 or 3 columns but found {}", columns.len()),
+                ))
+            }
+        };
+        graph.add_edge(t, src.as_str(), dst.as_str(), NO_PROPS, None)?;
+    }
+    Ok(graph)
+}
+
+/// Read a graph from the edge list file at `path`
+pub fn load_edge_list<P: AsRef<Path>>(path: P) -> Result<Graph, GraphError> {
+    read_edge_list(File::open(path)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let g = Graph::new();
+        g.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(2, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(3, 7, 8, NO_PROPS, None).unwrap();
+
+        let mut buf = vec![];
+        write_edge_list(&g, &mut buf).unwrap();
+        assert_eq!(
+            String::from_utf8(buf.clone()).unwrap(),
+            "a b 1\na b 2\n7 8 3\n"
+        );
+
+        let loaded = read_edge_list(buf.as_slice()).unwrap();
+        assert_eq!(loaded.edge("a", "b").unwrap().history(), vec![1, 2]);
+        assert_eq!(loaded.edge(7, 8).unwrap().history(), vec![3]);
+    }
+
+    #[test]
+    fn round_trip_names_with_spaces_and_quotes() {
+        let g = Graph::new();
+        g.add_edge(1, "Jane Doe", "#b", NO_PROPS, None).unwrap();
+        g.add_edge(2, "say \"hi\"", "back\\slash", NO_PROPS, None)
+            .unwrap();
+
+        let mut buf = vec![];
+        write_edge_list(&g, &mut buf).unwrap();
+        assert_eq!(
+            String::from_utf8(buf.clone()).unwrap(),
+            "\"Jane Doe\" \"#b\" 1\n\"say \\\"hi\\\"\" \"back\\\\slash\" 2\n"
+        );
+
+        let loaded = read_edge_list(buf.as_slice()).unwrap();
+        assert_eq!(loaded.count_vertices(), 4);
+        assert_eq!(loaded.edge("Jane Doe", "#b").unwrap().history(), vec![1]);
+        assert_eq!(
+            loaded.edge("say \"hi\"", "back\\slash").unwrap().history(),
+            vec![2]
+        );
+        assert!(read_edge_list("\"a b 1".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn read_untimed_list() {
+        let g = read_edge_list("# comment\n1 2\n\n2 3\n".as_bytes()).unwrap();
+        assert_eq!(g.count_edges(), 2);
+        assert_eq!(g.edge(1, 2).unwrap().history(), vec![0]);
+        assert!(read_edge_list("1 2 x".as_bytes()).is_err());
+        assert!(read_edge_list("1".as_bytes()).is_err());
+    }
+}
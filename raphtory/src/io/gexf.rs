@@ -0,0 +1,593 @@
+//! GEXF import and export.
+//!
+//! Graphs are written in GEXF 1.2 dynamic mode with integer time:
+//!
+//! * the `history()` of every vertex and edge becomes its `<spells>`, one spell per
+//!   update with equal start and end,
+//! * temporal properties become dynamic attributes with one `<attvalue>` per update,
+//! * constant properties become static attributes,
+//! * every layer of an edge is written as its own `<edge>` with the layer name as `kind`.
+//!
+//! Reading reverses the mapping. Elements without spells or timed values are added at their
+//! `start` time, or at time 0 for static graphs.
+
+use crate::{
+    core::{utils::errors::GraphError, ArcStr, Prop},
+    io::{
+        attr_type, exploded_edge_props, is_exportable, parse_prop, parse_time,
+        xml::{Tag, XmlEvent, XmlReader, XmlWriter},
+    },
+    prelude::*,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+/// Attributes declared for one class of element, in order of first use
+#[derive(Default)]
+struct Attributes {
+    ids: HashMap<(ArcStr, bool), usize>,
+    attributes: Vec<(ArcStr, &'static str, bool)>,
+}
+
+impl Attributes {
+    fn id(&mut self, name: &ArcStr, prop: &Prop, dynamic: bool) -> String {
+        let attributes = &mut self.attributes;
+        let id = *self.ids.entry((name.clone(), dynamic)).or_insert_with(|| {
+            attributes.push((name.clone(), attr_type(prop, "integer"), dynamic));
+            attributes.len() - 1
+        });
+        id.to_string()
+    }
+
+    fn write<W: Write>(&self, writer: &mut XmlWriter<W>, class: &str) -> std::io::Result<()> {
+        for (mode, dynamic) in [("static", false), ("dynamic", true)] {
+            let declared: Vec<_> = self
+                .attributes
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, _, d))| *d == dynamic)
+                .collect();
+            if declared.is_empty() {
+                continue;
+            }
+            writer.start("attributes", &[("class", class), ("mode", mode)])?;
+            for (id, (title, attr_type, _)) in declared {
+                writer.empty(
+                    "attribute",
+                    &[
+                        ("id", &id.to_string()),
+                        ("title", title),
+                        ("type", attr_type),
+                    ],
+                )?;
+            }
+            writer.end("attributes")?;
+        }
+        Ok(())
+    }
+}
+
+/// An element with its attribute values and update times
+struct Row {
+    attributes: Vec<(&'static str, String)>,
+    values: Vec<(String, String, Option<i64>)>,
+    times: Vec<i64>,
+}
+
+impl Row {
+    fn write<W: Write>(&self, writer: &mut XmlWriter<W>, tag: &str) -> std::io::Result<()> {
+        let attributes: Vec<_> = self
+            .attributes
+            .iter()
+            .map(|(k, v)| (*k, v.as_str()))
+            .collect();
+        writer.start(tag, &attributes)?;
+        if !self.values.is_empty() {
+            writer.start("attvalues", &[])?;
+            for (id, value, t) in &self.values {
+                match t {
+                    Some(t) => {
+                        let t = t.to_string();
+                        writer.empty(
+                            "attvalue",
+                            &[("for", id), ("value", value), ("start", &t), ("end", &t)],
+                        )?
+                    }
+                    None => writer.empty("attvalue", &[("for", id), ("value", value)])?,
+                }
+            }
+            writer.end("attvalues")?;
+        }
+        writer.start("spells", &[])?;
+        for t in &self.times {
+            let t = t.to_string();
+            writer.empty("spell", &[("start", &t), ("end", &t)])?;
+        }
+        writer.end("spells")?;
+        writer.end(tag)
+    }
+}
+
+/// Write `graph` as GEXF
+pub fn write_gexf<G: GraphViewOps, W: Write>(graph: &G, writer: W) -> Result<(), GraphError> {
+    let mut vertex_attributes = Attributes::default();
+    let mut edge_attributes = Attributes::default();
+
+    let mut vertices = vec![];
+    for vertex in graph.vertices() {
+        let mut values = vec![];
+        for (name, value) in vertex.properties().constant().iter() {
+            if is_exportable(&value) {
+                values.push((
+                    vertex_attributes.id(&name, &value, false),
+                    value.to_string(),
+                    None,
+                ));
+            }
+        }
+        for (name, prop) in vertex.properties().temporal().iter() {
+            for (t, value) in prop.iter() {
+                if is_exportable(&value) {
+                    let id = vertex_attributes.id(&name, &value, true);
+                    values.push((id, value.to_string(), Some(t)));
+                }
+            }
+        }
+        let name = vertex.name();
+        vertices.push(Row {
+            attributes: vec![("id", name.clone()), ("label", name)],
+            values,
+            times: vertex.history(),
+        });
+    }
+
+    let mut edges = vec![];
+    for (i, edge) in graph.edges().flat_map(|e| e.explode_layers()).enumerate() {
+        let mut values = vec![];
+        for (name, value) in edge.properties().constant().iter() {
+            if is_exportable(&value) {
+                values.push((
+                    edge_attributes.id(&name, &value, false),
+                    value.to_string(),
+                    None,
+                ));
+            }
+        }
+        let mut times = vec![];
+        for update in edge.explode() {
+            let t = update.time().unwrap_or(0);
+            times.push(t);
+            for (name, value) in exploded_edge_props(&update) {
+                if is_exportable(&value) {
+                    let id = edge_attributes.id(&name, &value, true);
+                    values.push((id, value.to_string(), Some(t)));
+                }
+            }
+        }
+        let mut attributes = vec![
+            ("id", i.to_string()),
+            ("source", edge.src().name()),
+            ("target", edge.dst().name()),
+        ];
+        if let Some(layer) = edge.layer_name() {
+            attributes.push(("kind", layer.to_string()));
+        }
+        edges.push(Row {
+            attributes,
+            values,
+            times,
+        });
+    }
+
+    let mut writer = XmlWriter::new(BufWriter::new(writer))?;
+    writer.start(
+        "gexf",
+        &[
+            ("xmlns", "http://www.gexf.net/1.2draft"),
+            ("version", "1.2"),
+        ],
+    )?;
+    writer.start(
+        "graph",
+        &[
+            ("mode", "dynamic"),
+            ("defaultedgetype", "directed"),
+            ("timeformat", "integer"),
+        ],
+    )?;
+    vertex_attributes.write(&mut writer, "node")?;
+    edge_attributes.write(&mut writer, "edge")?;
+    writer.start("nodes", &[])?;
+    for row in &vertices {
+        row.write(&mut writer, "node")?;
+    }
+    writer.end("nodes")?;
+    writer.start("edges", &[])?;
+    for row in &edges {
+        row.write(&mut writer, "edge")?;
+    }
+    writer.end("edges")?;
+    writer.end("graph")?;
+    writer.end("gexf")?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Write `graph` as GEXF to the file at `path`
+pub fn save_gexf<G: GraphViewOps, P: AsRef<Path>>(graph: &G, path: P) -> Result<(), GraphError> {
+    write_gexf(graph, File::create(path)?)
+}
+
+fn parse_error(reason: impl Into<String>) -> GraphError {
+    GraphError::ParseError {
+        format: "GEXF",
+        reason: reason.into(),
+    }
+}
+
+/// A declared attribute
+struct Attribute {
+    title: String,
+    attr_type: String,
+    dynamic: bool,
+}
+
+/// A `<node>` or `<edge>` with its `<attvalue>` and `<spell>` children
+struct Element {
+    tag: Tag,
+    values: Vec<Tag>,
+    spells: Vec<Tag>,
+}
+
+/// The properties of an element, split into constant values and values by time
+#[derive(Default)]
+struct ElementProps {
+    constant: Vec<(String, Prop)>,
+    temporal: BTreeMap<i64, Vec<(String, Prop)>>,
+}
+
+fn parse_time_attr(tag: &Tag, name: &str) -> Result<Option<i64>, GraphError> {
+    tag.attr(name)
+        .map(|t| parse_time(t).ok_or_else(|| parse_error(format!("invalid time '{t}'"))))
+        .transpose()
+}
+
+/// The start of the element's lifetime if it has one
+fn start_time(tag: &Tag) -> Result<Option<i64>, GraphError> {
+    Ok(parse_time_attr(tag, "start")?.or(parse_time_attr(tag, "timestamp")?))
+}
+
+fn element_props(
+    element: &Element,
+    attributes: &HashMap<String, Attribute>,
+) -> Result<ElementProps, GraphError> {
+    let mut props = ElementProps::default();
+    let default_time = start_time(&element.tag)?.unwrap_or(0);
+    for value in &element.values {
+        let id = value
+            .attr("for")
+            .or(value.attr("id"))
+            .ok_or_else(|| parse_error("<attvalue> without 'for'"))?;
+        let attribute = attributes
+            .get(id)
+            .ok_or_else(|| parse_error(format!("undeclared attribute '{id}'")))?;
+        let raw = value
+            .attr("value")
+            .ok_or_else(|| parse_error("<attvalue> without a value"))?;
+        let prop = parse_prop(raw, &attribute.attr_type).ok_or_else(|| {
+            parse_error(format!(
+                "invalid {} value '{raw}' for '{}'",
+                attribute.attr_type, attribute.title
+            ))
+        })?;
+        match start_time(value)? {
+            Some(t) => props
+                .temporal
+                .entry(t)
+                .or_default()
+                .push((attribute.title.clone(), prop)),
+            None if attribute.dynamic => props
+                .temporal
+                .entry(default_time)
+                .or_default()
+                .push((attribute.title.clone(), prop)),
+            None => props.constant.push((attribute.title.clone(), prop)),
+        }
+    }
+    Ok(props)
+}
+
+/// Update times of an element from its spells, timed values and start
+fn element_times(element: &Element, props: &ElementProps) -> Result<Vec<i64>, GraphError> {
+    let mut times = vec![];
+    for spell in &element.spells {
+        times.extend(start_time(spell)?);
+    }
+    times.extend(props.temporal.keys().copied());
+    times.sort();
+    times.dedup();
+    if times.is_empty() {
+        times.push(start_time(&element.tag)?.unwrap_or(0));
+    }
+    Ok(times)
+}
+
+fn declare_attribute(
+    declarations: &Tag,
+    attribute: &Tag,
+    vertex_attributes: &mut HashMap<String, Attribute>,
+    edge_attributes: &mut HashMap<String, Attribute>,
+) -> Result<(), GraphError> {
+    let attributes = match declarations.attr("class") {
+        Some("edge") => edge_attributes,
+        _ => vertex_attributes,
+    };
+    let id = attribute
+        .attr("id")
+        .ok_or_else(|| parse_error("<attribute> without an id"))?;
+    attributes.insert(
+        id.to_owned(),
+        Attribute {
+            title: attribute.attr("title").unwrap_or(id).to_owned(),
+            attr_type: attribute.attr("type").unwrap_or("string").to_owned(),
+            dynamic: declarations.attr("mode") == Some("dynamic"),
+        },
+    );
+    Ok(())
+}
+
+fn add_node(
+    graph: &Graph,
+    node: &Element,
+    attributes: &HashMap<String, Attribute>,
+) -> Result<(), GraphError> {
+    let id = node
+        .tag
+        .attr("id")
+        .ok_or_else(|| parse_error("<node> without an id"))?;
+    let mut props = element_props(node, attributes)?;
+    for t in element_times(node, &props)? {
+        let values = props.temporal.remove(&t).unwrap_or_default();
+        graph.add_vertex(t, id, values)?;
+    }
+    graph
+        .vertex(id)
+        .expect("vertex was just added")
+        .add_constant_properties(props.constant)?;
+    Ok(())
+}
+
+fn add_edge(
+    graph: &Graph,
+    edge: &Element,
+    attributes: &HashMap<String, Attribute>,
+) -> Result<(), GraphError> {
+    let src = edge
+        .tag
+        .attr("source")
+        .ok_or_else(|| parse_error("<edge> without a source"))?;
+    let dst = edge
+        .tag
+        .attr("target")
+        .ok_or_else(|| parse_error("<edge> without a target"))?;
+    let layer = edge.tag.attr("kind");
+    let mut props = element_props(edge, attributes)?;
+    for t in element_times(edge, &props)? {
+        let values = props.temporal.remove(&t).unwrap_or_default();
+        graph.add_edge(t, src, dst, values, layer)?;
+    }
+    graph
+        .edge(src, dst)
+        .expect("edge was just added")
+        .add_constant_properties(props.constant, layer)?;
+    Ok(())
+}
+
+/// Read a graph from GEXF
+///
+/// The document is streamed, vertices and edges are added as soon as their element ends.
+pub fn read_gexf<R: Read>(reader: R) -> Result<Graph, GraphError> {
+    let mut reader = XmlReader::new(BufReader::new(reader));
+    let root = reader.root().map_err(parse_error)?;
+    if root.name != "gexf" {
+        return Err(parse_error(format!(
+            "expected <gexf> but found <{}>",
+            root.name
+        )));
+    }
+
+    let graph = Graph::new();
+    let mut vertex_attributes = HashMap::new();
+    let mut edge_attributes = HashMap::new();
+    let mut path = vec![root.name];
+    // the `<attributes>` declaration and the node or edge being read
+    let mut declarations: Option<Tag> = None;
+    let mut element: Option<Element> = None;
+    loop {
+        match reader.next().map_err(parse_error)? {
+            XmlEvent::Start(tag) => {
+                let parent = path.last().map(|p| p.as_str());
+                match (path.len(), parent, tag.name.as_str()) {
+                    (2, Some("graph"), "attributes") => declarations = Some(tag.clone()),
+                    (3, Some("attributes"), "attribute") => {
+                        if let Some(declarations) = &declarations {
+                            declare_attribute(
+                                declarations,
+                                &tag,
+                                &mut vertex_attributes,
+                                &mut edge_attributes,
+                            )?;
+                        }
+                    }
+                    (3, Some("nodes"), "node") | (3, Some("edges"), "edge") => {
+                        element = Some(Element {
+                            tag: tag.clone(),
+                            values: vec![],
+                            spells: vec![],
+                        })
+                    }
+                    (5, Some("attvalues"), "attvalue") => {
+                        if let Some(element) = element.as_mut() {
+                            element.values.push(tag.clone());
+                        }
+                    }
+                    (5, Some("spells"), "spell") => {
+                        if let Some(element) = element.as_mut() {
+                            element.spells.push(tag.clone());
+                        }
+                    }
+                    _ => {}
+                }
+                path.push(tag.name);
+            }
+            XmlEvent::Text(_) => {}
+            XmlEvent::End(name) => {
+                path.pop();
+                match name.as_str() {
+                    "attributes" if path.len() == 2 => declarations = None,
+                    "node" | "edge" if path.len() == 3 => {
+                        if let Some(element) = element.take() {
+                            if element.tag.name == "node" {
+                                add_node(&graph, &element, &vertex_attributes)?;
+                            } else {
+                                add_edge(&graph, &element, &edge_attributes)?;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            XmlEvent::Eof => match path.last() {
+                Some(open) => return Err(parse_error(format!("missing '</{open}>'"))),
+                None => return Ok(graph),
+            },
+        }
+    }
+}
+
+/// Read a graph from the GEXF file at `path`
+pub fn load_gexf<P: AsRef<Path>>(path: P) -> Result<Graph, GraphError> {
+    read_gexf(File::open(path)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let g = Graph::new();
+        g.add_vertex(1, "a", [("score", Prop::F64(0.5))]).unwrap();
+        g.add_vertex(4, "a", [("score", Prop::F64(0.7))]).unwrap();
+        g.vertex("a")
+            .unwrap()
+            .add_constant_properties([("kind", Prop::str("user"))])
+            .unwrap();
+        g.add_edge(1, "a", "b", [("weight", Prop::I64(2))], Some("follows"))
+            .unwrap();
+        g.add_edge(3, "a", "b", [("weight", Prop::I64(4))], Some("follows"))
+            .unwrap();
+        g.add_edge(2, "a", "b", NO_PROPS, Some("likes")).unwrap();
+        g.edge("a", "b")
+            .unwrap()
+            .add_constant_properties([("since", Prop::I64(2000))], Some("follows"))
+            .unwrap();
+
+        let mut buf = vec![];
+        write_gexf(&g.window(0, 4), &mut buf).unwrap();
+        let loaded = read_gexf(buf.as_slice()).unwrap();
+
+        let a = loaded.vertex("a").unwrap();
+        assert_eq!(a.history(), vec![1, 2, 3]);
+        assert_eq!(
+            a.properties().constant().get("kind"),
+            Some(Prop::str("user"))
+        );
+        assert_eq!(
+            a.properties()
+                .temporal()
+                .get("score")
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![(1, Prop::F64(0.5))]
+        );
+
+        let e = loaded.edge("a", "b").unwrap();
+        assert_eq!(e.history(), vec![1, 2, 3]);
+        let follows = e.layer("follows").unwrap();
+        assert_eq!(follows.history(), vec![1, 3]);
+        assert_eq!(
+            follows.properties().constant().get("since"),
+            Some(Prop::I64(2000))
+        );
+        assert_eq!(
+            follows
+                .properties()
+                .temporal()
+                .get("weight")
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![(1, Prop::I64(2)), (3, Prop::I64(4))]
+        );
+        assert_eq!(e.layer("likes").unwrap().history(), vec![2]);
+    }
+
+    #[test]
+    fn read_prefixed_document() {
+        let doc = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE gexf [ <!ENTITY example "unused"> ]>
+<x:gexf xmlns:x="http://www.gexf.net/1.2draft" xmlns:viz="http://www.gexf.net/1.2draft/viz" version="1.2">
+  <x:graph mode="dynamic" timeformat="integer">
+    <x:attributes class="edge" mode="dynamic">
+      <x:attribute id="w" title="weight" type="double"/>
+    </x:attributes>
+    <x:nodes>
+      <x:node id="a"><viz:color r="1" g="2" b="3"/></x:node>
+      <x:node id="b"/>
+    </x:nodes>
+    <x:edges>
+      <x:edge id="0" source="a" target="b">
+        <x:attvalues><x:attvalue for="w" value="0.5" start="2" end="2"/></x:attvalues>
+        <x:spells><x:spell start="1" end="1"/></x:spells>
+      </x:edge>
+    </x:edges>
+  </x:graph>
+</x:gexf>"#;
+        let g = read_gexf(doc.as_bytes()).unwrap();
+        let e = g.edge("a", "b").unwrap();
+        assert_eq!(e.history(), vec![1, 2]);
+        assert_eq!(e.properties().get("weight"), Some(Prop::F64(0.5)));
+    }
+
+    #[test]
+    fn read_static_graph() {
+        let doc = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gexf xmlns="http://www.gexf.net/1.2draft" version="1.2">
+  <graph mode="static" defaultedgetype="directed">
+    <attributes class="node">
+      <attribute id="0" title="age" type="integer"/>
+    </attributes>
+    <nodes>
+      <node id="0" label="Hello"><attvalues><attvalue for="0" value="3"/></attvalues></node>
+      <node id="1" label="World" start="5"/>
+    </nodes>
+    <edges>
+      <edge id="0" source="0" target="1"/>
+    </edges>
+  </graph>
+</gexf>"#;
+        let g = read_gexf(doc.as_bytes()).unwrap();
+        assert_eq!(
+            g.vertex("0").unwrap().properties().get("age"),
+            Some(Prop::I32(3))
+        );
+        assert_eq!(g.vertex("1").unwrap().history(), vec![0, 5]);
+        assert_eq!(g.edge("0", "1").unwrap().history(), vec![0]);
+    }
+}
@@ -0,0 +1,511 @@
+//! GraphML import and export.
+//!
+//! GraphML describes static graphs, so time is stored in attributes:
+//!
+//! * every vertex is written once with a `time` attribute holding its earliest time, its
+//!   constant properties and the latest value of each temporal property,
+//! * every edge update is written as its own `<edge>` with `time` and `layer` attributes, the
+//!   temporal properties set by that update and the constant properties of the layer.
+//!
+//! When reading, vertex attributes become constant properties and edge attributes become
+//! temporal properties at the edge time. Elements without a `time` attribute are added at
+//! time 0, which makes static graphs written by other tools load as a single snapshot. Edges
+//! of undirected graphs are added in the direction they are listed.
+
+use crate::{
+    core::{utils::errors::GraphError, ArcStr, Prop},
+    io::{
+        attr_type, exploded_edge_props, is_exportable, parse_prop, parse_time,
+        xml::{Tag, XmlEvent, XmlReader, XmlWriter},
+    },
+    prelude::*,
+};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+const TIME: &str = "time";
+const LAYER: &str = "layer";
+
+/// Attribute keys declared for one kind of element, in order of first use
+#[derive(Default)]
+struct Keys {
+    prefix: &'static str,
+    ids: HashMap<ArcStr, usize>,
+    keys: Vec<(ArcStr, &'static str)>,
+}
+
+impl Keys {
+    fn new(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            ..Default::default()
+        }
+    }
+
+    fn id(&mut self, name: &ArcStr, prop: &Prop) -> String {
+        let keys = &mut self.keys;
+        let id = *self.ids.entry(name.clone()).or_insert_with(|| {
+            keys.push((name.clone(), attr_type(prop, "int")));
+            keys.len() - 1
+        });
+        format!("{}{}", self.prefix, id)
+    }
+
+    fn write<W: Write>(&self, writer: &mut XmlWriter<W>, domain: &str) -> std::io::Result<()> {
+        for (id, (name, attr_type)) in self.keys.iter().enumerate() {
+            writer.empty(
+                "key",
+                &[
+                    ("id", &format!("{}{}", self.prefix, id)),
+                    ("for", domain),
+                    ("attr.name", name),
+                    ("attr.type", attr_type),
+                ],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// An element with its attributes resolved to key ids
+struct Row {
+    attributes: Vec<(&'static str, String)>,
+    data: Vec<(String, String)>,
+}
+
+/// Write `graph` as GraphML
+pub fn write_graphml<G: GraphViewOps, W: Write>(graph: &G, writer: W) -> Result<(), GraphError> {
+    let mut vertex_keys = Keys::new("v");
+    let mut edge_keys = Keys::new("e");
+
+    let mut vertices = vec![];
+    for vertex in graph.vertices() {
+        let mut props: Vec<(ArcStr, Prop)> = vertex.properties().temporal().iter_latest().collect();
+        for (name, value) in vertex.properties().constant().iter() {
+            if !props.iter().any(|(n, _)| n == &name) {
+                props.push((name, value));
+            }
+        }
+        let mut data = vec![(
+            "v_time".to_owned(),
+            vertex.earliest_time().unwrap_or(0).to_string(),
+        )];
+        for (name, value) in props {
+            if is_exportable(&value) && name.as_ref() != TIME {
+                data.push((vertex_keys.id(&name, &value), value.to_string()));
+            }
+        }
+        vertices.push(Row {
+            attributes: vec![("id", vertex.name())],
+            data,
+        });
+    }
+
+    let mut edges = vec![];
+    for layer_edge in graph.edges().flat_map(|e| e.explode_layers()) {
+        let constants: Vec<_> = layer_edge.properties().constant().iter().collect();
+        for edge in layer_edge.explode() {
+            let mut data = vec![("e_time".to_owned(), edge.time().unwrap_or(0).to_string())];
+            if let Some(layer) = edge.layer_name() {
+                data.push(("e_layer".to_owned(), layer.to_string()));
+            }
+            for (name, value) in exploded_edge_props(&edge)
+                .into_iter()
+                .chain(constants.clone())
+            {
+                if is_exportable(&value) && name.as_ref() != TIME && name.as_ref() != LAYER {
+                    data.push((edge_keys.id(&name, &value), value.to_string()));
+                }
+            }
+            edges.push(Row {
+                attributes: vec![("source", edge.src().name()), ("target", edge.dst().name())],
+                data,
+            });
+        }
+    }
+
+    let mut writer = XmlWriter::new(BufWriter::new(writer))?;
+    writer.start(
+        "graphml",
+        &[("xmlns", "http://graphml.graphdrawing.org/xmlns")],
+    )?;
+    let reserved = [
+        ("v_time", "node", TIME, "long"),
+        ("e_time", "edge", TIME, "long"),
+        ("e_layer", "edge", LAYER, "string"),
+    ];
+    for (id, domain, name, attr_type) in reserved {
+        writer.empty(
+            "key",
+            &[
+                ("id", id),
+                ("for", domain),
+                ("attr.name", name),
+                ("attr.type", attr_type),
+            ],
+        )?;
+    }
+    vertex_keys.write(&mut writer, "node")?;
+    edge_keys.write(&mut writer, "edge")?;
+
+    writer.start("graph", &[("edgedefault", "directed")])?;
+    for (tag, rows) in [("node", vertices), ("edge", edges)] {
+        for row in rows {
+            let attributes: Vec<_> = row
+                .attributes
+                .iter()
+                .map(|(k, v)| (*k, v.as_str()))
+                .collect();
+            writer.start(tag, &attributes)?;
+            for (key, value) in &row.data {
+                writer.text("data", &[("key", key)], value)?;
+            }
+            writer.end(tag)?;
+        }
+    }
+    writer.end("graph")?;
+    writer.end("graphml")?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Write `graph` as GraphML to the file at `path`
+pub fn save_graphml<G: GraphViewOps, P: AsRef<Path>>(graph: &G, path: P) -> Result<(), GraphError> {
+    write_graphml(graph, File::create(path)?)
+}
+
+fn parse_error(reason: impl Into<String>) -> GraphError {
+    GraphError::ParseError {
+        format: "GraphML",
+        reason: reason.into(),
+    }
+}
+
+/// A declared attribute key
+struct Key {
+    name: String,
+    attr_type: String,
+    default: Option<String>,
+}
+
+/// The `<data>` values of an element by key, including defaults for missing values
+fn element_data(
+    data: Vec<(String, String)>,
+    keys: &HashMap<String, Key>,
+) -> Result<Vec<(&Key, String)>, GraphError> {
+    let mut values = vec![];
+    for (id, key) in keys {
+        if let Some(default) = &key.default {
+            if !data.iter().any(|(d, _)| d == id) {
+                values.push((key, default.clone()));
+            }
+        }
+    }
+    for (id, value) in data {
+        let key = keys
+            .get(&id)
+            .ok_or_else(|| parse_error(format!("undeclared key '{id}'")))?;
+        values.push((key, value));
+    }
+    Ok(values)
+}
+
+fn props(data: &[(&Key, String)]) -> Result<Vec<(String, Prop)>, GraphError> {
+    data.iter()
+        .filter(|(key, _)| key.name != TIME && key.name != LAYER)
+        .map(|(key, value)| {
+            let prop = parse_prop(value, &key.attr_type).ok_or_else(|| {
+                parse_error(format!(
+                    "invalid {} value '{value}' for '{}'",
+                    key.attr_type, key.name
+                ))
+            })?;
+            Ok((key.name.clone(), prop))
+        })
+        .collect()
+}
+
+fn value<'a>(data: &'a [(&Key, String)], name: &str) -> Option<&'a str> {
+    data.iter()
+        .find(|(key, _)| key.name == name)
+        .map(|(_, value)| value.as_str())
+}
+
+fn time(data: &[(&Key, String)]) -> Result<i64, GraphError> {
+    match value(data, TIME) {
+        Some(value) => {
+            parse_time(value).ok_or_else(|| parse_error(format!("invalid time '{value}'")))
+        }
+        None => Ok(0),
+    }
+}
+
+fn declare_key(
+    tag: &Tag,
+    default: Option<String>,
+    vertex_keys: &mut HashMap<String, Key>,
+    edge_keys: &mut HashMap<String, Key>,
+) -> Result<(), GraphError> {
+    let id = tag
+        .attr("id")
+        .ok_or_else(|| parse_error("<key> without an id"))?;
+    let domain = tag.attr("for").unwrap_or("all");
+    let parsed = || Key {
+        name: tag.attr("attr.name").unwrap_or(id).to_owned(),
+        attr_type: tag.attr("attr.type").unwrap_or("string").to_owned(),
+        default: default.clone(),
+    };
+    if matches!(domain, "node" | "all") {
+        vertex_keys.insert(id.to_owned(), parsed());
+    }
+    if matches!(domain, "edge" | "all") {
+        edge_keys.insert(id.to_owned(), parsed());
+    }
+    Ok(())
+}
+
+fn add_node(
+    graph: &Graph,
+    node: &Tag,
+    data: Vec<(String, String)>,
+    keys: &HashMap<String, Key>,
+) -> Result<(), GraphError> {
+    let id = node
+        .attr("id")
+        .ok_or_else(|| parse_error("<node> without an id"))?;
+    let data = element_data(data, keys)?;
+    let vertex = graph.add_vertex(time(&data)?, id, NO_PROPS)?;
+    vertex.add_constant_properties(props(&data)?)?;
+    Ok(())
+}
+
+fn add_edge(
+    graph: &Graph,
+    edge: &Tag,
+    data: Vec<(String, String)>,
+    keys: &HashMap<String, Key>,
+) -> Result<(), GraphError> {
+    let src = edge
+        .attr("source")
+        .ok_or_else(|| parse_error("<edge> without a source"))?;
+    let dst = edge
+        .attr("target")
+        .ok_or_else(|| parse_error("<edge> without a target"))?;
+    let data = element_data(data, keys)?;
+    graph.add_edge(time(&data)?, src, dst, props(&data)?, value(&data, LAYER))?;
+    Ok(())
+}
+
+/// Read a graph from GraphML
+///
+/// The document is streamed, vertices and edges are added as soon as their element ends.
+pub fn read_graphml<R: Read>(reader: R) -> Result<Graph, GraphError> {
+    let mut reader = XmlReader::new(BufReader::new(reader));
+    let root = reader.root().map_err(parse_error)?;
+    if root.name != "graphml" {
+        return Err(parse_error(format!(
+            "expected <graphml> but found <{}>",
+            root.name
+        )));
+    }
+
+    let graph = Graph::new();
+    let mut vertex_keys = HashMap::new();
+    let mut edge_keys = HashMap::new();
+    let mut path = vec![root.name];
+    // the key, node or edge being read and the `<default>` or `<data>` child being read
+    let mut key: Option<(Tag, Option<String>)> = None;
+    let mut element: Option<(Tag, Vec<(String, String)>)> = None;
+    let mut child: Option<(Tag, String)> = None;
+    loop {
+        match reader.next().map_err(parse_error)? {
+            XmlEvent::Start(tag) => {
+                let parent = path.last().map(|p| p.as_str());
+                match (path.len(), parent, tag.name.as_str()) {
+                    (1, _, "key") => key = Some((tag.clone(), None)),
+                    (2, Some("key"), "default") => child = Some((tag.clone(), String::new())),
+                    (2, Some("graph"), "node" | "edge") => element = Some((tag.clone(), vec![])),
+                    (3, Some("node" | "edge"), "data") => {
+                        child = Some((tag.clone(), String::new()))
+                    }
+                    _ => {}
+                }
+                path.push(tag.name);
+            }
+            XmlEvent::Text(text) => {
+                if let Some((_, value)) = child.as_mut() {
+                    value.push_str(&text);
+                }
+            }
+            XmlEvent::End(name) => {
+                path.pop();
+                match name.as_str() {
+                    "default" => {
+                        if let (Some((_, default)), Some((_, value))) = (key.as_mut(), child.take())
+                        {
+                            *default = Some(value);
+                        }
+                    }
+                    "key" => {
+                        if let Some((tag, default)) = key.take() {
+                            declare_key(&tag, default, &mut vertex_keys, &mut edge_keys)?;
+                        }
+                    }
+                    "data" => {
+                        if let (Some((_, data)), Some((tag, value))) =
+                            (element.as_mut(), child.take())
+                        {
+                            let id = tag
+                                .attr("key")
+                                .ok_or_else(|| parse_error("<data> without a key"))?;
+                            data.push((id.to_owned(), value));
+                        }
+                    }
+                    "node" | "edge" if path.len() == 2 => {
+                        if let Some((tag, data)) = element.take() {
+                            if tag.name == "node" {
+                                add_node(&graph, &tag, data, &vertex_keys)?;
+                            } else {
+                                add_edge(&graph, &tag, data, &edge_keys)?;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            XmlEvent::Eof => match path.last() {
+                Some(open) => return Err(parse_error(format!("missing '</{open}>'"))),
+                None => return Ok(graph),
+            },
+        }
+    }
+}
+
+/// Read a graph from the GraphML file at `path`
+pub fn load_graphml<P: AsRef<Path>>(path: P) -> Result<Graph, GraphError> {
+    read_graphml(File::open(path)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let g = Graph::new();
+        g.add_vertex(0, "a", [("score", Prop::F64(0.5))]).unwrap();
+        g.vertex("a")
+            .unwrap()
+            .add_constant_properties([("kind", Prop::str("user"))])
+            .unwrap();
+        g.add_edge(1, "a", "b", [("weight", Prop::I64(2))], Some("follows"))
+            .unwrap();
+        g.add_edge(3, "a", "b", [("weight", Prop::I64(4))], Some("follows"))
+            .unwrap();
+        g.add_edge(2, "b", "c", NO_PROPS, None).unwrap();
+
+        let mut buf = vec![];
+        write_graphml(&g, &mut buf).unwrap();
+        let loaded = read_graphml(buf.as_slice()).unwrap();
+
+        assert_eq!(loaded.count_vertices(), 3);
+        assert_eq!(loaded.count_edges(), 2);
+        let a = loaded.vertex("a").unwrap();
+        assert_eq!(a.properties().get("kind"), Some(Prop::str("user")));
+        assert_eq!(a.properties().get("score"), Some(Prop::F64(0.5)));
+        let e = loaded.edge("a", "b").unwrap();
+        assert_eq!(e.history(), vec![1, 3]);
+        assert_eq!(e.layer_names().collect::<Vec<_>>(), vec!["follows"]);
+        let weights: Vec<_> = e
+            .properties()
+            .temporal()
+            .get("weight")
+            .unwrap()
+            .iter()
+            .collect();
+        assert_eq!(weights, vec![(1, Prop::I64(2)), (3, Prop::I64(4))]);
+    }
+
+    #[test]
+    fn read_static_graph() {
+        let doc = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="color" attr.type="string"><default>red</default></key>
+  <key id="d1" for="edge" attr.name="weight" attr.type="double"/>
+  <graph edgedefault="undirected">
+    <node id="n0"><data key="d0">blue</data></node>
+    <node id="n1"/>
+    <edge source="n0" target="n1"><data key="d1">1.5</data></edge>
+  </graph>
+</graphml>"#;
+        let g = read_graphml(doc.as_bytes()).unwrap();
+        assert_eq!(
+            g.vertex("n0").unwrap().properties().get("color"),
+            Some(Prop::str("blue"))
+        );
+        assert_eq!(
+            g.vertex("n1").unwrap().properties().get("color"),
+            Some(Prop::str("red"))
+        );
+        let e = g.edge("n0", "n1").unwrap();
+        assert_eq!(e.history(), vec![0]);
+        assert_eq!(e.properties().get("weight"), Some(Prop::F64(1.5)));
+    }
+
+    #[test]
+    fn read_prefixed_document_with_doctype() {
+        let doc = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE g:graphml [
+  <!ELEMENT g:graphml ANY>
+  <!ATTLIST g:node id CDATA #REQUIRED>
+]>
+<!-- written by hand -->
+<g:graphml xmlns:g="http://graphml.graphdrawing.org/xmlns">
+  <g:key id="d0" for="edge" attr.name="time" attr.type="long"/>
+  <g:key id="d1" for="edge" attr.name="note" attr.type="string"/>
+  <g:graph edgedefault="directed">
+    <g:node id="a &amp; b"/>
+    <g:edge source="a &amp; b" target="c">
+      <g:data key="d0">7</g:data>
+      <g:data key="d1"><![CDATA[<raw>]]></g:data>
+    </g:edge>
+  </g:graph>
+</g:graphml>"#;
+        let g = read_graphml(doc.as_bytes()).unwrap();
+        assert_eq!(g.count_vertices(), 2);
+        let e = g.edge("a & b", "c").unwrap();
+        assert_eq!(e.history(), vec![7]);
+        assert_eq!(e.properties().get("note"), Some(Prop::str("<raw>")));
+    }
+
+    #[test]
+    fn malformed_documents_are_errors() {
+        for doc in [
+            "<graphml><graph></graphml>",
+            "<graphml><graph>",
+            "<gexf/>",
+            "",
+        ] {
+            assert!(
+                matches!(
+                    read_graphml(doc.as_bytes()),
+                    Err(GraphError::ParseError { .. })
+                ),
+                "{doc}"
+            );
+        }
+    }
+
+    #[test]
+    fn undeclared_key_is_an_error() {
+        let doc = r#"<graphml><graph><node id="a"><data key="x">1</data></node></graph></graphml>"#;
+        assert!(matches!(
+            read_graphml(doc.as_bytes()),
+            Err(GraphError::ParseError { .. })
+        ));
+    }
+}
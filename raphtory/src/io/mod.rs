@@ -0,0 +1,169 @@
+//! Readers and writers for common graph exchange formats.
+//!
+//! * [`graphml`] - GraphML, as read and written by NetworkX, yEd and Gephi
+//! * [`gexf`] - GEXF with dynamic (spell based) time, as used by Gephi
+//! * [`edge_list`] - plain whitespace separated `src dst time` lines
+//!
+//! Every graph view can be written with the methods of [`SaveOps`], or with the `write_*`
+//! functions of each module for arbitrary writers.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use raphtory::io::{gexf::load_gexf, SaveOps};
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! g.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+//! g.window(0, 10).save_gexf("graph.gexf").unwrap();
+//! let loaded = load_gexf("graph.gexf").unwrap();
+//! ```
+
+pub mod edge_list;
+pub mod gexf;
+pub mod graphml;
+mod xml;
+
+use crate::{
+    core::{
+        utils::{errors::GraphError, time::TryIntoTime},
+        ArcStr, Prop,
+    },
+    db::{api::view::EdgeViewInternalOps, graph::edge::EdgeView},
+    prelude::*,
+};
+use std::path::Path;
+
+/// Operations for writing a graph view to a file in one of the exchange formats
+pub trait SaveOps: GraphViewOps {
+    /// Write the graph as GraphML to the file at `path`, see [`graphml`]
+    fn save_graphml<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
+        graphml::save_graphml(self, path)
+    }
+
+    /// Write the graph as dynamic GEXF to the file at `path`, see [`gexf`]
+    fn save_gexf<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
+        gexf::save_gexf(self, path)
+    }
+
+    /// Write one `src dst time` line per edge update to the file at `path`, see [`edge_list`]
+    fn save_edge_list<P: AsRef<Path>>(&self, path: P) -> Result<(), GraphError> {
+        edge_list::save_edge_list(self, path)
+    }
+}
+
+impl<G: GraphViewOps> SaveOps for G {}
+
+/// Values of the temporal properties set by an exploded edge update
+pub(crate) fn exploded_edge_props<G: GraphViewOps>(edge: &EdgeView<G>) -> Vec<(ArcStr, Prop)> {
+    let Some(ti) = edge.time_and_index() else {
+        return vec![];
+    };
+    let graph = edge.graph();
+    let layer_ids = graph.layer_ids().constrain_from_edge(edge.eref());
+    edge.properties()
+        .temporal()
+        .keys()
+        .filter_map(|key| {
+            let id = graph.edge_meta().temporal_prop_meta().get_id(&key)?;
+            let value = graph
+                .temporal_edge_prop(edge.eref(), id, layer_ids.clone())?
+                .at(&ti)?;
+            Some((key, value))
+        })
+        .collect()
+}
+
+/// Properties that have a textual representation in the exchange formats
+pub(crate) fn is_exportable(prop: &Prop) -> bool {
+    !matches!(prop, Prop::List(_) | Prop::Map(_) | Prop::Graph(_))
+}
+
+/// Parse a time that is either a number or a date string
+pub(crate) fn parse_time(value: &str) -> Option<i64> {
+    let value = value.trim();
+    value
+        .parse::<i64>()
+        .ok()
+        .or_else(|| value.parse::<f64>().ok().map(|t| t as i64))
+        .or_else(|| value.try_into_time().ok())
+}
+
+/// Parse a property value of the given (GraphML or GEXF) attribute type
+pub(crate) fn parse_prop(value: &str, attr_type: &str) -> Option<Prop> {
+    let prop = match attr_type {
+        "int" | "integer" => Prop::I32(value.trim().parse().ok()?),
+        "long" => Prop::I64(value.trim().parse().ok()?),
+        "float" => Prop::F32(value.trim().parse().ok()?),
+        "double" => Prop::F64(value.trim().parse().ok()?),
+        "boolean" => match value.trim() {
+            "true" | "1" => Prop::Bool(true),
+            "false" | "0" => Prop::Bool(false),
+            _ => return None,
+        },
+        _ => Prop::str(value),
+    };
+    Some(prop)
+}
+
+/// The attribute type used for `prop` in GraphML and GEXF, with the name of the 32 bit integer
+/// type passed in as `int` as that is where the two formats differ
+pub(crate) fn attr_type(prop: &Prop, int: &'static str) -> &'static str {
+    match prop {
+        Prop::Bool(_) => "boolean",
+        Prop::U8(_) | Prop::U16(_) | Prop::I32(_) => int,
+        Prop::U32(_) | Prop::U64(_) | Prop::I64(_) => "long",
+        Prop::F32(_) => "float",
+        Prop::F64(_) => "double",
+        _ => "string",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tempdir::TempDir;
+
+    #[test]
+    fn save_views() {
+        let g = Graph::new();
+        g.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(5, "b", "c", NO_PROPS, None).unwrap();
+        let view = g.window(0, 3);
+
+        let dir = TempDir::new("io").unwrap();
+        let graphml_path = dir.path().join("graph.graphml");
+        let gexf_path = dir.path().join("graph.gexf");
+        let edge_list_path = dir.path().join("graph.txt");
+        view.save_graphml(&graphml_path).unwrap();
+        view.save_gexf(&gexf_path).unwrap();
+        view.save_edge_list(&edge_list_path).unwrap();
+
+        for loaded in [
+            graphml::load_graphml(&graphml_path).unwrap(),
+            gexf::load_gexf(&gexf_path).unwrap(),
+            edge_list::load_edge_list(&edge_list_path).unwrap(),
+        ] {
+            assert_eq!(loaded.count_edges(), 1);
+            assert_eq!(loaded.edge("a", "b").unwrap().history(), vec![1]);
+        }
+    }
+
+    #[test]
+    fn parse_attribute_values() {
+        assert_eq!(parse_prop(" 3", "int"), Some(Prop::I32(3)));
+        assert_eq!(parse_prop("3", "long"), Some(Prop::I64(3)));
+        assert_eq!(parse_prop("1.5", "double"), Some(Prop::F64(1.5)));
+        assert_eq!(parse_prop("true", "boolean"), Some(Prop::Bool(true)));
+        assert_eq!(parse_prop("x", "string"), Some(Prop::str("x")));
+        assert_eq!(parse_prop("x", "int"), None);
+    }
+
+    #[test]
+    fn parse_times() {
+        assert_eq!(parse_time("42"), Some(42));
+        assert_eq!(parse_time("42.0"), Some(42));
+        assert_eq!(parse_time("1970-01-01"), Some(0));
+        assert_eq!(parse_time("later"), None);
+    }
+}
@@ -0,0 +1,174 @@
+//! Reading and writing the XML underlying GraphML and GEXF documents.
+//!
+//! Documents are read as a stream of start tags, end tags and text with `quick-xml`, so the
+//! GraphML and GEXF readers never hold more than the element they are currently building.
+//! Namespace prefixes are stripped from element and attribute names and namespace
+//! declarations are dropped, so documents match on local names only.
+
+use quick_xml::{
+    escape::escape,
+    events::{BytesStart, Event},
+    Reader,
+};
+use std::io::{self, BufRead, Write};
+
+/// A start tag with its attributes
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Tag {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+}
+
+impl Tag {
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// The parts of a document the readers care about
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum XmlEvent {
+    Start(Tag),
+    End(String),
+    Text(String),
+    Eof,
+}
+
+/// Reads a document one event at a time, empty elements are reported as a start and an end
+pub(crate) struct XmlReader<R: BufRead> {
+    reader: Reader<R>,
+    buf: Vec<u8>,
+}
+
+impl<R: BufRead> XmlReader<R> {
+    pub fn new(reader: R) -> Self {
+        let mut reader = Reader::from_reader(reader);
+        reader.expand_empty_elements(true);
+        Self {
+            reader,
+            buf: vec![],
+        }
+    }
+
+    fn error(&self, reason: impl std::fmt::Display) -> String {
+        format!("{reason} at byte {}", self.reader.buffer_position())
+    }
+
+    /// The next start tag, end tag or text, skipping comments, processing instructions and
+    /// doctype declarations
+    pub fn next(&mut self) -> Result<XmlEvent, String> {
+        loop {
+            self.buf.clear();
+            let event = match self.reader.read_event_into(&mut self.buf) {
+                Ok(Event::Start(start)) => tag(&start).map(XmlEvent::Start),
+                Ok(Event::End(end)) => Ok(XmlEvent::End(local(end.local_name().as_ref()))),
+                Ok(Event::Text(text)) => text.unescape().map(|t| XmlEvent::Text(t.into_owned())),
+                Ok(Event::CData(data)) => Ok(XmlEvent::Text(
+                    String::from_utf8_lossy(&data.into_inner()).into_owned(),
+                )),
+                Ok(Event::Eof) => Ok(XmlEvent::Eof),
+                Ok(_) => continue,
+                Err(e) => Err(e),
+            };
+            return event.map_err(|e| self.error(e));
+        }
+    }
+
+    /// The root element of the document
+    pub fn root(&mut self) -> Result<Tag, String> {
+        loop {
+            match self.next()? {
+                XmlEvent::Start(tag) => return Ok(tag),
+                XmlEvent::Text(text) if text.trim().is_empty() => {}
+                XmlEvent::Text(_) => return Err(self.error("text before the root element")),
+                XmlEvent::End(name) => return Err(self.error(format!("unexpected '</{name}>'"))),
+                XmlEvent::Eof => return Err(self.error("missing root element")),
+            }
+        }
+    }
+}
+
+fn local(name: &[u8]) -> String {
+    String::from_utf8_lossy(name).into_owned()
+}
+
+fn tag(start: &BytesStart) -> Result<Tag, quick_xml::Error> {
+    let mut attributes = vec![];
+    for attribute in start.attributes() {
+        let attribute = attribute?;
+        if attribute.key.as_namespace_binding().is_some() {
+            continue;
+        }
+        attributes.push((
+            local(attribute.key.local_name().as_ref()),
+            attribute.unescape_value()?.into_owned(),
+        ));
+    }
+    Ok(Tag {
+        name: local(start.local_name().as_ref()),
+        attributes,
+    })
+}
+
+/// Writes indented elements
+pub(crate) struct XmlWriter<W: Write> {
+    writer: W,
+    depth: usize,
+}
+
+impl<W: Write> XmlWriter<W> {
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        Ok(Self { writer, depth: 0 })
+    }
+
+    fn open_tag(&mut self, name: &str, attributes: &[(&str, &str)]) -> io::Result<()> {
+        write!(
+            self.writer,
+            "{:indent$}<{name}",
+            "",
+            indent = 2 * self.depth
+        )?;
+        for (key, value) in attributes {
+            write!(self.writer, r#" {key}="{}""#, escape(value))?;
+        }
+        Ok(())
+    }
+
+    /// Write a start tag, the element is closed by the matching call to `end`
+    pub fn start(&mut self, name: &str, attributes: &[(&str, &str)]) -> io::Result<()> {
+        self.open_tag(name, attributes)?;
+        writeln!(self.writer, ">")?;
+        self.depth += 1;
+        Ok(())
+    }
+
+    pub fn end(&mut self, name: &str) -> io::Result<()> {
+        self.depth -= 1;
+        writeln!(
+            self.writer,
+            "{:indent$}</{name}>",
+            "",
+            indent = 2 * self.depth
+        )
+    }
+
+    /// Write an element without children
+    pub fn empty(&mut self, name: &str, attributes: &[(&str, &str)]) -> io::Result<()> {
+        self.open_tag(name, attributes)?;
+        writeln!(self.writer, "/>")
+    }
+
+    /// Write an element containing only text
+    pub fn text(&mut self, name: &str, attributes: &[(&str, &str)], text: &str) -> io::Result<()> {
+        self.open_tag(name, attributes)?;
+        writeln!(self.writer, ">{}</{name}>", escape(text))
+    }
+
+    pub fn finish(mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
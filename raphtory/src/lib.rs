@@ -89,6 +89,7 @@ pub mod algorithms;
 pub mod core;
 pub mod db;
 pub mod graphgen;
+pub mod io;
 pub mod usecase_algorithms;
 
 #[cfg(feature = "python")]
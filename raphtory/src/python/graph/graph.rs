@@ -21,10 +21,11 @@ use crate::{
         api::view::internal::{DynamicGraph, IntoDynamic},
         graph::{edge::EdgeView, vertex::VertexView},
     },
-    python::graph::pandas::{
-        dataframe::{process_pandas_py_df, GraphLoadException},
-        loaders::{load_edges_props_from_df, load_vertex_props_from_df},
+    graph_loader::source::arrow_loader::loaders::{
+        load_edges_from_df, load_edges_props_from_df, load_vertex_props_from_df,
+        load_vertices_from_df,
     },
+    python::graph::pandas::dataframe::{process_pandas_py_df, GraphLoadException},
 };
 use pyo3::types::{IntoPyDict, PyBytes};
 use std::{
@@ -33,8 +34,6 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use super::pandas::loaders::{load_edges_from_df, load_vertices_from_df};
-
 /// A temporal graph.
 #[derive(Clone)]
 #[pyclass(name="Graph", extends=PyGraphView)]
@@ -126,18 +125,20 @@ impl PyGraph {
     ///    timestamp (int, str, or datetime(utc)): The timestamp of the vertex.
     ///    id (str or int): The id of the vertex.
     ///    properties (dict): The properties of the vertex (optional).
+    ///    vertex_type (str): The type of the vertex (optional), it can not be changed once it is set.
     ///
     /// Returns:
     ///   None
-    #[pyo3(signature = (timestamp, id, properties=None))]
+    #[pyo3(signature = (timestamp, id, properties=None, vertex_type=None))]
     pub fn add_vertex(
         &self,
         timestamp: PyTime,
         id: PyInputVertex,
         properties: Option<HashMap<String, Prop>>,
+        vertex_type: Option<&str>,
     ) -> Result<VertexView<Graph>, GraphError> {
         self.graph
-            .add_vertex(timestamp, id, properties.unwrap_or_default())
+            .add_vertex_with_type(timestamp, id, properties.unwrap_or_default(), vertex_type)
     }
 
     /// Adds properties to the graph.
@@ -94,18 +94,20 @@ impl PyGraphWithDeletions {
     ///    timestamp (int, str, or datetime(utc)): The timestamp of the vertex.
     ///    id (str or int): The id of the vertex.
     ///    properties (dict): The properties of the vertex.
+    ///    vertex_type (str): The type of the vertex (optional), it can not be changed once it is set.
     ///
     /// Returns:
     ///   None
-    #[pyo3(signature = (timestamp, id, properties=None))]
+    #[pyo3(signature = (timestamp, id, properties=None, vertex_type=None))]
     pub fn add_vertex(
         &self,
         timestamp: PyTime,
         id: PyInputVertex,
         properties: Option<HashMap<String, Prop>>,
+        vertex_type: Option<&str>,
     ) -> Result<VertexView<GraphWithDeletions>, GraphError> {
         self.graph
-            .add_vertex(timestamp, id, properties.unwrap_or_default())
+            .add_vertex_with_type(timestamp, id, properties.unwrap_or_default(), vertex_type)
     }
 
     /// Adds properties to the graph.
@@ -182,6 +184,18 @@ impl PyGraphWithDeletions {
         self.graph.delete_edge(timestamp, src, dst, layer)
     }
 
+    /// Deletes a vertex and all its incident edges given the timestamp and vertex id
+    ///
+    /// Arguments:
+    ///   timestamp (int): The timestamp of the deletion.
+    ///   id (str or int): The id of the vertex.
+    ///
+    /// Returns:
+    ///  None or a GraphError if the vertex could not be deleted
+    pub fn delete_vertex(&self, timestamp: PyTime, id: PyInputVertex) -> Result<(), GraphError> {
+        self.graph.delete_vertex(timestamp, id)
+    }
+
     //FIXME: This is reimplemented here to get mutable views. If we switch the underlying graph to enum dispatch, this won't be necessary!
     /// Gets the vertex with the specified id
     ///
@@ -5,7 +5,7 @@ use crate::{
     core::{
         entities::vertices::vertex_ref::VertexRef,
         utils::{errors::GraphError, time::error::ParseTimeError},
-        Prop,
+        ArcStr, Prop,
     },
     db::{
         api::{
@@ -127,6 +127,15 @@ impl PyVertex {
         self.vertex.name()
     }
 
+    /// Returns the type of the vertex.
+    ///
+    /// Returns:
+    ///     The type of the vertex as a string or None if the vertex has no type.
+    #[getter]
+    pub fn vertex_type(&self) -> Option<ArcStr> {
+        self.vertex.vertex_type()
+    }
+
     /// Returns the earliest time that the vertex exists.
     ///
     /// Returns:
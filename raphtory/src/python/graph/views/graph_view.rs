@@ -18,8 +18,8 @@ use crate::{
             edge::EdgeView,
             vertex::VertexView,
             views::{
-                layer_graph::LayeredGraph, vertex_subgraph::VertexSubgraph,
-                window_graph::WindowedGraph,
+                edge_subgraph::EdgeSubgraph, layer_graph::LayeredGraph,
+                vertex_subgraph::VertexSubgraph, window_graph::WindowedGraph,
             },
         },
     },
@@ -99,6 +99,12 @@ impl<G: GraphViewOps + IntoDynamic> IntoPy<PyObject> for VertexSubgraph<G> {
     }
 }
 
+impl<G: GraphViewOps + IntoDynamic> IntoPy<PyObject> for EdgeSubgraph<G> {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        PyGraphView::from(self).into_py(py)
+    }
+}
+
 /// The API for querying a view of the graph in a read-only state
 #[pymethods]
 impl PyGraphView {
@@ -108,6 +114,12 @@ impl PyGraphView {
         self.graph.unique_layers().collect()
     }
 
+    /// Return the types of the vertices in the graph
+    #[getter]
+    pub fn vertex_types(&self) -> Vec<ArcStr> {
+        self.graph.vertex_types()
+    }
+
     //******  Metrics APIs ******//
 
     /// Timestamp of earliest activity in the graph
@@ -394,6 +406,28 @@ impl PyGraphView {
         self.graph.subgraph(vertices)
     }
 
+    /// Returns a subgraph given a set of edges
+    ///
+    /// Arguments:
+    ///   * `edges`: set of edges as `(src, dst)` pairs
+    ///
+    /// Returns:
+    ///    GraphView - Returns the subgraph of the edges and the vertices they connect
+    fn edge_subgraph(&self, edges: Vec<(VertexRef, VertexRef)>) -> EdgeSubgraph<DynamicGraph> {
+        self.graph.edge_subgraph(edges)
+    }
+
+    /// Returns a subgraph of the vertices with the given types
+    ///
+    /// Arguments:
+    ///   * `vertex_types`: the types of the vertices to keep
+    ///
+    /// Returns:
+    ///    GraphView - Returns the subgraph
+    fn type_filter(&self, vertex_types: Vec<&str>) -> VertexSubgraph<DynamicGraph> {
+        self.graph.type_filter(vertex_types)
+    }
+
     /// Returns a graph clone
     ///
     /// Arguments:
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Implementations of various graph algorithms that can be run on a graph.
 ///
@@ -8,24 +8,63 @@ use crate::{
     algorithms::{
         algorithm_result::AlgorithmResult,
         centrality::{
-            degree_centrality::degree_centrality as degree_centrality_rs, hits::hits as hits_rs,
-            pagerank::unweighted_page_rank,
+            betweenness::{
+                betweenness_centrality as betweenness_centrality_rs,
+                edge_betweenness_centrality as edge_betweenness_centrality_rs,
+            },
+            closeness::{
+                closeness_centrality as closeness_centrality_rs,
+                harmonic_centrality as harmonic_centrality_rs,
+            },
+            degree_centrality::degree_centrality as degree_centrality_rs,
+            eigenvector::eigenvector_centrality as eigenvector_centrality_rs,
+            hits::hits as hits_rs,
+            katz::katz_centrality as katz_centrality_rs,
+            pagerank::{page_rank, WeightAggregation},
+            temporal_pagerank::{
+                temporal_page_rank as temporal_page_rank_rs,
+                temporal_page_rank_checkpoints as temporal_page_rank_checkpoints_rs,
+                Checkpoints,
+            },
+        },
+        cores::{
+            cliques::maximal_cliques as maximal_cliques_rs,
+            k_truss::{k_truss as k_truss_rs, truss_numbers as truss_numbers_rs},
+        },
+        community_detection::{
+            connected_components, leiden::leiden as leiden_rs, louvain::louvain as louvain_rs,
+            strongly_connected_components::strongly_connected_components as strongly_connected_components_rs,
+        },
+        dynamics::epidemics::epidemic as epidemic_rs,
+        link_prediction::link_scores::{
+            link_scores as link_scores_rs, top_k_links as top_k_links_rs, LinkScore,
+        },
+        metrics::assortativity::{
+            degree_assortativity as degree_assortativity_rs,
+            property_assortativity as property_assortativity_rs,
         },
-        community_detection::connected_components,
         metrics::balance::balance as balance_rs,
         metrics::degree::{
-            average_degree as average_degree_rs, max_degree as max_degree_rs,
+            average_degree as average_degree_rs, degree_distribution as degree_distribution_rs,
+            max_degree as max_degree_rs,
             max_in_degree as max_in_degree_rs, max_out_degree as max_out_degree_rs,
             min_degree as min_degree_rs, min_in_degree as min_in_degree_rs,
             min_out_degree as min_out_degree_rs,
         },
         metrics::directed_graph_density::directed_graph_density as directed_graph_density_rs,
+        metrics::eccentricity::{
+            approximate_diameter as approximate_diameter_rs, diameter as diameter_rs,
+            eccentricity as eccentricity_rs, radius as radius_rs,
+        },
         metrics::local_clustering_coefficient::local_clustering_coefficient as local_clustering_coefficient_rs,
         metrics::reciprocity::{
             all_local_reciprocity as all_local_reciprocity_rs,
             global_reciprocity as global_reciprocity_rs,
         },
         motifs::local_triangle_count::local_triangle_count as local_triangle_count_rs,
+        motifs::temporal_cycles::{
+            count_temporal_cycles as count_temporal_cycles_rs, temporal_cycles as temporal_cycles_rs,
+        },
         motifs::three_node_temporal_motifs::{
             global_temporal_three_node_motif as global_temporal_three_node_motif_rs,
             global_temporal_three_node_motif_general as global_temporal_three_node_motif_general_rs,
@@ -33,17 +72,40 @@ use crate::{
         },
         pathing::{
             dijkstra::dijkstra_single_source_shortest_paths as dijkstra_single_source_shortest_paths_rs,
+            random_walks::{random_walks as random_walks_rs, WalkType},
             single_source_shortest_path::single_source_shortest_path as single_source_shortest_path_rs,
+            temporal_paths::{temporal_paths as temporal_paths_rs, TemporalPathType},
             temporal_reachability::temporally_reachable_nodes as temporal_reachability_rs,
         },
+        sampling::{
+            forest_fire::forest_fire_sample as forest_fire_sample_rs,
+            random_sampling::{
+                random_edge_sample as random_edge_sample_rs,
+                random_vertex_sample as random_vertex_sample_rs,
+            },
+            snowball::snowball_sample as snowball_sample_rs,
+            time_stratified::time_stratified_sample as time_stratified_sample_rs,
+        },
     },
     core::entities::vertices::vertex_ref::VertexRef,
-    python::{graph::views::graph_view::PyGraphView, utils::PyInputVertex},
+    db::{
+        api::view::internal::DynamicGraph,
+        graph::views::{edge_subgraph::EdgeSubgraph, vertex_subgraph::VertexSubgraph},
+    },
+    python::{
+        graph::{graph::PyGraph, views::graph_view::PyGraphView},
+        utils::{errors::adapt_err_value, PyInputVertex},
+    },
     usecase_algorithms::netflow_one_path_vertex::netflow_one_path_vertex as netflow_one_path_vertex_rs,
 };
-use crate::{core::Prop, python::graph::edge::PyDirection};
+use crate::{
+    core::Prop,
+    db::api::view::{GraphViewOps, VertexViewOps},
+    python::graph::edge::PyDirection,
+};
 use ordered_float::OrderedFloat;
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
+use rand_distr::Exp;
 
 /// Local triangle count - calculates the number of triangles (a cycle of length 3) a vertex participates in.
 ///
@@ -81,28 +143,208 @@ pub fn weakly_connected_components(
     connected_components::weakly_connected_components(&g.graph, iter_count, None)
 }
 
+/// Strongly connected components -- partitions the graph into node sets which are mutually reachable by a directed path
+///
+/// This function assigns a component id to each vertex such that vertices with the same component id are mutually reachable
+/// by a directed path. The component id is the smallest vertex id in the component.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult object with string keys and integer values mapping vertex names to their component ids.
+#[pyfunction]
+pub fn strongly_connected_components(g: &PyGraphView) -> AlgorithmResult<String, u64> {
+    strongly_connected_components_rs(&g.graph, None)
+}
+
+fn parse_weight_aggregation(weight_aggregation: &str) -> PyResult<WeightAggregation> {
+    match weight_aggregation {
+        "sum" => Ok(WeightAggregation::Sum),
+        "latest" => Ok(WeightAggregation::Latest),
+        "count" => Ok(WeightAggregation::Count),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown weight aggregation '{weight_aggregation}', expected 'sum', 'latest' or 'count'"
+        ))),
+    }
+}
+
 /// Pagerank -- pagerank centrality value of the vertices in a graph
 ///
 /// This function calculates the Pagerank value of each vertex in a graph. See https://en.wikipedia.org/wiki/PageRank for more information on PageRank centrality.
 /// A default damping factor of 0.85 is used. This is an iterative algorithm which terminates if the sum of the absolute difference in pagerank values between iterations
 /// is less than the max diff value given.
 ///
+/// Edges can be weighted by a numeric edge property, in which case the random surfer follows edges proportionally to their weight, and
+/// teleports can be restricted to a set of seed vertices (personalized PageRank).
+///
 /// Arguments:
 ///     g (Raphtory graph) : Raphtory graph
 ///     iter_count (int) : Maximum number of iterations to run. Note that this will terminate early if convergence is reached.
 ///     max_diff (float) : Optional parameter providing an alternative stopping condition. The algorithm will terminate if the sum of the absolute difference in pagerank values between iterations
 /// is less than the max diff value given.
+///     weight (str, optional) : The name of a numeric edge property to use as edge weight. All edges have weight 1 if not provided, negative weights are treated as 0.
+///     weight_aggregation (str) : How the updates of an edge in the view are combined into its weight, one of "sum" (default), "latest" or "count".
+///     personalization (dict, optional) : Teleport probabilities of seed vertices (normalised to sum to 1). Teleports are uniform if not provided.
 ///
 /// Returns:
 ///     AlgorithmResult : AlgorithmResult with string keys and float values mapping vertex names to their pagerank value.
 #[pyfunction]
-#[pyo3(signature = (g, iter_count=20, max_diff=None))]
+#[pyo3(signature = (g, iter_count=20, max_diff=None, weight=None, weight_aggregation="sum", personalization=None))]
 pub fn pagerank(
     g: &PyGraphView,
     iter_count: usize,
     max_diff: Option<f64>,
+    weight: Option<&str>,
+    weight_aggregation: &str,
+    personalization: Option<&PyDict>,
+) -> PyResult<AlgorithmResult<String, f64, OrderedFloat<f64>>> {
+    let aggregation = parse_weight_aggregation(weight_aggregation)?;
+    let personalization = match personalization {
+        None => None,
+        Some(seeds) => {
+            let mut personalization = HashMap::new();
+            for (v, p) in seeds.iter() {
+                let v: PyInputVertex = v.extract()?;
+                if let Some(v) = g.graph.vertex(v) {
+                    personalization.insert(v.name(), p.extract::<f64>()?);
+                }
+            }
+            Some(personalization)
+        }
+    };
+    Ok(page_rank(
+        &g.graph,
+        iter_count,
+        None,
+        max_diff,
+        true,
+        weight.map(|w| (w, aggregation)),
+        personalization.as_ref(),
+    ))
+}
+
+/// Temporal PageRank -- PageRank over time-respecting paths (Rozenshtein and Gionis)
+///
+/// The exploded edges of the graph are processed in time order, such that a walk can only continue along an edge that happened after
+/// the edge it arrived by. Only time-respecting paths contribute to the scores.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     alpha (float) : The probability of following an edge instead of teleporting (damping factor), defaults to 0.85.
+///     beta (float) : The probability of a walk waiting at a vertex instead of continuing along an edge, defaults to 0.5.
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult with string keys and float values mapping vertex names to their normalised score.
+#[pyfunction]
+#[pyo3(signature = (g, alpha=0.85, beta=0.5))]
+pub fn temporal_pagerank(
+    g: &PyGraphView,
+    alpha: f64,
+    beta: f64,
 ) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
-    unweighted_page_rank(&g.graph, iter_count, None, max_diff, true)
+    temporal_page_rank_rs(&g.graph, alpha, beta)
+}
+
+/// Temporal PageRank at checkpoints -- the temporal PageRank scores at a number of times computed in one pass
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     checkpoints (list[int]) : Times at which to report the scores, each based on the edges up to and including that time.
+///     alpha (float) : The probability of following an edge instead of teleporting (damping factor), defaults to 0.85.
+///     beta (float) : The probability of a walk waiting at a vertex instead of continuing along an edge, defaults to 0.5.
+///
+/// Returns:
+///     list[(int, AlgorithmResult)] : The time and the normalised scores of all vertices for each checkpoint in ascending order.
+#[pyfunction]
+#[pyo3(signature = (g, checkpoints, alpha=0.85, beta=0.5))]
+pub fn temporal_pagerank_checkpoints(
+    g: &PyGraphView,
+    checkpoints: Vec<i64>,
+    alpha: f64,
+    beta: f64,
+) -> Checkpoints {
+    temporal_page_rank_checkpoints_rs(&g.graph, alpha, beta, &checkpoints)
+}
+
+/// Eigenvector centrality -- the centrality of a vertex is proportional to the sum of the centralities of the vertices pointing to it
+///
+/// The scores are computed by power iteration and normalised to unit L2 norm. This is an iterative algorithm which terminates if the sum of
+/// the absolute difference in scores between iterations is less than tol times the number of vertices.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     iter_count (int) : Maximum number of iterations to run. Note that this will terminate early if convergence is reached.
+///     tol (float, optional) : The tolerance for convergence, defaults to 0.000001.
+///     weight (str, optional) : The name of a numeric edge property to use as edge weight. All edges have weight 1 if not provided.
+///     weight_aggregation (str) : How the updates of an edge in the view are combined into its weight, one of "sum" (default), "latest" or "count".
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult with string keys and float values mapping vertex names to their eigenvector centrality.
+#[pyfunction]
+#[pyo3(signature = (g, iter_count=100, tol=None, weight=None, weight_aggregation="sum"))]
+pub fn eigenvector_centrality(
+    g: &PyGraphView,
+    iter_count: usize,
+    tol: Option<f64>,
+    weight: Option<&str>,
+    weight_aggregation: &str,
+) -> PyResult<AlgorithmResult<String, f64, OrderedFloat<f64>>> {
+    let aggregation = parse_weight_aggregation(weight_aggregation)?;
+    Ok(eigenvector_centrality_rs(
+        &g.graph,
+        iter_count,
+        None,
+        tol,
+        weight.map(|w| (w, aggregation)),
+    ))
+}
+
+/// Katz centrality -- the attenuated number of walks ending at each vertex
+///
+/// The centrality of a vertex is alpha times the sum of the centralities of the vertices pointing to it plus beta. The iteration only
+/// converges if alpha is smaller than the reciprocal of the largest eigenvalue of the adjacency matrix.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     alpha (float) : The attenuation factor, defaults to 0.1.
+///     beta (float) : The centrality every vertex receives regardless of its neighbours, defaults to 1.0.
+///     iter_count (int) : Maximum number of iterations to run. Note that this will terminate early if convergence is reached.
+///     tol (float, optional) : The tolerance for convergence, defaults to 0.000001.
+///     normalized (bool) : Whether to normalise the scores to unit L2 norm, defaults to True.
+///     weight (str, optional) : The name of a numeric edge property to use as edge weight. All edges have weight 1 if not provided.
+///     weight_aggregation (str) : How the updates of an edge in the view are combined into its weight, one of "sum" (default), "latest" or "count".
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult with string keys and float values mapping vertex names to their Katz centrality.
+///
+/// Raises:
+///     Exception: If the scores do not converge within iter_count iterations, e.g. because alpha is too large.
+#[pyfunction]
+#[pyo3(signature = (g, alpha=0.1, beta=1.0, iter_count=1000, tol=None, normalized=true, weight=None, weight_aggregation="sum"))]
+#[allow(clippy::too_many_arguments)]
+pub fn katz_centrality(
+    g: &PyGraphView,
+    alpha: f64,
+    beta: f64,
+    iter_count: usize,
+    tol: Option<f64>,
+    normalized: bool,
+    weight: Option<&str>,
+    weight_aggregation: &str,
+) -> PyResult<AlgorithmResult<String, f64, OrderedFloat<f64>>> {
+    let aggregation = parse_weight_aggregation(weight_aggregation)?;
+    katz_centrality_rs(
+        &g.graph,
+        alpha,
+        beta,
+        iter_count,
+        None,
+        tol,
+        normalized,
+        weight.map(|w| (w, aggregation)),
+    )
+    .map_err(|e| adapt_err_value(&e))
 }
 
 /// Temporally reachable nodes -- the nodes that are reachable by a time respecting path followed out from a set of seed nodes at a starting time.
@@ -176,6 +418,127 @@ pub fn average_degree(g: &PyGraphView) -> f64 {
     average_degree_rs(&g.graph)
 }
 
+/// The degree distribution of the graph as a histogram
+///
+/// Arguments:
+///     g (Raphtory graph) : a Raphtory graph
+///     direction (Direction) : Whether to use the out-, in- or total degree, defaults to total ("BOTH").
+///
+/// Returns:
+///     dict[int, int] : the number of vertices with each degree, degrees without vertices are omitted
+#[pyfunction]
+#[pyo3(signature = (g, direction=PyDirection::new("BOTH")))]
+pub fn degree_distribution(g: &PyGraphView, direction: PyDirection) -> BTreeMap<usize, usize> {
+    degree_distribution_rs(&g.graph, direction.into())
+}
+
+/// The eccentricity of every vertex, the largest number of hops to a vertex it can reach
+///
+/// Vertices that cannot reach each other are ignored, so the eccentricity is computed within the reachable part of the graph.
+///
+/// Arguments:
+///     g (Raphtory graph) : a Raphtory graph
+///     direction (Direction) : Which edges are followed, defaults to "BOTH" which treats the graph as undirected.
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult with string keys and integer values mapping vertex names to their eccentricity
+#[pyfunction]
+#[pyo3(signature = (g, direction=PyDirection::new("BOTH")))]
+pub fn eccentricity(g: &PyGraphView, direction: PyDirection) -> AlgorithmResult<String, u64> {
+    eccentricity_rs(&g.graph, direction.into(), None)
+}
+
+/// The diameter of the graph, the largest number of hops between two vertices connected by a path
+///
+/// Arguments:
+///     g (Raphtory graph) : a Raphtory graph
+///     direction (Direction) : Which edges are followed, defaults to "BOTH" which treats the graph as undirected.
+///
+/// Returns:
+///     int : the diameter, 0 for a graph without edges
+#[pyfunction]
+#[pyo3(signature = (g, direction=PyDirection::new("BOTH")))]
+pub fn diameter(g: &PyGraphView, direction: PyDirection) -> usize {
+    diameter_rs(&g.graph, direction.into(), None)
+}
+
+/// A lower bound of the diameter of the graph from repeated double sweeps
+///
+/// Every sweep runs a breadth-first search from a random vertex and a second one from the farthest vertex found.
+/// This is exact for trees and usually very close on real-world graphs, while only needing a few searches.
+///
+/// Arguments:
+///     g (Raphtory graph) : a Raphtory graph
+///     direction (Direction) : Which edges are followed, defaults to "BOTH" which treats the graph as undirected.
+///     sweeps (int) : The number of double sweeps, defaults to 10.
+///     seed (int, optional) : The seed of the random number generator.
+///
+/// Returns:
+///     int : a lower bound of the diameter
+#[pyfunction]
+#[pyo3(signature = (g, direction=PyDirection::new("BOTH"), sweeps=10, seed=None))]
+pub fn approximate_diameter(
+    g: &PyGraphView,
+    direction: PyDirection,
+    sweeps: usize,
+    seed: Option<u64>,
+) -> usize {
+    approximate_diameter_rs(&g.graph, direction.into(), sweeps, seed)
+}
+
+/// The radius of the graph, the smallest eccentricity of a vertex that can reach at least one other vertex
+///
+/// Arguments:
+///     g (Raphtory graph) : a Raphtory graph
+///     direction (Direction) : Which edges are followed, defaults to "BOTH" which treats the graph as undirected.
+///
+/// Returns:
+///     int : the radius, 0 for a graph without edges
+#[pyfunction]
+#[pyo3(signature = (g, direction=PyDirection::new("BOTH")))]
+pub fn radius(g: &PyGraphView, direction: PyDirection) -> usize {
+    radius_rs(&g.graph, direction.into(), None)
+}
+
+/// Degree assortativity -- the Pearson correlation of the degrees at either end of the edges
+///
+/// Positive values mean that vertices tend to connect to vertices with similar degree.
+/// If both degrees are "BOTH", the graph is treated as undirected and every edge is counted in both directions.
+///
+/// Arguments:
+///     g (Raphtory graph) : a Raphtory graph
+///     source_degree (Direction) : Which degree of the source of every edge is used, defaults to "BOTH".
+///     target_degree (Direction) : Which degree of the destination of every edge is used, defaults to "BOTH".
+///
+/// Returns:
+///     Optional[float] : the degree assortativity, None if all degrees at one end of the edges are the same
+#[pyfunction]
+#[pyo3(signature = (g, source_degree=PyDirection::new("BOTH"), target_degree=PyDirection::new("BOTH")))]
+pub fn degree_assortativity(
+    g: &PyGraphView,
+    source_degree: PyDirection,
+    target_degree: PyDirection,
+) -> Option<f64> {
+    degree_assortativity_rs(&g.graph, source_degree.into(), target_degree.into())
+}
+
+/// Property assortativity -- whether edges tend to connect vertices with the same value of a categorical property
+///
+/// Property values are compared by their string representation. Edges with an endpoint without the property are ignored.
+///
+/// Arguments:
+///     g (Raphtory graph) : a Raphtory graph
+///     property (str) : The name of the vertex property.
+///     directed (bool) : Whether edges are directed, otherwise every edge is counted in both directions. Defaults to False.
+///
+/// Returns:
+///     Optional[float] : the property assortativity, None if it is undefined
+#[pyfunction]
+#[pyo3(signature = (g, property, directed=false))]
+pub fn property_assortativity(g: &PyGraphView, property: &str, directed: bool) -> Option<f64> {
+    property_assortativity_rs(&g.graph, property, directed)
+}
+
 /// The maximum out degree of any vertex in the graph.
 ///
 /// Arguments:
@@ -392,6 +755,97 @@ pub fn hits(
     hits_rs(&g.graph, iter_count, threads)
 }
 
+/// Betweenness centrality -- the fraction of shortest paths between other vertices that pass through each vertex
+///
+/// Shortest paths follow the direction of the edges. For large graphs the betweenness can be estimated
+/// from a random sample of `k` source vertices.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     k (int, optional) : Number of source vertices to sample. All vertices are used if not provided.
+///     normalized (bool) : Divide the result by the number of pairs of other vertices (defaults to True).
+///     weight (str, optional) : The name of a numeric edge property to use as edge length. All edges have length 1 if not provided.
+///     seed (int, optional) : Seed for the sampling of source vertices.
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult with string keys and float values mapping vertex names to their betweenness.
+#[pyfunction]
+#[pyo3(signature = (g, k=None, normalized=true, weight=None, seed=None))]
+pub fn betweenness_centrality(
+    g: &PyGraphView,
+    k: Option<usize>,
+    normalized: bool,
+    weight: Option<&str>,
+    seed: Option<u64>,
+) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
+    betweenness_centrality_rs(&g.graph, k, normalized, weight, seed, None)
+}
+
+/// Edge betweenness centrality -- the fraction of shortest paths between vertices that use each edge
+///
+/// Shortest paths follow the direction of the edges. For large graphs the betweenness can be estimated
+/// from a random sample of `k` source vertices.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     k (int, optional) : Number of source vertices to sample. All vertices are used if not provided.
+///     normalized (bool) : Divide the result by the number of pairs of vertices (defaults to True).
+///     weight (str, optional) : The name of a numeric edge property to use as edge length. All edges have length 1 if not provided.
+///     seed (int, optional) : Seed for the sampling of source vertices.
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult with (source name, destination name) keys and float values mapping edges to their betweenness.
+#[pyfunction]
+#[pyo3(signature = (g, k=None, normalized=true, weight=None, seed=None))]
+pub fn edge_betweenness_centrality(
+    g: &PyGraphView,
+    k: Option<usize>,
+    normalized: bool,
+    weight: Option<&str>,
+    seed: Option<u64>,
+) -> AlgorithmResult<(String, String), f64, OrderedFloat<f64>> {
+    edge_betweenness_centrality_rs(&g.graph, k, normalized, weight, seed, None)
+}
+
+/// Closeness centrality -- the inverse of the average distance from each vertex to the vertices it can reach
+///
+/// Distances follow the direction of the edges. The result is multiplied by the fraction of the other vertices that are
+/// reachable, such that the values of vertices in different components are comparable.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     weight (str, optional) : The name of a numeric edge property to use as edge length. All edges have length 1 if not provided.
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult with string keys and float values mapping vertex names to their closeness.
+#[pyfunction]
+#[pyo3(signature = (g, weight=None))]
+pub fn closeness_centrality(
+    g: &PyGraphView,
+    weight: Option<&str>,
+) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
+    closeness_centrality_rs(&g.graph, weight, None)
+}
+
+/// Harmonic centrality -- the sum of the inverse distances from each vertex to all other vertices
+///
+/// Distances follow the direction of the edges, vertices that are not reachable do not contribute.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     weight (str, optional) : The name of a numeric edge property to use as edge length. All edges have length 1 if not provided.
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult with string keys and float values mapping vertex names to their harmonic centrality.
+#[pyfunction]
+#[pyo3(signature = (g, weight=None))]
+pub fn harmonic_centrality(
+    g: &PyGraphView,
+    weight: Option<&str>,
+) -> AlgorithmResult<String, f64, OrderedFloat<f64>> {
+    harmonic_centrality_rs(&g.graph, weight, None)
+}
+
 /// Sums the weights of edges in the graph based on the specified direction.
 ///
 /// This function computes the sum of edge weights based on the direction provided, and can be executed in parallel using a given number of threads.
@@ -514,3 +968,488 @@ pub fn dijkstra_single_source_shortest_paths(
         Err(err_msg) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(err_msg)),
     }
 }
+
+/// `(departure, arrival, [(src, dst, time)])` of each path returned by `temporal_paths`
+type PyTemporalPaths = HashMap<String, (i64, i64, Vec<(String, String, i64)>)>;
+
+/// Time-respecting paths from a single source to all vertices it can reach.
+///
+/// The exploded edges of the graph are the possible hops. The source can be left at any time, afterwards an edge can be followed
+/// if it departs after the previous edge arrived and, if `max_wait` is given, at most `max_wait` after the previous edge arrived.
+///
+/// Arguments:
+///     g (Raphtory Graph): The graph to search in.
+///     source (InputVertex): The source vertex.
+///     path_type (str): Which path to return for each vertex, one of "earliest_arrival" (default), "latest_departure", "fastest" or "shortest".
+///     delay (str, optional): The name of a numeric edge property holding the time it takes to traverse an edge. Edges are traversed instantly if not provided.
+///     max_wait (int, optional): The maximum time to wait at a vertex between two edges.
+///
+/// Returns:
+///     Returns a `Dict` where the key is the target vertex and the value is a tuple containing the departure time, the arrival time and a list of the exploded edges of the path as (src, dst, time).
+///
+#[pyfunction]
+#[pyo3(signature = (g, source, path_type="earliest_arrival", delay=None, max_wait=None))]
+pub fn temporal_paths(
+    g: &PyGraphView,
+    source: PyInputVertex,
+    path_type: &str,
+    delay: Option<&str>,
+    max_wait: Option<i64>,
+) -> PyResult<PyTemporalPaths> {
+    let path_type = match path_type {
+        "earliest_arrival" => TemporalPathType::EarliestArrival,
+        "latest_departure" => TemporalPathType::LatestDeparture,
+        "fastest" => TemporalPathType::Fastest,
+        "shortest" => TemporalPathType::Shortest,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown path type '{path_type}', expected 'earliest_arrival', 'latest_departure', 'fastest' or 'shortest'"
+            )))
+        }
+    };
+    let paths = temporal_paths_rs(&g.graph, source, path_type, delay, max_wait)
+        .map_err(|e| adapt_err_value(&e))?;
+    Ok(paths
+        .into_iter()
+        .map(|(v, path)| (v, (path.departure, path.arrival, path.edges)))
+        .collect())
+}
+
+fn parse_link_score(score: &str) -> PyResult<LinkScore> {
+    match score {
+        "common_neighbours" => Ok(LinkScore::CommonNeighbours),
+        "jaccard" => Ok(LinkScore::Jaccard),
+        "adamic_adar" => Ok(LinkScore::AdamicAdar),
+        "resource_allocation" => Ok(LinkScore::ResourceAllocation),
+        "preferential_attachment" => Ok(LinkScore::PreferentialAttachment),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown link score '{score}', expected 'common_neighbours', 'jaccard', 'adamic_adar', 'resource_allocation' or 'preferential_attachment'"
+        ))),
+    }
+}
+
+/// Link prediction scores for pairs of vertices
+///
+/// Edges are treated as undirected. If a half-life is given, neighbours are weighted by the recency of their latest interaction,
+/// such that a neighbour with a latest interaction `half_life` before the latest time of the graph counts half.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     pairs (list[(InputVertex, InputVertex)]) : The pairs of vertices to score, pairs with vertices not in the graph are skipped.
+///     score (str) : One of "common_neighbours", "jaccard" (default), "adamic_adar", "resource_allocation" or "preferential_attachment".
+///     half_life (int, optional) : Weight neighbours by the recency of their latest interaction.
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult with pairs of vertex names as keys and their score as values.
+#[pyfunction]
+#[pyo3(signature = (g, pairs, score="jaccard", half_life=None))]
+pub fn link_prediction(
+    g: &PyGraphView,
+    pairs: Vec<(PyInputVertex, PyInputVertex)>,
+    score: &str,
+    half_life: Option<i64>,
+) -> PyResult<AlgorithmResult<(String, String), f64, OrderedFloat<f64>>> {
+    Ok(link_scores_rs(
+        &g.graph,
+        pairs,
+        parse_link_score(score)?,
+        half_life,
+    ))
+}
+
+/// The `k` most likely new links of every vertex
+///
+/// Vertices that are already neighbours are not considered. For all scores except preferential attachment only vertices
+/// that share a neighbour are considered as candidates.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     score (str) : One of "common_neighbours", "jaccard" (default), "adamic_adar", "resource_allocation" or "preferential_attachment".
+///     k (int) : The number of candidates to return for each vertex, defaults to 10.
+///     half_life (int, optional) : Weight neighbours by the recency of their latest interaction.
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult with pairs of a vertex name and a candidate name as keys and their score as values.
+#[pyfunction]
+#[pyo3(signature = (g, score="jaccard", k=10, half_life=None))]
+pub fn top_k_links(
+    g: &PyGraphView,
+    score: &str,
+    k: usize,
+    half_life: Option<i64>,
+) -> PyResult<AlgorithmResult<(String, String), f64, OrderedFloat<f64>>> {
+    Ok(top_k_links_rs(
+        &g.graph,
+        parse_link_score(score)?,
+        k,
+        half_life,
+        None,
+    ))
+}
+
+/// Random walks starting from every vertex of a graph, e.g. to train vertex embeddings
+///
+/// Walks end early if there is no edge to follow. The walks only depend on the seed and not on the number of threads used.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     walk_length (int) : The maximum number of vertices in a walk, defaults to 10.
+///     walks_per_vertex (int) : The number of walks starting from each vertex, defaults to 1.
+///     walk_type (str) : One of "uniform" (default), "node2vec" or "temporal". Node2vec walks return to the previous vertex with weight 1 / p and
+///                       move further away with weight 1 / q. Temporal walks follow the exploded edges forward in time.
+///     p (float) : The node2vec return parameter, defaults to 1.0.
+///     q (float) : The node2vec in-out parameter, defaults to 1.0.
+///     direction (`PyDirection`, default = PyDirection::new("OUT")): Which edges of a vertex can be followed.
+///     weight (str, optional) : The name of a numeric edge property to choose edges proportionally to. All edges have weight 1 if not provided.
+///     seed (int, optional) : The seed of the random number generator.
+///
+/// Returns:
+///     list[list[str]] : The walks as lists of vertex names.
+#[pyfunction]
+#[pyo3(signature = (g, walk_length=10, walks_per_vertex=1, walk_type="uniform", p=1.0, q=1.0, direction=PyDirection::new("OUT"), weight=None, seed=None))]
+#[allow(clippy::too_many_arguments)]
+pub fn random_walks(
+    g: &PyGraphView,
+    walk_length: usize,
+    walks_per_vertex: usize,
+    walk_type: &str,
+    p: f64,
+    q: f64,
+    direction: PyDirection,
+    weight: Option<&str>,
+    seed: Option<u64>,
+) -> PyResult<Vec<Vec<String>>> {
+    let walk_type = match walk_type {
+        "uniform" => WalkType::Uniform,
+        "node2vec" => WalkType::Node2Vec { p, q },
+        "temporal" => WalkType::Temporal,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown walk type '{walk_type}', expected 'uniform', 'node2vec' or 'temporal'"
+            )))
+        }
+    };
+    Ok(random_walks_rs(
+        &g.graph,
+        walk_length,
+        walks_per_vertex,
+        walk_type,
+        direction.into(),
+        weight,
+        seed,
+        None,
+    ))
+}
+
+/// Stochastic SI, SIR or SEIR epidemic simulation over the contacts of a graph
+///
+/// The exploded edges of the graph are replayed in time order as contacts. A contact between an infectious and a susceptible vertex infects
+/// the susceptible vertex with probability `infection_prob`. The seeds are infected and infectious at the start time. Recovery and incubation
+/// times are exponentially distributed and rounded up to whole time units.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     seeds (list[InputVertex]) : The initially infected vertices.
+///     infection_prob (float) : The probability that a contact infects a susceptible vertex.
+///     start_time (int, optional) : The time the seeds are infected, contacts before are ignored. Defaults to the earliest time of the graph.
+///     recovery_rate (float, optional) : The rate of the exponential distribution of the time between becoming infectious and recovering. Vertices never recover if not provided.
+///     incubation_rate (float, optional) : The rate of the exponential distribution of the time between being infected and becoming infectious. Vertices are infectious immediately if not provided.
+///     directed (bool) : Whether infections only spread from the source to the destination of an edge, defaults to False.
+///     seed (int, optional) : The seed of the random number generator.
+///
+/// Returns:
+///     (dict, list) : A dict mapping every infected vertex to its (infected, infectious, recovered) times and a list of
+///                    (time, susceptible, exposed, infectious, recovered) counts after every time a state changed.
+#[pyfunction]
+#[pyo3(signature = (g, seeds, infection_prob, start_time=None, recovery_rate=None, incubation_rate=None, directed=false, seed=None))]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn epidemic(
+    g: &PyGraphView,
+    seeds: Vec<PyInputVertex>,
+    infection_prob: f64,
+    start_time: Option<i64>,
+    recovery_rate: Option<f64>,
+    incubation_rate: Option<f64>,
+    directed: bool,
+    seed: Option<u64>,
+) -> PyResult<(
+    HashMap<String, (i64, i64, Option<i64>)>,
+    Vec<(i64, usize, usize, usize, usize)>,
+)> {
+    let exp = |rate: Option<f64>| {
+        rate.map(Exp::new)
+            .transpose()
+            .map_err(|e| PyValueError::new_err(format!("Invalid rate: {e}")))
+    };
+    let result = epidemic_rs(
+        &g.graph,
+        seeds,
+        infection_prob,
+        start_time,
+        exp(recovery_rate)?,
+        exp(incubation_rate)?,
+        directed,
+        seed,
+    )
+    .map_err(|e| adapt_err_value(&e))?;
+    let infections = result
+        .infections
+        .into_iter()
+        .map(|(v, i)| (v, (i.infected, i.infectious, i.recovered)))
+        .collect();
+    let curve = result
+        .curve
+        .into_iter()
+        .map(|p| (p.time, p.susceptible, p.exposed, p.infectious, p.recovered))
+        .collect();
+    Ok((infections, curve))
+}
+
+/// Temporal cycles -- cycles where every edge happens strictly after the previous one
+///
+/// A temporal cycle is a sequence of exploded edges (v_1, v_2, t_1), (v_2, v_3, t_2), ..., (v_k, v_1, t_k) between distinct vertices
+/// with t_1 < t_2 < ... < t_k, e.g. money moving in a ring between accounts.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     max_length (int) : The maximum number of edges in a cycle, defaults to 3.
+///     max_duration (int, optional) : The maximum time between the first and the last edge of a cycle.
+///     limit (int, optional) : Stop after this many cycles were found.
+///
+/// Returns:
+///     list[list[(str, str, int)]] : The cycles as lists of exploded edges (src, dst, time) in time order.
+#[pyfunction]
+#[pyo3(signature = (g, max_length=3, max_duration=None, limit=None))]
+pub fn temporal_cycles(
+    g: &PyGraphView,
+    max_length: usize,
+    max_duration: Option<i64>,
+    limit: Option<usize>,
+) -> Vec<Vec<(String, String, i64)>> {
+    temporal_cycles_rs(&g.graph, max_length, max_duration, limit)
+}
+
+/// Counts the temporal cycles of a graph without storing them, see `temporal_cycles`
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     max_length (int) : The maximum number of edges in a cycle, defaults to 3.
+///     max_duration (int, optional) : The maximum time between the first and the last edge of a cycle.
+///     limit (int, optional) : Stop counting at this number of cycles.
+///
+/// Returns:
+///     int : The number of cycles, at most `limit`.
+#[pyfunction]
+#[pyo3(signature = (g, max_length=3, max_duration=None, limit=None))]
+pub fn count_temporal_cycles(
+    g: &PyGraphView,
+    max_length: usize,
+    max_duration: Option<i64>,
+    limit: Option<usize>,
+) -> usize {
+    count_temporal_cycles_rs(&g.graph, max_length, max_duration, limit)
+}
+
+/// Uniform random vertex sample -- the subgraph induced by vertices sampled without replacement
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     sample_size (int) : The number of vertices to sample, all vertices are kept if the graph is smaller.
+///     seed (int, optional) : The seed of the random number generator.
+///
+/// Returns:
+///     GraphView : The subgraph induced by the sampled vertices
+#[pyfunction]
+#[pyo3(signature = (g, sample_size, seed=None))]
+pub fn random_vertex_sample(
+    g: &PyGraphView,
+    sample_size: usize,
+    seed: Option<u64>,
+) -> VertexSubgraph<DynamicGraph> {
+    random_vertex_sample_rs(&g.graph, sample_size, seed)
+}
+
+/// Uniform random edge sample -- the subgraph of edges sampled without replacement and the vertices they connect
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     sample_size (int) : The number of edges to sample, all edges are kept if the graph is smaller.
+///     seed (int, optional) : The seed of the random number generator.
+///
+/// Returns:
+///     GraphView : The subgraph of the sampled edges
+#[pyfunction]
+#[pyo3(signature = (g, sample_size, seed=None))]
+pub fn random_edge_sample(
+    g: &PyGraphView,
+    sample_size: usize,
+    seed: Option<u64>,
+) -> EdgeSubgraph<DynamicGraph> {
+    random_edge_sample_rs(&g.graph, sample_size, seed)
+}
+
+/// Snowball sample -- expands a sample from the seed vertices by adding the neighbours of the newest vertices in waves
+///
+/// Edges are followed in both directions.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     seeds (list[InputVertex]) : The vertices to start from.
+///     waves (int) : The number of times the sample is expanded, defaults to 2.
+///     max_neighbours (int, optional) : The maximum number of new neighbours added for each vertex in a wave, chosen at random. All neighbours are added if not provided.
+///     seed (int, optional) : The seed of the random number generator.
+///
+/// Returns:
+///     GraphView : The subgraph induced by the sampled vertices
+#[pyfunction]
+#[pyo3(signature = (g, seeds, waves=2, max_neighbours=None, seed=None))]
+pub fn snowball_sample(
+    g: &PyGraphView,
+    seeds: Vec<PyInputVertex>,
+    waves: usize,
+    max_neighbours: Option<usize>,
+    seed: Option<u64>,
+) -> PyResult<VertexSubgraph<DynamicGraph>> {
+    snowball_sample_rs(&g.graph, seeds, waves, max_neighbours, seed)
+        .map_err(|e| adapt_err_value(&e))
+}
+
+/// Forest fire sample -- burns a geometrically distributed number of neighbours of every burning vertex
+///
+/// The fire restarts from a random vertex whenever it dies out, until enough vertices are burned. Edges are followed in both directions.
+/// See Leskovec and Faloutsos, "Sampling from large graphs" (2006).
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     sample_size (int) : The number of vertices to sample, all vertices are kept if the graph is smaller.
+///     burn_probability (float) : The forward burning probability, each vertex burns on average p / (1 - p) neighbours. Defaults to 0.7.
+///     seed (int, optional) : The seed of the random number generator.
+///
+/// Returns:
+///     GraphView : The subgraph induced by the burned vertices
+#[pyfunction]
+#[pyo3(signature = (g, sample_size, burn_probability=0.7, seed=None))]
+pub fn forest_fire_sample(
+    g: &PyGraphView,
+    sample_size: usize,
+    burn_probability: f64,
+    seed: Option<u64>,
+) -> VertexSubgraph<DynamicGraph> {
+    forest_fire_sample_rs(&g.graph, sample_size, burn_probability, seed)
+}
+
+/// Time-stratified sample of exploded edges -- samples the same fraction of updates within each time stratum
+///
+/// The time span of the graph is split into strata of equal length. As a view cannot hide single updates, the sample is returned as a new graph
+/// that contains the sampled updates with their layers and properties.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     strata (int) : The number of time intervals of equal length.
+///     fraction (float) : The fraction of the updates to sample in each stratum.
+///     seed (int, optional) : The seed of the random number generator.
+///
+/// Returns:
+///     Graph : A new graph with the sampled updates, the vertices keep their ids, names, types and constant properties and the edges their constant properties
+#[pyfunction]
+#[pyo3(signature = (g, strata, fraction, seed=None))]
+pub fn time_stratified_sample(
+    g: &PyGraphView,
+    strata: usize,
+    fraction: f64,
+    seed: Option<u64>,
+) -> PyResult<Py<PyGraph>> {
+    let sample = time_stratified_sample_rs(&g.graph, strata, fraction, seed)
+        .map_err(|e| adapt_err_value(&e))?;
+    PyGraph::py_from_db_graph(sample)
+}
+
+/// Truss numbers -- the largest k such that an edge is in the k-truss of the undirected projection of the graph
+///
+/// The k-truss is the largest subgraph in which every edge is part of at least k - 2 triangles within the subgraph.
+/// Self-loops are ignored.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///
+/// Returns:
+///     AlgorithmResult : AlgorithmResult with (str, str) keys and integer values mapping every edge (src, dst) to its truss number
+#[pyfunction]
+pub fn truss_numbers(g: &PyGraphView) -> AlgorithmResult<(String, String), u64> {
+    truss_numbers_rs(&g.graph)
+}
+
+/// k-truss -- the largest subgraph in which every edge is part of at least k - 2 triangles within the subgraph
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     k (int) : Value of k
+///
+/// Returns:
+///     GraphView : The subgraph of the edges with truss number at least k
+#[pyfunction]
+pub fn k_truss(g: &PyGraphView, k: u64) -> EdgeSubgraph<DynamicGraph> {
+    k_truss_rs(&g.graph, k)
+}
+
+/// Maximal cliques -- enumerates the maximal cliques of the undirected projection of the graph
+///
+/// Uses the Bron-Kerbosch algorithm with pivoting. Self-loops are ignored, so isolated vertices are cliques of size 1.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     min_size (int) : The minimum number of vertices of the returned cliques, defaults to 2.
+///
+/// Returns:
+///     list[list[str]] : The maximal cliques, each as a sorted list of vertex names
+#[pyfunction]
+#[pyo3(signature = (g, min_size=2))]
+pub fn maximal_cliques(g: &PyGraphView, min_size: usize) -> Vec<Vec<String>> {
+    maximal_cliques_rs(&g.graph, min_size).collect()
+}
+
+/// Louvain community detection -- partitions the graph into communities by greedily optimising modularity
+///
+/// Vertices are moved between communities while this increases modularity, after which every community is
+/// collapsed into a single vertex and the process is repeated on the collapsed graph.
+/// Edges are treated as undirected.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     weight (str, optional) : The name of a numeric edge property to use as edge weight. All edges have weight 1 if not provided.
+///     resolution (float) : The resolution parameter, higher values result in smaller communities (defaults to 1.0).
+///
+/// Returns:
+///     (AlgorithmResult, float) : AlgorithmResult with string keys and integer values mapping vertex names to their community ids, and the modularity of the communities
+#[pyfunction]
+#[pyo3(signature = (g, weight=None, resolution=1.0))]
+pub fn louvain(
+    g: &PyGraphView,
+    weight: Option<&str>,
+    resolution: f64,
+) -> (AlgorithmResult<String, u64>, f64) {
+    louvain_rs(&g.graph, weight, resolution)
+}
+
+/// Leiden community detection -- partitions the graph into connected communities by optimising modularity
+///
+/// Improves on the Louvain algorithm by refining the communities before collapsing them, which guarantees
+/// that all communities are connected.
+/// Edges are treated as undirected.
+///
+/// Arguments:
+///     g (Raphtory graph) : Raphtory graph
+///     weight (str, optional) : The name of a numeric edge property to use as edge weight. All edges have weight 1 if not provided.
+///     resolution (float) : The resolution parameter, higher values result in smaller communities (defaults to 1.0).
+///
+/// Returns:
+///     (AlgorithmResult, float) : AlgorithmResult with string keys and integer values mapping vertex names to their community ids, and the modularity of the communities
+#[pyfunction]
+#[pyo3(signature = (g, weight=None, resolution=1.0))]
+pub fn leiden(
+    g: &PyGraphView,
+    weight: Option<&str>,
+    resolution: f64,
+) -> (AlgorithmResult<String, u64>, f64) {
+    leiden_rs(&g.graph, weight, resolution)
+}
@@ -1,10 +1,14 @@
 //! Provides functionality for generating graphs for testing and benchmarking.
-//! Allows us to generate graphs using the preferential attachment model and
-//! the random attachment model.
+//! Allows us to generate graphs using the preferential attachment model, the random
+//! attachment model, random graph models and temporal models of bursty activity.
 use crate::{
     graphgen::{
+        activity_driven::activity_driven as ad, configuration_model::configuration_model as cm,
+        erdos_renyi::erdos_renyi as er, hawkes::hawkes_edges as he,
         preferential_attachment::ba_preferential_attachment as pa,
         random_attachment::random_attachment as ra,
+        stochastic_block_model::stochastic_block_model as sbm,
+        watts_strogatz::watts_strogatz as ws,
     },
     python::graph::graph::PyGraph,
 };
@@ -53,3 +57,182 @@ pub fn random_attachment(g: &PyGraph, vertices_to_add: usize, edges_per_step: us
 pub fn ba_preferential_attachment(g: &PyGraph, vertices_to_add: usize, edges_per_step: usize) {
     pa(&g.graph, vertices_to_add, edges_per_step);
 }
+
+/// Generates a graph using the Erdős–Rényi model
+///
+/// Adds new vertices and connects every pair of them independently with the same probability.
+///
+/// Arguments:
+///    g: The graph you wish to add vertices and edges to
+///    vertices_to_add: The amount of vertices you wish to add to the graph
+///    edge_probability: The probability of an edge between any two new vertices
+///    seed: The seed of the random number generator
+///
+/// Returns:
+///
+/// None
+#[pyfunction]
+#[pyo3(signature = (g, vertices_to_add, edge_probability, seed=None))]
+pub fn erdos_renyi(g: &PyGraph, vertices_to_add: usize, edge_probability: f64, seed: Option<u64>) {
+    er(&g.graph, vertices_to_add, edge_probability, seed);
+}
+
+/// Generates a graph using the Watts–Strogatz small-world model
+///
+/// Adds new vertices arranged in a ring, where every vertex is connected to its nearest
+/// neighbours, and rewires every edge to a random vertex with the given probability.
+///
+/// Arguments:
+///    g: The graph you wish to add vertices and edges to
+///    vertices_to_add: The amount of vertices you wish to add to the graph
+///    neighbours: The number of ring neighbours of each vertex, odd values are rounded down
+///    rewire_probability: The probability of rewiring each edge
+///    seed: The seed of the random number generator
+///
+/// Returns:
+///
+/// None
+#[pyfunction]
+#[pyo3(signature = (g, vertices_to_add, neighbours, rewire_probability, seed=None))]
+pub fn watts_strogatz(
+    g: &PyGraph,
+    vertices_to_add: usize,
+    neighbours: usize,
+    rewire_probability: f64,
+    seed: Option<u64>,
+) {
+    ws(
+        &g.graph,
+        vertices_to_add,
+        neighbours,
+        rewire_probability,
+        seed,
+    );
+}
+
+/// Generates a graph using the stochastic block model
+///
+/// Adds a block of new vertices for every block size and connects two new vertices with the
+/// probability given for their blocks. The block of each vertex is stored in its constant
+/// property `block`.
+///
+/// Arguments:
+///    g: The graph you wish to add vertices and edges to
+///    block_sizes: The number of vertices in each block
+///    edge_probabilities: A matrix of the probabilities of an edge between vertices of any two blocks
+///    seed: The seed of the random number generator
+///
+/// Returns:
+///
+/// None
+#[pyfunction]
+#[pyo3(signature = (g, block_sizes, edge_probabilities, seed=None))]
+pub fn stochastic_block_model(
+    g: &PyGraph,
+    block_sizes: Vec<usize>,
+    edge_probabilities: Vec<Vec<f64>>,
+    seed: Option<u64>,
+) {
+    sbm(&g.graph, &block_sizes, &edge_probabilities, seed);
+}
+
+/// Generates a graph with a given degree sequence using the configuration model
+///
+/// Adds a vertex for every degree and connects them by randomly matching edge stubs. This can
+/// produce self-loops and repeated edges.
+///
+/// Arguments:
+///    g: The graph you wish to add vertices and edges to
+///    degrees: The degree of each new vertex
+///    seed: The seed of the random number generator
+///
+/// Returns:
+///
+/// None
+#[pyfunction]
+#[pyo3(signature = (g, degrees, seed=None))]
+pub fn configuration_model(g: &PyGraph, degrees: Vec<usize>, seed: Option<u64>) {
+    cm(&g.graph, &degrees, seed);
+}
+
+/// Generates a temporal graph using the activity-driven model
+///
+/// Every new vertex gets an activity drawn from a power law. At every time step each vertex is
+/// active with probability equal to its activity and then adds edges to randomly chosen vertices.
+///
+/// Arguments:
+///    g: The graph you wish to add vertices and edges to
+///    vertices_to_add: The amount of vertices you wish to add to the graph
+///    steps: The number of time steps to simulate
+///    edges_per_activation: The number of edges an active vertex adds
+///    exponent: The exponent of the activity distribution
+///    min_activity: The smallest possible activity
+///    seed: The seed of the random number generator
+///
+/// Returns:
+///
+/// None
+#[pyfunction]
+#[pyo3(signature = (g, vertices_to_add, steps, edges_per_activation=1, exponent=2.1, min_activity=0.01, seed=None))]
+pub fn activity_driven(
+    g: &PyGraph,
+    vertices_to_add: usize,
+    steps: usize,
+    edges_per_activation: usize,
+    exponent: f64,
+    min_activity: f64,
+    seed: Option<u64>,
+) {
+    ad(
+        &g.graph,
+        vertices_to_add,
+        steps,
+        edges_per_activation,
+        exponent,
+        min_activity,
+        seed,
+    );
+}
+
+/// Generates bursty temporal edges using Hawkes processes
+///
+/// Adds new vertices and distinct random edges between them. The updates of every edge follow a
+/// self-exciting Hawkes process, which is only stable if `alpha < beta`.
+///
+/// Arguments:
+///    g: The graph you wish to add vertices and edges to
+///    vertices_to_add: The amount of vertices you wish to add to the graph
+///    edges_to_add: The amount of distinct edges
+///    duration: The length of the simulated time span
+///    mu: The base rate of updates of an edge
+///    alpha: How much each update increases the rate of further updates
+///    beta: How fast the excitation of an update decays
+///    seed: The seed of the random number generator
+///
+/// Returns:
+///
+/// None
+#[pyfunction]
+#[pyo3(signature = (g, vertices_to_add, edges_to_add, duration, mu=0.01, alpha=0.5, beta=1.0, seed=None))]
+#[allow(clippy::too_many_arguments)]
+pub fn hawkes_edges(
+    g: &PyGraph,
+    vertices_to_add: usize,
+    edges_to_add: usize,
+    duration: i64,
+    mu: f64,
+    alpha: f64,
+    beta: f64,
+    seed: Option<u64>,
+) {
+    he(
+        &g.graph,
+        vertices_to_add,
+        edges_to_add,
+        mu,
+        alpha,
+        beta,
+        duration,
+        seed,
+    );
+}
@@ -638,15 +638,24 @@ impl<G: GraphViewOps + InternalAdditionOps> InternalAdditionOps for IndexedGraph
         self.graph.next_event_id()
     }
     #[inline]
+    fn set_next_event_id(&self, id: usize) {
+        self.graph.set_next_event_id(id)
+    }
+    #[inline]
     fn resolve_layer(&self, layer: Option<&str>) -> usize {
         self.graph.resolve_layer(layer)
     }
 
     #[inline]
-    fn resolve_vertex(&self, id: u64, name: Option<&str>) -> VID {
+    fn resolve_vertex(&self, id: u64, name: Option<&str>) -> Result<VID, GraphError> {
         self.graph.resolve_vertex(id, name)
     }
 
+    #[inline]
+    fn resolve_vertex_type(&self, vertex_type: &str) -> usize {
+        self.graph.resolve_vertex_type(vertex_type)
+    }
+
     #[inline]
     fn resolve_graph_property(&self, prop: &str, is_static: bool) -> usize {
         self.graph.resolve_graph_property(prop, is_static)
@@ -723,6 +732,11 @@ impl<G: GraphViewOps + InternalAdditionOps> InternalAdditionOps for IndexedGraph
         Ok(())
     }
 
+    #[inline]
+    fn internal_set_vertex_type(&self, v: VID, vertex_type: usize) -> Result<(), GraphError> {
+        self.graph.internal_set_vertex_type(v, vertex_type)
+    }
+
     fn internal_add_edge(
         &self,
         _t: TimeIndexEntry,